@@ -38,5 +38,19 @@ fn bench_words(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_cmn, bench_words);
+#[allow(unused_results)]
+fn bench_constants(c: &mut Criterion) {
+    c.bench_function("constants::lookup", |b| {
+        b.iter(|| {
+            black_box(cmn::constants::lookup(black_box("PLANCK")))
+        })
+    });
+
+    c.bench_function("Constants::constant", |b| {
+        let constants = Constants::new();
+        b.iter(|| black_box(constants.constant(black_box("PLANCK"))))
+    });
+}
+
+criterion_group!(benches, bench_cmn, bench_words, bench_constants);
 criterion_main!(benches);
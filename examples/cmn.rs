@@ -21,7 +21,7 @@ fn main() {
     println!("🦀 Constants: ✅ {serialized}");
 
     // The JSON string to deserialize
-    static JSON: &str = r#"[{"name":"EULER","value":"2.718281828459045"},{"name":"PI","value":"3.141592653589793"},{"name":"TAU","value":"6.283185307179586"},{"name":"SQRT2","value":"1.4142135623730951"},{"name":"SQRT1_2","value":"0.7071067811865476"},{"name":"LN2","value":"0.6931471805599453"},{"name":"LN10","value":"2.302585092994046"},{"name":"LOG2E","value":"1.4426950408889634"},{"name":"LOG10E","value":"0.4342944819032518"},{"name":"PHI","value":"1.618033988749895"},{"name":"GOLDEN_RATIO","value":"1.618033988749895"},{"name":"INFINITY","value":"Infinity"},{"name":"NEG_INFINITY","value":"-Infinity"},{"name":"NAN","value":"NaN"}]"#;
+    static JSON: &str = r#"[{"name":"EULER","value":{"Float":2.718281828459045}},{"name":"PI","value":{"Float":3.141592653589793}},{"name":"TAU","value":{"Float":6.283185307179586}},{"name":"SQRT2","value":{"Float":1.4142135623730951}},{"name":"PHI","value":{"Float":1.618033988749895}},{"name":"HASH_ALGORITHM","value":{"String":"Blake3"}},{"name":"HASH_COST","value":{"U32":8}},{"name":"HASH_LENGTH","value":{"Usize":32}}]"#;
 
     // Deserialize the constants from the JSON string
     let deserialized: Vec<Constant> =
@@ -4,7 +4,7 @@
 //! Demonstrates runtime constant lookup and the CONSTANTS_TABLE.
 
 use cmn::constants::{
-    Category, ConstantValue, Constants, CONSTANTS_TABLE,
+    lookup, Category, ConstantValue, Constants, CONSTANTS_TABLE,
 };
 
 fn main() {
@@ -61,28 +61,43 @@ fn main() {
 
     println!("\n=== CONSTANTS_TABLE (no_std) ===\n");
 
-    println!("Total float constants: {}", CONSTANTS_TABLE.len());
+    println!("Total constants: {}", CONSTANTS_TABLE.len());
 
     let math: Vec<_> = CONSTANTS_TABLE
         .iter()
-        .filter(|(_, _, cat)| *cat == Category::Mathematical)
+        .filter(|entry| entry.category == Category::Mathematical)
         .collect();
     let phys: Vec<_> = CONSTANTS_TABLE
         .iter()
-        .filter(|(_, _, cat)| *cat == Category::Physical)
+        .filter(|entry| entry.category == Category::Physical)
+        .collect();
+    let crypto: Vec<_> = CONSTANTS_TABLE
+        .iter()
+        .filter(|entry| entry.category == Category::Cryptographic)
         .collect();
 
     println!("Mathematical: {}", math.len());
     println!("Physical:     {}", phys.len());
+    println!("Cryptographic: {}", crypto.len());
 
     println!("\n--- First 5 mathematical ---");
-    for (name, val, _) in &math[..5] {
-        println!("  {name:20} = {val}");
+    for entry in &math[..5] {
+        println!("  {:20} = {}", entry.name, entry.value);
     }
 
     println!("\n--- First 5 physical ---");
-    for (name, val, _) in &phys[..5] {
-        println!("  {name:20} = {val}");
+    for entry in &phys[..5] {
+        println!(
+            "  {:20} = {} {}",
+            entry.name, entry.value, entry.unit
+        );
+    }
+
+    println!("\n--- Lookup by name ---");
+    if let Some(h) = lookup("PLANCK") {
+        if let Some(value) = h.value.as_f64() {
+            println!("  {:20} = {value:e} {}", h.name, h.unit);
+        }
     }
 
     println!("\n=== Constant Validation ===\n");
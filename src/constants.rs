@@ -1,10 +1,32 @@
 // Copyright © 2023-2026 Common (CMN) library. All rights reserved.
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use crate::units::Unit;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+
+pub mod aliases;
+pub mod codata2018;
+pub mod codata2022;
+pub mod correlations;
+#[cfg(feature = "std")]
+pub mod export;
+pub mod f32;
+#[cfg(feature = "std")]
+pub mod loader;
+#[cfg(feature = "std")]
+pub mod registry;
+pub mod relations;
+#[cfg(feature = "std")]
+pub mod search;
+pub mod tags;
+#[cfg(feature = "std")]
+pub use registry::ConstantRegistry;
+pub use tags::{Tag, Tags};
 
-/// A single named constant with its string-encoded value.
+/// A single named constant with its typed value.
 /// Requires the `std` feature.
 #[cfg(feature = "std")]
 #[non_exhaustive]
@@ -20,20 +42,49 @@ use serde::{Deserialize, Serialize};
     Deserialize,
 )]
 pub struct Constant {
-    /// The name of the constant.
-    pub name: &'static str,
+    /// The name of the constant. Borrowed for built-in constants,
+    /// owned when deserialized.
+    pub name: Cow<'static, str>,
 
     /// The value of the constant.
-    pub value: String,
+    pub value: ConstantValue,
 }
 
 /// Collection of 121 mathematical, physical, and cryptographic constants.
 /// Requires the `std` feature.
+///
+/// Serializes to and deserializes from the document described by
+/// [`JSON_SCHEMA`]; `dataset` may be omitted and defaults to
+/// [`Dataset::DEFAULT`].
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::{ConstantValue, Constants};
+///
+/// let json = r#"{
+///     "constants": [
+///         { "name": "PLANCK", "value": { "Float": 6.62607015e-34 } },
+///         { "name": "MY_GAIN", "value": { "Float": 2.5 } }
+///     ]
+/// }"#;
+/// let set: Constants = serde_json::from_str(json).unwrap();
+/// assert_eq!(set.get::<f64>("MY_GAIN"), Ok(2.5));
+///
+/// let round_trip: Constants =
+///     serde_json::from_str(&serde_json::to_string(&set).unwrap())
+///         .unwrap();
+/// assert_eq!(round_trip.constants, set.constants);
+/// ```
 #[cfg(feature = "std")]
-#[derive(Clone, Serialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Constants {
     /// A vector of constants.
     pub constants: Vec<Constant>,
+
+    /// The CODATA edition the physical values are taken from.
+    #[serde(default)]
+    dataset: Dataset,
 }
 
 #[cfg(feature = "std")]
@@ -48,7 +99,7 @@ impl Constants {
     ///
     /// let constants = Constants::new();
     /// let constant = constants.constant("EULER").unwrap();
-    /// let value = constants.get_value(constant.name);
+    /// let value = constants.get_value(&constant.name);
     ///
     /// if let Some(ConstantValue::Float(float_value)) = value {
     ///     assert!((float_value - 2.71828).abs() < 1e-5);
@@ -59,47 +110,57 @@ impl Constants {
     ///
     /// ```
     pub fn get_value(&self, name: &str) -> Option<ConstantValue> {
-        if let Some(constant) = self.constant(name) {
-            match name {
-                "HASH_COST" => {
-                    constant.value.parse().map(ConstantValue::U32).ok()
-                }
-                "HASH_LENGTH" => constant
-                    .value
-                    .parse()
-                    .map(ConstantValue::Usize)
-                    .ok(),
-                _ => {
-                    if let Ok(float_value) =
-                        constant.value.parse::<f64>()
-                    {
-                        Some(ConstantValue::Float(float_value))
-                    } else if let Some(char_array) =
-                        Self::get_char_array(name)
-                    {
-                        Some(ConstantValue::CharArray(char_array))
-                    } else {
-                        Some(ConstantValue::String(
-                            constant.value.clone(),
-                        ))
-                    }
-                }
-            }
-        } else {
-            None
-        }
+        self.constants
+            .iter()
+            .find(|constant| constant.name == name)
+            .map(|constant| constant.value.clone())
     }
 
-    fn get_char_array(name: &str) -> Option<&'static [char]> {
-        match name {
-            "SPECIAL_CHARS" => Some(SPECIAL_CHARS),
-            _ => None,
-        }
+    /// Returns the value of the named constant converted to `T`.
+    ///
+    /// Fails with [`ConstantError::NotFound`] for unknown names and
+    /// with [`ConstantError::TypeMismatch`] when the stored value
+    /// has a different type. No implicit numeric conversions are
+    /// performed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::{ConstantError, Constants};
+    ///
+    /// let constants = Constants::new();
+    /// let pi: f64 = constants.get("PI").unwrap();
+    /// assert_eq!(pi, std::f64::consts::PI);
+    ///
+    /// let cost = constants.get::<u32>("HASH_COST").unwrap();
+    /// assert_eq!(cost, 8);
+    ///
+    /// assert_eq!(
+    ///     constants.get::<u32>("PI"),
+    ///     Err(ConstantError::TypeMismatch {
+    ///         expected: "u32",
+    ///         found: "f64",
+    ///     })
+    /// );
+    /// assert_eq!(
+    ///     constants.get::<f64>("NONEXISTENT"),
+    ///     Err(ConstantError::NotFound)
+    /// );
+    /// ```
+    pub fn get<T>(&self, name: &str) -> Result<T, ConstantError>
+    where
+        T: TryFrom<ConstantValue, Error = ConstantError>,
+    {
+        self.get_value(name)
+            .ok_or(ConstantError::NotFound)
+            .and_then(T::try_from)
     }
 
     /// Looks up a single constant by name.
     ///
-    /// Returns `Some(Constant)` if found, `None` otherwise.
+    /// Returns `Some(Constant)` if found, `None` otherwise. This
+    /// scans the collection and clones the match; for hot paths
+    /// over float constants prefer the allocation-free [`lookup`].
     ///
     /// # Example
     ///
@@ -119,6 +180,95 @@ impl Constants {
             .cloned()
     }
 
+    /// Looks up a constant by canonical name, conventional symbol
+    /// or common English name, and reports which alias matched.
+    ///
+    /// The query is resolved with [`aliases::resolve`], so only
+    /// names from [`CONSTANTS_TABLE`] are found; use
+    /// [`constant`](Self::constant) for other names.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::aliases::MatchKind;
+    /// use cmn::constants::Constants;
+    ///
+    /// let constants = Constants::new();
+    /// let (constant, how) = constants.resolve("k_B").unwrap();
+    /// assert_eq!(constant.name, "BOLTZMANN");
+    /// assert_eq!((how.matched, how.kind), ("k_B", MatchKind::Symbol));
+    ///
+    /// let (constant, _) = constants.resolve("Avogadro's number").unwrap();
+    /// assert_eq!(constant.name, "AVOGADRO");
+    /// ```
+    pub fn resolve(
+        &self,
+        query: &str,
+    ) -> Option<(Constant, aliases::Resolved)> {
+        let resolved = aliases::resolve(query)?;
+        self.constant(resolved.name)
+            .map(|constant| (constant, resolved))
+    }
+
+    /// Ranks constants by similarity to `query` across their names,
+    /// symbols, English names and descriptions. See
+    /// [`search::score`] for the scoring rules.
+    ///
+    /// Results are sorted best first; a score of `1.0` means the
+    /// query equals a name or alias ignoring case and separators.
+    /// Only names from [`CONSTANTS_TABLE`] are ranked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::Constants;
+    ///
+    /// let constants = Constants::new();
+    /// let hits = constants.search("electron mass");
+    /// assert_eq!(hits[0].name, "ELECTRON_MASS");
+    /// assert_eq!(hits[0].score, 1.0);
+    /// assert!(hits.iter().any(|m| m.name == "ELECTRON_MASS_MEV"));
+    /// ```
+    pub fn search(
+        &self,
+        query: &str,
+    ) -> Vec<search::SearchMatch<'static>> {
+        let entries = self
+            .constants
+            .iter()
+            .filter_map(|c| self.dataset.entry(&c.name));
+        let names = entries.clone().map(|e| (e.name, e.name));
+        let descriptions =
+            entries.map(|e| (e.name, e.info.description));
+        let aliases = aliases::SYMBOLS
+            .iter()
+            .chain(aliases::NAMES)
+            .filter(|(_, name)| self.constant(name).is_some())
+            .map(|&(alias, name)| (name, alias));
+        search::rank(query, names.chain(descriptions).chain(aliases))
+    }
+
+    /// Returns up to `limit` constant names closest to `query`,
+    /// for "did you mean" hints when [`Constants::constant`]
+    /// returns `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::Constants;
+    ///
+    /// let constants = Constants::new();
+    /// assert!(constants.constant("PLANK").is_none());
+    /// assert_eq!(constants.suggest("PLANK", 1), ["PLANCK"]);
+    /// ```
+    pub fn suggest(&self, query: &str, limit: usize) -> Vec<&str> {
+        self.search(query)
+            .into_iter()
+            .take(limit)
+            .map(|m| m.name)
+            .collect()
+    }
+
     /// Returns a slice of all 121 constants.
     ///
     /// # Example
@@ -146,518 +296,273 @@ impl Constants {
     /// ```
     ///
     pub fn new() -> Self {
-        let mut constants = Vec::with_capacity(121);
-        constants.extend([
-            Constant {
-                name: "APERY",
-                value: APERY.to_string(),
-            },
-            Constant {
-                name: "AVOGADRO",
-                value: AVOGADRO.to_string(),
-            },
-            Constant {
-                name: "BOLTZMANN",
-                value: BOLTZMANN.to_string(),
-            },
-            Constant {
-                name: "CATALAN",
-                value: CATALAN.to_string(),
-            },
-            Constant {
-                name: "COULOMB",
-                value: COULOMB.to_string(),
-            },
-            Constant {
-                name: "EULER",
-                value: EULER.to_string(),
-            },
-            Constant {
-                name: "FARADAY",
-                value: FARADAY.to_string(),
-            },
-            Constant {
-                name: "GAMMA",
-                value: GAMMA.to_string(),
-            },
-            Constant {
-                name: "GAS_CONSTANT",
-                value: GAS_CONSTANT.to_string(),
-            },
-            Constant {
-                name: "GLAISHER_KINKELIN",
-                value: GLAISHER_KINKELIN.to_string(),
-            },
-            Constant {
-                name: "GRAVITATIONAL_CONSTANT",
-                value: GRAVITATIONAL_CONSTANT.to_string(),
-            },
-            Constant {
-                name: "HASH_ALGORITHM",
-                value: HASH_ALGORITHM.to_string(),
-            },
-            Constant {
-                name: "HASH_COST",
-                value: HASH_COST.to_string(),
-            },
-            Constant {
-                name: "HASH_LENGTH",
-                value: HASH_LENGTH.to_string(),
-            },
-            Constant {
-                name: "KHINCHIN",
-                value: KHINCHIN.to_string(),
-            },
-            Constant {
-                name: "PHI",
-                value: PHI.to_string(),
-            },
-            Constant {
-                name: "PI",
-                value: PI.to_string(),
-            },
-            Constant {
-                name: "PLANCK",
-                value: PLANCK.to_string(),
-            },
-            Constant {
-                name: "PLANCK_REDUCED",
-                value: PLANCK_REDUCED.to_string(),
-            },
-            Constant {
-                name: "SILVER_RATIO",
-                value: SILVER_RATIO.to_string(),
-            },
-            Constant {
-                name: "SPEED_OF_LIGHT",
-                value: SPEED_OF_LIGHT.to_string(),
-            },
-            Constant {
-                name: "SPECIAL_CHARS",
-                value: SPECIAL_CHARS.iter().collect::<String>(),
-            },
-            Constant {
-                name: "SQRT2",
-                value: SQRT2.to_string(),
-            },
-            Constant {
-                name: "SQRT3",
-                value: SQRT3.to_string(),
-            },
-            Constant {
-                name: "SQRT5",
-                value: SQRT5.to_string(),
-            },
-            Constant {
-                name: "TAU",
-                value: TAU.to_string(),
-            },
-            Constant {
-                name: "VACUUM_PERMEABILITY",
-                value: VACUUM_PERMEABILITY.to_string(),
-            },
-            Constant {
-                name: "VACUUM_PERMITTIVITY",
-                value: VACUUM_PERMITTIVITY.to_string(),
-            },
-            // --- std::f64::consts math constants ---
-            Constant {
-                name: "LN_2",
-                value: LN_2.to_string(),
-            },
-            Constant {
-                name: "LN_10",
-                value: LN_10.to_string(),
-            },
-            Constant {
-                name: "LOG2_E",
-                value: LOG2_E.to_string(),
-            },
-            Constant {
-                name: "LOG10_E",
-                value: LOG10_E.to_string(),
-            },
-            Constant {
-                name: "FRAC_1_SQRT_2",
-                value: FRAC_1_SQRT_2.to_string(),
-            },
-            Constant {
-                name: "FRAC_1_PI",
-                value: FRAC_1_PI.to_string(),
-            },
-            Constant {
-                name: "FRAC_2_PI",
-                value: FRAC_2_PI.to_string(),
-            },
-            Constant {
-                name: "FRAC_2_SQRT_PI",
-                value: FRAC_2_SQRT_PI.to_string(),
-            },
-            Constant {
-                name: "FRAC_PI_2",
-                value: FRAC_PI_2.to_string(),
-            },
-            Constant {
-                name: "FRAC_PI_3",
-                value: FRAC_PI_3.to_string(),
-            },
-            Constant {
-                name: "FRAC_PI_4",
-                value: FRAC_PI_4.to_string(),
-            },
-            Constant {
-                name: "FRAC_PI_6",
-                value: FRAC_PI_6.to_string(),
-            },
-            Constant {
-                name: "FRAC_PI_8",
-                value: FRAC_PI_8.to_string(),
-            },
-            // --- CODATA 2018 physical constants ---
-            Constant {
-                name: "ELEMENTARY_CHARGE",
-                value: ELEMENTARY_CHARGE.to_string(),
-            },
-            Constant {
-                name: "ELECTRON_MASS",
-                value: ELECTRON_MASS.to_string(),
-            },
-            Constant {
-                name: "PROTON_MASS",
-                value: PROTON_MASS.to_string(),
-            },
-            Constant {
-                name: "NEUTRON_MASS",
-                value: NEUTRON_MASS.to_string(),
-            },
-            Constant {
-                name: "STEFAN_BOLTZMANN",
-                value: STEFAN_BOLTZMANN.to_string(),
-            },
-            Constant {
-                name: "WIEN_DISPLACEMENT",
-                value: WIEN_DISPLACEMENT.to_string(),
-            },
-            Constant {
-                name: "STANDARD_GRAVITY",
-                value: STANDARD_GRAVITY.to_string(),
-            },
-            Constant {
-                name: "STANDARD_ATMOSPHERE",
-                value: STANDARD_ATMOSPHERE.to_string(),
-            },
-            Constant {
-                name: "ATOMIC_MASS_UNIT",
-                value: ATOMIC_MASS_UNIT.to_string(),
-            },
-            Constant {
-                name: "BOHR_RADIUS",
-                value: BOHR_RADIUS.to_string(),
-            },
-            Constant {
-                name: "FINE_STRUCTURE",
-                value: FINE_STRUCTURE.to_string(),
-            },
-            Constant {
-                name: "RYDBERG",
-                value: RYDBERG.to_string(),
-            },
-            Constant {
-                name: "MAGNETIC_FLUX_QUANTUM",
-                value: MAGNETIC_FLUX_QUANTUM.to_string(),
-            },
-            Constant {
-                name: "CONDUCTANCE_QUANTUM",
-                value: CONDUCTANCE_QUANTUM.to_string(),
-            },
-            // --- Particle masses ---
-            Constant {
-                name: "MUON_MASS",
-                value: MUON_MASS.to_string(),
-            },
-            Constant {
-                name: "TAU_PARTICLE_MASS",
-                value: TAU_PARTICLE_MASS.to_string(),
-            },
-            Constant {
-                name: "DEUTERON_MASS",
-                value: DEUTERON_MASS.to_string(),
-            },
-            Constant {
-                name: "TRITON_MASS",
-                value: TRITON_MASS.to_string(),
-            },
-            Constant {
-                name: "HELION_MASS",
-                value: HELION_MASS.to_string(),
-            },
-            Constant {
-                name: "ALPHA_PARTICLE_MASS",
-                value: ALPHA_PARTICLE_MASS.to_string(),
-            },
-            // --- Mass ratios ---
-            Constant {
-                name: "ELECTRON_PROTON_MASS_RATIO",
-                value: ELECTRON_PROTON_MASS_RATIO.to_string(),
-            },
-            Constant {
-                name: "PROTON_ELECTRON_MASS_RATIO",
-                value: PROTON_ELECTRON_MASS_RATIO.to_string(),
-            },
-            Constant {
-                name: "MUON_ELECTRON_MASS_RATIO",
-                value: MUON_ELECTRON_MASS_RATIO.to_string(),
-            },
-            Constant {
-                name: "NEUTRON_PROTON_MASS_RATIO",
-                value: NEUTRON_PROTON_MASS_RATIO.to_string(),
-            },
-            Constant {
-                name: "DEUTERON_PROTON_MASS_RATIO",
-                value: DEUTERON_PROTON_MASS_RATIO.to_string(),
-            },
-            // --- Magnetic moments ---
-            Constant {
-                name: "BOHR_MAGNETON",
-                value: BOHR_MAGNETON.to_string(),
-            },
-            Constant {
-                name: "NUCLEAR_MAGNETON",
-                value: NUCLEAR_MAGNETON.to_string(),
-            },
-            Constant {
-                name: "ELECTRON_MAGNETIC_MOMENT",
-                value: ELECTRON_MAGNETIC_MOMENT.to_string(),
-            },
-            Constant {
-                name: "PROTON_MAGNETIC_MOMENT",
-                value: PROTON_MAGNETIC_MOMENT.to_string(),
-            },
-            Constant {
-                name: "NEUTRON_MAGNETIC_MOMENT",
-                value: NEUTRON_MAGNETIC_MOMENT.to_string(),
-            },
-            Constant {
-                name: "ELECTRON_G_FACTOR",
-                value: ELECTRON_G_FACTOR.to_string(),
-            },
-            Constant {
-                name: "PROTON_G_FACTOR",
-                value: PROTON_G_FACTOR.to_string(),
-            },
-            // --- eV equivalents ---
-            Constant {
-                name: "ELECTRON_VOLT",
-                value: ELECTRON_VOLT.to_string(),
-            },
-            Constant {
-                name: "EV_TO_KG",
-                value: EV_TO_KG.to_string(),
-            },
-            Constant {
-                name: "EV_TO_AMU",
-                value: EV_TO_AMU.to_string(),
-            },
-            Constant {
-                name: "EV_TO_HZ",
-                value: EV_TO_HZ.to_string(),
-            },
-            Constant {
-                name: "EV_TO_KELVIN",
-                value: EV_TO_KELVIN.to_string(),
-            },
-            Constant {
-                name: "EV_TO_INVERSE_METER",
-                value: EV_TO_INVERSE_METER.to_string(),
-            },
-            // --- Atomic & nuclear ---
-            Constant {
-                name: "CLASSICAL_ELECTRON_RADIUS",
-                value: CLASSICAL_ELECTRON_RADIUS.to_string(),
-            },
-            Constant {
-                name: "ELECTRON_COMPTON_WAVELENGTH",
-                value: ELECTRON_COMPTON_WAVELENGTH.to_string(),
-            },
-            Constant {
-                name: "PROTON_COMPTON_WAVELENGTH",
-                value: PROTON_COMPTON_WAVELENGTH.to_string(),
-            },
-            Constant {
-                name: "NEUTRON_COMPTON_WAVELENGTH",
-                value: NEUTRON_COMPTON_WAVELENGTH.to_string(),
-            },
-            Constant {
-                name: "THOMSON_CROSS_SECTION",
-                value: THOMSON_CROSS_SECTION.to_string(),
-            },
-            Constant {
-                name: "FIRST_RADIATION_CONSTANT",
-                value: FIRST_RADIATION_CONSTANT.to_string(),
-            },
-            Constant {
-                name: "SECOND_RADIATION_CONSTANT",
-                value: SECOND_RADIATION_CONSTANT.to_string(),
-            },
-            Constant {
-                name: "JOSEPHSON_CONSTANT",
-                value: JOSEPHSON_CONSTANT.to_string(),
-            },
-            Constant {
-                name: "VON_KLITZING_CONSTANT",
-                value: VON_KLITZING_CONSTANT.to_string(),
-            },
-            Constant {
-                name: "HARTREE_ENERGY",
-                value: HARTREE_ENERGY.to_string(),
-            },
-            Constant {
-                name: "HARTREE_ENERGY_EV",
-                value: HARTREE_ENERGY_EV.to_string(),
-            },
-            // --- Planck units ---
-            Constant {
-                name: "PLANCK_MASS",
-                value: PLANCK_MASS.to_string(),
-            },
-            Constant {
-                name: "PLANCK_LENGTH",
-                value: PLANCK_LENGTH.to_string(),
-            },
-            Constant {
-                name: "PLANCK_TIME",
-                value: PLANCK_TIME.to_string(),
-            },
-            Constant {
-                name: "PLANCK_TEMPERATURE",
-                value: PLANCK_TEMPERATURE.to_string(),
-            },
-            Constant {
-                name: "PLANCK_CHARGE",
-                value: PLANCK_CHARGE.to_string(),
-            },
-            // --- Molar & thermodynamic ---
-            Constant {
-                name: "MOLAR_MASS_CONSTANT",
-                value: MOLAR_MASS_CONSTANT.to_string(),
-            },
-            Constant {
-                name: "MOLAR_PLANCK_CONSTANT",
-                value: MOLAR_PLANCK_CONSTANT.to_string(),
-            },
-            Constant {
-                name: "LOSCHMIDT_CONSTANT",
-                value: LOSCHMIDT_CONSTANT.to_string(),
-            },
-            Constant {
-                name: "MOLAR_VOLUME_IDEAL_GAS",
-                value: MOLAR_VOLUME_IDEAL_GAS.to_string(),
-            },
-            Constant {
-                name: "SACKUR_TETRODE_CONSTANT",
-                value: SACKUR_TETRODE_CONSTANT.to_string(),
-            },
-            // --- Electromagnetic additional ---
-            Constant {
-                name: "IMPEDANCE_OF_FREE_SPACE",
-                value: IMPEDANCE_OF_FREE_SPACE.to_string(),
-            },
-            Constant {
-                name: "INVERSE_FINE_STRUCTURE",
-                value: INVERSE_FINE_STRUCTURE.to_string(),
-            },
-            Constant {
-                name: "ELECTRON_CHARGE_TO_MASS",
-                value: ELECTRON_CHARGE_TO_MASS.to_string(),
-            },
-            Constant {
-                name: "PROTON_CHARGE_TO_MASS",
-                value: PROTON_CHARGE_TO_MASS.to_string(),
-            },
-            // --- Atomic units ---
-            Constant {
-                name: "ATOMIC_UNIT_OF_LENGTH",
-                value: ATOMIC_UNIT_OF_LENGTH.to_string(),
-            },
-            Constant {
-                name: "ATOMIC_UNIT_OF_TIME",
-                value: ATOMIC_UNIT_OF_TIME.to_string(),
-            },
-            Constant {
-                name: "ATOMIC_UNIT_OF_VELOCITY",
-                value: ATOMIC_UNIT_OF_VELOCITY.to_string(),
-            },
-            Constant {
-                name: "ATOMIC_UNIT_OF_FORCE",
-                value: ATOMIC_UNIT_OF_FORCE.to_string(),
-            },
-            Constant {
-                name: "ATOMIC_UNIT_OF_ELECTRIC_FIELD",
-                value: ATOMIC_UNIT_OF_ELECTRIC_FIELD.to_string(),
-            },
-            Constant {
-                name: "ATOMIC_UNIT_OF_POLARIZABILITY",
-                value: ATOMIC_UNIT_OF_POLARIZABILITY.to_string(),
-            },
-            // --- Boson masses & MeV forms ---
-            Constant {
-                name: "W_BOSON_MASS_GEV",
-                value: W_BOSON_MASS_GEV.to_string(),
-            },
-            Constant {
-                name: "Z_BOSON_MASS_GEV",
-                value: Z_BOSON_MASS_GEV.to_string(),
-            },
-            Constant {
-                name: "HIGGS_BOSON_MASS_GEV",
-                value: HIGGS_BOSON_MASS_GEV.to_string(),
-            },
-            Constant {
-                name: "ELECTRON_MASS_MEV",
-                value: ELECTRON_MASS_MEV.to_string(),
-            },
-            Constant {
-                name: "PROTON_MASS_MEV",
-                value: PROTON_MASS_MEV.to_string(),
-            },
-            Constant {
-                name: "NEUTRON_MASS_MEV",
-                value: NEUTRON_MASS_MEV.to_string(),
-            },
-            Constant {
-                name: "MUON_MASS_MEV",
-                value: MUON_MASS_MEV.to_string(),
-            },
-            // --- Reduced Compton wavelengths ---
-            Constant {
-                name: "ELECTRON_REDUCED_COMPTON",
-                value: ELECTRON_REDUCED_COMPTON.to_string(),
-            },
-            Constant {
-                name: "PROTON_REDUCED_COMPTON",
-                value: PROTON_REDUCED_COMPTON.to_string(),
-            },
-            Constant {
-                name: "NEUTRON_REDUCED_COMPTON",
-                value: NEUTRON_REDUCED_COMPTON.to_string(),
-            },
-            // --- Gas constant variant ---
-            Constant {
-                name: "GAS_CONSTANT_L_ATM",
-                value: GAS_CONSTANT_L_ATM.to_string(),
-            },
-        ]);
+        Self::with_dataset(Dataset::DEFAULT)
+    }
+
+    /// Creates a new instance whose physical constants resolve
+    /// against the given CODATA edition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::{codata2022, ConstantValue, Constants, Dataset};
+    ///
+    /// let constants = Constants::with_dataset(Dataset::Codata2022);
+    /// assert_eq!(constants.dataset(), Dataset::Codata2022);
+    ///
+    /// let value = constants.get_value("ELECTRON_MASS");
+    /// assert!(matches!(
+    ///     value,
+    ///     Some(ConstantValue::Float(v)) if v == codata2022::ELECTRON_MASS
+    /// ));
+    /// ```
+    pub fn with_dataset(dataset: Dataset) -> Self {
+        let constants = dataset
+            .table()
+            .iter()
+            .map(|entry| Constant {
+                name: Cow::Borrowed(entry.name),
+                value: entry.value.into(),
+            })
+            .collect();
+        Self { constants, dataset }
+    }
+
+    /// Returns the CODATA edition this instance resolves against.
+    pub fn dataset(&self) -> Dataset {
+        self.dataset
+    }
+
+    /// Returns the unit of the named constant, with its SI
+    /// dimension.
+    ///
+    /// Returns `None` for unknown names and for constants that
+    /// are not physical quantities (e.g. `HASH_ALGORITHM`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::Constants;
+    ///
+    /// let constants = Constants::new();
+    /// let unit = constants.unit("BOLTZMANN").unwrap();
+    /// assert_eq!(unit.symbol, "J K^-1");
+    /// assert!(constants.unit("PI").unwrap().dimension.is_dimensionless());
+    /// ```
+    pub fn unit(&self, name: &str) -> Option<Unit> {
+        self.dataset
+            .entry(name)
+            .filter(|entry| entry.value.is_float())
+            .map(|entry| entry.unit)
+    }
+
+    /// Returns the absolute and relative standard uncertainty of
+    /// the named constant in this instance's dataset. See
+    /// [`uncertainty`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::Constants;
+    ///
+    /// let constants = Constants::new();
+    /// let g = constants.uncertainty("GRAVITATIONAL_CONSTANT").unwrap();
+    /// assert!((g.relative - 2.2e-5).abs() < 1e-6);
+    /// assert!(constants.uncertainty("SPEED_OF_LIGHT").unwrap().exact);
+    /// ```
+    pub fn uncertainty(
+        &self,
+        name: &str,
+    ) -> Option<StandardUncertainty> {
+        self.dataset
+            .entry(name)
+            .filter(|entry| entry.value.is_float())
+            .map(ConstantEntry::standard_uncertainty)
+    }
+
+    /// Returns the description, symbols, formula and source of the
+    /// named constant in this instance's dataset. See [`info`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::{Constants, Dataset, Source};
+    ///
+    /// let constants = Constants::with_dataset(Dataset::Codata2022);
+    /// let m_e = constants.info("ELECTRON_MASS").unwrap();
+    /// assert_eq!(m_e.description, "Electron mass");
+    /// assert_eq!(m_e.source, Source::Codata2022);
+    /// ```
+    pub fn info(&self, name: &str) -> Option<&'static ConstantInfo> {
+        self.dataset.info(name)
+    }
+
+    /// Returns the category of the named constant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::{Category, Constants};
+    ///
+    /// let constants = Constants::new();
+    /// assert_eq!(constants.category("PI"), Some(Category::Mathematical));
+    /// assert_eq!(
+    ///     constants.category("HASH_COST"),
+    ///     Some(Category::Cryptographic),
+    /// );
+    /// ```
+    pub fn category(&self, name: &str) -> Option<Category> {
+        self.dataset.category(name)
+    }
+
+    /// Returns the tags of the named constant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::{Constants, Tag};
+    ///
+    /// let tags = Constants::new().tags("MUON_MASS").unwrap();
+    /// assert!(tags.contains(Tag::ParticleMass));
+    /// ```
+    pub fn tags(&self, name: &str) -> Option<Tags> {
+        self.dataset.tags(name)
+    }
+
+    /// Iterates over the constants carrying `tag`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::{Constants, Tag};
+    ///
+    /// let constants = Constants::new();
+    /// let masses: Vec<_> = constants
+    ///     .by_tag(Tag::ParticleMass)
+    ///     .map(|c| &*c.name)
+    ///     .collect();
+    /// assert!(masses.contains(&"ELECTRON_MASS"));
+    /// assert!(masses.contains(&"HIGGS_BOSON_MASS_GEV"));
+    /// ```
+    pub fn by_tag(&self, tag: Tag) -> impl Iterator<Item = &Constant> {
+        self.constants.iter().filter(move |c| {
+            self.tags(&c.name).is_some_and(|tags| tags.contains(tag))
+        })
+    }
 
-        Self { constants }
+    /// Iterates over the constants in `category`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::{Category, Constants};
+    ///
+    /// let constants = Constants::new();
+    /// assert_eq!(constants.by_category(Category::Cryptographic).count(), 4);
+    /// ```
+    pub fn by_category(
+        &self,
+        category: Category,
+    ) -> impl Iterator<Item = &Constant> {
+        self.constants
+            .iter()
+            .filter(move |c| self.category(&c.name) == Some(category))
+    }
+
+    /// Appends the constants defined in `text`; see [`loader`] for
+    /// the accepted formats and fields.
+    ///
+    /// Only names and values are kept; load into a
+    /// [`ConstantRegistry`] to keep units, uncertainties and
+    /// categories too. Fails without changing the collection if any
+    /// definition is invalid or its name is already present.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::loader::Format;
+    /// use cmn::constants::Constants;
+    ///
+    /// let mut constants = Constants::new();
+    /// let json = r#"{
+    ///     "constants": [{ "name": "LAB_GAIN", "value": 2.5 }]
+    /// }"#;
+    /// constants.load(Format::Json, json).unwrap();
+    /// assert_eq!(constants.get::<f64>("LAB_GAIN"), Ok(2.5));
+    ///
+    /// let error = constants.load(Format::Json, json).unwrap_err();
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "line 2: duplicate constant `LAB_GAIN`"
+    /// );
+    /// ```
+    pub fn load(
+        &mut self,
+        format: loader::Format,
+        text: &str,
+    ) -> Result<(), loader::LoadError> {
+        let definitions = loader::parse(format, text, &|name| {
+            self.constants.iter().any(|c| c.name == name)
+        })?;
+        self.constants.extend(definitions.into_iter().map(|d| {
+            Constant {
+                name: d.name,
+                value: d.value,
+            }
+        }));
+        Ok(())
     }
 
     /// Returns `true` if the `Constants` structure is valid.
     /// Otherwise, returns `false`.
     pub fn is_valid(&self) -> bool {
         self.constants().iter().all(|constant| {
-            !constant.name.is_empty() && !constant.value.is_empty()
+            !constant.name.is_empty()
+                && !constant.value.to_string().is_empty()
         })
     }
+
+    /// Checks every identity in [`relations::RELATIONS`] against the
+    /// values held by this collection.
+    ///
+    /// Relations whose constants are missing are skipped. Returns
+    /// every relation that does not hold within its tolerance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::{ConstantValue, Constants};
+    ///
+    /// let mut constants = Constants::new();
+    /// assert!(constants.verify_relations().is_ok());
+    ///
+    /// // Transposed digits in a hand-entered literal.
+    /// let ev = constants
+    ///     .constants
+    ///     .iter_mut()
+    ///     .find(|c| c.name == "EV_TO_KG")
+    ///     .unwrap();
+    /// ev.value = ConstantValue::Float(1.782_661_291e-36);
+    ///
+    /// let errors = constants.verify_relations().unwrap_err();
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(errors[0].relation.formula, "1 eV/c² = e/c²");
+    /// ```
+    pub fn verify_relations(
+        &self,
+    ) -> Result<(), Vec<relations::Inconsistency>> {
+        let errors: Vec<_> = relations::RELATIONS
+            .iter()
+            .filter_map(|relation| {
+                relation.check(|name| self.get(name).ok())?.err()
+            })
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -667,10 +572,31 @@ impl Default for Constants {
     }
 }
 
+/// JSON Schema (draft 2020-12) of the document [`Constants`]
+/// serializes to and deserializes from. Requires the `std` feature.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::JSON_SCHEMA;
+///
+/// let schema: serde_json::Value =
+///     serde_json::from_str(JSON_SCHEMA).unwrap();
+/// assert_eq!(schema["required"][0], "constants");
+/// ```
+#[cfg(feature = "std")]
+pub const JSON_SCHEMA: &str =
+    include_str!("constants/constants.schema.json");
+
 /// Enum to represent the different constant values.
 /// Requires the `std` feature.
+///
+/// Equality, ordering and hashing are total: floats compare by
+/// [`f64::total_cmp`], and values of different variants order by
+/// variant. `CharArray` values deserialize only when they equal a
+/// built-in character set such as [`SPECIAL_CHARS`].
 #[cfg(feature = "std")]
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ConstantValue {
     /// A float value represented as `f64`.
     Float(f64),
@@ -682,21 +608,337 @@ pub enum ConstantValue {
     /// as `usize`.
     Usize(usize),
     /// An array of characters represented as `&'static [char]`.
+    #[serde(deserialize_with = "static_chars")]
     CharArray(&'static [char]),
 }
 
-#[cfg(feature = "std")]
-impl core::fmt::Display for ConstantValue {
+/// Deserializes a character array into the built-in `&'static
+/// [char]` constant with the same characters.
+#[cfg(feature = "std")]
+fn static_chars<'de, D>(
+    deserializer: D,
+) -> Result<&'static [char], D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let chars = Vec::<char>::deserialize(deserializer)?;
+    CONSTANTS_TABLE
+        .iter()
+        .find_map(|entry| match entry.value {
+            ConstValue::Chars(known) if known == chars.as_slice() => {
+                Some(known)
+            }
+            _ => None,
+        })
+        .ok_or_else(|| {
+            serde::de::Error::custom(
+                "only built-in character sets can be deserialized",
+            )
+        })
+}
+
+#[cfg(feature = "std")]
+impl ConstantValue {
+    /// Returns the Rust type name of the stored value, e.g.
+    /// `"f64"` or `"&[char]"`.
+    pub const fn type_name(&self) -> &'static str {
+        match self {
+            Self::Float(_) => "f64",
+            Self::String(_) => "String",
+            Self::U32(_) => "u32",
+            Self::Usize(_) => "usize",
+            Self::CharArray(_) => "&[char]",
+        }
+    }
+
+    const fn rank(&self) -> u8 {
+        match self {
+            Self::Float(_) => 0,
+            Self::String(_) => 1,
+            Self::U32(_) => 2,
+            Self::Usize(_) => 3,
+            Self::CharArray(_) => 4,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl PartialEq for ConstantValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == core::cmp::Ordering::Equal
+    }
+}
+
+#[cfg(feature = "std")]
+impl Eq for ConstantValue {}
+
+#[cfg(feature = "std")]
+impl PartialOrd for ConstantValue {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Ord for ConstantValue {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        match (self, other) {
+            (Self::Float(a), Self::Float(b)) => a.total_cmp(b),
+            (Self::String(a), Self::String(b)) => a.cmp(b),
+            (Self::U32(a), Self::U32(b)) => a.cmp(b),
+            (Self::Usize(a), Self::Usize(b)) => a.cmp(b),
+            (Self::CharArray(a), Self::CharArray(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::hash::Hash for ConstantValue {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.rank().hash(state);
+        match self {
+            Self::Float(v) => v.to_bits().hash(state),
+            Self::String(v) => v.hash(state),
+            Self::U32(v) => v.hash(state),
+            Self::Usize(v) => v.hash(state),
+            Self::CharArray(v) => v.hash(state),
+        }
+    }
+}
+
+macro_rules! impl_constant_value_conversions {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            #[cfg(feature = "std")]
+            impl From<$ty> for ConstantValue {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value)
+                }
+            }
+
+            #[cfg(feature = "std")]
+            impl TryFrom<ConstantValue> for $ty {
+                type Error = ConstantError;
+
+                fn try_from(
+                    value: ConstantValue,
+                ) -> Result<Self, Self::Error> {
+                    match value {
+                        ConstantValue::$variant(v) => Ok(v),
+                        other => Err(ConstantError::TypeMismatch {
+                            expected: stringify!($ty),
+                            found: other.type_name(),
+                        }),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_constant_value_conversions! {
+    f64 => Float,
+    String => String,
+    u32 => U32,
+    usize => Usize,
+    &'static [char] => CharArray,
+}
+
+/// Errors returned by [`Constants::get`].
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConstantError {
+    /// No constant with the given name exists.
+    NotFound,
+    /// The constant exists but holds a different type.
+    TypeMismatch {
+        /// The requested type.
+        expected: &'static str,
+        /// The type of the stored value.
+        found: &'static str,
+    },
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for ConstantError {
+    fn fmt(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "unknown constant"),
+            Self::TypeMismatch { expected, found } => {
+                write!(
+                    f,
+                    "type mismatch: expected {expected}, found {found}"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConstantError {}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for ConstantValue {
+    fn fmt(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        match self {
+            Self::Float(v) => write!(f, "{v}"),
+            Self::String(v) => write!(f, "{v}"),
+            Self::U32(v) => write!(f, "{v}"),
+            Self::Usize(v) => write!(f, "{v}"),
+            Self::CharArray(v) => {
+                for ch in *v {
+                    write!(f, "{ch}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Constant category for [`CONSTANTS_TABLE`].
+///
+/// Each category is refined by one or more [`Tag`]s; see
+/// [`Tag::category`].
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Category {
+    /// Pure mathematical constant.
+    Mathematical,
+    /// Physical / SI constant, from the selected [`Dataset`].
+    Physical,
+    /// Cryptographic or utility constant.
+    Cryptographic,
+}
+
+/// Standard uncertainty of a constant's value.
+///
+/// Available in `no_std`.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Uncertainty {
+    /// Exact by definition: an SI defining constant (h, e, k_B,
+    /// N_A, c, …), a quantity derived only from those, a
+    /// conventional value such as the standard atmosphere, or a
+    /// mathematical constant.
+    Exact,
+    /// One-sigma absolute standard uncertainty, expressed in the
+    /// unit of the constant.
+    Standard(f64),
+}
+
+impl Uncertainty {
+    /// Returns `true` if the value is exact by definition.
+    pub const fn is_exact(&self) -> bool {
+        matches!(self, Self::Exact)
+    }
+
+    /// Returns the absolute standard uncertainty (`0.0` if exact).
+    pub const fn absolute(&self) -> f64 {
+        match self {
+            Self::Exact => 0.0,
+            Self::Standard(sigma) => *sigma,
+        }
+    }
+
+    /// Returns the relative standard uncertainty with respect to
+    /// `value`, i.e. `absolute / |value|` (`0.0` if exact).
+    pub fn relative(&self, value: f64) -> f64 {
+        match self {
+            Self::Exact => 0.0,
+            Self::Standard(sigma) => sigma / abs(value),
+        }
+    }
+}
+
+/// Absolute and relative standard uncertainty of a named
+/// constant, as returned by [`uncertainty`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StandardUncertainty {
+    /// Absolute standard uncertainty, in the unit of the constant.
+    pub absolute: f64,
+    /// Relative standard uncertainty (`absolute / |value|`).
+    pub relative: f64,
+    /// `true` if the constant is exact by definition.
+    pub exact: bool,
+}
+
+/// The value of a [`ConstantEntry`]: a `const`-friendly
+/// counterpart of `ConstantValue` that can represent every
+/// constant type without allocating.
+///
+/// Available in `no_std`.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::{lookup, ConstValue};
+///
+/// assert_eq!(lookup("HASH_COST").unwrap().value, ConstValue::U32(8));
+/// assert_eq!(
+///     lookup("PI").unwrap().value.as_f64(),
+///     Some(core::f64::consts::PI),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConstValue {
+    /// A float value.
+    Float(f64),
+    /// A string value.
+    Str(&'static str),
+    /// A `u32` value.
+    U32(u32),
+    /// A `usize` value.
+    Usize(usize),
+    /// An array of characters.
+    Chars(&'static [char]),
+}
+
+impl ConstValue {
+    /// Returns the float value, or `None` for non-float values.
+    pub const fn as_f64(self) -> Option<f64> {
+        match self {
+            Self::Float(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` for [`ConstValue::Float`].
+    pub const fn is_float(self) -> bool {
+        matches!(self, Self::Float(_))
+    }
+
+    /// Returns the Rust type name of the value, matching
+    /// `ConstantValue::type_name`.
+    pub const fn type_name(self) -> &'static str {
+        match self {
+            Self::Float(_) => "f64",
+            Self::Str(_) => "String",
+            Self::U32(_) => "u32",
+            Self::Usize(_) => "usize",
+            Self::Chars(_) => "&[char]",
+        }
+    }
+}
+
+impl core::fmt::Display for ConstValue {
     fn fmt(
         &self,
         f: &mut core::fmt::Formatter<'_>,
     ) -> core::fmt::Result {
         match self {
             Self::Float(v) => write!(f, "{v}"),
-            Self::String(v) => write!(f, "{v}"),
+            Self::Str(v) => f.write_str(v),
             Self::U32(v) => write!(f, "{v}"),
             Self::Usize(v) => write!(f, "{v}"),
-            Self::CharArray(v) => {
+            Self::Chars(v) => {
                 for ch in *v {
                     write!(f, "{ch}")?;
                 }
@@ -706,178 +948,742 @@ impl core::fmt::Display for ConstantValue {
     }
 }
 
-/// Constant category for [`CONSTANTS_TABLE`].
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum Category {
-    /// Pure mathematical constant.
-    Mathematical,
-    /// Physical / SI constant (CODATA 2018).
-    Physical,
-    /// Cryptographic or utility constant.
-    Cryptographic,
+#[cfg(feature = "std")]
+impl From<ConstValue> for ConstantValue {
+    fn from(value: ConstValue) -> Self {
+        match value {
+            ConstValue::Float(v) => Self::Float(v),
+            ConstValue::Str(v) => Self::String(v.to_string()),
+            ConstValue::U32(v) => Self::U32(v),
+            ConstValue::Usize(v) => Self::Usize(v),
+            ConstValue::Chars(v) => Self::CharArray(v),
+        }
+    }
+}
+
+/// A single row of [`CONSTANTS_TABLE`]: a named constant with its
+/// value, category, unit, standard uncertainty and descriptive
+/// metadata.
+///
+/// Available in `no_std`. Zero allocation.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConstantEntry {
+    /// The name of the constant, e.g. `"PLANCK"`.
+    pub name: &'static str,
+    /// The value of the constant, expressed in [`ConstantEntry::unit`].
+    pub value: ConstValue,
+    /// The category of the constant.
+    pub category: Category,
+    /// The unit of [`ConstantEntry::value`], with its SI dimension.
+    pub unit: Unit,
+    /// The standard uncertainty of [`ConstantEntry::value`].
+    pub uncertainty: Uncertainty,
+    /// Description, symbols, formula and source citation.
+    pub info: ConstantInfo,
+    /// Fine-grained classification; see [`Tag`].
+    pub tags: Tags,
+}
+
+/// The publication a constant's value is taken from.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Source {
+    /// CODATA 2018 recommended values.
+    Codata2018,
+    /// CODATA 2022 recommended values.
+    Codata2022,
+    /// Particle Data Group, Review of Particle Physics 2022.
+    Pdg2022,
+    /// A mathematical constant, exact by definition.
+    Mathematics,
+    /// Computed by this crate from other constants.
+    Derived,
+    /// A configuration default of this crate.
+    Cmn,
+}
+
+impl Source {
+    /// Returns a short citation, e.g. `"CODATA 2018"`.
+    pub const fn citation(self) -> &'static str {
+        match self {
+            Self::Codata2018 => "CODATA 2018",
+            Self::Codata2022 => "CODATA 2022",
+            Self::Pdg2022 => "PDG 2022",
+            Self::Mathematics => "Mathematical definition",
+            Self::Derived => "Derived from CODATA values",
+            Self::Cmn => "cmn default",
+        }
+    }
+}
+
+impl core::fmt::Display for Source {
+    fn fmt(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        f.write_str(self.citation())
+    }
+}
+
+/// Human-readable metadata for a constant.
+///
+/// Available in `no_std` as `&'static` data through [`info`] and
+/// [`ConstantEntry::info`].
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::{info, Source};
+///
+/// let hbar = info("PLANCK_REDUCED").unwrap();
+/// assert_eq!(hbar.description, "Reduced Planck constant");
+/// assert_eq!(hbar.symbol, "ħ");
+/// assert_eq!(hbar.latex, r"\hbar");
+/// assert_eq!(hbar.formula, Some("h/(2π)"));
+/// assert_eq!(hbar.source, Source::Codata2018);
+/// ```
+#[cfg_attr(feature = "std", derive(Serialize))]
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ConstantInfo {
+    /// A short human description, e.g. `"Bohr radius"`.
+    pub description: &'static str,
+    /// The conventional Unicode symbol, e.g. `"a₀"` or `"mₑ"`.
+    /// Subscripts without a Unicode form, such as the capital in
+    /// `"N_A"`, are written with `_`. Empty for constants without
+    /// one.
+    pub symbol: &'static str,
+    /// The symbol as LaTeX math, e.g. `r"a_0"`.
+    pub latex: &'static str,
+    /// The defining formula in terms of other constants, for
+    /// derived constants.
+    pub formula: Option<&'static str>,
+    /// Where the value comes from.
+    pub source: Source,
+}
+
+impl ConstantEntry {
+    /// Returns the absolute and relative standard uncertainty of
+    /// this entry.
+    pub fn standard_uncertainty(&self) -> StandardUncertainty {
+        StandardUncertainty {
+            absolute: self.uncertainty.absolute(),
+            relative: self
+                .uncertainty
+                .relative(self.value.as_f64().unwrap_or(0.0)),
+            exact: self.uncertainty.is_exact(),
+        }
+    }
+}
+
+const fn entry(
+    name: &'static str,
+    value: f64,
+    category: Category,
+    unit: Unit,
+    uncertainty: Uncertainty,
+    info: ConstantInfo,
+    tags: Tags,
+) -> ConstantEntry {
+    ConstantEntry {
+        name,
+        value: ConstValue::Float(value),
+        category,
+        unit,
+        uncertainty,
+        info,
+        tags,
+    }
+}
+
+const fn describe(
+    source: Source,
+    description: &'static str,
+    symbol: &'static str,
+    latex: &'static str,
+    formula: Option<&'static str>,
+) -> ConstantInfo {
+    ConstantInfo {
+        description,
+        symbol,
+        latex,
+        formula,
+        source,
+    }
+}
+
+/// Builds a [`Category::Cryptographic`] row for one of the
+/// non-float configuration constants.
+const fn utility_entry(
+    name: &'static str,
+    value: ConstValue,
+    info: ConstantInfo,
+    tags: Tags,
+) -> ConstantEntry {
+    ConstantEntry {
+        name,
+        value,
+        category: Category::Cryptographic,
+        unit: crate::units::ONE,
+        uncertainty: Uncertainty::Exact,
+        info,
+        tags,
+    }
+}
+
+/// Returns the description, symbols, formula and source of the
+/// named constant in [`Dataset::DEFAULT`], or `None` if the name is
+/// unknown.
+///
+/// Covers every constant of [`Constants`], including
+/// the non-float ones. Available in `no_std`.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::{info, Source};
+///
+/// let a0 = info("BOHR_RADIUS").unwrap();
+/// assert_eq!(a0.symbol, "a₀");
+/// assert_eq!(a0.formula, Some("ħ/(αm_e c)"));
+///
+/// assert_eq!(info("W_BOSON_MASS_GEV").unwrap().source, Source::Pdg2022);
+/// assert_eq!(info("HASH_COST").unwrap().source, Source::Cmn);
+/// assert!(info("UNKNOWN").is_none());
+/// ```
+pub fn info(name: &str) -> Option<&'static ConstantInfo> {
+    Dataset::DEFAULT.info(name)
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Copies `base` into an array, replacing the value, uncertainty
+/// and source of every entry named in `adjusted`.
+const fn with_adjusted<const N: usize>(
+    base: &[ConstantEntry],
+    adjusted: &[(&str, f64, f64)],
+    source: Source,
+) -> [ConstantEntry; N] {
+    let mut table = [base[0]; N];
+    let mut i = 0;
+    while i < N {
+        table[i] = base[i];
+        let mut j = 0;
+        while j < adjusted.len() {
+            let (name, value, sigma) = adjusted[j];
+            if str_eq(base[i].name, name) {
+                table[i].value = ConstValue::Float(value);
+                table[i].uncertainty = Uncertainty::Standard(sigma);
+                table[i].info.source = source;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    table
+}
+
+/// An edition of the CODATA recommended values.
+///
+/// Selects which values [`CONSTANTS_TABLE`], [`uncertainty`] and
+/// [`Constants::with_dataset`] resolve against. Constants outside
+/// the CODATA adjustment (mathematical constants, PDG boson
+/// masses) are identical in every edition. Available in `no_std`.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::{Dataset, ELECTRON_MASS};
+///
+/// let m_e_2018 = Dataset::Codata2018.entry("ELECTRON_MASS").unwrap();
+/// let m_e_2022 = Dataset::Codata2022.entry("ELECTRON_MASS").unwrap();
+/// assert_eq!(m_e_2018.value.as_f64(), Some(ELECTRON_MASS));
+/// assert_ne!(m_e_2018.value, m_e_2022.value);
+///
+/// // Exact constants do not change between editions.
+/// assert_eq!(
+///     Dataset::Codata2018.entry("PLANCK"),
+///     Dataset::Codata2022.entry("PLANCK"),
+/// );
+/// ```
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Dataset {
+    /// CODATA 2018 — see [`codata2018`].
+    Codata2018,
+    /// CODATA 2022 — see [`codata2022`].
+    Codata2022,
+}
+
+impl Dataset {
+    /// The dataset selected at compile time: CODATA 2022 when the
+    /// `codata2022` feature is enabled, CODATA 2018 otherwise.
+    pub const DEFAULT: Self = if cfg!(feature = "codata2022") {
+        Self::Codata2022
+    } else {
+        Self::Codata2018
+    };
+
+    /// Every edition, oldest first.
+    pub const ALL: [Self; 2] = [Self::Codata2018, Self::Codata2022];
+
+    /// Returns the full constants table for this edition.
+    pub const fn table(self) -> &'static [ConstantEntry] {
+        match self {
+            Self::Codata2018 => codata2018::TABLE,
+            Self::Codata2022 => codata2022::TABLE,
+        }
+    }
+
+    /// Returns the constants re-adjusted between editions, with
+    /// their values in this edition.
+    pub const fn adjusted(self) -> &'static [(&'static str, f64, f64)] {
+        match self {
+            Self::Codata2018 => codata2018::ADJUSTED,
+            Self::Codata2022 => codata2022::ADJUSTED,
+        }
+    }
+
+    /// Returns the metadata of the named constant in this edition.
+    /// See [`info`].
+    pub fn info(self, name: &str) -> Option<&'static ConstantInfo> {
+        self.entry(name).map(|entry| &entry.info)
+    }
+
+    /// Returns the category of the named constant.
+    pub fn category(self, name: &str) -> Option<Category> {
+        self.entry(name).map(|entry| entry.category)
+    }
+
+    /// Returns the tags of the named constant.
+    pub fn tags(self, name: &str) -> Option<Tags> {
+        self.entry(name).map(|entry| entry.tags)
+    }
+
+    /// Looks up a constant by name in this edition. Runs in
+    /// O(log n) and in `const` contexts; see [`lookup`].
+    pub const fn entry(
+        self,
+        name: &str,
+    ) -> Option<&'static ConstantEntry> {
+        match position(name) {
+            Some(i) => Some(&self.table()[i]),
+            None => None,
+        }
+    }
+
+    /// Returns the float value of the named constant in this
+    /// edition, or `None` if the name is unknown or the constant
+    /// is not a float. Runs in `const` contexts; see [`by_name`].
+    pub const fn by_name(self, name: &str) -> Option<f64> {
+        match self.entry(name) {
+            Some(entry) => entry.value.as_f64(),
+            None => None,
+        }
+    }
+
+    /// Returns the modelled correlation coefficient of two constants
+    /// in this edition; see [`correlations::modelled_correlation`].
+    pub fn modelled_correlation(self, a: &str, b: &str) -> Option<f64> {
+        correlations::modelled_correlation(self, a, b)
+    }
+
+    /// Returns the modelled covariance of two constants in this
+    /// edition, in the product of their units; see
+    /// [`correlations::modelled_covariance`].
+    pub fn modelled_covariance(self, a: &str, b: &str) -> Option<f64> {
+        correlations::modelled_covariance(self, a, b)
+    }
+
+    /// Returns the display name of the edition, e.g. `"CODATA 2018"`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Codata2018 => "CODATA 2018",
+            Self::Codata2022 => "CODATA 2022",
+        }
+    }
+}
+
+impl Default for Dataset {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
 }
 
-/// A static lookup table of all named float constants with
-/// category metadata.
+impl core::fmt::Display for Dataset {
+    fn fmt(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+const fn str_lt(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    a.len() < b.len()
+}
+
+/// Sorts the positions of `table` by entry name, failing the build
+/// on duplicate names.
+const fn sorted_index<const N: usize>(
+    table: &[ConstantEntry],
+) -> [u16; N] {
+    let mut index = [0u16; N];
+    let mut i = 0;
+    while i < N {
+        index[i] = i as u16;
+        let mut j = i;
+        while j > 0
+            && str_lt(table[i].name, table[index[j - 1] as usize].name)
+        {
+            index[j] = index[j - 1];
+            j -= 1;
+        }
+        if j > 0
+            && str_eq(table[i].name, table[index[j - 1] as usize].name)
+        {
+            panic!("duplicate constant name in CONSTANTS_TABLE");
+        }
+        index[j] = i as u16;
+        i += 1;
+    }
+    index
+}
+
+const TABLE_LEN: usize = codata2018::TABLE.len();
+
+/// Returns the row of the named constant, which is the same in
+/// every dataset, by binary search over [`NAME_INDEX`].
+const fn position(name: &str) -> Option<usize> {
+    let table = codata2018::TABLE;
+    let (mut low, mut high) = (0, NAME_INDEX.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let i = NAME_INDEX[mid] as usize;
+        if str_eq(table[i].name, name) {
+            return Some(i);
+        } else if str_lt(table[i].name, name) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    None
+}
+
+/// Absolute value of `x`, as `core` has no `f64::abs` before Rust
+/// 1.85.
+pub(crate) fn abs(x: f64) -> f64 {
+    if x < 0.0 {
+        -x
+    } else {
+        x
+    }
+}
+
+/// Square root of a non-negative, finite `x` by Newton's method, as
+/// `core` has no `f64::sqrt`.
+pub(crate) fn sqrt(x: f64) -> f64 {
+    if x == 0.0 {
+        return 0.0;
+    }
+    // Halving the exponent bits gives a first guess within a
+    // factor of two; each step then doubles the correct digits.
+    let mut y = f64::from_bits((x.to_bits() >> 1) + (1023 << 51));
+    for _ in 0..6 {
+        y = 0.5 * (y + x / y);
+    }
+    y
+}
+
+/// Table positions in name order. Every dataset shares the row
+/// order of [`codata2018::TABLE`], so one index serves them all.
+const NAME_INDEX: [u16; TABLE_LEN] =
+    sorted_index::<TABLE_LEN>(codata2018::TABLE);
+
+/// Looks up a constant by its exact name in [`CONSTANTS_TABLE`].
+///
+/// The name index is sorted at compile time, so each lookup is a
+/// binary search with no allocation or hashing. Available in
+/// `no_std` and in `const` contexts.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::{lookup, Category};
+///
+/// let c = lookup("SPEED_OF_LIGHT").unwrap();
+/// assert_eq!(c.value.as_f64(), Some(299_792_458.0));
+/// assert_eq!(c.category, Category::Physical);
+///
+/// assert!(lookup("speed_of_light").is_none());
+/// ```
+pub const fn lookup(name: &str) -> Option<&'static ConstantEntry> {
+    Dataset::DEFAULT.entry(name)
+}
+
+/// Returns the float value of the named constant in
+/// [`Dataset::DEFAULT`], or `None` if the name is unknown or the
+/// constant is not a float (e.g. `HASH_COST`).
+///
+/// This is a `const fn`, so firmware configured by constant name
+/// resolves the value at compile time. Use
+/// [`cmn_const!`](crate::cmn_const) to fail the build on unknown
+/// names instead of getting `None`. Available in `no_std`.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::by_name;
+///
+/// const C: Option<f64> = by_name("SPEED_OF_LIGHT");
+/// assert_eq!(C, Some(299_792_458.0));
+///
+/// const HASH_COST: Option<f64> = by_name("HASH_COST");
+/// assert_eq!(HASH_COST, None);
+/// assert_eq!(by_name("speed_of_light"), None);
+/// ```
+pub const fn by_name(name: &str) -> Option<f64> {
+    Dataset::DEFAULT.by_name(name)
+}
+
+/// Returns the modelled correlation coefficient of two constants in
+/// [`Dataset::DEFAULT`], between -1 and 1, or `None` if either name
+/// is unknown or not a float.
+///
+/// The coefficients follow from how each constant depends on the
+/// adjusted inputs (α, R∞, mass ratios, G, ...), which are treated
+/// as uncorrelated with one another; they are not the published
+/// CODATA coefficients. See [`correlations::modelled_correlation`].
+/// Available in `no_std`.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::modelled_correlation as r;
+///
+/// assert!(r("ELECTRON_MASS", "BOHR_MAGNETON").unwrap() < -0.99);
+/// assert_eq!(r("PI", "PI"), Some(1.0));
+/// assert_eq!(r("PLANCK", "ELECTRON_MASS"), Some(0.0));
+/// assert_eq!(r("HASH_COST", "PI"), None);
+/// ```
+pub fn modelled_correlation(a: &str, b: &str) -> Option<f64> {
+    Dataset::DEFAULT.modelled_correlation(a, b)
+}
+
+/// Returns the absolute and relative standard uncertainty of the
+/// named constant in [`Dataset::DEFAULT`], or `None` if the name
+/// is unknown or the constant is not a float (e.g. `HASH_COST`).
+///
+/// Exact constants (the SI defining constants, quantities derived
+/// only from them and all mathematical constants) report
+/// `exact: true` and zero uncertainty. Available in `no_std`.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::uncertainty;
+///
+/// let a0 = uncertainty("BOHR_RADIUS").unwrap();
+/// assert!(a0.absolute > 0.0);
+/// assert!(a0.relative < 1e-9);
+/// assert!(!a0.exact);
 ///
-/// Available in `no_std`. Zero allocation. Use this for
-/// compile-time constant lookup by name without the `std`
-/// feature.
+/// assert!(uncertainty("PLANCK").unwrap().exact);
+/// assert!(uncertainty("UNKNOWN").is_none());
+/// ```
+pub fn uncertainty(name: &str) -> Option<StandardUncertainty> {
+    lookup(name)
+        .filter(|entry| entry.value.is_float())
+        .map(ConstantEntry::standard_uncertainty)
+}
+
+/// Iterates over the entries of [`CONSTANTS_TABLE`] carrying
+/// `tag`, in table order. Available in `no_std`.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::{by_tag, Tag};
+///
+/// let planck: Vec<_> =
+///     by_tag(Tag::PlanckUnits).map(|e| e.name).collect();
+/// assert!(planck.contains(&"PLANCK_LENGTH"));
+/// assert!(!planck.contains(&"PLANCK"));
+/// ```
+pub fn by_tag(
+    tag: Tag,
+) -> impl Iterator<Item = &'static ConstantEntry> {
+    CONSTANTS_TABLE
+        .iter()
+        .filter(move |entry| entry.tags.contains(tag))
+}
+
+/// Iterates over the entries of [`CONSTANTS_TABLE`] in
+/// `category`, in table order. Available in `no_std`.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::{by_category, Category};
+///
+/// assert!(by_category(Category::Mathematical)
+///     .any(|e| e.name == "PI"));
+/// ```
+pub fn by_category(
+    category: Category,
+) -> impl Iterator<Item = &'static ConstantEntry> {
+    CONSTANTS_TABLE
+        .iter()
+        .filter(move |entry| entry.category == category)
+}
+
+/// Number of decimal places stored for each mathematical constant;
+/// see [`digits`].
+pub const MAX_DIGITS: usize = 120;
+
+/// Decimal expansions of the mathematical constants, by name.
+const DIGITS: &[(&str, &str)] = &[
+    ("APERY", APERY_DIGITS),
+    ("CATALAN", CATALAN_DIGITS),
+    ("EULER", EULER_DIGITS),
+    ("FRAC_1_PI", FRAC_1_PI_DIGITS),
+    ("FRAC_1_SQRT_2", FRAC_1_SQRT_2_DIGITS),
+    ("FRAC_2_PI", FRAC_2_PI_DIGITS),
+    ("FRAC_2_SQRT_PI", FRAC_2_SQRT_PI_DIGITS),
+    ("FRAC_PI_2", FRAC_PI_2_DIGITS),
+    ("FRAC_PI_3", FRAC_PI_3_DIGITS),
+    ("FRAC_PI_4", FRAC_PI_4_DIGITS),
+    ("FRAC_PI_6", FRAC_PI_6_DIGITS),
+    ("FRAC_PI_8", FRAC_PI_8_DIGITS),
+    ("GAMMA", GAMMA_DIGITS),
+    ("GLAISHER_KINKELIN", GLAISHER_KINKELIN_DIGITS),
+    ("KHINCHIN", KHINCHIN_DIGITS),
+    ("LN_10", LN_10_DIGITS),
+    ("LN_2", LN_2_DIGITS),
+    ("LOG10_E", LOG10_E_DIGITS),
+    ("LOG2_E", LOG2_E_DIGITS),
+    ("PHI", PHI_DIGITS),
+    ("PI", PI_DIGITS),
+    ("SILVER_RATIO", SILVER_RATIO_DIGITS),
+    ("SQRT2", SQRT2_DIGITS),
+    ("SQRT3", SQRT3_DIGITS),
+    ("SQRT5", SQRT5_DIGITS),
+    ("TAU", TAU_DIGITS),
+];
+
+/// Returns the decimal expansion of the named mathematical constant
+/// to `places` decimal places, truncated (not rounded).
+///
+/// Every [`Category::Mathematical`] constant is stored to
+/// [`MAX_DIGITS`] places. Returns `None` for other constants,
+/// unknown names, or `places > MAX_DIGITS`. With `places == 0`
+/// only the integer part is returned. Available in `no_std`.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::{digits, APERY, MAX_DIGITS};
+///
+/// let zeta3 = digits("APERY", 100).unwrap();
+/// assert_eq!(zeta3.len(), 102);
+/// assert!(zeta3.starts_with("1.20205690315959428539973816"));
+/// assert_eq!(zeta3.parse::<f64>(), Ok(APERY));
+///
+/// assert_eq!(digits("PI", 4), Some("3.1415"));
+/// assert_eq!(digits("PI", 0), Some("3"));
+/// assert!(digits("PI", MAX_DIGITS + 1).is_none());
+/// assert!(digits("PLANCK", 10).is_none());
+/// ```
+pub fn digits(name: &str, places: usize) -> Option<&'static str> {
+    if places > MAX_DIGITS {
+        return None;
+    }
+    let (_, expansion) = DIGITS.iter().find(|(n, _)| *n == name)?;
+    let point = expansion.find('.')?;
+    let end = if places == 0 {
+        point
+    } else {
+        point + 1 + places
+    };
+    expansion.get(..end)
+}
+
+/// A static lookup table of all 121 named constants with
+/// category, unit and uncertainty metadata.
+///
+/// Non-float constants (`HASH_ALGORITHM`, `HASH_COST`,
+/// `HASH_LENGTH`, `SPECIAL_CHARS`) are [`Category::Cryptographic`]
+/// rows whose [`ConstValue`] holds the string, integer or character
+/// array; they are dimensionless and exact.
+///
+/// Resolves to [`codata2018::TABLE`] by default, or to
+/// [`codata2022::TABLE`] when the `codata2022` feature is enabled
+/// (see [`Dataset::DEFAULT`]). The top-level `const` items are
+/// always the CODATA 2018 values.
+///
+/// Available in `no_std`. Zero allocation. Use [`lookup`] to
+/// find an entry by name without the `std` feature.
 ///
 /// # Example
 ///
 /// ```
-/// use cmn::constants::{CONSTANTS_TABLE, Category};
+/// use cmn::constants::{lookup, CONSTANTS_TABLE, Category, ConstValue};
 ///
-/// let pi = CONSTANTS_TABLE.iter()
-///     .find(|(name, _, _)| *name == "PI")
-///     .map(|(_, val, _)| *val);
+/// let pi = lookup("PI").and_then(|entry| entry.value.as_f64());
 /// assert_eq!(pi, Some(core::f64::consts::PI));
+/// assert_eq!(CONSTANTS_TABLE.len(), 121);
 ///
 /// // Filter by category
 /// let physical: Vec<_> = CONSTANTS_TABLE.iter()
-///     .filter(|(_, _, cat)| *cat == Category::Physical)
+///     .filter(|entry| entry.category == Category::Physical)
 ///     .collect();
 /// assert!(physical.len() > 10);
+///
+/// let hash = lookup("HASH_ALGORITHM").unwrap();
+/// assert_eq!(hash.category, Category::Cryptographic);
+/// assert_eq!(hash.value, ConstValue::Str("Blake3"));
+///
+/// // Machine-readable units
+/// let planck = lookup("PLANCK").unwrap();
+/// assert_eq!(planck.unit.symbol, "J s");
+/// assert_eq!(planck.unit.dimension.to_string(), "m^2 kg s^-1");
 /// ```
-use Category::{Mathematical as M, Physical as P};
-/// A static lookup table of all named float constants.
-pub const CONSTANTS_TABLE: &[(&str, f64, Category)] = &[
-    ("APERY", APERY, M),
-    ("AVOGADRO", AVOGADRO, P),
-    ("BOLTZMANN", BOLTZMANN, P),
-    ("CATALAN", CATALAN, M),
-    ("COULOMB", COULOMB, P),
-    ("EULER", EULER, M),
-    ("FARADAY", FARADAY, P),
-    ("GAMMA", GAMMA, M),
-    ("GAS_CONSTANT", GAS_CONSTANT, P),
-    ("GLAISHER_KINKELIN", GLAISHER_KINKELIN, M),
-    ("GRAVITATIONAL_CONSTANT", GRAVITATIONAL_CONSTANT, P),
-    ("KHINCHIN", KHINCHIN, M),
-    ("PHI", PHI, M),
-    ("PI", PI, M),
-    ("PLANCK", PLANCK, P),
-    ("PLANCK_REDUCED", PLANCK_REDUCED, P),
-    ("SILVER_RATIO", SILVER_RATIO, M),
-    ("SPEED_OF_LIGHT", SPEED_OF_LIGHT, P),
-    ("SQRT2", SQRT2, M),
-    ("SQRT3", SQRT3, M),
-    ("SQRT5", SQRT5, M),
-    ("TAU", TAU, M),
-    ("VACUUM_PERMEABILITY", VACUUM_PERMEABILITY, P),
-    ("VACUUM_PERMITTIVITY", VACUUM_PERMITTIVITY, P),
-    ("LN_2", LN_2, M),
-    ("LN_10", LN_10, M),
-    ("LOG2_E", LOG2_E, M),
-    ("LOG10_E", LOG10_E, M),
-    ("FRAC_1_SQRT_2", FRAC_1_SQRT_2, M),
-    ("FRAC_1_PI", FRAC_1_PI, M),
-    ("FRAC_2_PI", FRAC_2_PI, M),
-    ("FRAC_2_SQRT_PI", FRAC_2_SQRT_PI, M),
-    ("FRAC_PI_2", FRAC_PI_2, M),
-    ("FRAC_PI_3", FRAC_PI_3, M),
-    ("FRAC_PI_4", FRAC_PI_4, M),
-    ("FRAC_PI_6", FRAC_PI_6, M),
-    ("FRAC_PI_8", FRAC_PI_8, M),
-    ("ELEMENTARY_CHARGE", ELEMENTARY_CHARGE, P),
-    ("ELECTRON_MASS", ELECTRON_MASS, P),
-    ("PROTON_MASS", PROTON_MASS, P),
-    ("NEUTRON_MASS", NEUTRON_MASS, P),
-    ("STEFAN_BOLTZMANN", STEFAN_BOLTZMANN, P),
-    ("WIEN_DISPLACEMENT", WIEN_DISPLACEMENT, P),
-    ("STANDARD_GRAVITY", STANDARD_GRAVITY, P),
-    ("STANDARD_ATMOSPHERE", STANDARD_ATMOSPHERE, P),
-    ("ATOMIC_MASS_UNIT", ATOMIC_MASS_UNIT, P),
-    ("BOHR_RADIUS", BOHR_RADIUS, P),
-    ("FINE_STRUCTURE", FINE_STRUCTURE, P),
-    ("RYDBERG", RYDBERG, P),
-    ("MAGNETIC_FLUX_QUANTUM", MAGNETIC_FLUX_QUANTUM, P),
-    ("CONDUCTANCE_QUANTUM", CONDUCTANCE_QUANTUM, P),
-    ("MUON_MASS", MUON_MASS, P),
-    ("TAU_PARTICLE_MASS", TAU_PARTICLE_MASS, P),
-    ("DEUTERON_MASS", DEUTERON_MASS, P),
-    ("TRITON_MASS", TRITON_MASS, P),
-    ("HELION_MASS", HELION_MASS, P),
-    ("ALPHA_PARTICLE_MASS", ALPHA_PARTICLE_MASS, P),
-    ("ELECTRON_PROTON_MASS_RATIO", ELECTRON_PROTON_MASS_RATIO, P),
-    ("PROTON_ELECTRON_MASS_RATIO", PROTON_ELECTRON_MASS_RATIO, P),
-    ("MUON_ELECTRON_MASS_RATIO", MUON_ELECTRON_MASS_RATIO, P),
-    ("NEUTRON_PROTON_MASS_RATIO", NEUTRON_PROTON_MASS_RATIO, P),
-    ("DEUTERON_PROTON_MASS_RATIO", DEUTERON_PROTON_MASS_RATIO, P),
-    ("BOHR_MAGNETON", BOHR_MAGNETON, P),
-    ("NUCLEAR_MAGNETON", NUCLEAR_MAGNETON, P),
-    ("ELECTRON_MAGNETIC_MOMENT", ELECTRON_MAGNETIC_MOMENT, P),
-    ("PROTON_MAGNETIC_MOMENT", PROTON_MAGNETIC_MOMENT, P),
-    ("NEUTRON_MAGNETIC_MOMENT", NEUTRON_MAGNETIC_MOMENT, P),
-    ("ELECTRON_G_FACTOR", ELECTRON_G_FACTOR, P),
-    ("PROTON_G_FACTOR", PROTON_G_FACTOR, P),
-    ("ELECTRON_VOLT", ELECTRON_VOLT, P),
-    ("EV_TO_KG", EV_TO_KG, P),
-    ("EV_TO_AMU", EV_TO_AMU, P),
-    ("EV_TO_HZ", EV_TO_HZ, P),
-    ("EV_TO_KELVIN", EV_TO_KELVIN, P),
-    ("EV_TO_INVERSE_METER", EV_TO_INVERSE_METER, P),
-    ("CLASSICAL_ELECTRON_RADIUS", CLASSICAL_ELECTRON_RADIUS, P),
-    (
-        "ELECTRON_COMPTON_WAVELENGTH",
-        ELECTRON_COMPTON_WAVELENGTH,
-        P,
-    ),
-    ("PROTON_COMPTON_WAVELENGTH", PROTON_COMPTON_WAVELENGTH, P),
-    ("NEUTRON_COMPTON_WAVELENGTH", NEUTRON_COMPTON_WAVELENGTH, P),
-    ("THOMSON_CROSS_SECTION", THOMSON_CROSS_SECTION, P),
-    ("FIRST_RADIATION_CONSTANT", FIRST_RADIATION_CONSTANT, P),
-    ("SECOND_RADIATION_CONSTANT", SECOND_RADIATION_CONSTANT, P),
-    ("JOSEPHSON_CONSTANT", JOSEPHSON_CONSTANT, P),
-    ("VON_KLITZING_CONSTANT", VON_KLITZING_CONSTANT, P),
-    ("HARTREE_ENERGY", HARTREE_ENERGY, P),
-    ("HARTREE_ENERGY_EV", HARTREE_ENERGY_EV, P),
-    ("PLANCK_MASS", PLANCK_MASS, P),
-    ("PLANCK_LENGTH", PLANCK_LENGTH, P),
-    ("PLANCK_TIME", PLANCK_TIME, P),
-    ("PLANCK_TEMPERATURE", PLANCK_TEMPERATURE, P),
-    ("PLANCK_CHARGE", PLANCK_CHARGE, P),
-    ("MOLAR_MASS_CONSTANT", MOLAR_MASS_CONSTANT, P),
-    ("MOLAR_PLANCK_CONSTANT", MOLAR_PLANCK_CONSTANT, P),
-    ("LOSCHMIDT_CONSTANT", LOSCHMIDT_CONSTANT, P),
-    ("MOLAR_VOLUME_IDEAL_GAS", MOLAR_VOLUME_IDEAL_GAS, P),
-    ("SACKUR_TETRODE_CONSTANT", SACKUR_TETRODE_CONSTANT, P),
-    ("IMPEDANCE_OF_FREE_SPACE", IMPEDANCE_OF_FREE_SPACE, P),
-    ("INVERSE_FINE_STRUCTURE", INVERSE_FINE_STRUCTURE, P),
-    ("ELECTRON_CHARGE_TO_MASS", ELECTRON_CHARGE_TO_MASS, P),
-    ("PROTON_CHARGE_TO_MASS", PROTON_CHARGE_TO_MASS, P),
-    ("ATOMIC_UNIT_OF_LENGTH", ATOMIC_UNIT_OF_LENGTH, P),
-    ("ATOMIC_UNIT_OF_TIME", ATOMIC_UNIT_OF_TIME, P),
-    ("ATOMIC_UNIT_OF_VELOCITY", ATOMIC_UNIT_OF_VELOCITY, P),
-    ("ATOMIC_UNIT_OF_FORCE", ATOMIC_UNIT_OF_FORCE, P),
-    (
-        "ATOMIC_UNIT_OF_ELECTRIC_FIELD",
-        ATOMIC_UNIT_OF_ELECTRIC_FIELD,
-        P,
-    ),
-    (
-        "ATOMIC_UNIT_OF_POLARIZABILITY",
-        ATOMIC_UNIT_OF_POLARIZABILITY,
-        P,
-    ),
-    ("W_BOSON_MASS_GEV", W_BOSON_MASS_GEV, P),
-    ("Z_BOSON_MASS_GEV", Z_BOSON_MASS_GEV, P),
-    ("HIGGS_BOSON_MASS_GEV", HIGGS_BOSON_MASS_GEV, P),
-    ("ELECTRON_MASS_MEV", ELECTRON_MASS_MEV, P),
-    ("PROTON_MASS_MEV", PROTON_MASS_MEV, P),
-    ("NEUTRON_MASS_MEV", NEUTRON_MASS_MEV, P),
-    ("MUON_MASS_MEV", MUON_MASS_MEV, P),
-    ("ELECTRON_REDUCED_COMPTON", ELECTRON_REDUCED_COMPTON, P),
-    ("PROTON_REDUCED_COMPTON", PROTON_REDUCED_COMPTON, P),
-    ("NEUTRON_REDUCED_COMPTON", NEUTRON_REDUCED_COMPTON, P),
-    ("GAS_CONSTANT_L_ATM", GAS_CONSTANT_L_ATM, P),
-];
+pub const CONSTANTS_TABLE: &[ConstantEntry] = Dataset::DEFAULT.table();
 
 /// Apéry's constant, which is the sum of the reciprocals of the positive cubes.
 /// ζ(3) ≈ 1.2020569032
 pub const APERY: f64 = 1.2020569031595942;
 
+/// [`APERY`] to 120 decimal places, truncated. See [`digits`].
+pub const APERY_DIGITS: &str = concat!(
+    "1.",
+    "202056903159594285399738161511449990764986292340498881792271",
+    "555341838205786313090186455873609335258146199157795260719418",
+);
+
 /// Avogadro's constant (CODATA 2018, exact since 2019 SI).
 /// N_A = 6.02214076 x 10^23 mol^-1
 pub const AVOGADRO: f64 = 6.02214076e23;
@@ -890,16 +1696,31 @@ pub const BOLTZMANN: f64 = 1.380_649e-23;
 /// C ≈ 0.915965594177219
 pub const CATALAN: f64 = 0.915_965_594_177_219;
 
+/// [`CATALAN`] to 120 decimal places, truncated. See [`digits`].
+pub const CATALAN_DIGITS: &str = concat!(
+    "0.",
+    "915965594177219015054603514932384110774149374281672134266498",
+    "119621763019776254769479356512926115106248574422619196199579",
+);
+
 /// Coulomb's constant (CODATA 2018).
 /// k_e ≈ 8.9875517923 x 10^9 N m^2 C^-2
 pub const COULOMB: f64 = 8.9875517923e9;
-/// Standard uncertainty of [`COULOMB`]. Source: CODATA 2018.
-pub const COULOMB_UNCERTAINTY: f64 = 0.0;
+/// Standard uncertainty of [`COULOMB`], propagated from
+/// [`VACUUM_PERMITTIVITY_UNCERTAINTY`] via k_e = 1/(4πε₀).
+pub const COULOMB_UNCERTAINTY: f64 = 0.000_000_001_4e9;
 
 /// The base of the natural logarithm, Euler's number.
 /// e ≈ 2.7182818284590452353602874713527
 pub const EULER: f64 = core::f64::consts::E;
 
+/// [`EULER`] to 120 decimal places, truncated. See [`digits`].
+pub const EULER_DIGITS: &str = concat!(
+    "2.",
+    "718281828459045235360287471352662497757247093699959574966967",
+    "627724076630353547594571382178525166427427466391932003059921",
+);
+
 /// Faraday constant (CODATA 2018, exact since 2019 SI).
 /// F = N_A * e = 96485.33212 C mol^-1
 pub const FARADAY: f64 = 96485.33212;
@@ -908,6 +1729,13 @@ pub const FARADAY: f64 = 96485.33212;
 /// γ ≈ 0.5772156649015329
 pub const GAMMA: f64 = 0.5772156649015329;
 
+/// [`GAMMA`] to 120 decimal places, truncated. See [`digits`].
+pub const GAMMA_DIGITS: &str = concat!(
+    "0.",
+    "577215664901532860606512090082402431042159335939923598805767",
+    "234884867726777664670936947063291746749514631447249807082480",
+);
+
 /// The molar gas constant (CODATA 2018, exact since 2019 SI).
 /// R = N_A * k_B = 8.314462618 J mol^-1 K^-1
 pub const GAS_CONSTANT: f64 = 8.314462618;
@@ -916,6 +1744,14 @@ pub const GAS_CONSTANT: f64 = 8.314462618;
 /// A ≈ 1.2824271291
 pub const GLAISHER_KINKELIN: f64 = 1.2824271291006226;
 
+/// [`GLAISHER_KINKELIN`] to 120 decimal places, truncated. See
+/// [`digits`].
+pub const GLAISHER_KINKELIN_DIGITS: &str = concat!(
+    "1.",
+    "282427129100622636875342568869791727767688927325001192063740",
+    "021740406308858826461129736491958202374394206461203990007489",
+);
+
 /// The Newtonian gravitational constant (CODATA 2018).
 /// G ≈ 6.67430 x 10^-11 m^3 kg^-1 s^-2
 pub const GRAVITATIONAL_CONSTANT: f64 = 6.67430e-11;
@@ -938,14 +1774,35 @@ pub const HASH_LENGTH: usize = 32;
 /// K ≈ 2.6854520010
 pub const KHINCHIN: f64 = 2.6854520010653064;
 
+/// [`KHINCHIN`] to 120 decimal places, truncated. See [`digits`].
+pub const KHINCHIN_DIGITS: &str = concat!(
+    "2.",
+    "685452001065306445309714835481795693820382293994462953051152",
+    "345557218859537152002801141174931847697995153465905288090082",
+);
+
 /// The golden ratio, which is the limit of the ratio of consecutive Fibonacci numbers.
 /// φ = (1 + √5) / 2 ≈ 1.6180339887498948482045868343656
 pub const PHI: f64 = (1.0 + SQRT5) / 2.0;
 
+/// [`PHI`] to 120 decimal places, truncated. See [`digits`].
+pub const PHI_DIGITS: &str = concat!(
+    "1.",
+    "618033988749894848204586834365638117720309179805762862135448",
+    "622705260462818902449707207204189391137484754088075386891752",
+);
+
 /// The ratio of a circle's circumference to its diameter.
 /// π ≈ 3.14159265358979323846264338327950288
 pub const PI: f64 = core::f64::consts::PI;
 
+/// [`PI`] to 120 decimal places, truncated. See [`digits`].
+pub const PI_DIGITS: &str = concat!(
+    "3.",
+    "141592653589793238462643383279502884197169399375105820974944",
+    "592307816406286208998628034825342117067982148086513282306647",
+);
+
 /// Planck's constant (CODATA 2018, exact since 2019 SI).
 /// h = 6.62607015 x 10^-34 J s
 pub const PLANCK: f64 = 6.62607015e-34;
@@ -958,6 +1815,13 @@ pub const PLANCK_REDUCED: f64 = PLANCK / (2.0 * PI);
 /// δ_s = 1 + √2 ≈ 2.4142135623730950488016887242097
 pub const SILVER_RATIO: f64 = 1.0 + SQRT2;
 
+/// [`SILVER_RATIO`] to 120 decimal places, truncated. See [`digits`].
+pub const SILVER_RATIO_DIGITS: &str = concat!(
+    "2.",
+    "414213562373095048801688724209698078569671875376948073176679",
+    "737990732478462107038850387534327641572735013846230912297024",
+);
+
 /// The speed of light in vacuum (exact since 1983 SI definition).
 /// c = 299792458 m s^-1
 pub const SPEED_OF_LIGHT: f64 = 299792458.0;
@@ -973,18 +1837,46 @@ pub const SPECIAL_CHARS: &[char] = &[
 /// √2 ≈ 1.4142135623730950488016887242097
 pub const SQRT2: f64 = core::f64::consts::SQRT_2;
 
+/// [`SQRT2`] to 120 decimal places, truncated. See [`digits`].
+pub const SQRT2_DIGITS: &str = concat!(
+    "1.",
+    "414213562373095048801688724209698078569671875376948073176679",
+    "737990732478462107038850387534327641572735013846230912297024",
+);
+
 /// The square root of 3.
 /// √3 ≈ 1.7320508075688772935274463415059
 pub const SQRT3: f64 = 1.7320508075688772;
 
+/// [`SQRT3`] to 120 decimal places, truncated. See [`digits`].
+pub const SQRT3_DIGITS: &str = concat!(
+    "1.",
+    "732050807568877293527446341505872366942805253810380628055806",
+    "979451933016908800037081146186757248575675626141415406703029",
+);
+
 /// The square root of 5.
 /// √5 ≈ 2.23606797749979
 pub const SQRT5: f64 = 2.236_067_977_499_79;
 
+/// [`SQRT5`] to 120 decimal places, truncated. See [`digits`].
+pub const SQRT5_DIGITS: &str = concat!(
+    "2.",
+    "236067977499789696409173668731276235440618359611525724270897",
+    "245410520925637804899414414408378782274969508176150773783504",
+);
+
 /// The circle constant, which is the ratio of a circle's circumference to its radius.
 /// τ = 2π ≈ 6.28318530717958647692528676655900577
 pub const TAU: f64 = core::f64::consts::TAU;
 
+/// [`TAU`] to 120 decimal places, truncated. See [`digits`].
+pub const TAU_DIGITS: &str = concat!(
+    "6.",
+    "283185307179586476925286766559005768394338798750211641949889",
+    "184615632812572417997256069650684234135964296173026564613294",
+);
+
 /// The vacuum permeability (CODATA 2018).
 /// μ_0 ≈ 1.25663706212 x 10^-6 N A^-2
 pub const VACUUM_PERMEABILITY: f64 = 1.25663706212e-6;
@@ -1005,54 +1897,145 @@ pub const VACUUM_PERMITTIVITY_UNCERTAINTY: f64 = 0.0000000013e-12;
 /// ln(2) ≈ 0.6931471805599453
 pub const LN_2: f64 = core::f64::consts::LN_2;
 
+/// [`LN_2`] to 120 decimal places, truncated. See [`digits`].
+pub const LN_2_DIGITS: &str = concat!(
+    "0.",
+    "693147180559945309417232121458176568075500134360255254120680",
+    "009493393621969694715605863326996418687542001481020570685733",
+);
+
 /// The natural logarithm of 10.
 /// ln(10) ≈ 2.302585092994046
 pub const LN_10: f64 = core::f64::consts::LN_10;
 
+/// [`LN_10`] to 120 decimal places, truncated. See [`digits`].
+pub const LN_10_DIGITS: &str = concat!(
+    "2.",
+    "302585092994045684017991454684364207601101488628772976033327",
+    "900967572609677352480235997205089598298341967784042286248633",
+);
+
 /// The base-2 logarithm of Euler's number.
 /// log₂(e) ≈ 1.4426950408889634
 pub const LOG2_E: f64 = core::f64::consts::LOG2_E;
 
+/// [`LOG2_E`] to 120 decimal places, truncated. See [`digits`].
+pub const LOG2_E_DIGITS: &str = concat!(
+    "1.",
+    "442695040888963407359924681001892137426645954152985934135449",
+    "406931109219181185079885526622893506344496997518309652544255",
+);
+
 /// The base-10 logarithm of Euler's number.
 /// log₁₀(e) ≈ 0.4342944819032518
 pub const LOG10_E: f64 = core::f64::consts::LOG10_E;
 
+/// [`LOG10_E`] to 120 decimal places, truncated. See [`digits`].
+pub const LOG10_E_DIGITS: &str = concat!(
+    "0.",
+    "434294481903251827651128918916605082294397005803666566114453",
+    "783165864649208870774729224949338431748318706106744766303733",
+);
+
 /// The reciprocal of the square root of 2.
 /// 1/√2 ≈ 0.7071067811865476
 pub const FRAC_1_SQRT_2: f64 = core::f64::consts::FRAC_1_SQRT_2;
 
+/// [`FRAC_1_SQRT_2`] to 120 decimal places, truncated. See [`digits`].
+pub const FRAC_1_SQRT_2_DIGITS: &str = concat!(
+    "0.",
+    "707106781186547524400844362104849039284835937688474036588339",
+    "868995366239231053519425193767163820786367506923115456148512",
+);
+
 /// The reciprocal of pi.
 /// 1/π ≈ 0.3183098861837907
 pub const FRAC_1_PI: f64 = core::f64::consts::FRAC_1_PI;
 
+/// [`FRAC_1_PI`] to 120 decimal places, truncated. See [`digits`].
+pub const FRAC_1_PI_DIGITS: &str = concat!(
+    "0.",
+    "318309886183790671537767526745028724068919291480912897495334",
+    "688117793595268453070180227605532506171912145685453515916073",
+);
+
 /// Two divided by pi.
 /// 2/π ≈ 0.6366197723675814
 pub const FRAC_2_PI: f64 = core::f64::consts::FRAC_2_PI;
 
+/// [`FRAC_2_PI`] to 120 decimal places, truncated. See [`digits`].
+pub const FRAC_2_PI_DIGITS: &str = concat!(
+    "0.",
+    "636619772367581343075535053490057448137838582961825794990669",
+    "376235587190536906140360455211065012343824291370907031832147",
+);
+
 /// Two divided by the square root of pi.
 /// 2/√π ≈ 1.1283791670955126
 pub const FRAC_2_SQRT_PI: f64 = core::f64::consts::FRAC_2_SQRT_PI;
 
+/// [`FRAC_2_SQRT_PI`] to 120 decimal places, truncated. See [`digits`].
+pub const FRAC_2_SQRT_PI_DIGITS: &str = concat!(
+    "1.",
+    "128379167095512573896158903121545171688101258657997713688171",
+    "443421284936882986828973487320404214726886056695812723414703",
+);
+
 /// Pi divided by 2 (a right angle in radians).
 /// π/2 ≈ 1.5707963267948966
 pub const FRAC_PI_2: f64 = core::f64::consts::FRAC_PI_2;
 
+/// [`FRAC_PI_2`] to 120 decimal places, truncated. See [`digits`].
+pub const FRAC_PI_2_DIGITS: &str = concat!(
+    "1.",
+    "570796326794896619231321691639751442098584699687552910487472",
+    "296153908203143104499314017412671058533991074043256641153323",
+);
+
 /// Pi divided by 3 (60 degrees in radians).
 /// π/3 ≈ 1.0471975511965979
 pub const FRAC_PI_3: f64 = core::f64::consts::FRAC_PI_3;
 
+/// [`FRAC_PI_3`] to 120 decimal places, truncated. See [`digits`].
+pub const FRAC_PI_3_DIGITS: &str = concat!(
+    "1.",
+    "047197551196597746154214461093167628065723133125035273658314",
+    "864102605468762069666209344941780705689327382695504427435549",
+);
+
 /// Pi divided by 4 (45 degrees in radians).
 /// π/4 ≈ 0.7853981633974483
 pub const FRAC_PI_4: f64 = core::f64::consts::FRAC_PI_4;
 
+/// [`FRAC_PI_4`] to 120 decimal places, truncated. See [`digits`].
+pub const FRAC_PI_4_DIGITS: &str = concat!(
+    "0.",
+    "785398163397448309615660845819875721049292349843776455243736",
+    "148076954101571552249657008706335529266995537021628320576661",
+);
+
 /// Pi divided by 6 (30 degrees in radians).
 /// π/6 ≈ 0.5235987755982989
 pub const FRAC_PI_6: f64 = core::f64::consts::FRAC_PI_6;
 
+/// [`FRAC_PI_6`] to 120 decimal places, truncated. See [`digits`].
+pub const FRAC_PI_6_DIGITS: &str = concat!(
+    "0.",
+    "523598775598298873077107230546583814032861566562517636829157",
+    "432051302734381034833104672470890352844663691347752213717774",
+);
+
 /// Pi divided by 8 (22.5 degrees in radians).
 /// π/8 ≈ 0.39269908169872414
 pub const FRAC_PI_8: f64 = core::f64::consts::FRAC_PI_8;
 
+/// [`FRAC_PI_8`] to 120 decimal places, truncated. See [`digits`].
+pub const FRAC_PI_8_DIGITS: &str = concat!(
+    "0.",
+    "392699081698724154807830422909937860524646174921888227621868",
+    "074038477050785776124828504353167764633497768510814160288330",
+);
+
 // ---------------------------------------------------------------
 // Physical constants — CODATA 2018 recommended values
 // ---------------------------------------------------------------
@@ -1083,11 +2066,12 @@ pub const NEUTRON_MASS_UNCERTAINTY: f64 = 0.000_000_000_95e-27;
 /// σ = 5.670374419 x 10^-8 W m^-2 K^-4
 pub const STEFAN_BOLTZMANN: f64 = 5.670_374_419e-8;
 
-/// Wien's displacement constant (CODATA 2018).
-/// b ≈ 2.897771955 x 10^-3 m K
+/// Wien's displacement constant (CODATA 2018, exact since 2019 SI).
+/// b = 2.897771955 x 10^-3 m K
 pub const WIEN_DISPLACEMENT: f64 = 2.897_771_955e-3;
-/// Standard uncertainty of [`WIEN_DISPLACEMENT`]. Source: CODATA 2018.
-pub const WIEN_DISPLACEMENT_UNCERTAINTY: f64 = 0.000_000_013e-3;
+/// Standard uncertainty of [`WIEN_DISPLACEMENT`]. Zero: b = hc/(k_B x)
+/// is exact since the 2019 SI redefinition. Source: CODATA 2018.
+pub const WIEN_DISPLACEMENT_UNCERTAINTY: f64 = 0.0;
 
 /// Standard acceleration of gravity (exact by definition).
 /// g = 9.80665 m s^-2
@@ -1136,26 +2120,38 @@ pub const CONDUCTANCE_QUANTUM: f64 = 7.748_091_729e-5;
 /// Muon mass (CODATA 2018).
 /// m_μ ≈ 1.883531627e-28 kg
 pub const MUON_MASS: f64 = 1.883_531_627e-28;
+/// Standard uncertainty of [`MUON_MASS`]. Source: CODATA 2018.
+pub const MUON_MASS_UNCERTAINTY: f64 = 0.000_000_042e-28;
 
 /// Tau lepton mass (CODATA 2018).
 /// m_τ ≈ 3.16754e-27 kg
 pub const TAU_PARTICLE_MASS: f64 = 3.167_54e-27;
+/// Standard uncertainty of [`TAU_PARTICLE_MASS`]. Source: CODATA 2018.
+pub const TAU_PARTICLE_MASS_UNCERTAINTY: f64 = 0.000_21e-27;
 
 /// Deuteron mass (CODATA 2018).
 /// m_d ≈ 3.3435837724e-27 kg
 pub const DEUTERON_MASS: f64 = 3.343_583_772_4e-27;
+/// Standard uncertainty of [`DEUTERON_MASS`]. Source: CODATA 2018.
+pub const DEUTERON_MASS_UNCERTAINTY: f64 = 0.000_000_001_0e-27;
 
 /// Triton mass (CODATA 2018).
 /// m_t ≈ 5.0073567446e-27 kg
 pub const TRITON_MASS: f64 = 5.007_356_744_6e-27;
+/// Standard uncertainty of [`TRITON_MASS`]. Source: CODATA 2018.
+pub const TRITON_MASS_UNCERTAINTY: f64 = 0.000_000_001_5e-27;
 
 /// Helion (He-3 nucleus) mass (CODATA 2018).
 /// m_h ≈ 5.0064127796e-27 kg
 pub const HELION_MASS: f64 = 5.006_412_779_6e-27;
+/// Standard uncertainty of [`HELION_MASS`]. Source: CODATA 2018.
+pub const HELION_MASS_UNCERTAINTY: f64 = 0.000_000_001_5e-27;
 
 /// Alpha particle mass (CODATA 2018).
 /// m_α ≈ 6.6446573357e-27 kg
 pub const ALPHA_PARTICLE_MASS: f64 = 6.644_657_335_7e-27;
+/// Standard uncertainty of [`ALPHA_PARTICLE_MASS`]. Source: CODATA 2018.
+pub const ALPHA_PARTICLE_MASS_UNCERTAINTY: f64 = 0.000_000_002_0e-27;
 
 // ---------------------------------------------------------------
 // Mass ratios — CODATA 2018
@@ -1164,22 +2160,34 @@ pub const ALPHA_PARTICLE_MASS: f64 = 6.644_657_335_7e-27;
 /// Electron-to-proton mass ratio (CODATA 2018).
 /// m_e / m_p ≈ 5.44617021487e-4
 pub const ELECTRON_PROTON_MASS_RATIO: f64 = 5.446_170_214_87e-4;
+/// Standard uncertainty of [`ELECTRON_PROTON_MASS_RATIO`]. Source: CODATA 2018.
+pub const ELECTRON_PROTON_MASS_RATIO_UNCERTAINTY: f64 =
+    0.000_000_000_33e-4;
 
 /// Proton-to-electron mass ratio (CODATA 2018).
 /// m_p / m_e ≈ 1836.15267343
 pub const PROTON_ELECTRON_MASS_RATIO: f64 = 1_836.152_673_43;
+/// Standard uncertainty of [`PROTON_ELECTRON_MASS_RATIO`]. Source: CODATA 2018.
+pub const PROTON_ELECTRON_MASS_RATIO_UNCERTAINTY: f64 = 0.000_000_11;
 
 /// Muon-to-electron mass ratio (CODATA 2018).
 /// m_μ / m_e ≈ 206.7682830
 pub const MUON_ELECTRON_MASS_RATIO: f64 = 206.768_283_0;
+/// Standard uncertainty of [`MUON_ELECTRON_MASS_RATIO`]. Source: CODATA 2018.
+pub const MUON_ELECTRON_MASS_RATIO_UNCERTAINTY: f64 = 0.000_004_6;
 
 /// Neutron-to-proton mass ratio (CODATA 2018).
 /// m_n / m_p ≈ 1.00137841931
 pub const NEUTRON_PROTON_MASS_RATIO: f64 = 1.001_378_419_31;
+/// Standard uncertainty of [`NEUTRON_PROTON_MASS_RATIO`]. Source: CODATA 2018.
+pub const NEUTRON_PROTON_MASS_RATIO_UNCERTAINTY: f64 = 0.000_000_000_49;
 
 /// Deuteron-to-proton mass ratio (CODATA 2018).
 /// m_d / m_p ≈ 1.99900750139
 pub const DEUTERON_PROTON_MASS_RATIO: f64 = 1.999_007_501_39;
+/// Standard uncertainty of [`DEUTERON_PROTON_MASS_RATIO`]. Source: CODATA 2018.
+pub const DEUTERON_PROTON_MASS_RATIO_UNCERTAINTY: f64 =
+    0.000_000_000_11;
 
 // ---------------------------------------------------------------
 // Magnetic moments — CODATA 2018
@@ -1188,30 +2196,46 @@ pub const DEUTERON_PROTON_MASS_RATIO: f64 = 1.999_007_501_39;
 /// Bohr magneton (CODATA 2018).
 /// μ_B = eℏ/(2m_e) ≈ 9.2740100783e-24 J/T
 pub const BOHR_MAGNETON: f64 = 9.274_010_078_3e-24;
+/// Standard uncertainty of [`BOHR_MAGNETON`]. Source: CODATA 2018.
+pub const BOHR_MAGNETON_UNCERTAINTY: f64 = 0.000_000_002_8e-24;
 
 /// Nuclear magneton (CODATA 2018).
 /// μ_N = eℏ/(2m_p) ≈ 5.0507837461e-27 J/T
 pub const NUCLEAR_MAGNETON: f64 = 5.050_783_746_1e-27;
+/// Standard uncertainty of [`NUCLEAR_MAGNETON`]. Source: CODATA 2018.
+pub const NUCLEAR_MAGNETON_UNCERTAINTY: f64 = 0.000_000_001_5e-27;
 
 /// Electron magnetic moment (CODATA 2018).
 /// μ_e ≈ -9.2847647043e-24 J/T
 pub const ELECTRON_MAGNETIC_MOMENT: f64 = -9.284_764_704_3e-24;
+/// Standard uncertainty of [`ELECTRON_MAGNETIC_MOMENT`]. Source: CODATA 2018.
+pub const ELECTRON_MAGNETIC_MOMENT_UNCERTAINTY: f64 =
+    0.000_000_002_8e-24;
 
 /// Proton magnetic moment (CODATA 2018).
 /// μ_p ≈ 1.41060679736e-26 J/T
 pub const PROTON_MAGNETIC_MOMENT: f64 = 1.410_606_797_36e-26;
+/// Standard uncertainty of [`PROTON_MAGNETIC_MOMENT`]. Source: CODATA 2018.
+pub const PROTON_MAGNETIC_MOMENT_UNCERTAINTY: f64 =
+    0.000_000_000_60e-26;
 
 /// Neutron magnetic moment (CODATA 2018).
 /// μ_n ≈ -9.6623651e-27 J/T
 pub const NEUTRON_MAGNETIC_MOMENT: f64 = -9.662_365_1e-27;
+/// Standard uncertainty of [`NEUTRON_MAGNETIC_MOMENT`]. Source: CODATA 2018.
+pub const NEUTRON_MAGNETIC_MOMENT_UNCERTAINTY: f64 = 0.000_002_3e-27;
 
 /// Electron g-factor (CODATA 2018).
 /// g_e ≈ -2.00231930436256
 pub const ELECTRON_G_FACTOR: f64 = -2.002_319_304_362_56;
+/// Standard uncertainty of [`ELECTRON_G_FACTOR`]. Source: CODATA 2018.
+pub const ELECTRON_G_FACTOR_UNCERTAINTY: f64 = 0.000_000_000_000_35;
 
 /// Proton g-factor (CODATA 2018).
 /// g_p ≈ 5.5856946893
 pub const PROTON_G_FACTOR: f64 = 5.585_694_689_3;
+/// Standard uncertainty of [`PROTON_G_FACTOR`]. Source: CODATA 2018.
+pub const PROTON_G_FACTOR_UNCERTAINTY: f64 = 0.000_000_001_6;
 
 // ---------------------------------------------------------------
 // Electron volt equivalents — CODATA 2018 (exact since 2019)
@@ -1228,6 +2252,8 @@ pub const EV_TO_KG: f64 = 1.782_661_921e-36;
 /// Electron volt–atomic mass unit relationship.
 /// 1 eV/c² ≈ 1.07354410233e-9 u
 pub const EV_TO_AMU: f64 = 1.073_544_102_33e-9;
+/// Standard uncertainty of [`EV_TO_AMU`]. Source: CODATA 2018.
+pub const EV_TO_AMU_UNCERTAINTY: f64 = 0.000_000_000_32e-9;
 
 /// Electron volt–hertz relationship (exact).
 /// 1 eV/h ≈ 2.417989242e14 Hz
@@ -1248,22 +2274,36 @@ pub const EV_TO_INVERSE_METER: f64 = 8.065_543_937e5;
 /// Classical electron radius (CODATA 2018).
 /// r_e = α²a_0 ≈ 2.8179403262e-15 m
 pub const CLASSICAL_ELECTRON_RADIUS: f64 = 2.817_940_326_2e-15;
+/// Standard uncertainty of [`CLASSICAL_ELECTRON_RADIUS`]. Source: CODATA 2018.
+pub const CLASSICAL_ELECTRON_RADIUS_UNCERTAINTY: f64 =
+    0.000_000_001_3e-15;
 
 /// Compton wavelength of the electron (CODATA 2018).
 /// λ_C = h/(m_e c) ≈ 2.42631023867e-12 m
 pub const ELECTRON_COMPTON_WAVELENGTH: f64 = 2.426_310_238_67e-12;
+/// Standard uncertainty of [`ELECTRON_COMPTON_WAVELENGTH`]. Source: CODATA 2018.
+pub const ELECTRON_COMPTON_WAVELENGTH_UNCERTAINTY: f64 =
+    0.000_000_000_73e-12;
 
 /// Compton wavelength of the proton (CODATA 2018).
 /// λ_C,p = h/(m_p c) ≈ 1.32140985539e-15 m
 pub const PROTON_COMPTON_WAVELENGTH: f64 = 1.321_409_855_39e-15;
+/// Standard uncertainty of [`PROTON_COMPTON_WAVELENGTH`]. Source: CODATA 2018.
+pub const PROTON_COMPTON_WAVELENGTH_UNCERTAINTY: f64 =
+    0.000_000_000_40e-15;
 
 /// Compton wavelength of the neutron (CODATA 2018).
 /// λ_C,n = h/(m_n c) ≈ 1.31959090581e-15 m
 pub const NEUTRON_COMPTON_WAVELENGTH: f64 = 1.319_590_905_81e-15;
+/// Standard uncertainty of [`NEUTRON_COMPTON_WAVELENGTH`]. Source: CODATA 2018.
+pub const NEUTRON_COMPTON_WAVELENGTH_UNCERTAINTY: f64 =
+    0.000_000_000_75e-15;
 
 /// Thomson cross section (CODATA 2018).
 /// σ_T = (8π/3)r_e² ≈ 6.6524587321e-29 m²
 pub const THOMSON_CROSS_SECTION: f64 = 6.652_458_732_1e-29;
+/// Standard uncertainty of [`THOMSON_CROSS_SECTION`]. Source: CODATA 2018.
+pub const THOMSON_CROSS_SECTION_UNCERTAINTY: f64 = 0.000_000_006_0e-29;
 
 /// First radiation constant (CODATA 2018).
 /// c_1 = 2πhc² ≈ 3.741771852e-16 W m²
@@ -1284,10 +2324,14 @@ pub const VON_KLITZING_CONSTANT: f64 = 25_812.807_45;
 /// Hartree energy (CODATA 2018).
 /// E_h = m_e c² α² ≈ 4.3597447222071e-18 J
 pub const HARTREE_ENERGY: f64 = 4.359_744_722_207_1e-18;
+/// Standard uncertainty of [`HARTREE_ENERGY`]. Source: CODATA 2018.
+pub const HARTREE_ENERGY_UNCERTAINTY: f64 = 0.000_000_000_008_5e-18;
 
 /// Hartree energy in eV (CODATA 2018).
 /// E_h ≈ 27.211386245988 eV
 pub const HARTREE_ENERGY_EV: f64 = 27.211_386_245_988;
+/// Standard uncertainty of [`HARTREE_ENERGY_EV`]. Source: CODATA 2018.
+pub const HARTREE_ENERGY_EV_UNCERTAINTY: f64 = 0.000_000_000_053;
 
 // ---------------------------------------------------------------
 // Planck units — derived from fundamental constants
@@ -1296,22 +2340,33 @@ pub const HARTREE_ENERGY_EV: f64 = 27.211_386_245_988;
 /// Planck mass.
 /// m_P = √(ℏc/G) ≈ 2.176434e-8 kg
 pub const PLANCK_MASS: f64 = 2.176_434e-8;
+/// Standard uncertainty of [`PLANCK_MASS`]. Source: CODATA 2018.
+pub const PLANCK_MASS_UNCERTAINTY: f64 = 0.000_024e-8;
 
 /// Planck length.
 /// l_P = √(ℏG/c³) ≈ 1.616255e-35 m
 pub const PLANCK_LENGTH: f64 = 1.616_255e-35;
+/// Standard uncertainty of [`PLANCK_LENGTH`]. Source: CODATA 2018.
+pub const PLANCK_LENGTH_UNCERTAINTY: f64 = 0.000_018e-35;
 
 /// Planck time.
 /// t_P = √(ℏG/c⁵) ≈ 5.391247e-44 s
 pub const PLANCK_TIME: f64 = 5.391_247e-44;
+/// Standard uncertainty of [`PLANCK_TIME`]. Source: CODATA 2018.
+pub const PLANCK_TIME_UNCERTAINTY: f64 = 0.000_060e-44;
 
 /// Planck temperature.
 /// T_P = m_P c²/k_B ≈ 1.416784e32 K
 pub const PLANCK_TEMPERATURE: f64 = 1.416_784e32;
+/// Standard uncertainty of [`PLANCK_TEMPERATURE`]. Source: CODATA 2018.
+pub const PLANCK_TEMPERATURE_UNCERTAINTY: f64 = 0.000_016e32;
 
 /// Planck charge.
 /// q_P = √(4πε₀ℏc) ≈ 1.875546e-18 C
 pub const PLANCK_CHARGE: f64 = 1.875_546e-18;
+/// Standard uncertainty of [`PLANCK_CHARGE`], propagated from
+/// [`FINE_STRUCTURE_UNCERTAINTY`] via q_P = e/√α.
+pub const PLANCK_CHARGE_UNCERTAINTY: f64 = 0.000_000_000_14e-18;
 
 // ---------------------------------------------------------------
 // Molar & thermodynamic — CODATA 2018
@@ -1320,14 +2375,17 @@ pub const PLANCK_CHARGE: f64 = 1.875_546e-18;
 /// Molar mass constant (CODATA 2018).
 /// M_u ≈ 0.99999999965e-3 kg/mol
 pub const MOLAR_MASS_CONSTANT: f64 = 0.999_999_999_65e-3;
+/// Standard uncertainty of [`MOLAR_MASS_CONSTANT`]. Source: CODATA 2018.
+pub const MOLAR_MASS_CONSTANT_UNCERTAINTY: f64 = 0.000_000_000_30e-3;
 
 /// Molar Planck constant (exact).
 /// N_A h ≈ 3.990312712e-10 J s/mol
 pub const MOLAR_PLANCK_CONSTANT: f64 = 3.990_312_712e-10;
 
-/// Loschmidt constant at 273.15 K, 101.325 kPa.
-/// n_0 ≈ 2.6867774e25 /m³
-pub const LOSCHMIDT_CONSTANT: f64 = 2.686_777_4e25;
+/// Loschmidt constant at 273.15 K, 101.325 kPa (CODATA 2018,
+/// exact since 2019 SI).
+/// n_0 = 2.686780111e25 /m³
+pub const LOSCHMIDT_CONSTANT: f64 = 2.686_780_111e25;
 
 /// Molar volume of ideal gas at STP (273.15 K, 100 kPa).
 /// V_m ≈ 22.71095e-3 m³/mol
@@ -1336,6 +2394,8 @@ pub const MOLAR_VOLUME_IDEAL_GAS: f64 = 22.710_95e-3;
 /// Sackur-Tetrode constant at 1 K, 101.325 kPa.
 /// S_0/R ≈ -1.15170753706
 pub const SACKUR_TETRODE_CONSTANT: f64 = -1.151_707_537_06;
+/// Standard uncertainty of [`SACKUR_TETRODE_CONSTANT`]. Source: CODATA 2018.
+pub const SACKUR_TETRODE_CONSTANT_UNCERTAINTY: f64 = 0.000_000_000_45;
 
 // ---------------------------------------------------------------
 // Electromagnetic — additional CODATA 2018
@@ -1344,18 +2404,27 @@ pub const SACKUR_TETRODE_CONSTANT: f64 = -1.151_707_537_06;
 /// Impedance of free space (CODATA 2018).
 /// Z_0 = μ₀c ≈ 376.730313668 Ω
 pub const IMPEDANCE_OF_FREE_SPACE: f64 = 376.730_313_668;
+/// Standard uncertainty of [`IMPEDANCE_OF_FREE_SPACE`]. Source: CODATA 2018.
+pub const IMPEDANCE_OF_FREE_SPACE_UNCERTAINTY: f64 = 0.000_000_057;
 
 /// Inverse fine-structure constant (CODATA 2018).
 /// 1/α ≈ 137.035999084
 pub const INVERSE_FINE_STRUCTURE: f64 = 137.035_999_084;
+/// Standard uncertainty of [`INVERSE_FINE_STRUCTURE`]. Source: CODATA 2018.
+pub const INVERSE_FINE_STRUCTURE_UNCERTAINTY: f64 = 0.000_000_021;
 
 /// Electron charge-to-mass quotient (CODATA 2018).
 /// e/m_e ≈ -1.75882001076e11 C/kg
 pub const ELECTRON_CHARGE_TO_MASS: f64 = -1.758_820_010_76e11;
+/// Standard uncertainty of [`ELECTRON_CHARGE_TO_MASS`]. Source: CODATA 2018.
+pub const ELECTRON_CHARGE_TO_MASS_UNCERTAINTY: f64 =
+    0.000_000_000_53e11;
 
 /// Proton charge-to-mass quotient (CODATA 2018).
 /// e/m_p ≈ 9.5788332e7 C/kg
 pub const PROTON_CHARGE_TO_MASS: f64 = 9.578_833_2e7;
+/// Standard uncertainty of [`PROTON_CHARGE_TO_MASS`]. Source: CODATA 2018.
+pub const PROTON_CHARGE_TO_MASS_UNCERTAINTY: f64 = 0.000_000_002_9e7;
 
 // ---------------------------------------------------------------
 // Atomic unit conversions — CODATA 2018
@@ -1364,26 +2433,42 @@ pub const PROTON_CHARGE_TO_MASS: f64 = 9.578_833_2e7;
 /// Atomic unit of length (= Bohr radius).
 /// a_0 ≈ 5.29177210903e-11 m
 pub const ATOMIC_UNIT_OF_LENGTH: f64 = BOHR_RADIUS;
+/// Standard uncertainty of [`ATOMIC_UNIT_OF_LENGTH`] (= [`BOHR_RADIUS_UNCERTAINTY`]).
+pub const ATOMIC_UNIT_OF_LENGTH_UNCERTAINTY: f64 =
+    BOHR_RADIUS_UNCERTAINTY;
 
 /// Atomic unit of time.
 /// ℏ/E_h ≈ 2.4188843265857e-17 s
 pub const ATOMIC_UNIT_OF_TIME: f64 = 2.418_884_326_585_7e-17;
+/// Standard uncertainty of [`ATOMIC_UNIT_OF_TIME`]. Source: CODATA 2018.
+pub const ATOMIC_UNIT_OF_TIME_UNCERTAINTY: f64 =
+    0.000_000_000_004_7e-17;
 
 /// Atomic unit of velocity.
 /// a_0 E_h/ℏ ≈ 2.18769126364e6 m/s
 pub const ATOMIC_UNIT_OF_VELOCITY: f64 = 2.187_691_263_64e6;
+/// Standard uncertainty of [`ATOMIC_UNIT_OF_VELOCITY`]. Source: CODATA 2018.
+pub const ATOMIC_UNIT_OF_VELOCITY_UNCERTAINTY: f64 = 0.000_000_000_33e6;
 
 /// Atomic unit of force.
 /// E_h/a_0 ≈ 8.2387234983e-8 N
 pub const ATOMIC_UNIT_OF_FORCE: f64 = 8.238_723_498_3e-8;
+/// Standard uncertainty of [`ATOMIC_UNIT_OF_FORCE`]. Source: CODATA 2018.
+pub const ATOMIC_UNIT_OF_FORCE_UNCERTAINTY: f64 = 0.000_000_001_2e-8;
 
 /// Atomic unit of electric field.
 /// E_h/(ea_0) ≈ 5.14220674763e11 V/m
 pub const ATOMIC_UNIT_OF_ELECTRIC_FIELD: f64 = 5.142_206_747_63e11;
+/// Standard uncertainty of [`ATOMIC_UNIT_OF_ELECTRIC_FIELD`]. Source: CODATA 2018.
+pub const ATOMIC_UNIT_OF_ELECTRIC_FIELD_UNCERTAINTY: f64 =
+    0.000_000_000_78e11;
 
 /// Atomic unit of electric polarizability.
 /// e²a₀²/E_h ≈ 1.64877727436e-41 C²m²/J
 pub const ATOMIC_UNIT_OF_POLARIZABILITY: f64 = 1.648_777_274_36e-41;
+/// Standard uncertainty of [`ATOMIC_UNIT_OF_POLARIZABILITY`]. Source: CODATA 2018.
+pub const ATOMIC_UNIT_OF_POLARIZABILITY_UNCERTAINTY: f64 =
+    0.000_000_000_50e-41;
 
 // ---------------------------------------------------------------
 // Particle masses in MeV/c² and boson masses
@@ -1392,30 +2477,44 @@ pub const ATOMIC_UNIT_OF_POLARIZABILITY: f64 = 1.648_777_274_36e-41;
 /// W boson mass (PDG 2022).
 /// m_W ≈ 80.377 GeV/c²
 pub const W_BOSON_MASS_GEV: f64 = 80.377;
+/// Standard uncertainty of [`W_BOSON_MASS_GEV`]. Source: PDG 2022.
+pub const W_BOSON_MASS_GEV_UNCERTAINTY: f64 = 0.012;
 
 /// Z boson mass (PDG 2022).
 /// m_Z ≈ 91.1876 GeV/c²
 pub const Z_BOSON_MASS_GEV: f64 = 91.1876;
+/// Standard uncertainty of [`Z_BOSON_MASS_GEV`]. Source: PDG 2022.
+pub const Z_BOSON_MASS_GEV_UNCERTAINTY: f64 = 0.002_1;
 
 /// Higgs boson mass (PDG 2022).
 /// m_H ≈ 125.25 GeV/c²
 pub const HIGGS_BOSON_MASS_GEV: f64 = 125.25;
+/// Standard uncertainty of [`HIGGS_BOSON_MASS_GEV`]. Source: PDG 2022.
+pub const HIGGS_BOSON_MASS_GEV_UNCERTAINTY: f64 = 0.17;
 
 /// Electron mass in MeV/c² (CODATA 2018).
 /// m_e c² ≈ 0.51099895000 MeV
 pub const ELECTRON_MASS_MEV: f64 = 0.510_998_950_00;
+/// Standard uncertainty of [`ELECTRON_MASS_MEV`]. Source: CODATA 2018.
+pub const ELECTRON_MASS_MEV_UNCERTAINTY: f64 = 0.000_000_000_15;
 
 /// Proton mass in MeV/c² (CODATA 2018).
 /// m_p c² ≈ 938.27208816 MeV
 pub const PROTON_MASS_MEV: f64 = 938.272_088_16;
+/// Standard uncertainty of [`PROTON_MASS_MEV`]. Source: CODATA 2018.
+pub const PROTON_MASS_MEV_UNCERTAINTY: f64 = 0.000_000_29;
 
 /// Neutron mass in MeV/c² (CODATA 2018).
 /// m_n c² ≈ 939.56542052 MeV
 pub const NEUTRON_MASS_MEV: f64 = 939.565_420_52;
+/// Standard uncertainty of [`NEUTRON_MASS_MEV`]. Source: CODATA 2018.
+pub const NEUTRON_MASS_MEV_UNCERTAINTY: f64 = 0.000_000_54;
 
 /// Muon mass in MeV/c² (CODATA 2018).
 /// m_μ c² ≈ 105.6583755 MeV
 pub const MUON_MASS_MEV: f64 = 105.658_375_5;
+/// Standard uncertainty of [`MUON_MASS_MEV`]. Source: CODATA 2018.
+pub const MUON_MASS_MEV_UNCERTAINTY: f64 = 0.000_002_3;
 
 // ---------------------------------------------------------------
 // Reduced Compton wavelengths (λ̄ = ℏ/(mc))
@@ -1424,14 +2523,23 @@ pub const MUON_MASS_MEV: f64 = 105.658_375_5;
 /// Reduced Compton wavelength of the electron.
 /// λ̄_e = ℏ/(m_e c) ≈ 3.8615926796e-13 m
 pub const ELECTRON_REDUCED_COMPTON: f64 = 3.861_592_679_6e-13;
+/// Standard uncertainty of [`ELECTRON_REDUCED_COMPTON`]. Source: CODATA 2018.
+pub const ELECTRON_REDUCED_COMPTON_UNCERTAINTY: f64 =
+    0.000_000_001_2e-13;
 
 /// Reduced Compton wavelength of the proton.
 /// λ̄_p = ℏ/(m_p c) ≈ 2.10308910336e-16 m
 pub const PROTON_REDUCED_COMPTON: f64 = 2.103_089_103_36e-16;
+/// Standard uncertainty of [`PROTON_REDUCED_COMPTON`]. Source: CODATA 2018.
+pub const PROTON_REDUCED_COMPTON_UNCERTAINTY: f64 =
+    0.000_000_000_64e-16;
 
 /// Reduced Compton wavelength of the neutron.
 /// λ̄_n = ℏ/(m_n c) ≈ 2.10019415600e-16 m
 pub const NEUTRON_REDUCED_COMPTON: f64 = 2.100_194_156_00e-16;
+/// Standard uncertainty of [`NEUTRON_REDUCED_COMPTON`]. Source: CODATA 2018.
+pub const NEUTRON_REDUCED_COMPTON_UNCERTAINTY: f64 =
+    0.000_000_001_2e-16;
 
 // ---------------------------------------------------------------
 // Molar gas constant in other units
@@ -0,0 +1,304 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Conventional symbols and common English names for the entries
+//! of [`CONSTANTS_TABLE`].
+//!
+//! [`resolve`] maps user input such as `"c"`, `"hbar"`, `"k_B"`,
+//! `"speed of light"` or `"Planck constant"` to the canonical
+//! SCREAMING_CASE name and reports which alias matched. Available
+//! in `no_std`; no allocation.
+
+use super::{lookup, ConstantEntry, CONSTANTS_TABLE};
+
+/// Conventional symbols as `(symbol, canonical name)` pairs.
+///
+/// Matched case-sensitively first, so `"G"` (gravitational
+/// constant) and `"g"` (standard gravity) stay distinct. Greek
+/// letters are listed both as Unicode and spelled out.
+pub const SYMBOLS: &[(&str, &str)] = &[
+    ("c", "SPEED_OF_LIGHT"),
+    ("h", "PLANCK"),
+    ("ħ", "PLANCK_REDUCED"),
+    ("hbar", "PLANCK_REDUCED"),
+    ("h_bar", "PLANCK_REDUCED"),
+    ("k", "BOLTZMANN"),
+    ("k_B", "BOLTZMANN"),
+    ("N_A", "AVOGADRO"),
+    ("e", "ELEMENTARY_CHARGE"),
+    ("G", "GRAVITATIONAL_CONSTANT"),
+    ("g", "STANDARD_GRAVITY"),
+    ("g_n", "STANDARD_GRAVITY"),
+    ("R", "GAS_CONSTANT"),
+    ("F", "FARADAY"),
+    ("α", "FINE_STRUCTURE"),
+    ("alpha", "FINE_STRUCTURE"),
+    ("α^-1", "INVERSE_FINE_STRUCTURE"),
+    ("alpha^-1", "INVERSE_FINE_STRUCTURE"),
+    ("ε_0", "VACUUM_PERMITTIVITY"),
+    ("epsilon_0", "VACUUM_PERMITTIVITY"),
+    ("eps0", "VACUUM_PERMITTIVITY"),
+    ("μ_0", "VACUUM_PERMEABILITY"),
+    ("mu_0", "VACUUM_PERMEABILITY"),
+    ("mu0", "VACUUM_PERMEABILITY"),
+    ("k_e", "COULOMB"),
+    ("σ", "STEFAN_BOLTZMANN"),
+    ("sigma", "STEFAN_BOLTZMANN"),
+    ("b", "WIEN_DISPLACEMENT"),
+    ("atm", "STANDARD_ATMOSPHERE"),
+    ("u", "ATOMIC_MASS_UNIT"),
+    ("m_u", "ATOMIC_MASS_UNIT"),
+    ("Da", "ATOMIC_MASS_UNIT"),
+    ("a_0", "BOHR_RADIUS"),
+    ("R_∞", "RYDBERG"),
+    ("R_inf", "RYDBERG"),
+    ("Φ_0", "MAGNETIC_FLUX_QUANTUM"),
+    ("Phi_0", "MAGNETIC_FLUX_QUANTUM"),
+    ("G_0", "CONDUCTANCE_QUANTUM"),
+    ("m_e", "ELECTRON_MASS"),
+    ("m_p", "PROTON_MASS"),
+    ("m_n", "NEUTRON_MASS"),
+    ("m_μ", "MUON_MASS"),
+    ("m_mu", "MUON_MASS"),
+    ("m_τ", "TAU_PARTICLE_MASS"),
+    ("m_tau", "TAU_PARTICLE_MASS"),
+    ("m_d", "DEUTERON_MASS"),
+    ("m_t", "TRITON_MASS"),
+    ("m_h", "HELION_MASS"),
+    ("m_α", "ALPHA_PARTICLE_MASS"),
+    ("m_alpha", "ALPHA_PARTICLE_MASS"),
+    ("μ_B", "BOHR_MAGNETON"),
+    ("mu_B", "BOHR_MAGNETON"),
+    ("μ_N", "NUCLEAR_MAGNETON"),
+    ("mu_N", "NUCLEAR_MAGNETON"),
+    ("μ_e", "ELECTRON_MAGNETIC_MOMENT"),
+    ("mu_e", "ELECTRON_MAGNETIC_MOMENT"),
+    ("μ_p", "PROTON_MAGNETIC_MOMENT"),
+    ("mu_p", "PROTON_MAGNETIC_MOMENT"),
+    ("μ_n", "NEUTRON_MAGNETIC_MOMENT"),
+    ("mu_n", "NEUTRON_MAGNETIC_MOMENT"),
+    ("g_e", "ELECTRON_G_FACTOR"),
+    ("g_p", "PROTON_G_FACTOR"),
+    ("eV", "ELECTRON_VOLT"),
+    ("r_e", "CLASSICAL_ELECTRON_RADIUS"),
+    ("λ_C", "ELECTRON_COMPTON_WAVELENGTH"),
+    ("lambda_C", "ELECTRON_COMPTON_WAVELENGTH"),
+    ("ƛ_C", "ELECTRON_REDUCED_COMPTON"),
+    ("σ_e", "THOMSON_CROSS_SECTION"),
+    ("sigma_e", "THOMSON_CROSS_SECTION"),
+    ("c_1", "FIRST_RADIATION_CONSTANT"),
+    ("c_2", "SECOND_RADIATION_CONSTANT"),
+    ("K_J", "JOSEPHSON_CONSTANT"),
+    ("R_K", "VON_KLITZING_CONSTANT"),
+    ("E_h", "HARTREE_ENERGY"),
+    ("m_P", "PLANCK_MASS"),
+    ("l_P", "PLANCK_LENGTH"),
+    ("t_P", "PLANCK_TIME"),
+    ("T_P", "PLANCK_TEMPERATURE"),
+    ("q_P", "PLANCK_CHARGE"),
+    ("M_u", "MOLAR_MASS_CONSTANT"),
+    ("n_0", "LOSCHMIDT_CONSTANT"),
+    ("V_m", "MOLAR_VOLUME_IDEAL_GAS"),
+    ("Z_0", "IMPEDANCE_OF_FREE_SPACE"),
+    ("m_W", "W_BOSON_MASS_GEV"),
+    ("m_Z", "Z_BOSON_MASS_GEV"),
+    ("m_H", "HIGGS_BOSON_MASS_GEV"),
+    ("π", "PI"),
+    ("τ", "TAU"),
+    ("φ", "PHI"),
+    ("γ", "GAMMA"),
+    ("ζ(3)", "APERY"),
+    ("√2", "SQRT2"),
+    ("√3", "SQRT3"),
+    ("√5", "SQRT5"),
+    ("ln2", "LN_2"),
+    ("ln10", "LN_10"),
+];
+
+/// Common English names as `(name, canonical name)` pairs.
+///
+/// Matched ignoring ASCII/Unicode case, spaces, hyphens and
+/// underscores. Names that already normalise to a canonical
+/// identifier (e.g. `"electron mass"`) are not listed.
+pub const NAMES: &[(&str, &str)] = &[
+    ("speed of light in vacuum", "SPEED_OF_LIGHT"),
+    ("planck constant", "PLANCK"),
+    ("planck's constant", "PLANCK"),
+    ("reduced planck constant", "PLANCK_REDUCED"),
+    ("dirac constant", "PLANCK_REDUCED"),
+    ("boltzmann constant", "BOLTZMANN"),
+    ("boltzmann's constant", "BOLTZMANN"),
+    ("avogadro constant", "AVOGADRO"),
+    ("avogadro number", "AVOGADRO"),
+    ("avogadro's number", "AVOGADRO"),
+    (
+        "newtonian constant of gravitation",
+        "GRAVITATIONAL_CONSTANT",
+    ),
+    ("newton's constant", "GRAVITATIONAL_CONSTANT"),
+    ("big g", "GRAVITATIONAL_CONSTANT"),
+    ("molar gas constant", "GAS_CONSTANT"),
+    ("universal gas constant", "GAS_CONSTANT"),
+    ("ideal gas constant", "GAS_CONSTANT"),
+    ("faraday constant", "FARADAY"),
+    ("fine-structure constant", "FINE_STRUCTURE"),
+    ("inverse fine-structure constant", "INVERSE_FINE_STRUCTURE"),
+    ("electric constant", "VACUUM_PERMITTIVITY"),
+    ("permittivity of free space", "VACUUM_PERMITTIVITY"),
+    ("magnetic constant", "VACUUM_PERMEABILITY"),
+    ("permeability of free space", "VACUUM_PERMEABILITY"),
+    ("coulomb constant", "COULOMB"),
+    ("coulomb's constant", "COULOMB"),
+    ("stefan-boltzmann constant", "STEFAN_BOLTZMANN"),
+    ("wien displacement law constant", "WIEN_DISPLACEMENT"),
+    ("wien's constant", "WIEN_DISPLACEMENT"),
+    ("standard acceleration of gravity", "STANDARD_GRAVITY"),
+    ("unified atomic mass unit", "ATOMIC_MASS_UNIT"),
+    ("dalton", "ATOMIC_MASS_UNIT"),
+    ("rydberg constant", "RYDBERG"),
+    ("tau mass", "TAU_PARTICLE_MASS"),
+    ("compton wavelength", "ELECTRON_COMPTON_WAVELENGTH"),
+    ("hartree", "HARTREE_ENERGY"),
+    ("molar volume", "MOLAR_VOLUME_IDEAL_GAS"),
+    (
+        "characteristic impedance of vacuum",
+        "IMPEDANCE_OF_FREE_SPACE",
+    ),
+    ("vacuum impedance", "IMPEDANCE_OF_FREE_SPACE"),
+    ("golden ratio", "PHI"),
+    ("euler's number", "EULER"),
+    ("napier's constant", "EULER"),
+    ("euler-mascheroni constant", "GAMMA"),
+    ("apery's constant", "APERY"),
+    ("catalan's constant", "CATALAN"),
+    ("khinchin's constant", "KHINCHIN"),
+    ("glaisher-kinkelin constant", "GLAISHER_KINKELIN"),
+    ("w boson mass", "W_BOSON_MASS_GEV"),
+    ("z boson mass", "Z_BOSON_MASS_GEV"),
+    ("higgs boson mass", "HIGGS_BOSON_MASS_GEV"),
+    ("higgs mass", "HIGGS_BOSON_MASS_GEV"),
+    ("pythagoras' constant", "SQRT2"),
+];
+
+/// How a query was matched by [`resolve`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MatchKind {
+    /// The query is the canonical name.
+    Exact,
+    /// The query is a conventional symbol from [`SYMBOLS`].
+    Symbol,
+    /// The query equals the canonical name ignoring case, spaces,
+    /// hyphens and underscores (e.g. `"speed of light"`).
+    Normalized,
+    /// The query is an English name from [`NAMES`].
+    Name,
+    /// The query equals a symbol ignoring case and separators
+    /// (e.g. `"K_B"`), and no other symbol matches that way.
+    SymbolNormalized,
+}
+
+/// The outcome of [`resolve`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Resolved {
+    /// The canonical constant name.
+    pub name: &'static str,
+    /// The registry key that matched: the alias, or the canonical
+    /// name for [`MatchKind::Exact`] and [`MatchKind::Normalized`].
+    pub matched: &'static str,
+    /// How the query matched.
+    pub kind: MatchKind,
+}
+
+impl Resolved {
+    /// Returns the [`CONSTANTS_TABLE`] entry of the resolved name.
+    pub fn entry(&self) -> Option<&'static ConstantEntry> {
+        lookup(self.name)
+    }
+}
+
+/// Resolves a canonical name, symbol or English name to its
+/// canonical constant name.
+///
+/// Tried in order: exact canonical name, case-sensitive symbol,
+/// normalised canonical name, normalised English name, then
+/// normalised symbol when exactly one symbol matches. Returns
+/// `None` when nothing matches.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::aliases::{resolve, MatchKind};
+///
+/// let hbar = resolve("hbar").unwrap();
+/// assert_eq!(hbar.name, "PLANCK_REDUCED");
+/// assert_eq!(hbar.kind, MatchKind::Symbol);
+///
+/// let c = resolve("speed of light").unwrap();
+/// assert_eq!(c.name, "SPEED_OF_LIGHT");
+/// assert_eq!(c.kind, MatchKind::Normalized);
+///
+/// let h = resolve("Planck's constant").unwrap();
+/// assert_eq!((h.name, h.matched), ("PLANCK", "planck's constant"));
+/// assert_eq!(h.entry().unwrap().unit.symbol, "J s");
+///
+/// assert!(resolve("not a constant").is_none());
+/// ```
+pub fn resolve(query: &str) -> Option<Resolved> {
+    if let Some(entry) = lookup(query) {
+        return Some(Resolved {
+            name: entry.name,
+            matched: entry.name,
+            kind: MatchKind::Exact,
+        });
+    }
+    if let Some(&(symbol, name)) =
+        SYMBOLS.iter().find(|(symbol, _)| *symbol == query)
+    {
+        return Some(Resolved {
+            name,
+            matched: symbol,
+            kind: MatchKind::Symbol,
+        });
+    }
+    if let Some(entry) = CONSTANTS_TABLE
+        .iter()
+        .find(|entry| normalized_eq(entry.name, query))
+    {
+        return Some(Resolved {
+            name: entry.name,
+            matched: entry.name,
+            kind: MatchKind::Normalized,
+        });
+    }
+    if let Some(&(alias, name)) =
+        NAMES.iter().find(|(alias, _)| normalized_eq(alias, query))
+    {
+        return Some(Resolved {
+            name,
+            matched: alias,
+            kind: MatchKind::Name,
+        });
+    }
+    let mut matches = SYMBOLS
+        .iter()
+        .filter(|(symbol, _)| normalized_eq(symbol, query));
+    match (matches.next(), matches.next()) {
+        (Some(&(symbol, name)), None) => Some(Resolved {
+            name,
+            matched: symbol,
+            kind: MatchKind::SymbolNormalized,
+        }),
+        _ => None,
+    }
+}
+
+/// Returns `true` if `a` and `b` are equal ignoring case, spaces,
+/// hyphens and underscores.
+pub(crate) fn normalized_eq(a: &str, b: &str) -> bool {
+    fn normalize(s: &str) -> impl Iterator<Item = char> + '_ {
+        s.chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '_'))
+            .flat_map(char::to_lowercase)
+    }
+    normalize(a).eq(normalize(b))
+}
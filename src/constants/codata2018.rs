@@ -0,0 +1,2081 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! CODATA 2018 recommended values.
+//!
+//! This is the edition the top-level `const` items in
+//! [`crate::constants`] are taken from, and the default
+//! [`Dataset`]. The values that were re-adjusted in
+//! CODATA 2022 are re-exported here so that
+//! `codata2018::ELECTRON_MASS` and `codata2022::ELECTRON_MASS` can
+//! be used side by side.
+
+use super::*;
+use crate::units as unit;
+use Category::{Mathematical as M, Physical as P};
+use Uncertainty::{Exact, Standard};
+
+pub use super::{
+    ALPHA_PARTICLE_MASS, ALPHA_PARTICLE_MASS_UNCERTAINTY,
+    ATOMIC_MASS_UNIT, ATOMIC_MASS_UNIT_UNCERTAINTY,
+    ATOMIC_UNIT_OF_ELECTRIC_FIELD,
+    ATOMIC_UNIT_OF_ELECTRIC_FIELD_UNCERTAINTY, ATOMIC_UNIT_OF_FORCE,
+    ATOMIC_UNIT_OF_FORCE_UNCERTAINTY, ATOMIC_UNIT_OF_LENGTH,
+    ATOMIC_UNIT_OF_LENGTH_UNCERTAINTY, ATOMIC_UNIT_OF_POLARIZABILITY,
+    ATOMIC_UNIT_OF_POLARIZABILITY_UNCERTAINTY, ATOMIC_UNIT_OF_TIME,
+    ATOMIC_UNIT_OF_TIME_UNCERTAINTY, ATOMIC_UNIT_OF_VELOCITY,
+    ATOMIC_UNIT_OF_VELOCITY_UNCERTAINTY, BOHR_MAGNETON,
+    BOHR_MAGNETON_UNCERTAINTY, BOHR_RADIUS, BOHR_RADIUS_UNCERTAINTY,
+    CLASSICAL_ELECTRON_RADIUS, CLASSICAL_ELECTRON_RADIUS_UNCERTAINTY,
+    COULOMB, COULOMB_UNCERTAINTY, DEUTERON_MASS,
+    DEUTERON_MASS_UNCERTAINTY, DEUTERON_PROTON_MASS_RATIO,
+    DEUTERON_PROTON_MASS_RATIO_UNCERTAINTY, ELECTRON_CHARGE_TO_MASS,
+    ELECTRON_CHARGE_TO_MASS_UNCERTAINTY, ELECTRON_COMPTON_WAVELENGTH,
+    ELECTRON_COMPTON_WAVELENGTH_UNCERTAINTY, ELECTRON_G_FACTOR,
+    ELECTRON_G_FACTOR_UNCERTAINTY, ELECTRON_MAGNETIC_MOMENT,
+    ELECTRON_MAGNETIC_MOMENT_UNCERTAINTY, ELECTRON_MASS,
+    ELECTRON_MASS_MEV, ELECTRON_MASS_MEV_UNCERTAINTY,
+    ELECTRON_MASS_UNCERTAINTY, ELECTRON_PROTON_MASS_RATIO,
+    ELECTRON_PROTON_MASS_RATIO_UNCERTAINTY, ELECTRON_REDUCED_COMPTON,
+    ELECTRON_REDUCED_COMPTON_UNCERTAINTY, EV_TO_AMU,
+    EV_TO_AMU_UNCERTAINTY, FINE_STRUCTURE, FINE_STRUCTURE_UNCERTAINTY,
+    HARTREE_ENERGY, HARTREE_ENERGY_EV, HARTREE_ENERGY_EV_UNCERTAINTY,
+    HARTREE_ENERGY_UNCERTAINTY, HELION_MASS, HELION_MASS_UNCERTAINTY,
+    IMPEDANCE_OF_FREE_SPACE, IMPEDANCE_OF_FREE_SPACE_UNCERTAINTY,
+    INVERSE_FINE_STRUCTURE, INVERSE_FINE_STRUCTURE_UNCERTAINTY,
+    MOLAR_MASS_CONSTANT, MOLAR_MASS_CONSTANT_UNCERTAINTY,
+    MUON_ELECTRON_MASS_RATIO, MUON_ELECTRON_MASS_RATIO_UNCERTAINTY,
+    NEUTRON_COMPTON_WAVELENGTH, NEUTRON_COMPTON_WAVELENGTH_UNCERTAINTY,
+    NEUTRON_MAGNETIC_MOMENT, NEUTRON_MAGNETIC_MOMENT_UNCERTAINTY,
+    NEUTRON_MASS, NEUTRON_MASS_MEV, NEUTRON_MASS_MEV_UNCERTAINTY,
+    NEUTRON_MASS_UNCERTAINTY, NEUTRON_PROTON_MASS_RATIO,
+    NEUTRON_PROTON_MASS_RATIO_UNCERTAINTY, NEUTRON_REDUCED_COMPTON,
+    NEUTRON_REDUCED_COMPTON_UNCERTAINTY, NUCLEAR_MAGNETON,
+    NUCLEAR_MAGNETON_UNCERTAINTY, PROTON_CHARGE_TO_MASS,
+    PROTON_CHARGE_TO_MASS_UNCERTAINTY, PROTON_COMPTON_WAVELENGTH,
+    PROTON_COMPTON_WAVELENGTH_UNCERTAINTY, PROTON_ELECTRON_MASS_RATIO,
+    PROTON_ELECTRON_MASS_RATIO_UNCERTAINTY, PROTON_MAGNETIC_MOMENT,
+    PROTON_MAGNETIC_MOMENT_UNCERTAINTY, PROTON_MASS, PROTON_MASS_MEV,
+    PROTON_MASS_MEV_UNCERTAINTY, PROTON_MASS_UNCERTAINTY,
+    PROTON_REDUCED_COMPTON, PROTON_REDUCED_COMPTON_UNCERTAINTY,
+    RYDBERG, RYDBERG_UNCERTAINTY, SACKUR_TETRODE_CONSTANT,
+    SACKUR_TETRODE_CONSTANT_UNCERTAINTY, THOMSON_CROSS_SECTION,
+    THOMSON_CROSS_SECTION_UNCERTAINTY, TRITON_MASS,
+    TRITON_MASS_UNCERTAINTY, VACUUM_PERMEABILITY,
+    VACUUM_PERMEABILITY_UNCERTAINTY, VACUUM_PERMITTIVITY,
+    VACUUM_PERMITTIVITY_UNCERTAINTY,
+};
+
+/// The constants whose CODATA 2018 values differ from CODATA 2022,
+/// as `(name, value, standard uncertainty)`.
+pub const ADJUSTED: &[(&str, f64, f64)] = &[
+    (
+        "VACUUM_PERMEABILITY",
+        VACUUM_PERMEABILITY,
+        VACUUM_PERMEABILITY_UNCERTAINTY,
+    ),
+    (
+        "VACUUM_PERMITTIVITY",
+        VACUUM_PERMITTIVITY,
+        VACUUM_PERMITTIVITY_UNCERTAINTY,
+    ),
+    ("COULOMB", COULOMB, COULOMB_UNCERTAINTY),
+    ("ELECTRON_MASS", ELECTRON_MASS, ELECTRON_MASS_UNCERTAINTY),
+    ("PROTON_MASS", PROTON_MASS, PROTON_MASS_UNCERTAINTY),
+    ("NEUTRON_MASS", NEUTRON_MASS, NEUTRON_MASS_UNCERTAINTY),
+    (
+        "ATOMIC_MASS_UNIT",
+        ATOMIC_MASS_UNIT,
+        ATOMIC_MASS_UNIT_UNCERTAINTY,
+    ),
+    ("BOHR_RADIUS", BOHR_RADIUS, BOHR_RADIUS_UNCERTAINTY),
+    ("FINE_STRUCTURE", FINE_STRUCTURE, FINE_STRUCTURE_UNCERTAINTY),
+    ("RYDBERG", RYDBERG, RYDBERG_UNCERTAINTY),
+    ("DEUTERON_MASS", DEUTERON_MASS, DEUTERON_MASS_UNCERTAINTY),
+    ("TRITON_MASS", TRITON_MASS, TRITON_MASS_UNCERTAINTY),
+    ("HELION_MASS", HELION_MASS, HELION_MASS_UNCERTAINTY),
+    (
+        "ALPHA_PARTICLE_MASS",
+        ALPHA_PARTICLE_MASS,
+        ALPHA_PARTICLE_MASS_UNCERTAINTY,
+    ),
+    (
+        "ELECTRON_PROTON_MASS_RATIO",
+        ELECTRON_PROTON_MASS_RATIO,
+        ELECTRON_PROTON_MASS_RATIO_UNCERTAINTY,
+    ),
+    (
+        "PROTON_ELECTRON_MASS_RATIO",
+        PROTON_ELECTRON_MASS_RATIO,
+        PROTON_ELECTRON_MASS_RATIO_UNCERTAINTY,
+    ),
+    (
+        "MUON_ELECTRON_MASS_RATIO",
+        MUON_ELECTRON_MASS_RATIO,
+        MUON_ELECTRON_MASS_RATIO_UNCERTAINTY,
+    ),
+    (
+        "NEUTRON_PROTON_MASS_RATIO",
+        NEUTRON_PROTON_MASS_RATIO,
+        NEUTRON_PROTON_MASS_RATIO_UNCERTAINTY,
+    ),
+    (
+        "DEUTERON_PROTON_MASS_RATIO",
+        DEUTERON_PROTON_MASS_RATIO,
+        DEUTERON_PROTON_MASS_RATIO_UNCERTAINTY,
+    ),
+    ("BOHR_MAGNETON", BOHR_MAGNETON, BOHR_MAGNETON_UNCERTAINTY),
+    (
+        "NUCLEAR_MAGNETON",
+        NUCLEAR_MAGNETON,
+        NUCLEAR_MAGNETON_UNCERTAINTY,
+    ),
+    (
+        "ELECTRON_MAGNETIC_MOMENT",
+        ELECTRON_MAGNETIC_MOMENT,
+        ELECTRON_MAGNETIC_MOMENT_UNCERTAINTY,
+    ),
+    (
+        "PROTON_MAGNETIC_MOMENT",
+        PROTON_MAGNETIC_MOMENT,
+        PROTON_MAGNETIC_MOMENT_UNCERTAINTY,
+    ),
+    (
+        "NEUTRON_MAGNETIC_MOMENT",
+        NEUTRON_MAGNETIC_MOMENT,
+        NEUTRON_MAGNETIC_MOMENT_UNCERTAINTY,
+    ),
+    (
+        "ELECTRON_G_FACTOR",
+        ELECTRON_G_FACTOR,
+        ELECTRON_G_FACTOR_UNCERTAINTY,
+    ),
+    ("EV_TO_AMU", EV_TO_AMU, EV_TO_AMU_UNCERTAINTY),
+    (
+        "CLASSICAL_ELECTRON_RADIUS",
+        CLASSICAL_ELECTRON_RADIUS,
+        CLASSICAL_ELECTRON_RADIUS_UNCERTAINTY,
+    ),
+    (
+        "ELECTRON_COMPTON_WAVELENGTH",
+        ELECTRON_COMPTON_WAVELENGTH,
+        ELECTRON_COMPTON_WAVELENGTH_UNCERTAINTY,
+    ),
+    (
+        "PROTON_COMPTON_WAVELENGTH",
+        PROTON_COMPTON_WAVELENGTH,
+        PROTON_COMPTON_WAVELENGTH_UNCERTAINTY,
+    ),
+    (
+        "NEUTRON_COMPTON_WAVELENGTH",
+        NEUTRON_COMPTON_WAVELENGTH,
+        NEUTRON_COMPTON_WAVELENGTH_UNCERTAINTY,
+    ),
+    (
+        "THOMSON_CROSS_SECTION",
+        THOMSON_CROSS_SECTION,
+        THOMSON_CROSS_SECTION_UNCERTAINTY,
+    ),
+    ("HARTREE_ENERGY", HARTREE_ENERGY, HARTREE_ENERGY_UNCERTAINTY),
+    (
+        "HARTREE_ENERGY_EV",
+        HARTREE_ENERGY_EV,
+        HARTREE_ENERGY_EV_UNCERTAINTY,
+    ),
+    (
+        "MOLAR_MASS_CONSTANT",
+        MOLAR_MASS_CONSTANT,
+        MOLAR_MASS_CONSTANT_UNCERTAINTY,
+    ),
+    (
+        "SACKUR_TETRODE_CONSTANT",
+        SACKUR_TETRODE_CONSTANT,
+        SACKUR_TETRODE_CONSTANT_UNCERTAINTY,
+    ),
+    (
+        "IMPEDANCE_OF_FREE_SPACE",
+        IMPEDANCE_OF_FREE_SPACE,
+        IMPEDANCE_OF_FREE_SPACE_UNCERTAINTY,
+    ),
+    (
+        "INVERSE_FINE_STRUCTURE",
+        INVERSE_FINE_STRUCTURE,
+        INVERSE_FINE_STRUCTURE_UNCERTAINTY,
+    ),
+    (
+        "ELECTRON_CHARGE_TO_MASS",
+        ELECTRON_CHARGE_TO_MASS,
+        ELECTRON_CHARGE_TO_MASS_UNCERTAINTY,
+    ),
+    (
+        "PROTON_CHARGE_TO_MASS",
+        PROTON_CHARGE_TO_MASS,
+        PROTON_CHARGE_TO_MASS_UNCERTAINTY,
+    ),
+    (
+        "ATOMIC_UNIT_OF_LENGTH",
+        ATOMIC_UNIT_OF_LENGTH,
+        ATOMIC_UNIT_OF_LENGTH_UNCERTAINTY,
+    ),
+    (
+        "ATOMIC_UNIT_OF_TIME",
+        ATOMIC_UNIT_OF_TIME,
+        ATOMIC_UNIT_OF_TIME_UNCERTAINTY,
+    ),
+    (
+        "ATOMIC_UNIT_OF_VELOCITY",
+        ATOMIC_UNIT_OF_VELOCITY,
+        ATOMIC_UNIT_OF_VELOCITY_UNCERTAINTY,
+    ),
+    (
+        "ATOMIC_UNIT_OF_FORCE",
+        ATOMIC_UNIT_OF_FORCE,
+        ATOMIC_UNIT_OF_FORCE_UNCERTAINTY,
+    ),
+    (
+        "ATOMIC_UNIT_OF_ELECTRIC_FIELD",
+        ATOMIC_UNIT_OF_ELECTRIC_FIELD,
+        ATOMIC_UNIT_OF_ELECTRIC_FIELD_UNCERTAINTY,
+    ),
+    (
+        "ATOMIC_UNIT_OF_POLARIZABILITY",
+        ATOMIC_UNIT_OF_POLARIZABILITY,
+        ATOMIC_UNIT_OF_POLARIZABILITY_UNCERTAINTY,
+    ),
+    (
+        "ELECTRON_MASS_MEV",
+        ELECTRON_MASS_MEV,
+        ELECTRON_MASS_MEV_UNCERTAINTY,
+    ),
+    (
+        "PROTON_MASS_MEV",
+        PROTON_MASS_MEV,
+        PROTON_MASS_MEV_UNCERTAINTY,
+    ),
+    (
+        "NEUTRON_MASS_MEV",
+        NEUTRON_MASS_MEV,
+        NEUTRON_MASS_MEV_UNCERTAINTY,
+    ),
+    (
+        "ELECTRON_REDUCED_COMPTON",
+        ELECTRON_REDUCED_COMPTON,
+        ELECTRON_REDUCED_COMPTON_UNCERTAINTY,
+    ),
+    (
+        "PROTON_REDUCED_COMPTON",
+        PROTON_REDUCED_COMPTON,
+        PROTON_REDUCED_COMPTON_UNCERTAINTY,
+    ),
+    (
+        "NEUTRON_REDUCED_COMPTON",
+        NEUTRON_REDUCED_COMPTON,
+        NEUTRON_REDUCED_COMPTON_UNCERTAINTY,
+    ),
+];
+
+/// Every constant with its CODATA 2018 value, category, unit,
+/// uncertainty and metadata.
+pub const TABLE: &[ConstantEntry] = &[
+    entry(
+        "APERY",
+        APERY,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Apéry's constant, ζ(3)",
+            "ζ(3)",
+            r"\zeta(3)",
+            Some("Σ 1/n³"),
+        ),
+        Tags::of(&[Tag::NumberTheory]),
+    ),
+    entry(
+        "AVOGADRO",
+        AVOGADRO,
+        P,
+        unit::PER_MOLE,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Avogadro constant",
+            "N_A",
+            r"N_\mathrm{A}",
+            None,
+        ),
+        Tags::of(&[Tag::SiDefining, Tag::PhysicoChemical]),
+    ),
+    entry(
+        "BOLTZMANN",
+        BOLTZMANN,
+        P,
+        unit::JOULE_PER_KELVIN,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Boltzmann constant",
+            "k",
+            "k",
+            None,
+        ),
+        Tags::of(&[Tag::SiDefining, Tag::Thermodynamic]),
+    ),
+    entry(
+        "CATALAN",
+        CATALAN,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Catalan's constant",
+            "G",
+            "G",
+            Some("Σ (-1)ⁿ/(2n+1)²"),
+        ),
+        Tags::of(&[Tag::NumberTheory]),
+    ),
+    entry(
+        "COULOMB",
+        COULOMB,
+        P,
+        unit::NEWTON_SQUARE_METER_PER_COULOMB_SQUARED,
+        Standard(COULOMB_UNCERTAINTY),
+        describe(
+            Source::Derived,
+            "Coulomb constant",
+            "kₑ",
+            r"k_\mathrm{e}",
+            Some("1/(4πε₀)"),
+        ),
+        Tags::of(&[Tag::Electromagnetic]),
+    ),
+    entry(
+        "EULER",
+        EULER,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Euler's number, the base of the natural logarithm",
+            "e",
+            "e",
+            Some("lim (1 + 1/n)ⁿ"),
+        ),
+        Tags::of(&[Tag::Logarithmic]),
+    ),
+    entry(
+        "FARADAY",
+        FARADAY,
+        P,
+        unit::COULOMB_PER_MOLE,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Faraday constant",
+            "F",
+            "F",
+            Some("N_A e"),
+        ),
+        Tags::of(&[Tag::PhysicoChemical]),
+    ),
+    entry(
+        "GAMMA",
+        GAMMA,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Euler–Mascheroni constant",
+            "γ",
+            r"\gamma",
+            Some("lim (Σ 1/k − ln n)"),
+        ),
+        Tags::of(&[Tag::NumberTheory]),
+    ),
+    entry(
+        "GAS_CONSTANT",
+        GAS_CONSTANT,
+        P,
+        unit::JOULE_PER_MOLE_KELVIN,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Molar gas constant",
+            "R",
+            "R",
+            Some("N_A k"),
+        ),
+        Tags::of(&[Tag::Thermodynamic, Tag::PhysicoChemical]),
+    ),
+    entry(
+        "GLAISHER_KINKELIN",
+        GLAISHER_KINKELIN,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Glaisher–Kinkelin constant",
+            "A",
+            "A",
+            None,
+        ),
+        Tags::of(&[Tag::NumberTheory]),
+    ),
+    entry(
+        "GRAVITATIONAL_CONSTANT",
+        GRAVITATIONAL_CONSTANT,
+        P,
+        unit::CUBIC_METER_PER_KILOGRAM_SECOND_SQUARED,
+        Standard(GRAVITATIONAL_CONSTANT_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Newtonian constant of gravitation",
+            "G",
+            "G",
+            None,
+        ),
+        Tags::of(&[Tag::Universal, Tag::Gravitation]),
+    ),
+    utility_entry(
+        "HASH_ALGORITHM",
+        ConstValue::Str(HASH_ALGORITHM),
+        describe(
+            Source::Cmn,
+            "Default password hash algorithm",
+            "",
+            "",
+            None,
+        ),
+        Tags::of(&[Tag::Hashing]),
+    ),
+    utility_entry(
+        "HASH_COST",
+        ConstValue::U32(HASH_COST),
+        describe(
+            Source::Cmn,
+            "Default password hash cost factor",
+            "",
+            "",
+            None,
+        ),
+        Tags::of(&[Tag::Hashing]),
+    ),
+    utility_entry(
+        "HASH_LENGTH",
+        ConstValue::Usize(HASH_LENGTH),
+        describe(
+            Source::Cmn,
+            "Default password hash length in bytes",
+            "",
+            "",
+            None,
+        ),
+        Tags::of(&[Tag::Hashing]),
+    ),
+    entry(
+        "KHINCHIN",
+        KHINCHIN,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Khinchin's constant",
+            "K₀",
+            "K_0",
+            None,
+        ),
+        Tags::of(&[Tag::NumberTheory]),
+    ),
+    entry(
+        "PHI",
+        PHI,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Golden ratio",
+            "φ",
+            r"\varphi",
+            Some("(1 + √5)/2"),
+        ),
+        Tags::of(&[Tag::MetallicMean]),
+    ),
+    entry(
+        "PI",
+        PI,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Ratio of a circle's circumference to its diameter",
+            "π",
+            r"\pi",
+            None,
+        ),
+        Tags::of(&[Tag::Circle]),
+    ),
+    entry(
+        "PLANCK",
+        PLANCK,
+        P,
+        unit::JOULE_SECOND,
+        Exact,
+        describe(Source::Codata2018, "Planck constant", "h", "h", None),
+        Tags::of(&[Tag::SiDefining, Tag::Universal]),
+    ),
+    entry(
+        "PLANCK_REDUCED",
+        PLANCK_REDUCED,
+        P,
+        unit::JOULE_SECOND,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Reduced Planck constant",
+            "ħ",
+            r"\hbar",
+            Some("h/(2π)"),
+        ),
+        Tags::of(&[Tag::Universal]),
+    ),
+    entry(
+        "SILVER_RATIO",
+        SILVER_RATIO,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Silver ratio",
+            "δ_S",
+            r"\delta_S",
+            Some("1 + √2"),
+        ),
+        Tags::of(&[Tag::MetallicMean]),
+    ),
+    entry(
+        "SPEED_OF_LIGHT",
+        SPEED_OF_LIGHT,
+        P,
+        unit::METER_PER_SECOND,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Speed of light in vacuum",
+            "c",
+            "c",
+            None,
+        ),
+        Tags::of(&[Tag::SiDefining, Tag::Universal]),
+    ),
+    utility_entry(
+        "SPECIAL_CHARS",
+        ConstValue::Chars(SPECIAL_CHARS),
+        describe(
+            Source::Cmn,
+            "Special characters accepted in passwords",
+            "",
+            "",
+            None,
+        ),
+        Tags::of(&[Tag::CharacterSet]),
+    ),
+    entry(
+        "SQRT2",
+        SQRT2,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Square root of 2",
+            "√2",
+            r"\sqrt{2}",
+            None,
+        ),
+        Tags::of(&[Tag::Root]),
+    ),
+    entry(
+        "SQRT3",
+        SQRT3,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Square root of 3",
+            "√3",
+            r"\sqrt{3}",
+            None,
+        ),
+        Tags::of(&[Tag::Root]),
+    ),
+    entry(
+        "SQRT5",
+        SQRT5,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Square root of 5",
+            "√5",
+            r"\sqrt{5}",
+            None,
+        ),
+        Tags::of(&[Tag::Root]),
+    ),
+    entry(
+        "TAU",
+        TAU,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Circle constant, circumference over radius",
+            "τ",
+            r"\tau",
+            Some("2π"),
+        ),
+        Tags::of(&[Tag::Circle]),
+    ),
+    entry(
+        "VACUUM_PERMEABILITY",
+        VACUUM_PERMEABILITY,
+        P,
+        unit::NEWTON_PER_AMPERE_SQUARED,
+        Standard(VACUUM_PERMEABILITY_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Vacuum magnetic permeability",
+            "μ₀",
+            r"\mu_0",
+            Some("2αh/(e²c)"),
+        ),
+        Tags::of(&[Tag::Universal, Tag::Electromagnetic]),
+    ),
+    entry(
+        "VACUUM_PERMITTIVITY",
+        VACUUM_PERMITTIVITY,
+        P,
+        unit::FARAD_PER_METER,
+        Standard(VACUUM_PERMITTIVITY_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Vacuum electric permittivity",
+            "ε₀",
+            r"\varepsilon_0",
+            Some("1/(μ₀c²)"),
+        ),
+        Tags::of(&[Tag::Universal, Tag::Electromagnetic]),
+    ),
+    entry(
+        "LN_2",
+        LN_2,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Natural logarithm of 2",
+            "ln 2",
+            r"\ln 2",
+            None,
+        ),
+        Tags::of(&[Tag::Logarithmic]),
+    ),
+    entry(
+        "LN_10",
+        LN_10,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Natural logarithm of 10",
+            "ln 10",
+            r"\ln 10",
+            None,
+        ),
+        Tags::of(&[Tag::Logarithmic]),
+    ),
+    entry(
+        "LOG2_E",
+        LOG2_E,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Base-2 logarithm of e",
+            "log₂ e",
+            r"\log_2 e",
+            Some("1/ln 2"),
+        ),
+        Tags::of(&[Tag::Logarithmic]),
+    ),
+    entry(
+        "LOG10_E",
+        LOG10_E,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Base-10 logarithm of e",
+            "log₁₀ e",
+            r"\log_{10} e",
+            Some("1/ln 10"),
+        ),
+        Tags::of(&[Tag::Logarithmic]),
+    ),
+    entry(
+        "FRAC_1_SQRT_2",
+        FRAC_1_SQRT_2,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Reciprocal of the square root of 2",
+            "1/√2",
+            r"\frac{1}{\sqrt{2}}",
+            None,
+        ),
+        Tags::of(&[Tag::Root]),
+    ),
+    entry(
+        "FRAC_1_PI",
+        FRAC_1_PI,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Reciprocal of pi",
+            "1/π",
+            r"\frac{1}{\pi}",
+            None,
+        ),
+        Tags::of(&[Tag::Circle]),
+    ),
+    entry(
+        "FRAC_2_PI",
+        FRAC_2_PI,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Two divided by pi",
+            "2/π",
+            r"\frac{2}{\pi}",
+            None,
+        ),
+        Tags::of(&[Tag::Circle]),
+    ),
+    entry(
+        "FRAC_2_SQRT_PI",
+        FRAC_2_SQRT_PI,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Two divided by the square root of pi",
+            "2/√π",
+            r"\frac{2}{\sqrt{\pi}}",
+            None,
+        ),
+        Tags::of(&[Tag::Circle, Tag::Root]),
+    ),
+    entry(
+        "FRAC_PI_2",
+        FRAC_PI_2,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Pi divided by 2, a right angle in radians",
+            "π/2",
+            r"\frac{\pi}{2}",
+            None,
+        ),
+        Tags::of(&[Tag::TrigonometricFraction]),
+    ),
+    entry(
+        "FRAC_PI_3",
+        FRAC_PI_3,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Pi divided by 3, 60 degrees in radians",
+            "π/3",
+            r"\frac{\pi}{3}",
+            None,
+        ),
+        Tags::of(&[Tag::TrigonometricFraction]),
+    ),
+    entry(
+        "FRAC_PI_4",
+        FRAC_PI_4,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Pi divided by 4, 45 degrees in radians",
+            "π/4",
+            r"\frac{\pi}{4}",
+            None,
+        ),
+        Tags::of(&[Tag::TrigonometricFraction]),
+    ),
+    entry(
+        "FRAC_PI_6",
+        FRAC_PI_6,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Pi divided by 6, 30 degrees in radians",
+            "π/6",
+            r"\frac{\pi}{6}",
+            None,
+        ),
+        Tags::of(&[Tag::TrigonometricFraction]),
+    ),
+    entry(
+        "FRAC_PI_8",
+        FRAC_PI_8,
+        M,
+        unit::ONE,
+        Exact,
+        describe(
+            Source::Mathematics,
+            "Pi divided by 8, 22.5 degrees in radians",
+            "π/8",
+            r"\frac{\pi}{8}",
+            None,
+        ),
+        Tags::of(&[Tag::TrigonometricFraction]),
+    ),
+    entry(
+        "ELEMENTARY_CHARGE",
+        ELEMENTARY_CHARGE,
+        P,
+        unit::COULOMB,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Elementary charge",
+            "e",
+            "e",
+            None,
+        ),
+        Tags::of(&[Tag::SiDefining, Tag::Electromagnetic]),
+    ),
+    entry(
+        "ELECTRON_MASS",
+        ELECTRON_MASS,
+        P,
+        unit::KILOGRAM,
+        Standard(ELECTRON_MASS_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Electron mass",
+            "mₑ",
+            r"m_\mathrm{e}",
+            None,
+        ),
+        Tags::of(&[Tag::ParticleMass]),
+    ),
+    entry(
+        "PROTON_MASS",
+        PROTON_MASS,
+        P,
+        unit::KILOGRAM,
+        Standard(PROTON_MASS_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Proton mass",
+            "mₚ",
+            r"m_\mathrm{p}",
+            None,
+        ),
+        Tags::of(&[Tag::ParticleMass]),
+    ),
+    entry(
+        "NEUTRON_MASS",
+        NEUTRON_MASS,
+        P,
+        unit::KILOGRAM,
+        Standard(NEUTRON_MASS_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Neutron mass",
+            "mₙ",
+            r"m_\mathrm{n}",
+            None,
+        ),
+        Tags::of(&[Tag::ParticleMass]),
+    ),
+    entry(
+        "STEFAN_BOLTZMANN",
+        STEFAN_BOLTZMANN,
+        P,
+        unit::WATT_PER_SQUARE_METER_KELVIN_FOURTH,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Stefan–Boltzmann constant",
+            "σ",
+            r"\sigma",
+            Some("2π⁵k⁴/(15h³c²)"),
+        ),
+        Tags::of(&[Tag::Thermodynamic]),
+    ),
+    entry(
+        "WIEN_DISPLACEMENT",
+        WIEN_DISPLACEMENT,
+        P,
+        unit::METER_KELVIN,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Wien wavelength displacement law constant",
+            "b",
+            "b",
+            Some("hc/(4.965114231… k)"),
+        ),
+        Tags::of(&[Tag::Thermodynamic]),
+    ),
+    entry(
+        "STANDARD_GRAVITY",
+        STANDARD_GRAVITY,
+        P,
+        unit::METER_PER_SECOND_SQUARED,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Standard acceleration of gravity",
+            "gₙ",
+            r"g_\mathrm{n}",
+            None,
+        ),
+        Tags::of(&[Tag::Gravitation]),
+    ),
+    entry(
+        "STANDARD_ATMOSPHERE",
+        STANDARD_ATMOSPHERE,
+        P,
+        unit::PASCAL,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Standard atmosphere",
+            "atm",
+            r"\mathrm{atm}",
+            None,
+        ),
+        Tags::of(&[Tag::ConversionFactor, Tag::Thermodynamic]),
+    ),
+    entry(
+        "ATOMIC_MASS_UNIT",
+        ATOMIC_MASS_UNIT,
+        P,
+        unit::KILOGRAM,
+        Standard(ATOMIC_MASS_UNIT_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Atomic mass constant (dalton)",
+            "mᵤ",
+            r"m_\mathrm{u}",
+            Some("m(¹²C)/12"),
+        ),
+        Tags::of(&[
+            Tag::AtomicNuclear,
+            Tag::ParticleMass,
+            Tag::PhysicoChemical,
+        ]),
+    ),
+    entry(
+        "BOHR_RADIUS",
+        BOHR_RADIUS,
+        P,
+        unit::METER,
+        Standard(BOHR_RADIUS_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Bohr radius",
+            "a₀",
+            "a_0",
+            Some("ħ/(αm_e c)"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear, Tag::AtomicUnits]),
+    ),
+    entry(
+        "FINE_STRUCTURE",
+        FINE_STRUCTURE,
+        P,
+        unit::ONE,
+        Standard(FINE_STRUCTURE_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Fine-structure constant",
+            "α",
+            r"\alpha",
+            Some("e²/(4πε₀ħc)"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear]),
+    ),
+    entry(
+        "RYDBERG",
+        RYDBERG,
+        P,
+        unit::PER_METER,
+        Standard(RYDBERG_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Rydberg constant",
+            "R_∞",
+            r"R_\infty",
+            Some("α²m_e c/(2h)"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear]),
+    ),
+    entry(
+        "MAGNETIC_FLUX_QUANTUM",
+        MAGNETIC_FLUX_QUANTUM,
+        P,
+        unit::WEBER,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Magnetic flux quantum",
+            "Φ₀",
+            r"\Phi_0",
+            Some("h/(2e)"),
+        ),
+        Tags::of(&[Tag::Electromagnetic]),
+    ),
+    entry(
+        "CONDUCTANCE_QUANTUM",
+        CONDUCTANCE_QUANTUM,
+        P,
+        unit::SIEMENS,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Conductance quantum",
+            "G₀",
+            "G_0",
+            Some("2e²/h"),
+        ),
+        Tags::of(&[Tag::Electromagnetic]),
+    ),
+    entry(
+        "MUON_MASS",
+        MUON_MASS,
+        P,
+        unit::KILOGRAM,
+        Standard(MUON_MASS_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Muon mass",
+            "m_μ",
+            r"m_\mu",
+            None,
+        ),
+        Tags::of(&[Tag::ParticleMass]),
+    ),
+    entry(
+        "TAU_PARTICLE_MASS",
+        TAU_PARTICLE_MASS,
+        P,
+        unit::KILOGRAM,
+        Standard(TAU_PARTICLE_MASS_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Tau lepton mass",
+            "m_τ",
+            r"m_\tau",
+            None,
+        ),
+        Tags::of(&[Tag::ParticleMass]),
+    ),
+    entry(
+        "DEUTERON_MASS",
+        DEUTERON_MASS,
+        P,
+        unit::KILOGRAM,
+        Standard(DEUTERON_MASS_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Deuteron mass",
+            "m_d",
+            r"m_\mathrm{d}",
+            None,
+        ),
+        Tags::of(&[Tag::ParticleMass]),
+    ),
+    entry(
+        "TRITON_MASS",
+        TRITON_MASS,
+        P,
+        unit::KILOGRAM,
+        Standard(TRITON_MASS_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Triton mass",
+            "mₜ",
+            r"m_\mathrm{t}",
+            None,
+        ),
+        Tags::of(&[Tag::ParticleMass]),
+    ),
+    entry(
+        "HELION_MASS",
+        HELION_MASS,
+        P,
+        unit::KILOGRAM,
+        Standard(HELION_MASS_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Helion (helium-3 nucleus) mass",
+            "mₕ",
+            r"m_\mathrm{h}",
+            None,
+        ),
+        Tags::of(&[Tag::ParticleMass]),
+    ),
+    entry(
+        "ALPHA_PARTICLE_MASS",
+        ALPHA_PARTICLE_MASS,
+        P,
+        unit::KILOGRAM,
+        Standard(ALPHA_PARTICLE_MASS_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Alpha particle mass",
+            "m_α",
+            r"m_\alpha",
+            None,
+        ),
+        Tags::of(&[Tag::ParticleMass]),
+    ),
+    entry(
+        "ELECTRON_PROTON_MASS_RATIO",
+        ELECTRON_PROTON_MASS_RATIO,
+        P,
+        unit::ONE,
+        Standard(ELECTRON_PROTON_MASS_RATIO_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Electron-to-proton mass ratio",
+            "mₑ/mₚ",
+            r"m_\mathrm{e}/m_\mathrm{p}",
+            Some("m_e/m_p"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear, Tag::ParticleMass]),
+    ),
+    entry(
+        "PROTON_ELECTRON_MASS_RATIO",
+        PROTON_ELECTRON_MASS_RATIO,
+        P,
+        unit::ONE,
+        Standard(PROTON_ELECTRON_MASS_RATIO_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Proton-to-electron mass ratio",
+            "mₚ/mₑ",
+            r"m_\mathrm{p}/m_\mathrm{e}",
+            Some("m_p/m_e"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear, Tag::ParticleMass]),
+    ),
+    entry(
+        "MUON_ELECTRON_MASS_RATIO",
+        MUON_ELECTRON_MASS_RATIO,
+        P,
+        unit::ONE,
+        Standard(MUON_ELECTRON_MASS_RATIO_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Muon-to-electron mass ratio",
+            "m_μ/mₑ",
+            r"m_\mu/m_\mathrm{e}",
+            Some("m_μ/m_e"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear, Tag::ParticleMass]),
+    ),
+    entry(
+        "NEUTRON_PROTON_MASS_RATIO",
+        NEUTRON_PROTON_MASS_RATIO,
+        P,
+        unit::ONE,
+        Standard(NEUTRON_PROTON_MASS_RATIO_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Neutron-to-proton mass ratio",
+            "mₙ/mₚ",
+            r"m_\mathrm{n}/m_\mathrm{p}",
+            Some("m_n/m_p"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear, Tag::ParticleMass]),
+    ),
+    entry(
+        "DEUTERON_PROTON_MASS_RATIO",
+        DEUTERON_PROTON_MASS_RATIO,
+        P,
+        unit::ONE,
+        Standard(DEUTERON_PROTON_MASS_RATIO_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Deuteron-to-proton mass ratio",
+            "m_d/mₚ",
+            r"m_\mathrm{d}/m_\mathrm{p}",
+            Some("m_d/m_p"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear, Tag::ParticleMass]),
+    ),
+    entry(
+        "BOHR_MAGNETON",
+        BOHR_MAGNETON,
+        P,
+        unit::JOULE_PER_TESLA,
+        Standard(BOHR_MAGNETON_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Bohr magneton",
+            "μ_B",
+            r"\mu_\mathrm{B}",
+            Some("eħ/(2m_e)"),
+        ),
+        Tags::of(&[
+            Tag::Electromagnetic,
+            Tag::AtomicNuclear,
+            Tag::MagneticMoment,
+        ]),
+    ),
+    entry(
+        "NUCLEAR_MAGNETON",
+        NUCLEAR_MAGNETON,
+        P,
+        unit::JOULE_PER_TESLA,
+        Standard(NUCLEAR_MAGNETON_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Nuclear magneton",
+            "μ_N",
+            r"\mu_\mathrm{N}",
+            Some("eħ/(2m_p)"),
+        ),
+        Tags::of(&[
+            Tag::Electromagnetic,
+            Tag::AtomicNuclear,
+            Tag::MagneticMoment,
+        ]),
+    ),
+    entry(
+        "ELECTRON_MAGNETIC_MOMENT",
+        ELECTRON_MAGNETIC_MOMENT,
+        P,
+        unit::JOULE_PER_TESLA,
+        Standard(ELECTRON_MAGNETIC_MOMENT_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Electron magnetic moment",
+            "μₑ",
+            r"\mu_\mathrm{e}",
+            None,
+        ),
+        Tags::of(&[Tag::AtomicNuclear, Tag::MagneticMoment]),
+    ),
+    entry(
+        "PROTON_MAGNETIC_MOMENT",
+        PROTON_MAGNETIC_MOMENT,
+        P,
+        unit::JOULE_PER_TESLA,
+        Standard(PROTON_MAGNETIC_MOMENT_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Proton magnetic moment",
+            "μₚ",
+            r"\mu_\mathrm{p}",
+            None,
+        ),
+        Tags::of(&[Tag::AtomicNuclear, Tag::MagneticMoment]),
+    ),
+    entry(
+        "NEUTRON_MAGNETIC_MOMENT",
+        NEUTRON_MAGNETIC_MOMENT,
+        P,
+        unit::JOULE_PER_TESLA,
+        Standard(NEUTRON_MAGNETIC_MOMENT_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Neutron magnetic moment",
+            "μₙ",
+            r"\mu_\mathrm{n}",
+            None,
+        ),
+        Tags::of(&[Tag::AtomicNuclear, Tag::MagneticMoment]),
+    ),
+    entry(
+        "ELECTRON_G_FACTOR",
+        ELECTRON_G_FACTOR,
+        P,
+        unit::ONE,
+        Standard(ELECTRON_G_FACTOR_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Electron g-factor",
+            "gₑ",
+            r"g_\mathrm{e}",
+            Some("2μ_e/μ_B"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear, Tag::MagneticMoment]),
+    ),
+    entry(
+        "PROTON_G_FACTOR",
+        PROTON_G_FACTOR,
+        P,
+        unit::ONE,
+        Standard(PROTON_G_FACTOR_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Proton g-factor",
+            "gₚ",
+            r"g_\mathrm{p}",
+            Some("2μ_p/μ_N"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear, Tag::MagneticMoment]),
+    ),
+    entry(
+        "ELECTRON_VOLT",
+        ELECTRON_VOLT,
+        P,
+        unit::JOULE,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Electron volt",
+            "eV",
+            r"\mathrm{eV}",
+            Some("e × 1 V"),
+        ),
+        Tags::of(&[Tag::ConversionFactor]),
+    ),
+    entry(
+        "EV_TO_KG",
+        EV_TO_KG,
+        P,
+        unit::KILOGRAM,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Electron volt–kilogram relationship",
+            "eV/c²",
+            r"\mathrm{eV}/c^2",
+            Some("e/c²"),
+        ),
+        Tags::of(&[Tag::ConversionFactor]),
+    ),
+    entry(
+        "EV_TO_AMU",
+        EV_TO_AMU,
+        P,
+        unit::DALTON,
+        Standard(EV_TO_AMU_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Electron volt–atomic mass unit relationship",
+            "eV/(mᵤ c²)",
+            r"\mathrm{eV}/(m_\mathrm{u} c^2)",
+            Some("e/(m_u c²)"),
+        ),
+        Tags::of(&[Tag::ConversionFactor]),
+    ),
+    entry(
+        "EV_TO_HZ",
+        EV_TO_HZ,
+        P,
+        unit::HERTZ,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Electron volt–hertz relationship",
+            "eV/h",
+            r"\mathrm{eV}/h",
+            Some("e/h"),
+        ),
+        Tags::of(&[Tag::ConversionFactor]),
+    ),
+    entry(
+        "EV_TO_KELVIN",
+        EV_TO_KELVIN,
+        P,
+        unit::KELVIN,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Electron volt–kelvin relationship",
+            "eV/k",
+            r"\mathrm{eV}/k",
+            Some("e/k"),
+        ),
+        Tags::of(&[Tag::ConversionFactor, Tag::Thermodynamic]),
+    ),
+    entry(
+        "EV_TO_INVERSE_METER",
+        EV_TO_INVERSE_METER,
+        P,
+        unit::PER_METER,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Electron volt–inverse metre relationship",
+            "eV/(hc)",
+            r"\mathrm{eV}/(hc)",
+            Some("e/(hc)"),
+        ),
+        Tags::of(&[Tag::ConversionFactor]),
+    ),
+    entry(
+        "CLASSICAL_ELECTRON_RADIUS",
+        CLASSICAL_ELECTRON_RADIUS,
+        P,
+        unit::METER,
+        Standard(CLASSICAL_ELECTRON_RADIUS_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Classical electron radius",
+            "rₑ",
+            r"r_\mathrm{e}",
+            Some("α²a₀"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear]),
+    ),
+    entry(
+        "ELECTRON_COMPTON_WAVELENGTH",
+        ELECTRON_COMPTON_WAVELENGTH,
+        P,
+        unit::METER,
+        Standard(ELECTRON_COMPTON_WAVELENGTH_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Electron Compton wavelength",
+            "λ_C",
+            r"\lambda_\mathrm{C}",
+            Some("h/(m_e c)"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear]),
+    ),
+    entry(
+        "PROTON_COMPTON_WAVELENGTH",
+        PROTON_COMPTON_WAVELENGTH,
+        P,
+        unit::METER,
+        Standard(PROTON_COMPTON_WAVELENGTH_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Proton Compton wavelength",
+            "λ_C,p",
+            r"\lambda_{\mathrm{C},\mathrm{p}}",
+            Some("h/(m_p c)"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear]),
+    ),
+    entry(
+        "NEUTRON_COMPTON_WAVELENGTH",
+        NEUTRON_COMPTON_WAVELENGTH,
+        P,
+        unit::METER,
+        Standard(NEUTRON_COMPTON_WAVELENGTH_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Neutron Compton wavelength",
+            "λ_C,n",
+            r"\lambda_{\mathrm{C},\mathrm{n}}",
+            Some("h/(m_n c)"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear]),
+    ),
+    entry(
+        "THOMSON_CROSS_SECTION",
+        THOMSON_CROSS_SECTION,
+        P,
+        unit::SQUARE_METER,
+        Standard(THOMSON_CROSS_SECTION_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Thomson cross section",
+            "σₑ",
+            r"\sigma_\mathrm{e}",
+            Some("(8π/3)r_e²"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear]),
+    ),
+    entry(
+        "FIRST_RADIATION_CONSTANT",
+        FIRST_RADIATION_CONSTANT,
+        P,
+        unit::WATT_SQUARE_METER,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "First radiation constant",
+            "c₁",
+            "c_1",
+            Some("2πhc²"),
+        ),
+        Tags::of(&[Tag::Thermodynamic]),
+    ),
+    entry(
+        "SECOND_RADIATION_CONSTANT",
+        SECOND_RADIATION_CONSTANT,
+        P,
+        unit::METER_KELVIN,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Second radiation constant",
+            "c₂",
+            "c_2",
+            Some("hc/k"),
+        ),
+        Tags::of(&[Tag::Thermodynamic]),
+    ),
+    entry(
+        "JOSEPHSON_CONSTANT",
+        JOSEPHSON_CONSTANT,
+        P,
+        unit::HERTZ_PER_VOLT,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Josephson constant",
+            "K_J",
+            r"K_\mathrm{J}",
+            Some("2e/h"),
+        ),
+        Tags::of(&[Tag::Electromagnetic]),
+    ),
+    entry(
+        "VON_KLITZING_CONSTANT",
+        VON_KLITZING_CONSTANT,
+        P,
+        unit::OHM,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Von Klitzing constant",
+            "R_K",
+            r"R_\mathrm{K}",
+            Some("h/e²"),
+        ),
+        Tags::of(&[Tag::Electromagnetic]),
+    ),
+    entry(
+        "HARTREE_ENERGY",
+        HARTREE_ENERGY,
+        P,
+        unit::JOULE,
+        Standard(HARTREE_ENERGY_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Hartree energy",
+            "Eₕ",
+            r"E_\mathrm{h}",
+            Some("α²m_e c²"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear, Tag::AtomicUnits]),
+    ),
+    entry(
+        "HARTREE_ENERGY_EV",
+        HARTREE_ENERGY_EV,
+        P,
+        unit::ELECTRONVOLT,
+        Standard(HARTREE_ENERGY_EV_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Hartree energy in electron volts",
+            "Eₕ",
+            r"E_\mathrm{h}",
+            Some("α²m_e c²/e"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear, Tag::ConversionFactor]),
+    ),
+    entry(
+        "PLANCK_MASS",
+        PLANCK_MASS,
+        P,
+        unit::KILOGRAM,
+        Standard(PLANCK_MASS_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Planck mass",
+            "m_P",
+            r"m_\mathrm{P}",
+            Some("√(ħc/G)"),
+        ),
+        Tags::of(&[Tag::PlanckUnits, Tag::Gravitation]),
+    ),
+    entry(
+        "PLANCK_LENGTH",
+        PLANCK_LENGTH,
+        P,
+        unit::METER,
+        Standard(PLANCK_LENGTH_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Planck length",
+            "l_P",
+            r"l_\mathrm{P}",
+            Some("√(ħG/c³)"),
+        ),
+        Tags::of(&[Tag::PlanckUnits, Tag::Gravitation]),
+    ),
+    entry(
+        "PLANCK_TIME",
+        PLANCK_TIME,
+        P,
+        unit::SECOND,
+        Standard(PLANCK_TIME_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Planck time",
+            "t_P",
+            r"t_\mathrm{P}",
+            Some("√(ħG/c⁵)"),
+        ),
+        Tags::of(&[Tag::PlanckUnits, Tag::Gravitation]),
+    ),
+    entry(
+        "PLANCK_TEMPERATURE",
+        PLANCK_TEMPERATURE,
+        P,
+        unit::KELVIN,
+        Standard(PLANCK_TEMPERATURE_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Planck temperature",
+            "T_P",
+            r"T_\mathrm{P}",
+            Some("m_P c²/k"),
+        ),
+        Tags::of(&[Tag::PlanckUnits, Tag::Thermodynamic]),
+    ),
+    entry(
+        "PLANCK_CHARGE",
+        PLANCK_CHARGE,
+        P,
+        unit::COULOMB,
+        Standard(PLANCK_CHARGE_UNCERTAINTY),
+        describe(
+            Source::Derived,
+            "Planck charge",
+            "q_P",
+            r"q_\mathrm{P}",
+            Some("√(4πε₀ħc)"),
+        ),
+        Tags::of(&[Tag::PlanckUnits]),
+    ),
+    entry(
+        "MOLAR_MASS_CONSTANT",
+        MOLAR_MASS_CONSTANT,
+        P,
+        unit::KILOGRAM_PER_MOLE,
+        Standard(MOLAR_MASS_CONSTANT_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Molar mass constant",
+            "Mᵤ",
+            r"M_\mathrm{u}",
+            Some("N_A m_u"),
+        ),
+        Tags::of(&[Tag::PhysicoChemical]),
+    ),
+    entry(
+        "MOLAR_PLANCK_CONSTANT",
+        MOLAR_PLANCK_CONSTANT,
+        P,
+        unit::JOULE_SECOND_PER_MOLE,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Molar Planck constant",
+            "N_A h",
+            r"N_\mathrm{A} h",
+            Some("N_A h"),
+        ),
+        Tags::of(&[Tag::PhysicoChemical]),
+    ),
+    entry(
+        "LOSCHMIDT_CONSTANT",
+        LOSCHMIDT_CONSTANT,
+        P,
+        unit::PER_CUBIC_METER,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Loschmidt constant (273.15 K, 101.325 kPa)",
+            "n₀",
+            "n_0",
+            Some("p/(kT)"),
+        ),
+        Tags::of(&[Tag::Thermodynamic, Tag::PhysicoChemical]),
+    ),
+    entry(
+        "MOLAR_VOLUME_IDEAL_GAS",
+        MOLAR_VOLUME_IDEAL_GAS,
+        P,
+        unit::CUBIC_METER_PER_MOLE,
+        Exact,
+        describe(
+            Source::Codata2018,
+            "Molar volume of an ideal gas (273.15 K, 100 kPa)",
+            "Vₘ",
+            r"V_\mathrm{m}",
+            Some("RT/p"),
+        ),
+        Tags::of(&[Tag::Thermodynamic, Tag::PhysicoChemical]),
+    ),
+    entry(
+        "SACKUR_TETRODE_CONSTANT",
+        SACKUR_TETRODE_CONSTANT,
+        P,
+        unit::ONE,
+        Standard(SACKUR_TETRODE_CONSTANT_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Sackur–Tetrode constant (1 K, 101.325 kPa)",
+            "S₀/R",
+            "S_0/R",
+            Some("5/2 + ln[(m_u kT/(2πħ²))^(3/2) kT/p]"),
+        ),
+        Tags::of(&[Tag::Thermodynamic]),
+    ),
+    entry(
+        "IMPEDANCE_OF_FREE_SPACE",
+        IMPEDANCE_OF_FREE_SPACE,
+        P,
+        unit::OHM,
+        Standard(IMPEDANCE_OF_FREE_SPACE_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Characteristic impedance of vacuum",
+            "Z₀",
+            "Z_0",
+            Some("μ₀c"),
+        ),
+        Tags::of(&[Tag::Universal, Tag::Electromagnetic]),
+    ),
+    entry(
+        "INVERSE_FINE_STRUCTURE",
+        INVERSE_FINE_STRUCTURE,
+        P,
+        unit::ONE,
+        Standard(INVERSE_FINE_STRUCTURE_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Inverse fine-structure constant",
+            "α⁻¹",
+            r"\alpha^{-1}",
+            Some("1/α"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear]),
+    ),
+    entry(
+        "ELECTRON_CHARGE_TO_MASS",
+        ELECTRON_CHARGE_TO_MASS,
+        P,
+        unit::COULOMB_PER_KILOGRAM,
+        Standard(ELECTRON_CHARGE_TO_MASS_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Electron charge-to-mass quotient",
+            "−e/mₑ",
+            r"-e/m_\mathrm{e}",
+            Some("−e/m_e"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear]),
+    ),
+    entry(
+        "PROTON_CHARGE_TO_MASS",
+        PROTON_CHARGE_TO_MASS,
+        P,
+        unit::COULOMB_PER_KILOGRAM,
+        Standard(PROTON_CHARGE_TO_MASS_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Proton charge-to-mass quotient",
+            "e/mₚ",
+            r"e/m_\mathrm{p}",
+            Some("e/m_p"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear]),
+    ),
+    entry(
+        "ATOMIC_UNIT_OF_LENGTH",
+        ATOMIC_UNIT_OF_LENGTH,
+        P,
+        unit::METER,
+        Standard(ATOMIC_UNIT_OF_LENGTH_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Atomic unit of length",
+            "a₀",
+            "a_0",
+            Some("ħ/(αm_e c)"),
+        ),
+        Tags::of(&[Tag::AtomicUnits]),
+    ),
+    entry(
+        "ATOMIC_UNIT_OF_TIME",
+        ATOMIC_UNIT_OF_TIME,
+        P,
+        unit::SECOND,
+        Standard(ATOMIC_UNIT_OF_TIME_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Atomic unit of time",
+            "ħ/Eₕ",
+            r"\hbar/E_\mathrm{h}",
+            Some("ħ/E_h"),
+        ),
+        Tags::of(&[Tag::AtomicUnits]),
+    ),
+    entry(
+        "ATOMIC_UNIT_OF_VELOCITY",
+        ATOMIC_UNIT_OF_VELOCITY,
+        P,
+        unit::METER_PER_SECOND,
+        Standard(ATOMIC_UNIT_OF_VELOCITY_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Atomic unit of velocity",
+            "a₀Eₕ/ħ",
+            r"a_0 E_\mathrm{h}/\hbar",
+            Some("a₀E_h/ħ"),
+        ),
+        Tags::of(&[Tag::AtomicUnits]),
+    ),
+    entry(
+        "ATOMIC_UNIT_OF_FORCE",
+        ATOMIC_UNIT_OF_FORCE,
+        P,
+        unit::NEWTON,
+        Standard(ATOMIC_UNIT_OF_FORCE_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Atomic unit of force",
+            "Eₕ/a₀",
+            r"E_\mathrm{h}/a_0",
+            Some("E_h/a₀"),
+        ),
+        Tags::of(&[Tag::AtomicUnits]),
+    ),
+    entry(
+        "ATOMIC_UNIT_OF_ELECTRIC_FIELD",
+        ATOMIC_UNIT_OF_ELECTRIC_FIELD,
+        P,
+        unit::VOLT_PER_METER,
+        Standard(ATOMIC_UNIT_OF_ELECTRIC_FIELD_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Atomic unit of electric field",
+            "Eₕ/(ea₀)",
+            r"E_\mathrm{h}/(e a_0)",
+            Some("E_h/(ea₀)"),
+        ),
+        Tags::of(&[Tag::AtomicUnits]),
+    ),
+    entry(
+        "ATOMIC_UNIT_OF_POLARIZABILITY",
+        ATOMIC_UNIT_OF_POLARIZABILITY,
+        P,
+        unit::COULOMB_SQUARED_SQUARE_METER_PER_JOULE,
+        Standard(ATOMIC_UNIT_OF_POLARIZABILITY_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Atomic unit of electric polarizability",
+            "e²a₀²/Eₕ",
+            r"e^2 a_0^2/E_\mathrm{h}",
+            Some("e²a₀²/E_h"),
+        ),
+        Tags::of(&[Tag::AtomicUnits]),
+    ),
+    entry(
+        "W_BOSON_MASS_GEV",
+        W_BOSON_MASS_GEV,
+        P,
+        unit::GIGAELECTRONVOLT_PER_C2,
+        Standard(W_BOSON_MASS_GEV_UNCERTAINTY),
+        describe(Source::Pdg2022, "W boson mass", "m_W", "m_W", None),
+        Tags::of(&[Tag::ParticleMass]),
+    ),
+    entry(
+        "Z_BOSON_MASS_GEV",
+        Z_BOSON_MASS_GEV,
+        P,
+        unit::GIGAELECTRONVOLT_PER_C2,
+        Standard(Z_BOSON_MASS_GEV_UNCERTAINTY),
+        describe(Source::Pdg2022, "Z boson mass", "m_Z", "m_Z", None),
+        Tags::of(&[Tag::ParticleMass]),
+    ),
+    entry(
+        "HIGGS_BOSON_MASS_GEV",
+        HIGGS_BOSON_MASS_GEV,
+        P,
+        unit::GIGAELECTRONVOLT_PER_C2,
+        Standard(HIGGS_BOSON_MASS_GEV_UNCERTAINTY),
+        describe(
+            Source::Pdg2022,
+            "Higgs boson mass",
+            "m_H",
+            "m_H",
+            None,
+        ),
+        Tags::of(&[Tag::ParticleMass]),
+    ),
+    entry(
+        "ELECTRON_MASS_MEV",
+        ELECTRON_MASS_MEV,
+        P,
+        unit::MEGAELECTRONVOLT_PER_C2,
+        Standard(ELECTRON_MASS_MEV_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Electron mass energy equivalent in MeV",
+            "mₑ c²",
+            r"m_\mathrm{e} c^2",
+            Some("m_e c²/e"),
+        ),
+        Tags::of(&[Tag::ParticleMass, Tag::ConversionFactor]),
+    ),
+    entry(
+        "PROTON_MASS_MEV",
+        PROTON_MASS_MEV,
+        P,
+        unit::MEGAELECTRONVOLT_PER_C2,
+        Standard(PROTON_MASS_MEV_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Proton mass energy equivalent in MeV",
+            "mₚ c²",
+            r"m_\mathrm{p} c^2",
+            Some("m_p c²/e"),
+        ),
+        Tags::of(&[Tag::ParticleMass, Tag::ConversionFactor]),
+    ),
+    entry(
+        "NEUTRON_MASS_MEV",
+        NEUTRON_MASS_MEV,
+        P,
+        unit::MEGAELECTRONVOLT_PER_C2,
+        Standard(NEUTRON_MASS_MEV_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Neutron mass energy equivalent in MeV",
+            "mₙ c²",
+            r"m_\mathrm{n} c^2",
+            Some("m_n c²/e"),
+        ),
+        Tags::of(&[Tag::ParticleMass, Tag::ConversionFactor]),
+    ),
+    entry(
+        "MUON_MASS_MEV",
+        MUON_MASS_MEV,
+        P,
+        unit::MEGAELECTRONVOLT_PER_C2,
+        Standard(MUON_MASS_MEV_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Muon mass energy equivalent in MeV",
+            "m_μ c²",
+            r"m_\mu c^2",
+            Some("m_μ c²/e"),
+        ),
+        Tags::of(&[Tag::ParticleMass, Tag::ConversionFactor]),
+    ),
+    entry(
+        "ELECTRON_REDUCED_COMPTON",
+        ELECTRON_REDUCED_COMPTON,
+        P,
+        unit::METER,
+        Standard(ELECTRON_REDUCED_COMPTON_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Reduced electron Compton wavelength",
+            "ƛ_C",
+            r"\bar{\lambda}_\mathrm{C}",
+            Some("ħ/(m_e c)"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear]),
+    ),
+    entry(
+        "PROTON_REDUCED_COMPTON",
+        PROTON_REDUCED_COMPTON,
+        P,
+        unit::METER,
+        Standard(PROTON_REDUCED_COMPTON_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Reduced proton Compton wavelength",
+            "ƛ_C,p",
+            r"\bar{\lambda}_{\mathrm{C},\mathrm{p}}",
+            Some("ħ/(m_p c)"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear]),
+    ),
+    entry(
+        "NEUTRON_REDUCED_COMPTON",
+        NEUTRON_REDUCED_COMPTON,
+        P,
+        unit::METER,
+        Standard(NEUTRON_REDUCED_COMPTON_UNCERTAINTY),
+        describe(
+            Source::Codata2018,
+            "Reduced neutron Compton wavelength",
+            "ƛ_C,n",
+            r"\bar{\lambda}_{\mathrm{C},\mathrm{n}}",
+            Some("ħ/(m_n c)"),
+        ),
+        Tags::of(&[Tag::AtomicNuclear]),
+    ),
+    entry(
+        "GAS_CONSTANT_L_ATM",
+        GAS_CONSTANT_L_ATM,
+        P,
+        unit::LITER_ATMOSPHERE_PER_MOLE_KELVIN,
+        Exact,
+        describe(
+            Source::Derived,
+            "Molar gas constant in litre-atmospheres",
+            "R",
+            "R",
+            Some("R/(10⁻³ m³ × atm)"),
+        ),
+        Tags::of(&[
+            Tag::ConversionFactor,
+            Tag::Thermodynamic,
+            Tag::PhysicoChemical,
+        ]),
+    ),
+];
@@ -0,0 +1,553 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! CODATA 2022 recommended values.
+//!
+//! Only the constants that were re-adjusted since CODATA 2018 are
+//! defined here; everything else (the exact SI defining constants,
+//! G, the Planck units, …) is unchanged and shared with
+//! [`codata2018`]. [`TABLE`] is built at compile
+//! time by applying [`ADJUSTED`] to the CODATA 2018 table.
+
+use super::{codata2018, with_adjusted, ConstantEntry, Source};
+
+/// The vacuum magnetic permeability (CODATA 2022).
+/// μ_0 ≈ 1.25663706127 x 10^-6 N A^-2
+pub const VACUUM_PERMEABILITY: f64 = 1.256_637_061_27e-6;
+/// Standard uncertainty of [`VACUUM_PERMEABILITY`]. Source: CODATA 2022.
+pub const VACUUM_PERMEABILITY_UNCERTAINTY: f64 = 0.000_000_000_20e-6;
+
+/// The vacuum electric permittivity (CODATA 2022).
+/// ε_0 ≈ 8.8541878188 x 10^-12 F m^-1
+pub const VACUUM_PERMITTIVITY: f64 = 8.854_187_818_8e-12;
+/// Standard uncertainty of [`VACUUM_PERMITTIVITY`]. Source: CODATA 2022.
+pub const VACUUM_PERMITTIVITY_UNCERTAINTY: f64 = 0.000_000_001_4e-12;
+
+/// Coulomb's constant (CODATA 2022).
+/// k_e = 1/(4πε_0) ≈ 8.9875517862 x 10^9 N m^2 C^-2
+pub const COULOMB: f64 = 8.987_551_786_2e9;
+/// Standard uncertainty of [`COULOMB`]. Source: CODATA 2022.
+pub const COULOMB_UNCERTAINTY: f64 = 0.000_000_001_4e9;
+
+/// The rest mass of an electron (CODATA 2022).
+/// m_e ≈ 9.1093837139 x 10^-31 kg
+pub const ELECTRON_MASS: f64 = 9.109_383_713_9e-31;
+/// Standard uncertainty of [`ELECTRON_MASS`]. Source: CODATA 2022.
+pub const ELECTRON_MASS_UNCERTAINTY: f64 = 0.000_000_002_8e-31;
+
+/// The rest mass of a proton (CODATA 2022).
+/// m_p ≈ 1.67262192595 x 10^-27 kg
+pub const PROTON_MASS: f64 = 1.672_621_925_95e-27;
+/// Standard uncertainty of [`PROTON_MASS`]. Source: CODATA 2022.
+pub const PROTON_MASS_UNCERTAINTY: f64 = 0.000_000_000_52e-27;
+
+/// The rest mass of a neutron (CODATA 2022).
+/// m_n ≈ 1.67492750056 x 10^-27 kg
+pub const NEUTRON_MASS: f64 = 1.674_927_500_56e-27;
+/// Standard uncertainty of [`NEUTRON_MASS`]. Source: CODATA 2022.
+pub const NEUTRON_MASS_UNCERTAINTY: f64 = 0.000_000_000_85e-27;
+
+/// The unified atomic mass unit / dalton (CODATA 2022).
+/// u ≈ 1.66053906892 x 10^-27 kg
+pub const ATOMIC_MASS_UNIT: f64 = 1.660_539_068_92e-27;
+/// Standard uncertainty of [`ATOMIC_MASS_UNIT`]. Source: CODATA 2022.
+pub const ATOMIC_MASS_UNIT_UNCERTAINTY: f64 = 0.000_000_000_52e-27;
+
+/// The Bohr radius (CODATA 2022).
+/// a_0 ≈ 5.29177210544 x 10^-11 m
+pub const BOHR_RADIUS: f64 = 5.291_772_105_44e-11;
+/// Standard uncertainty of [`BOHR_RADIUS`]. Source: CODATA 2022.
+pub const BOHR_RADIUS_UNCERTAINTY: f64 = 0.000_000_000_82e-11;
+
+/// The fine-structure constant (CODATA 2022).
+/// α ≈ 7.2973525643 x 10^-3 (dimensionless)
+pub const FINE_STRUCTURE: f64 = 7.297_352_564_3e-3;
+/// Standard uncertainty of [`FINE_STRUCTURE`]. Source: CODATA 2022.
+pub const FINE_STRUCTURE_UNCERTAINTY: f64 = 0.000_000_001_1e-3;
+
+/// The Rydberg constant (CODATA 2022).
+/// R∞ ≈ 10973731.568157 m^-1
+pub const RYDBERG: f64 = 10_973_731.568_157;
+/// Standard uncertainty of [`RYDBERG`]. Source: CODATA 2022.
+pub const RYDBERG_UNCERTAINTY: f64 = 0.000_012;
+
+/// Deuteron mass (CODATA 2022).
+/// m_d ≈ 3.3435837768e-27 kg
+pub const DEUTERON_MASS: f64 = 3.343_583_776_8e-27;
+/// Standard uncertainty of [`DEUTERON_MASS`]. Source: CODATA 2022.
+pub const DEUTERON_MASS_UNCERTAINTY: f64 = 0.000_000_001_0e-27;
+
+/// Triton mass (CODATA 2022).
+/// m_t ≈ 5.0073567512e-27 kg
+pub const TRITON_MASS: f64 = 5.007_356_751_2e-27;
+/// Standard uncertainty of [`TRITON_MASS`]. Source: CODATA 2022.
+pub const TRITON_MASS_UNCERTAINTY: f64 = 0.000_000_001_6e-27;
+
+/// Helion (He-3 nucleus) mass (CODATA 2022).
+/// m_h ≈ 5.0064127862e-27 kg
+pub const HELION_MASS: f64 = 5.006_412_786_2e-27;
+/// Standard uncertainty of [`HELION_MASS`]. Source: CODATA 2022.
+pub const HELION_MASS_UNCERTAINTY: f64 = 0.000_000_001_6e-27;
+
+/// Alpha particle mass (CODATA 2022).
+/// m_α ≈ 6.6446573450e-27 kg
+pub const ALPHA_PARTICLE_MASS: f64 = 6.644_657_345_0e-27;
+/// Standard uncertainty of [`ALPHA_PARTICLE_MASS`]. Source: CODATA 2022.
+pub const ALPHA_PARTICLE_MASS_UNCERTAINTY: f64 = 0.000_000_002_1e-27;
+
+/// Electron-to-proton mass ratio (CODATA 2022).
+/// m_e / m_p ≈ 5.446170214889e-4
+pub const ELECTRON_PROTON_MASS_RATIO: f64 = 5.446_170_214_889e-4;
+/// Standard uncertainty of [`ELECTRON_PROTON_MASS_RATIO`]. Source: CODATA 2022.
+pub const ELECTRON_PROTON_MASS_RATIO_UNCERTAINTY: f64 =
+    0.000_000_000_094e-4;
+
+/// Proton-to-electron mass ratio (CODATA 2022).
+/// m_p / m_e ≈ 1836.152673426
+pub const PROTON_ELECTRON_MASS_RATIO: f64 = 1_836.152_673_426;
+/// Standard uncertainty of [`PROTON_ELECTRON_MASS_RATIO`]. Source: CODATA 2022.
+pub const PROTON_ELECTRON_MASS_RATIO_UNCERTAINTY: f64 = 0.000_000_032;
+
+/// Muon-to-electron mass ratio (CODATA 2022).
+/// m_μ / m_e ≈ 206.7682827
+pub const MUON_ELECTRON_MASS_RATIO: f64 = 206.768_282_7;
+/// Standard uncertainty of [`MUON_ELECTRON_MASS_RATIO`]. Source: CODATA 2022.
+pub const MUON_ELECTRON_MASS_RATIO_UNCERTAINTY: f64 = 0.000_004_6;
+
+/// Neutron-to-proton mass ratio (CODATA 2022).
+/// m_n / m_p ≈ 1.00137841946
+pub const NEUTRON_PROTON_MASS_RATIO: f64 = 1.001_378_419_46;
+/// Standard uncertainty of [`NEUTRON_PROTON_MASS_RATIO`]. Source: CODATA 2022.
+pub const NEUTRON_PROTON_MASS_RATIO_UNCERTAINTY: f64 = 0.000_000_000_40;
+
+/// Deuteron-to-proton mass ratio (CODATA 2022).
+/// m_d / m_p ≈ 1.9990075012699
+pub const DEUTERON_PROTON_MASS_RATIO: f64 = 1.999_007_501_269_9;
+/// Standard uncertainty of [`DEUTERON_PROTON_MASS_RATIO`]. Source: CODATA 2022.
+pub const DEUTERON_PROTON_MASS_RATIO_UNCERTAINTY: f64 =
+    0.000_000_000_008_4;
+
+/// Bohr magneton (CODATA 2022).
+/// μ_B = eℏ/(2m_e) ≈ 9.2740100657e-24 J/T
+pub const BOHR_MAGNETON: f64 = 9.274_010_065_7e-24;
+/// Standard uncertainty of [`BOHR_MAGNETON`]. Source: CODATA 2022.
+pub const BOHR_MAGNETON_UNCERTAINTY: f64 = 0.000_000_002_9e-24;
+
+/// Nuclear magneton (CODATA 2022).
+/// μ_N = eℏ/(2m_p) ≈ 5.0507837393e-27 J/T
+pub const NUCLEAR_MAGNETON: f64 = 5.050_783_739_3e-27;
+/// Standard uncertainty of [`NUCLEAR_MAGNETON`]. Source: CODATA 2022.
+pub const NUCLEAR_MAGNETON_UNCERTAINTY: f64 = 0.000_000_001_6e-27;
+
+/// Electron magnetic moment (CODATA 2022).
+/// μ_e ≈ -9.2847646917e-24 J/T
+pub const ELECTRON_MAGNETIC_MOMENT: f64 = -9.284_764_691_7e-24;
+/// Standard uncertainty of [`ELECTRON_MAGNETIC_MOMENT`]. Source: CODATA 2022.
+pub const ELECTRON_MAGNETIC_MOMENT_UNCERTAINTY: f64 =
+    0.000_000_002_9e-24;
+
+/// Proton magnetic moment (CODATA 2022).
+/// μ_p ≈ 1.41060679545e-26 J/T
+pub const PROTON_MAGNETIC_MOMENT: f64 = 1.410_606_795_45e-26;
+/// Standard uncertainty of [`PROTON_MAGNETIC_MOMENT`]. Source: CODATA 2022.
+pub const PROTON_MAGNETIC_MOMENT_UNCERTAINTY: f64 =
+    0.000_000_000_60e-26;
+
+/// Neutron magnetic moment (CODATA 2022).
+/// μ_n ≈ -9.6623653e-27 J/T
+pub const NEUTRON_MAGNETIC_MOMENT: f64 = -9.662_365_3e-27;
+/// Standard uncertainty of [`NEUTRON_MAGNETIC_MOMENT`]. Source: CODATA 2022.
+pub const NEUTRON_MAGNETIC_MOMENT_UNCERTAINTY: f64 = 0.000_002_3e-27;
+
+/// Electron g-factor (CODATA 2022).
+/// g_e ≈ -2.00231930436092
+pub const ELECTRON_G_FACTOR: f64 = -2.002_319_304_360_92;
+/// Standard uncertainty of [`ELECTRON_G_FACTOR`]. Source: CODATA 2022.
+pub const ELECTRON_G_FACTOR_UNCERTAINTY: f64 = 0.000_000_000_000_36;
+
+/// Electron volt–atomic mass unit relationship (CODATA 2022).
+/// 1 eV/c² ≈ 1.07354410083e-9 u
+pub const EV_TO_AMU: f64 = 1.073_544_100_83e-9;
+/// Standard uncertainty of [`EV_TO_AMU`]. Source: CODATA 2022.
+pub const EV_TO_AMU_UNCERTAINTY: f64 = 0.000_000_000_33e-9;
+
+/// Classical electron radius (CODATA 2022).
+/// r_e = α²a_0 ≈ 2.8179403205e-15 m
+pub const CLASSICAL_ELECTRON_RADIUS: f64 = 2.817_940_320_5e-15;
+/// Standard uncertainty of [`CLASSICAL_ELECTRON_RADIUS`]. Source: CODATA 2022.
+pub const CLASSICAL_ELECTRON_RADIUS_UNCERTAINTY: f64 =
+    0.000_000_001_3e-15;
+
+/// Compton wavelength of the electron (CODATA 2022).
+/// λ_C = h/(m_e c) ≈ 2.42631023538e-12 m
+pub const ELECTRON_COMPTON_WAVELENGTH: f64 = 2.426_310_235_38e-12;
+/// Standard uncertainty of [`ELECTRON_COMPTON_WAVELENGTH`]. Source: CODATA 2022.
+pub const ELECTRON_COMPTON_WAVELENGTH_UNCERTAINTY: f64 =
+    0.000_000_000_76e-12;
+
+/// Compton wavelength of the proton (CODATA 2022).
+/// λ_C,p = h/(m_p c) ≈ 1.32140985360e-15 m
+pub const PROTON_COMPTON_WAVELENGTH: f64 = 1.321_409_853_60e-15;
+/// Standard uncertainty of [`PROTON_COMPTON_WAVELENGTH`]. Source: CODATA 2022.
+pub const PROTON_COMPTON_WAVELENGTH_UNCERTAINTY: f64 =
+    0.000_000_000_41e-15;
+
+/// Compton wavelength of the neutron (CODATA 2022).
+/// λ_C,n = h/(m_n c) ≈ 1.31959090382e-15 m
+pub const NEUTRON_COMPTON_WAVELENGTH: f64 = 1.319_590_903_82e-15;
+/// Standard uncertainty of [`NEUTRON_COMPTON_WAVELENGTH`]. Source: CODATA 2022.
+pub const NEUTRON_COMPTON_WAVELENGTH_UNCERTAINTY: f64 =
+    0.000_000_000_67e-15;
+
+/// Thomson cross section (CODATA 2022).
+/// σ_T = (8π/3)r_e² ≈ 6.6524587051e-29 m²
+pub const THOMSON_CROSS_SECTION: f64 = 6.652_458_705_1e-29;
+/// Standard uncertainty of [`THOMSON_CROSS_SECTION`]. Source: CODATA 2022.
+pub const THOMSON_CROSS_SECTION_UNCERTAINTY: f64 = 0.000_000_006_2e-29;
+
+/// Hartree energy (CODATA 2022).
+/// E_h = m_e c² α² ≈ 4.3597447222060e-18 J
+pub const HARTREE_ENERGY: f64 = 4.359_744_722_206_0e-18;
+/// Standard uncertainty of [`HARTREE_ENERGY`]. Source: CODATA 2022.
+pub const HARTREE_ENERGY_UNCERTAINTY: f64 = 0.000_000_000_004_8e-18;
+
+/// Hartree energy in eV (CODATA 2022).
+/// E_h ≈ 27.211386245981 eV
+pub const HARTREE_ENERGY_EV: f64 = 27.211_386_245_981;
+/// Standard uncertainty of [`HARTREE_ENERGY_EV`]. Source: CODATA 2022.
+pub const HARTREE_ENERGY_EV_UNCERTAINTY: f64 = 0.000_000_000_030;
+
+/// Molar mass constant (CODATA 2022).
+/// M_u ≈ 1.00000000105e-3 kg/mol
+pub const MOLAR_MASS_CONSTANT: f64 = 1.000_000_001_05e-3;
+/// Standard uncertainty of [`MOLAR_MASS_CONSTANT`]. Source: CODATA 2022.
+pub const MOLAR_MASS_CONSTANT_UNCERTAINTY: f64 = 0.000_000_000_31e-3;
+
+/// Sackur-Tetrode constant at 1 K, 101.325 kPa (CODATA 2022).
+/// S_0/R ≈ -1.15170753496
+pub const SACKUR_TETRODE_CONSTANT: f64 = -1.151_707_534_96;
+/// Standard uncertainty of [`SACKUR_TETRODE_CONSTANT`]. Source: CODATA 2022.
+pub const SACKUR_TETRODE_CONSTANT_UNCERTAINTY: f64 = 0.000_000_000_47;
+
+/// Impedance of free space (CODATA 2022).
+/// Z_0 = μ₀c ≈ 376.730313412 Ω
+pub const IMPEDANCE_OF_FREE_SPACE: f64 = 376.730_313_412;
+/// Standard uncertainty of [`IMPEDANCE_OF_FREE_SPACE`]. Source: CODATA 2022.
+pub const IMPEDANCE_OF_FREE_SPACE_UNCERTAINTY: f64 = 0.000_000_059;
+
+/// Inverse fine-structure constant (CODATA 2022).
+/// 1/α ≈ 137.035999177
+pub const INVERSE_FINE_STRUCTURE: f64 = 137.035_999_177;
+/// Standard uncertainty of [`INVERSE_FINE_STRUCTURE`]. Source: CODATA 2022.
+pub const INVERSE_FINE_STRUCTURE_UNCERTAINTY: f64 = 0.000_000_021;
+
+/// Electron charge-to-mass quotient (CODATA 2022).
+/// e/m_e ≈ -1.75882000838e11 C/kg
+pub const ELECTRON_CHARGE_TO_MASS: f64 = -1.758_820_008_38e11;
+/// Standard uncertainty of [`ELECTRON_CHARGE_TO_MASS`]. Source: CODATA 2022.
+pub const ELECTRON_CHARGE_TO_MASS_UNCERTAINTY: f64 =
+    0.000_000_000_55e11;
+
+/// Proton charge-to-mass quotient (CODATA 2022).
+/// e/m_p ≈ 9.5788331430e7 C/kg
+pub const PROTON_CHARGE_TO_MASS: f64 = 9.578_833_143_0e7;
+/// Standard uncertainty of [`PROTON_CHARGE_TO_MASS`]. Source: CODATA 2022.
+pub const PROTON_CHARGE_TO_MASS_UNCERTAINTY: f64 = 0.000_000_003_0e7;
+
+/// Atomic unit of length (= Bohr radius).
+/// a_0 ≈ 5.29177210544e-11 m
+pub const ATOMIC_UNIT_OF_LENGTH: f64 = BOHR_RADIUS;
+/// Standard uncertainty of [`ATOMIC_UNIT_OF_LENGTH`] (= [`BOHR_RADIUS_UNCERTAINTY`]).
+pub const ATOMIC_UNIT_OF_LENGTH_UNCERTAINTY: f64 =
+    BOHR_RADIUS_UNCERTAINTY;
+
+/// Atomic unit of time (CODATA 2022).
+/// ℏ/E_h ≈ 2.4188843265864e-17 s
+pub const ATOMIC_UNIT_OF_TIME: f64 = 2.418_884_326_586_4e-17;
+/// Standard uncertainty of [`ATOMIC_UNIT_OF_TIME`]. Source: CODATA 2022.
+pub const ATOMIC_UNIT_OF_TIME_UNCERTAINTY: f64 =
+    0.000_000_000_002_6e-17;
+
+/// Atomic unit of velocity (CODATA 2022).
+/// a_0 E_h/ℏ ≈ 2.18769126216e6 m/s
+pub const ATOMIC_UNIT_OF_VELOCITY: f64 = 2.187_691_262_16e6;
+/// Standard uncertainty of [`ATOMIC_UNIT_OF_VELOCITY`]. Source: CODATA 2022.
+pub const ATOMIC_UNIT_OF_VELOCITY_UNCERTAINTY: f64 = 0.000_000_000_34e6;
+
+/// Atomic unit of force (CODATA 2022).
+/// E_h/a_0 ≈ 8.2387235038e-8 N
+pub const ATOMIC_UNIT_OF_FORCE: f64 = 8.238_723_503_8e-8;
+/// Standard uncertainty of [`ATOMIC_UNIT_OF_FORCE`]. Source: CODATA 2022.
+pub const ATOMIC_UNIT_OF_FORCE_UNCERTAINTY: f64 = 0.000_000_001_3e-8;
+
+/// Atomic unit of electric field (CODATA 2022).
+/// E_h/(ea_0) ≈ 5.14220675112e11 V/m
+pub const ATOMIC_UNIT_OF_ELECTRIC_FIELD: f64 = 5.142_206_751_12e11;
+/// Standard uncertainty of [`ATOMIC_UNIT_OF_ELECTRIC_FIELD`]. Source: CODATA 2022.
+pub const ATOMIC_UNIT_OF_ELECTRIC_FIELD_UNCERTAINTY: f64 =
+    0.000_000_000_80e11;
+
+/// Atomic unit of electric polarizability (CODATA 2022).
+/// e²a₀²/E_h ≈ 1.64877727212e-41 C²m²/J
+pub const ATOMIC_UNIT_OF_POLARIZABILITY: f64 = 1.648_777_272_12e-41;
+/// Standard uncertainty of [`ATOMIC_UNIT_OF_POLARIZABILITY`]. Source: CODATA 2022.
+pub const ATOMIC_UNIT_OF_POLARIZABILITY_UNCERTAINTY: f64 =
+    0.000_000_000_51e-41;
+
+/// Electron mass in MeV/c² (CODATA 2022).
+/// m_e c² ≈ 0.51099895069 MeV
+pub const ELECTRON_MASS_MEV: f64 = 0.510_998_950_69;
+/// Standard uncertainty of [`ELECTRON_MASS_MEV`]. Source: CODATA 2022.
+pub const ELECTRON_MASS_MEV_UNCERTAINTY: f64 = 0.000_000_000_16;
+
+/// Proton mass in MeV/c² (CODATA 2022).
+/// m_p c² ≈ 938.27208943 MeV
+pub const PROTON_MASS_MEV: f64 = 938.272_089_43;
+/// Standard uncertainty of [`PROTON_MASS_MEV`]. Source: CODATA 2022.
+pub const PROTON_MASS_MEV_UNCERTAINTY: f64 = 0.000_000_29;
+
+/// Neutron mass in MeV/c² (CODATA 2022).
+/// m_n c² ≈ 939.56542194 MeV
+pub const NEUTRON_MASS_MEV: f64 = 939.565_421_94;
+/// Standard uncertainty of [`NEUTRON_MASS_MEV`]. Source: CODATA 2022.
+pub const NEUTRON_MASS_MEV_UNCERTAINTY: f64 = 0.000_000_48;
+
+/// Reduced Compton wavelength of the electron (CODATA 2022).
+/// λ̄_e = ℏ/(m_e c) ≈ 3.8615926744e-13 m
+pub const ELECTRON_REDUCED_COMPTON: f64 = 3.861_592_674_4e-13;
+/// Standard uncertainty of [`ELECTRON_REDUCED_COMPTON`]. Source: CODATA 2022.
+pub const ELECTRON_REDUCED_COMPTON_UNCERTAINTY: f64 =
+    0.000_000_001_2e-13;
+
+/// Reduced Compton wavelength of the proton (CODATA 2022).
+/// λ̄_p = ℏ/(m_p c) ≈ 2.10308910051e-16 m
+pub const PROTON_REDUCED_COMPTON: f64 = 2.103_089_100_51e-16;
+/// Standard uncertainty of [`PROTON_REDUCED_COMPTON`]. Source: CODATA 2022.
+pub const PROTON_REDUCED_COMPTON_UNCERTAINTY: f64 =
+    0.000_000_000_66e-16;
+
+/// Reduced Compton wavelength of the neutron (CODATA 2022).
+/// λ̄_n = ℏ/(m_n c) ≈ 2.1001941520e-16 m
+pub const NEUTRON_REDUCED_COMPTON: f64 = 2.100_194_152_0e-16;
+/// Standard uncertainty of [`NEUTRON_REDUCED_COMPTON`]. Source: CODATA 2022.
+pub const NEUTRON_REDUCED_COMPTON_UNCERTAINTY: f64 =
+    0.000_000_001_1e-16;
+
+/// The constants whose CODATA 2022 values differ from CODATA 2018,
+/// as `(name, value, standard uncertainty)`.
+pub const ADJUSTED: &[(&str, f64, f64)] = &[
+    (
+        "VACUUM_PERMEABILITY",
+        VACUUM_PERMEABILITY,
+        VACUUM_PERMEABILITY_UNCERTAINTY,
+    ),
+    (
+        "VACUUM_PERMITTIVITY",
+        VACUUM_PERMITTIVITY,
+        VACUUM_PERMITTIVITY_UNCERTAINTY,
+    ),
+    ("COULOMB", COULOMB, COULOMB_UNCERTAINTY),
+    ("ELECTRON_MASS", ELECTRON_MASS, ELECTRON_MASS_UNCERTAINTY),
+    ("PROTON_MASS", PROTON_MASS, PROTON_MASS_UNCERTAINTY),
+    ("NEUTRON_MASS", NEUTRON_MASS, NEUTRON_MASS_UNCERTAINTY),
+    (
+        "ATOMIC_MASS_UNIT",
+        ATOMIC_MASS_UNIT,
+        ATOMIC_MASS_UNIT_UNCERTAINTY,
+    ),
+    ("BOHR_RADIUS", BOHR_RADIUS, BOHR_RADIUS_UNCERTAINTY),
+    ("FINE_STRUCTURE", FINE_STRUCTURE, FINE_STRUCTURE_UNCERTAINTY),
+    ("RYDBERG", RYDBERG, RYDBERG_UNCERTAINTY),
+    ("DEUTERON_MASS", DEUTERON_MASS, DEUTERON_MASS_UNCERTAINTY),
+    ("TRITON_MASS", TRITON_MASS, TRITON_MASS_UNCERTAINTY),
+    ("HELION_MASS", HELION_MASS, HELION_MASS_UNCERTAINTY),
+    (
+        "ALPHA_PARTICLE_MASS",
+        ALPHA_PARTICLE_MASS,
+        ALPHA_PARTICLE_MASS_UNCERTAINTY,
+    ),
+    (
+        "ELECTRON_PROTON_MASS_RATIO",
+        ELECTRON_PROTON_MASS_RATIO,
+        ELECTRON_PROTON_MASS_RATIO_UNCERTAINTY,
+    ),
+    (
+        "PROTON_ELECTRON_MASS_RATIO",
+        PROTON_ELECTRON_MASS_RATIO,
+        PROTON_ELECTRON_MASS_RATIO_UNCERTAINTY,
+    ),
+    (
+        "MUON_ELECTRON_MASS_RATIO",
+        MUON_ELECTRON_MASS_RATIO,
+        MUON_ELECTRON_MASS_RATIO_UNCERTAINTY,
+    ),
+    (
+        "NEUTRON_PROTON_MASS_RATIO",
+        NEUTRON_PROTON_MASS_RATIO,
+        NEUTRON_PROTON_MASS_RATIO_UNCERTAINTY,
+    ),
+    (
+        "DEUTERON_PROTON_MASS_RATIO",
+        DEUTERON_PROTON_MASS_RATIO,
+        DEUTERON_PROTON_MASS_RATIO_UNCERTAINTY,
+    ),
+    ("BOHR_MAGNETON", BOHR_MAGNETON, BOHR_MAGNETON_UNCERTAINTY),
+    (
+        "NUCLEAR_MAGNETON",
+        NUCLEAR_MAGNETON,
+        NUCLEAR_MAGNETON_UNCERTAINTY,
+    ),
+    (
+        "ELECTRON_MAGNETIC_MOMENT",
+        ELECTRON_MAGNETIC_MOMENT,
+        ELECTRON_MAGNETIC_MOMENT_UNCERTAINTY,
+    ),
+    (
+        "PROTON_MAGNETIC_MOMENT",
+        PROTON_MAGNETIC_MOMENT,
+        PROTON_MAGNETIC_MOMENT_UNCERTAINTY,
+    ),
+    (
+        "NEUTRON_MAGNETIC_MOMENT",
+        NEUTRON_MAGNETIC_MOMENT,
+        NEUTRON_MAGNETIC_MOMENT_UNCERTAINTY,
+    ),
+    (
+        "ELECTRON_G_FACTOR",
+        ELECTRON_G_FACTOR,
+        ELECTRON_G_FACTOR_UNCERTAINTY,
+    ),
+    ("EV_TO_AMU", EV_TO_AMU, EV_TO_AMU_UNCERTAINTY),
+    (
+        "CLASSICAL_ELECTRON_RADIUS",
+        CLASSICAL_ELECTRON_RADIUS,
+        CLASSICAL_ELECTRON_RADIUS_UNCERTAINTY,
+    ),
+    (
+        "ELECTRON_COMPTON_WAVELENGTH",
+        ELECTRON_COMPTON_WAVELENGTH,
+        ELECTRON_COMPTON_WAVELENGTH_UNCERTAINTY,
+    ),
+    (
+        "PROTON_COMPTON_WAVELENGTH",
+        PROTON_COMPTON_WAVELENGTH,
+        PROTON_COMPTON_WAVELENGTH_UNCERTAINTY,
+    ),
+    (
+        "NEUTRON_COMPTON_WAVELENGTH",
+        NEUTRON_COMPTON_WAVELENGTH,
+        NEUTRON_COMPTON_WAVELENGTH_UNCERTAINTY,
+    ),
+    (
+        "THOMSON_CROSS_SECTION",
+        THOMSON_CROSS_SECTION,
+        THOMSON_CROSS_SECTION_UNCERTAINTY,
+    ),
+    ("HARTREE_ENERGY", HARTREE_ENERGY, HARTREE_ENERGY_UNCERTAINTY),
+    (
+        "HARTREE_ENERGY_EV",
+        HARTREE_ENERGY_EV,
+        HARTREE_ENERGY_EV_UNCERTAINTY,
+    ),
+    (
+        "MOLAR_MASS_CONSTANT",
+        MOLAR_MASS_CONSTANT,
+        MOLAR_MASS_CONSTANT_UNCERTAINTY,
+    ),
+    (
+        "SACKUR_TETRODE_CONSTANT",
+        SACKUR_TETRODE_CONSTANT,
+        SACKUR_TETRODE_CONSTANT_UNCERTAINTY,
+    ),
+    (
+        "IMPEDANCE_OF_FREE_SPACE",
+        IMPEDANCE_OF_FREE_SPACE,
+        IMPEDANCE_OF_FREE_SPACE_UNCERTAINTY,
+    ),
+    (
+        "INVERSE_FINE_STRUCTURE",
+        INVERSE_FINE_STRUCTURE,
+        INVERSE_FINE_STRUCTURE_UNCERTAINTY,
+    ),
+    (
+        "ELECTRON_CHARGE_TO_MASS",
+        ELECTRON_CHARGE_TO_MASS,
+        ELECTRON_CHARGE_TO_MASS_UNCERTAINTY,
+    ),
+    (
+        "PROTON_CHARGE_TO_MASS",
+        PROTON_CHARGE_TO_MASS,
+        PROTON_CHARGE_TO_MASS_UNCERTAINTY,
+    ),
+    (
+        "ATOMIC_UNIT_OF_LENGTH",
+        ATOMIC_UNIT_OF_LENGTH,
+        ATOMIC_UNIT_OF_LENGTH_UNCERTAINTY,
+    ),
+    (
+        "ATOMIC_UNIT_OF_TIME",
+        ATOMIC_UNIT_OF_TIME,
+        ATOMIC_UNIT_OF_TIME_UNCERTAINTY,
+    ),
+    (
+        "ATOMIC_UNIT_OF_VELOCITY",
+        ATOMIC_UNIT_OF_VELOCITY,
+        ATOMIC_UNIT_OF_VELOCITY_UNCERTAINTY,
+    ),
+    (
+        "ATOMIC_UNIT_OF_FORCE",
+        ATOMIC_UNIT_OF_FORCE,
+        ATOMIC_UNIT_OF_FORCE_UNCERTAINTY,
+    ),
+    (
+        "ATOMIC_UNIT_OF_ELECTRIC_FIELD",
+        ATOMIC_UNIT_OF_ELECTRIC_FIELD,
+        ATOMIC_UNIT_OF_ELECTRIC_FIELD_UNCERTAINTY,
+    ),
+    (
+        "ATOMIC_UNIT_OF_POLARIZABILITY",
+        ATOMIC_UNIT_OF_POLARIZABILITY,
+        ATOMIC_UNIT_OF_POLARIZABILITY_UNCERTAINTY,
+    ),
+    (
+        "ELECTRON_MASS_MEV",
+        ELECTRON_MASS_MEV,
+        ELECTRON_MASS_MEV_UNCERTAINTY,
+    ),
+    (
+        "PROTON_MASS_MEV",
+        PROTON_MASS_MEV,
+        PROTON_MASS_MEV_UNCERTAINTY,
+    ),
+    (
+        "NEUTRON_MASS_MEV",
+        NEUTRON_MASS_MEV,
+        NEUTRON_MASS_MEV_UNCERTAINTY,
+    ),
+    (
+        "ELECTRON_REDUCED_COMPTON",
+        ELECTRON_REDUCED_COMPTON,
+        ELECTRON_REDUCED_COMPTON_UNCERTAINTY,
+    ),
+    (
+        "PROTON_REDUCED_COMPTON",
+        PROTON_REDUCED_COMPTON,
+        PROTON_REDUCED_COMPTON_UNCERTAINTY,
+    ),
+    (
+        "NEUTRON_REDUCED_COMPTON",
+        NEUTRON_REDUCED_COMPTON,
+        NEUTRON_REDUCED_COMPTON_UNCERTAINTY,
+    ),
+];
+
+const LEN: usize = codata2018::TABLE.len();
+
+/// Every constant with its CODATA 2022 value, category, unit,
+/// uncertainty and metadata: the rows of [`codata2018::TABLE`],
+/// cryptographic ones included, with [`ADJUSTED`] applied.
+pub const TABLE: &[ConstantEntry] = &with_adjusted::<LEN>(
+    codata2018::TABLE,
+    ADJUSTED,
+    Source::Codata2022,
+);
@@ -0,0 +1,482 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Correlation coefficients between measured constants.
+//!
+//! Since the 2019 SI fixed h, e, k and N_A, most measured
+//! constants are exact functions of a few adjusted quantities: the
+//! fine-structure constant α, the Rydberg constant R∞, particle mass
+//! ratios, g-factors and G. m_e = 2hR∞/(α²c), a₀ = α/(4πR∞) and
+//! μ_B = eħ/(2m_e) all inherit the uncertainty of α, so their
+//! errors are almost fully (anti-)correlated, and treating them as
+//! independent misstates any combined uncertainty.
+//!
+//! Each [`Dependence`] in [`DEPENDENCES`] gives, to first order, how
+//! one constant scales with others, and the correlation of two
+//! constants follows from the inputs they share. This is a model,
+//! not the CODATA correlation matrix, hence the `modelled_` names:
+//! the coefficients are derived from these relations, and the
+//! correlations between the adjusted inputs themselves (α, R∞, the
+//! mass ratios, ...), which are small in the CODATA adjustments,
+//! are not modelled. The PDG masses of the W, Z and Higgs bosons and
+//! the CODATA τ mass, on which no other tabulated constant depends,
+//! are independent inputs. Available in `no_std`.
+//!
+//! # Example
+//!
+//! ```
+//! use cmn::constants::{modelled_correlation as r, Dataset};
+//!
+//! // The electron mass goes as α⁻², so it is anticorrelated
+//! // with α and correlated with the Bohr radius a₀ = α/(4πR∞).
+//! assert!(r("ELECTRON_MASS", "FINE_STRUCTURE").unwrap() < -0.999);
+//! assert!(r("BOHR_RADIUS", "FINE_STRUCTURE").unwrap() > 0.999);
+//!
+//! // G only correlates with the Planck units.
+//! let d = Dataset::Codata2018;
+//! let g = "GRAVITATIONAL_CONSTANT";
+//! assert_eq!(d.modelled_correlation(g, "PLANCK"), Some(0.0));
+//! assert!(d.modelled_correlation("PLANCK_MASS", g).unwrap() < -0.99);
+//! ```
+
+use super::{abs, position, sqrt, ConstantEntry, Dataset, TABLE_LEN};
+
+/// How one constant depends, to first order, on others.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Dependence {
+    /// The name of the dependent constant.
+    pub constant: &'static str,
+    /// The defining relation in conventional notation.
+    pub formula: &'static str,
+    /// The constants it depends on, with the exponent of each:
+    /// `δx/x = Σ exponent · δy/y`.
+    pub on: &'static [(&'static str, f64)],
+    /// `true` if the constant also depends on a measured input
+    /// outside the table, such as A_r(e) for m_u. That input is an
+    /// independent residual, sized so that the total matches the
+    /// tabulated uncertainty.
+    pub residual: bool,
+    /// `true` if the exponents give the absolute change `δx`
+    /// rather than `δx/x`, as for the logarithmic Sackur-Tetrode
+    /// constant.
+    pub absolute: bool,
+}
+
+const fn dependence(
+    constant: &'static str,
+    formula: &'static str,
+    on: &'static [(&'static str, f64)],
+) -> Dependence {
+    Dependence {
+        constant,
+        formula,
+        on,
+        residual: false,
+        absolute: false,
+    }
+}
+
+const fn with_residual(
+    constant: &'static str,
+    formula: &'static str,
+    on: &'static [(&'static str, f64)],
+) -> Dependence {
+    Dependence {
+        residual: true,
+        ..dependence(constant, formula, on)
+    }
+}
+
+const ALPHA: &str = "FINE_STRUCTURE";
+const R_INF: &str = "RYDBERG";
+const M_E: &str = "ELECTRON_MASS";
+const M_P: &str = "PROTON_MASS";
+const M_N: &str = "NEUTRON_MASS";
+const M_U: &str = "ATOMIC_MASS_UNIT";
+const A_0: &str = "BOHR_RADIUS";
+const E_H: &str = "HARTREE_ENERGY";
+const G: &str = "GRAVITATIONAL_CONSTANT";
+
+/// The first-order dependences between measured constants, each
+/// listed after the constants it depends on.
+///
+/// Constants not listed here (α, R∞, the mass ratios, the
+/// g-factors, G, the τ mass and the PDG boson masses) are the
+/// independent inputs.
+/// A listed constant without a residual is an exact function of
+/// its inputs; its tabulated uncertainty differs from the one they
+/// imply only by the rounding of the published digits.
+pub const DEPENDENCES: &[Dependence] = &[
+    // Electromagnetic: μ₀ and everything built on it scale with α.
+    dependence("INVERSE_FINE_STRUCTURE", "1/α", &[(ALPHA, -1.0)]),
+    dependence(
+        "VACUUM_PERMEABILITY",
+        "μ₀ = 2αh/(e²c)",
+        &[(ALPHA, 1.0)],
+    ),
+    dependence(
+        "VACUUM_PERMITTIVITY",
+        "ε₀ = 1/(μ₀c²)",
+        &[("VACUUM_PERMEABILITY", -1.0)],
+    ),
+    dependence(
+        "COULOMB",
+        "k_e = 1/(4πε₀)",
+        &[("VACUUM_PERMITTIVITY", -1.0)],
+    ),
+    dependence(
+        "IMPEDANCE_OF_FREE_SPACE",
+        "Z₀ = μ₀c",
+        &[("VACUUM_PERMEABILITY", 1.0)],
+    ),
+    dependence(
+        "PLANCK_CHARGE",
+        "q_P = √(4πε₀ħc)",
+        &[("VACUUM_PERMITTIVITY", 0.5)],
+    ),
+    dependence("ATOMIC_UNIT_OF_VELOCITY", "αc", &[(ALPHA, 1.0)]),
+    // Electron
+    dependence(M_E, "m_e = 2hR∞/(α²c)", &[(ALPHA, -2.0), (R_INF, 1.0)]),
+    dependence("ELECTRON_MASS_MEV", "m_e c²/e", &[(M_E, 1.0)]),
+    dependence(
+        "ELECTRON_COMPTON_WAVELENGTH",
+        "h/(m_e c)",
+        &[(M_E, -1.0)],
+    ),
+    dependence("ELECTRON_REDUCED_COMPTON", "ħ/(m_e c)", &[(M_E, -1.0)]),
+    dependence("ELECTRON_CHARGE_TO_MASS", "−e/m_e", &[(M_E, -1.0)]),
+    dependence("BOHR_MAGNETON", "μ_B = eħ/(2m_e)", &[(M_E, -1.0)]),
+    dependence(
+        "ELECTRON_MAGNETIC_MOMENT",
+        "μ_e = g_e μ_B/2",
+        &[("ELECTRON_G_FACTOR", 1.0), ("BOHR_MAGNETON", 1.0)],
+    ),
+    // Atomic units
+    dependence(E_H, "E_h = 2hcR∞", &[(R_INF, 1.0)]),
+    dependence("HARTREE_ENERGY_EV", "E_h/e", &[(E_H, 1.0)]),
+    dependence("ATOMIC_UNIT_OF_TIME", "ħ/E_h", &[(E_H, -1.0)]),
+    dependence(A_0, "a₀ = α/(4πR∞)", &[(ALPHA, 1.0), (R_INF, -1.0)]),
+    dependence("ATOMIC_UNIT_OF_LENGTH", "a₀", &[(A_0, 1.0)]),
+    dependence(
+        "ATOMIC_UNIT_OF_FORCE",
+        "E_h/a₀",
+        &[(E_H, 1.0), (A_0, -1.0)],
+    ),
+    dependence(
+        "ATOMIC_UNIT_OF_ELECTRIC_FIELD",
+        "E_h/(ea₀)",
+        &[(E_H, 1.0), (A_0, -1.0)],
+    ),
+    dependence(
+        "ATOMIC_UNIT_OF_POLARIZABILITY",
+        "e²a₀²/E_h",
+        &[(A_0, 2.0), (E_H, -1.0)],
+    ),
+    dependence(
+        "CLASSICAL_ELECTRON_RADIUS",
+        "r_e = α²a₀",
+        &[(ALPHA, 2.0), (A_0, 1.0)],
+    ),
+    dependence(
+        "THOMSON_CROSS_SECTION",
+        "σ_e = (8π/3)r_e²",
+        &[("CLASSICAL_ELECTRON_RADIUS", 2.0)],
+    ),
+    // Proton and neutron
+    dependence(
+        "ELECTRON_PROTON_MASS_RATIO",
+        "m_e/m_p",
+        &[("PROTON_ELECTRON_MASS_RATIO", -1.0)],
+    ),
+    dependence(
+        M_P,
+        "m_p = m_e (m_p/m_e)",
+        &[(M_E, 1.0), ("PROTON_ELECTRON_MASS_RATIO", 1.0)],
+    ),
+    dependence("PROTON_MASS_MEV", "m_p c²/e", &[(M_P, 1.0)]),
+    dependence(
+        "PROTON_COMPTON_WAVELENGTH",
+        "h/(m_p c)",
+        &[(M_P, -1.0)],
+    ),
+    dependence("PROTON_REDUCED_COMPTON", "ħ/(m_p c)", &[(M_P, -1.0)]),
+    dependence("PROTON_CHARGE_TO_MASS", "e/m_p", &[(M_P, -1.0)]),
+    dependence("NUCLEAR_MAGNETON", "μ_N = eħ/(2m_p)", &[(M_P, -1.0)]),
+    dependence(
+        "PROTON_MAGNETIC_MOMENT",
+        "μ_p = g_p μ_N/2",
+        &[("PROTON_G_FACTOR", 1.0), ("NUCLEAR_MAGNETON", 1.0)],
+    ),
+    dependence(
+        M_N,
+        "m_n = m_p (m_n/m_p)",
+        &[(M_P, 1.0), ("NEUTRON_PROTON_MASS_RATIO", 1.0)],
+    ),
+    dependence("NEUTRON_MASS_MEV", "m_n c²/e", &[(M_N, 1.0)]),
+    dependence(
+        "NEUTRON_COMPTON_WAVELENGTH",
+        "h/(m_n c)",
+        &[(M_N, -1.0)],
+    ),
+    dependence("NEUTRON_REDUCED_COMPTON", "ħ/(m_n c)", &[(M_N, -1.0)]),
+    with_residual(
+        "NEUTRON_MAGNETIC_MOMENT",
+        "μ_n = (μ_n/μ_e) μ_e",
+        &[("ELECTRON_MAGNETIC_MOMENT", 1.0)],
+    ),
+    // Muon and light nuclei
+    dependence(
+        "MUON_MASS",
+        "m_μ = m_e (m_μ/m_e)",
+        &[(M_E, 1.0), ("MUON_ELECTRON_MASS_RATIO", 1.0)],
+    ),
+    dependence("MUON_MASS_MEV", "m_μ c²/e", &[("MUON_MASS", 1.0)]),
+    dependence(
+        "DEUTERON_MASS",
+        "m_d = m_p (m_d/m_p)",
+        &[(M_P, 1.0), ("DEUTERON_PROTON_MASS_RATIO", 1.0)],
+    ),
+    with_residual(M_U, "m_u = m_e/A_r(e)", &[(M_E, 1.0)]),
+    dependence("MOLAR_MASS_CONSTANT", "M_u = N_A m_u", &[(M_U, 1.0)]),
+    dependence("EV_TO_AMU", "e/(m_u c²)", &[(M_U, -1.0)]),
+    with_residual("TRITON_MASS", "m_t = A_r(t) m_u", &[(M_U, 1.0)]),
+    with_residual("HELION_MASS", "m_h = A_r(h) m_u", &[(M_U, 1.0)]),
+    with_residual(
+        "ALPHA_PARTICLE_MASS",
+        "m_α = A_r(α) m_u",
+        &[(M_U, 1.0)],
+    ),
+    Dependence {
+        constant: "SACKUR_TETRODE_CONSTANT",
+        formula: "S₀/k = 5/2 + (3/2) ln m_u + ...",
+        on: &[(M_U, 1.5)],
+        residual: false,
+        absolute: true,
+    },
+    // Planck units
+    dependence("PLANCK_MASS", "m_P = √(ħc/G)", &[(G, -0.5)]),
+    dependence("PLANCK_LENGTH", "l_P = √(ħG/c³)", &[(G, 0.5)]),
+    dependence("PLANCK_TIME", "t_P = √(ħG/c⁵)", &[(G, 0.5)]),
+    dependence(
+        "PLANCK_TEMPERATURE",
+        "T_P = m_P c²/k",
+        &[("PLANCK_MASS", 1.0)],
+    ),
+];
+
+type Vector = [f64; TABLE_LEN];
+
+/// The variance of every independent input of a dataset: the
+/// relative variance of each input constant, and of the residual of
+/// each constant that has one.
+struct Model {
+    table: &'static [ConstantEntry],
+    variance: Vector,
+}
+
+impl Model {
+    fn new(dataset: Dataset) -> Self {
+        let mut model = Self {
+            table: dataset.table(),
+            variance: [0.0; TABLE_LEN],
+        };
+        for (i, entry) in model.table.iter().enumerate() {
+            let u = relative_uncertainty(entry);
+            model.variance[i] = u * u;
+        }
+        // Dependences come after their inputs, so every variance
+        // they use is final by the time it is read.
+        for dependence in DEPENDENCES {
+            let Some(i) = position(dependence.constant) else {
+                continue;
+            };
+            let own = model.variance[i];
+            model.variance[i] = 0.0;
+            if dependence.residual {
+                let explained = model.variance_of(i);
+                model.variance[i] = (own - explained).max(0.0);
+            }
+        }
+        model
+    }
+
+    /// Returns the sensitivity of row `i` to every input.
+    fn sensitivity(&self, i: usize) -> Vector {
+        let mut out = [0.0; TABLE_LEN];
+        self.expand(i, 1.0, &mut out);
+        out
+    }
+
+    /// Adds the sensitivity of row `i` to every input, scaled by
+    /// `weight`, to `out`.
+    fn expand(&self, i: usize, weight: f64, out: &mut Vector) {
+        out[i] += weight;
+        let entry = &self.table[i];
+        let Some(dependence) =
+            DEPENDENCES.iter().find(|d| d.constant == entry.name)
+        else {
+            return;
+        };
+        let scale = match entry.value.as_f64() {
+            Some(value) if dependence.absolute => 1.0 / value,
+            _ => 1.0,
+        };
+        for &(name, exponent) in dependence.on {
+            if let Some(j) = position(name) {
+                self.expand(j, weight * exponent * scale, out);
+            }
+        }
+    }
+
+    fn covariance_of(&self, x: &Vector, y: &Vector) -> f64 {
+        x.iter()
+            .zip(y)
+            .zip(&self.variance)
+            .map(|((a, b), v)| a * b * v)
+            .sum()
+    }
+
+    /// Returns the relative variance the model gives row `i`.
+    fn variance_of(&self, i: usize) -> f64 {
+        let s = self.sensitivity(i);
+        self.covariance_of(&s, &s)
+    }
+
+    fn correlation(&self, a: &str, b: &str) -> Option<f64> {
+        let (i, j) = (position(a)?, position(b)?);
+        let vx = self.table[i].value.as_f64()?;
+        let vy = self.table[j].value.as_f64()?;
+        if i == j {
+            return Some(1.0);
+        }
+        let (sx, sy) = (self.sensitivity(i), self.sensitivity(j));
+        let product =
+            self.covariance_of(&sx, &sx) * self.covariance_of(&sy, &sy);
+        if product == 0.0 {
+            return Some(0.0);
+        }
+        let r = self.covariance_of(&sx, &sy) / sqrt(product);
+        // Correlate the values, not their magnitudes.
+        let r = if (vx < 0.0) != (vy < 0.0) { -r } else { r };
+        Some(r.clamp(-1.0, 1.0))
+    }
+}
+
+fn relative_uncertainty(entry: &ConstantEntry) -> f64 {
+    match entry.value.as_f64() {
+        Some(value) if value != 0.0 => {
+            entry.uncertainty.absolute() / abs(value)
+        }
+        _ => 0.0,
+    }
+}
+
+/// Returns the correlation coefficient of two constants in
+/// `dataset`, between -1 and 1, or `None` if either name is unknown
+/// or not a float.
+///
+/// A constant is fully correlated with itself, and exact constants
+/// are uncorrelated with everything else.
+///
+/// The coefficient is derived from [`DEPENDENCES`]; it is not the
+/// published CODATA coefficient. The adjusted inputs (α, R∞, the
+/// mass ratios, the g-factors, ...) are treated as mutually
+/// uncorrelated, so constants that depend on disjoint inputs, such
+/// as α and R∞, get `0.0` even where the CODATA adjustment
+/// correlates them weakly.
+pub fn modelled_correlation(
+    dataset: Dataset,
+    a: &str,
+    b: &str,
+) -> Option<f64> {
+    Model::new(dataset).correlation(a, b)
+}
+
+/// Returns the relative standard uncertainty of the named constant
+/// implied by [`DEPENDENCES`], or `None` if the name is unknown or
+/// not a float.
+///
+/// It matches the tabulated uncertainty up to the rounding of the
+/// published digits, which makes it a check on the dependences.
+pub fn modelled_uncertainty(
+    dataset: Dataset,
+    name: &str,
+) -> Option<f64> {
+    let model = Model::new(dataset);
+    let i =
+        position(name).filter(|&i| model.table[i].value.is_float())?;
+    Some(sqrt(model.variance_of(i)))
+}
+
+/// Returns the covariance of two constants in `dataset`, in the
+/// product of their units: `r(a, b) u(a) u(b)`, with `r` from
+/// [`modelled_correlation`].
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::{correlations, Dataset};
+/// use cmn::measured::Measured;
+///
+/// // m_p/m_e from the table, and recomputed from the two masses.
+/// let d = Dataset::Codata2018;
+/// let m_p = Measured::from_constant_in(d, "PROTON_MASS").unwrap();
+/// let m_e = Measured::from_constant_in(d, "ELECTRON_MASS").unwrap();
+/// let cov = correlations::modelled_covariance(
+///     d,
+///     "PROTON_MASS",
+///     "ELECTRON_MASS",
+/// );
+/// let ratio = m_p.div_with_covariance(m_e, cov.unwrap());
+/// let naive = m_p / m_e;
+///
+/// // The shared α uncertainty cancels in the ratio.
+/// assert!(ratio.relative() < 1e-10);
+/// assert!(naive.relative() > 4e-10);
+/// ```
+pub fn modelled_covariance(
+    dataset: Dataset,
+    a: &str,
+    b: &str,
+) -> Option<f64> {
+    let r = modelled_correlation(dataset, a, b)?;
+    let u =
+        |name| dataset.entry(name).map(|e| e.uncertainty.absolute());
+    Some(r * u(a)? * u(b)?)
+}
+
+/// Returns the matrix of [`modelled_correlation`] coefficients of
+/// `names` in `dataset`, or `None` if a name is unknown or not a
+/// float.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::{correlations, Dataset};
+///
+/// // G is measured independently of everything else.
+/// let m = correlations::modelled_matrix(
+///     Dataset::Codata2022,
+///     ["FINE_STRUCTURE", "ELECTRON_MASS", "GRAVITATIONAL_CONSTANT"],
+/// )
+/// .unwrap();
+/// assert_eq!(m[0][0], 1.0);
+/// assert_eq!(m[0][1], m[1][0]);
+/// assert!(m[0][1] < -0.99);
+/// assert_eq!(m[0][2], 0.0);
+/// ```
+pub fn modelled_matrix<const N: usize>(
+    dataset: Dataset,
+    names: [&str; N],
+) -> Option<[[f64; N]; N]> {
+    let model = Model::new(dataset);
+    let mut out = [[0.0; N]; N];
+    for (i, a) in names.iter().enumerate() {
+        for (j, b) in names.iter().enumerate().skip(i) {
+            let r = model.correlation(a, b)?;
+            out[i][j] = r;
+            out[j][i] = r;
+        }
+    }
+    Some(out)
+}
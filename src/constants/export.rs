@@ -0,0 +1,494 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Render the constant catalogue as CSV, Markdown, LaTeX or JSON.
+//!
+//! A [`Catalogue`] collects one [`Row`] per constant, with its name,
+//! symbol, value, unit, standard uncertainty and category, from
+//! [`CONSTANTS_TABLE`], a [`Constants`]
+//! collection or a [`ConstantRegistry`]. It can be narrowed to a
+//! [`Category`] and rendered in any [`Format`]. Requires the `std`
+//! feature.
+//!
+//! Floats are written in their shortest round-trip form, in
+//! scientific notation outside `1e-3..1e9`. Exact values have no
+//! uncertainty: the cell is empty in CSV, reads `exact` in Markdown
+//! and LaTeX, and is `null` in JSON.
+//!
+//! # Example
+//!
+//! ```
+//! use cmn::constants::export::{Catalogue, Format};
+//! use cmn::constants::Category;
+//!
+//! let maths = Catalogue::new().in_category(Category::Mathematical);
+//! let markdown = maths.render(Format::Markdown);
+//! assert!(markdown.starts_with(
+//!     "| Name | Symbol | Value | Unit | Uncertainty | Category |\n"
+//! ));
+//! assert!(markdown.contains(
+//!     "| `PI` | π | 3.141592653589793 | 1 | exact | Mathematical |\n"
+//! ));
+//!
+//! let csv = Catalogue::new().render(Format::Csv);
+//! assert!(csv.contains("\nPLANCK,h,6.62607015e-34,J s,,Physical\n"));
+//! ```
+
+use super::{
+    Category, ConstantEntry, ConstantRegistry, ConstantValue,
+    Constants, Uncertainty, CONSTANTS_TABLE,
+};
+use crate::units::{self, Unit};
+use std::borrow::Cow;
+use std::fmt::Write;
+
+/// An output format of [`Catalogue::render`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Format {
+    /// Comma-separated values with a header row.
+    Csv,
+    /// A GitHub-flavoured Markdown table.
+    Markdown,
+    /// A LaTeX `tabular` environment.
+    Latex,
+    /// A pretty-printed JSON array of objects.
+    Json,
+}
+
+/// One constant of a [`Catalogue`].
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Row {
+    /// The name of the constant.
+    pub name: Cow<'static, str>,
+    /// The conventional Unicode symbol; empty if there is none.
+    pub symbol: &'static str,
+    /// The symbol as LaTeX math; empty if there is none.
+    pub latex: &'static str,
+    /// The value, expressed in [`Row::unit`].
+    pub value: ConstantValue,
+    /// The unit of the value.
+    pub unit: Unit,
+    /// The symbol of the unit as written, which `unit.symbol`
+    /// leaves empty for units read from text; see
+    /// [`Definition::unit_symbol`].
+    ///
+    /// [`Definition::unit_symbol`]:
+    ///     super::registry::Definition::unit_symbol
+    pub unit_symbol: Cow<'static, str>,
+    /// The standard uncertainty of the value.
+    pub uncertainty: Uncertainty,
+    /// The category, or `None` for constants without metadata.
+    pub category: Option<Category>,
+}
+
+/// The rows to export, in catalogue order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Catalogue {
+    rows: Vec<Row>,
+}
+
+impl Catalogue {
+    /// Creates a catalogue of [`CONSTANTS_TABLE`].
+    pub fn new() -> Self {
+        Self::from_table(CONSTANTS_TABLE)
+    }
+
+    /// Creates a catalogue of the given table rows, e.g.
+    /// [`Dataset::table`](super::Dataset::table).
+    pub fn from_table(table: &'static [ConstantEntry]) -> Self {
+        let rows = table
+            .iter()
+            .map(|entry| Row {
+                name: Cow::Borrowed(entry.name),
+                symbol: entry.info.symbol,
+                latex: entry.info.latex,
+                value: entry.value.into(),
+                unit: entry.unit,
+                unit_symbol: Cow::Borrowed(entry.unit.symbol),
+                uncertainty: entry.uncertainty,
+                category: Some(entry.category),
+            })
+            .collect();
+        Self { rows }
+    }
+
+    /// Creates a catalogue of the values held by `constants`.
+    ///
+    /// Metadata comes from the collection's dataset; constants
+    /// that are not in it are exported as dimensionless and exact,
+    /// with no symbol or category.
+    pub fn from_constants(constants: &Constants) -> Self {
+        let dataset = constants.dataset();
+        let rows = constants
+            .constants()
+            .iter()
+            .map(|constant| {
+                let entry = dataset.entry(&constant.name);
+                let unit = entry.map_or(units::ONE, |e| e.unit);
+                Row {
+                    name: constant.name.clone(),
+                    symbol: entry.map_or("", |e| e.info.symbol),
+                    latex: entry.map_or("", |e| e.info.latex),
+                    value: constant.value.clone(),
+                    unit,
+                    unit_symbol: Cow::Borrowed(unit.symbol),
+                    uncertainty: entry
+                        .map_or(Uncertainty::Exact, |e| e.uncertainty),
+                    category: entry.map(|e| e.category),
+                }
+            })
+            .collect();
+        Self { rows }
+    }
+
+    /// Creates a catalogue of every constant in `registry`,
+    /// including the user-defined ones.
+    pub fn from_registry(registry: &ConstantRegistry) -> Self {
+        let dataset = registry.dataset();
+        let rows = registry
+            .definitions()
+            .iter()
+            .map(|definition| {
+                let info = dataset.info(&definition.name);
+                Row {
+                    name: definition.name.clone(),
+                    symbol: info.map_or("", |i| i.symbol),
+                    latex: info.map_or("", |i| i.latex),
+                    value: definition.value.clone(),
+                    unit: definition.unit,
+                    unit_symbol: Cow::Owned(
+                        definition.unit_symbol().to_string(),
+                    ),
+                    uncertainty: definition.uncertainty,
+                    category: Some(definition.category),
+                }
+            })
+            .collect();
+        Self { rows }
+    }
+
+    /// Keeps only the rows in `category`.
+    pub fn in_category(mut self, category: Category) -> Self {
+        self.rows.retain(|row| row.category == Some(category));
+        self
+    }
+
+    /// Returns the rows in catalogue order.
+    pub fn rows(&self) -> &[Row] {
+        &self.rows
+    }
+
+    /// Renders the catalogue in `format`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::export::{Catalogue, Format};
+    /// use cmn::constants::Category;
+    ///
+    /// let crypto =
+    ///     Catalogue::new().in_category(Category::Cryptographic);
+    /// let json: serde_json::Value =
+    ///     serde_json::from_str(&crypto.render(Format::Json)).unwrap();
+    /// assert_eq!(json[1]["name"], "HASH_COST");
+    /// assert_eq!(json[1]["value"], 8);
+    /// assert_eq!(json[1]["uncertainty"], serde_json::Value::Null);
+    ///
+    /// let latex = crypto.render(Format::Latex);
+    /// assert!(latex.starts_with(r"\begin{tabular}"));
+    /// assert!(latex.contains(r"\texttt{HASH\_COST} &  & $8$ &"));
+    /// ```
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Csv => self.csv(),
+            Format::Markdown => self.markdown(),
+            Format::Latex => self.latex(),
+            Format::Json => self.json(),
+        }
+    }
+
+    fn csv(&self) -> String {
+        let mut out = String::from(
+            "name,symbol,value,unit,uncertainty,category\n",
+        );
+        for row in &self.rows {
+            let cells = [
+                csv_cell(&row.name),
+                csv_cell(row.symbol),
+                csv_cell(&value_text(&row.value)),
+                csv_cell(&row.unit_symbol),
+                uncertainty(row).map_or(String::new(), number),
+                row.category
+                    .map_or(String::new(), |c| format!("{c:?}")),
+            ];
+            out.push_str(&cells.join(","));
+            out.push('\n');
+        }
+        out
+    }
+
+    fn markdown(&self) -> String {
+        let mut out = String::from(
+            "| Name | Symbol | Value | Unit | Uncertainty | \
+             Category |\n\
+             |:---|:---|---:|:---|---:|:---|\n",
+        );
+        for row in &self.rows {
+            let value = match row.value {
+                ConstantValue::Float(v) => number(v),
+                ConstantValue::U32(v) => v.to_string(),
+                ConstantValue::Usize(v) => v.to_string(),
+                ref other => markdown_code(&other.to_string()),
+            };
+            let _ = writeln!(
+                out,
+                "| {} | {} | {} | {} | {} | {} |",
+                markdown_code(&row.name),
+                markdown_text(row.symbol),
+                value,
+                markdown_text(&row.unit_symbol),
+                uncertainty(row).map_or("exact".to_string(), number),
+                row.category
+                    .map_or(String::new(), |c| format!("{c:?}")),
+            );
+        }
+        out
+    }
+
+    fn latex(&self) -> String {
+        let mut out = String::from(
+            "\\begin{tabular}{llrlrl}\n\\hline\n\
+             Name & Symbol & Value & Unit & Uncertainty & \
+             Category \\\\\n\
+             \\hline\n",
+        );
+        for row in &self.rows {
+            let symbol = if row.latex.is_empty() {
+                String::new()
+            } else {
+                format!("${}$", row.latex)
+            };
+            let value = match row.value {
+                ConstantValue::Float(v) => latex_number(v),
+                ConstantValue::U32(v) => format!("${v}$"),
+                ConstantValue::Usize(v) => format!("${v}$"),
+                ref other => {
+                    format!(
+                        "\\texttt{{{}}}",
+                        latex_text(&other.to_string())
+                    )
+                }
+            };
+            let _ = writeln!(
+                out,
+                "\\texttt{{{}}} & {} & {} & {} & {} & {} \\\\",
+                latex_text(&row.name),
+                symbol,
+                value,
+                latex_unit(&row.unit_symbol),
+                uncertainty(row)
+                    .map_or("exact".to_string(), latex_number),
+                row.category
+                    .map_or(String::new(), |c| format!("{c:?}")),
+            );
+        }
+        out.push_str("\\hline\n\\end{tabular}\n");
+        out
+    }
+
+    fn json(&self) -> String {
+        let rows: Vec<_> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let value = match &row.value {
+                    ConstantValue::Float(v) => serde_json::json!(v),
+                    ConstantValue::U32(v) => serde_json::json!(v),
+                    ConstantValue::Usize(v) => serde_json::json!(v),
+                    other => serde_json::json!(other.to_string()),
+                };
+                serde_json::json!({
+                    "name": row.name,
+                    "symbol": row.symbol,
+                    "value": value,
+                    "unit": row.unit_symbol,
+                    "uncertainty": uncertainty(row),
+                    "category": row.category,
+                })
+            })
+            .collect();
+        let mut out = serde_json::to_string_pretty(&rows)
+            .unwrap_or_else(|_| "[]".to_string());
+        out.push('\n');
+        out
+    }
+}
+
+impl Default for Catalogue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the absolute uncertainty of a float row, or `None` if it
+/// is exact or not a float.
+fn uncertainty(row: &Row) -> Option<f64> {
+    match (&row.value, row.uncertainty) {
+        (ConstantValue::Float(_), Uncertainty::Standard(sigma)) => {
+            Some(sigma)
+        }
+        _ => None,
+    }
+}
+
+/// Formats a float in its shortest round-trip form, in scientific
+/// notation outside `1e-3..1e9`.
+fn number(value: f64) -> String {
+    let magnitude = value.abs();
+    if magnitude == 0.0 || (1e-3..1e9).contains(&magnitude) {
+        format!("{value}")
+    } else {
+        format!("{value:e}")
+    }
+}
+
+fn value_text(value: &ConstantValue) -> String {
+    match value {
+        ConstantValue::Float(v) => number(*v),
+        other => other.to_string(),
+    }
+}
+
+fn csv_cell(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) || text.trim() != text {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn markdown_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '|' | '*' | '_' | '`' | '[' | ']' | '<' | '>'
+        ) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Wraps `text` in a code span long enough to hold its backticks.
+/// Pipes are still escaped, as GitHub splits cells on them first.
+fn markdown_code(text: &str) -> String {
+    let longest =
+        text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest + 1);
+    let pad = if text.starts_with('`') || text.ends_with('`') {
+        " "
+    } else {
+        ""
+    };
+    let text = text.replace('|', "\\|");
+    format!("{fence}{pad}{text}{pad}{fence}")
+}
+
+fn latex_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str("\\textbackslash{}"),
+            '~' => out.push_str("\\textasciitilde{}"),
+            '^' => out.push_str("\\textasciicircum{}"),
+            '#' | '$' | '%' | '&' | '_' | '{' | '}' => {
+                out.push('\\');
+                out.push(c);
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn latex_number(value: f64) -> String {
+    let text = number(value);
+    match text.split_once('e') {
+        Some((mantissa, exponent)) => {
+            format!("${mantissa} \\times 10^{{{exponent}}}$")
+        }
+        None => format!("${text}$"),
+    }
+}
+
+/// Renders a unit symbol such as `"J mol^-1 K^-1"` or `"W·m⁻²"` as
+/// upright LaTeX math, in ASCII.
+fn latex_unit(symbol: &str) -> String {
+    let mut out = String::new();
+    let mut chars = symbol.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            ' ' => out.push_str("\\,"),
+            '^' => {
+                out.push_str("^{");
+                while let Some(&d) = chars.peek() {
+                    if !(d == '-' || d.is_ascii_digit()) {
+                        break;
+                    }
+                    out.push(d);
+                    let _ = chars.next();
+                }
+                out.push('}');
+            }
+            '·' | '⋅' => out.push_str("\\cdot "),
+            '°' => out.push_str("^\\circ "),
+            'ħ' => out.push_str("\\hbar "),
+            'μ' | 'µ' => out.push_str("\\mu "),
+            'Ω' => out.push_str("\\Omega "),
+            'Å' => out.push_str("\\text{\\AA}"),
+            '\\' => out.push_str("\\backslash "),
+            '~' => out.push_str("\\text{\\textasciitilde}"),
+            '#' | '$' | '%' | '&' | '{' | '}' => {
+                out.push('\\');
+                out.push(c);
+            }
+            _ => match superscript(c) {
+                Some(text) => {
+                    out.push_str("^{");
+                    out.push_str(text);
+                    while let Some(text) =
+                        chars.peek().copied().and_then(superscript)
+                    {
+                        out.push_str(text);
+                        let _ = chars.next();
+                    }
+                    out.push('}');
+                }
+                None => out.push(c),
+            },
+        }
+    }
+    format!("$\\mathrm{{{out}}}$")
+}
+
+/// Returns the ASCII form of a superscript digit or minus sign.
+fn superscript(c: char) -> Option<&'static str> {
+    Some(match c {
+        '⁻' => "-",
+        '⁰' => "0",
+        '¹' => "1",
+        '²' => "2",
+        '³' => "3",
+        '⁴' => "4",
+        '⁵' => "5",
+        '⁶' => "6",
+        '⁷' => "7",
+        '⁸' => "8",
+        '⁹' => "9",
+        _ => return None,
+    })
+}
@@ -0,0 +1,732 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Single-precision (`f32`) constants for targets with an `f32`-only
+//! FPU, such as Cortex-M4F.
+//!
+//! Every float constant in [`CONSTANTS_TABLE`](super::CONSTANTS_TABLE)
+//! has an `f32` twin here with the same name. Each value is correctly
+//! rounded (to nearest, ties to even) from the exact value: the
+//! published CODATA decimal, or the mathematical constant itself.
+//! It is not the `f64` constant cast with `as f32`, which rounds
+//! twice. Like the top-level constants, the named items carry CODATA
+//! 2018 values; [`get`] follows [`Dataset::DEFAULT`].
+//!
+//! Available in `no_std`.
+//!
+//! # Example
+//!
+//! ```
+//! use cmn::constants::{f32, Dataset};
+//!
+//! let hbar = f32::PLANCK / f32::TAU;
+//! assert!((hbar / f32::PLANCK_REDUCED - 1.0).abs() < 1e-6);
+//! assert_eq!(f32::PLANCK, 6.626_070_15e-34_f32);
+//!
+//! assert_eq!(f32::get("SPEED_OF_LIGHT"), Some(299_792_458.0));
+//! assert_eq!(
+//!     f32::get_in(Dataset::Codata2022, "ELECTRON_MASS"),
+//!     Some(9.109_383_7e-31),
+//! );
+//! assert!(f32::get("HASH_COST").is_none());
+//! ```
+
+use super::Dataset;
+
+/// [`APERY`](super::APERY), correctly rounded to `f32`.
+pub const APERY: f32 = 1.202_056_9;
+
+/// [`AVOGADRO`](super::AVOGADRO), correctly rounded to `f32`.
+pub const AVOGADRO: f32 = 6.022_140_6e23;
+
+/// [`BOLTZMANN`](super::BOLTZMANN), correctly rounded to `f32`.
+pub const BOLTZMANN: f32 = 1.380_649e-23;
+
+/// [`CATALAN`](super::CATALAN), correctly rounded to `f32`.
+pub const CATALAN: f32 = 0.915_965_6;
+
+/// [`COULOMB`](super::COULOMB), correctly rounded to `f32`.
+pub const COULOMB: f32 = 8.987_552e9;
+
+/// [`EULER`](super::EULER), correctly rounded to `f32`.
+pub const EULER: f32 = core::f32::consts::E;
+
+/// [`FARADAY`](super::FARADAY), correctly rounded to `f32`.
+pub const FARADAY: f32 = 96_485.336;
+
+/// [`GAMMA`](super::GAMMA), correctly rounded to `f32`.
+pub const GAMMA: f32 = 0.577_215_7;
+
+/// [`GAS_CONSTANT`](super::GAS_CONSTANT), correctly rounded to `f32`.
+pub const GAS_CONSTANT: f32 = 8.314_463;
+
+/// [`GLAISHER_KINKELIN`][c], correctly rounded to `f32`.
+///
+/// [c]: super::GLAISHER_KINKELIN
+pub const GLAISHER_KINKELIN: f32 = 1.282_427_1;
+
+/// [`GRAVITATIONAL_CONSTANT`][c], correctly rounded to `f32`.
+///
+/// [c]: super::GRAVITATIONAL_CONSTANT
+pub const GRAVITATIONAL_CONSTANT: f32 = 6.674_3e-11;
+
+/// [`KHINCHIN`](super::KHINCHIN), correctly rounded to `f32`.
+pub const KHINCHIN: f32 = 2.685_452;
+
+/// [`PHI`](super::PHI), correctly rounded to `f32`.
+pub const PHI: f32 = 1.618_034;
+
+/// [`PI`](super::PI), correctly rounded to `f32`.
+pub const PI: f32 = core::f32::consts::PI;
+
+/// [`PLANCK`](super::PLANCK), correctly rounded to `f32`.
+pub const PLANCK: f32 = 6.626_07e-34;
+
+/// [`PLANCK_REDUCED`][c], correctly rounded to `f32`.
+///
+/// [c]: super::PLANCK_REDUCED
+pub const PLANCK_REDUCED: f32 = 1.054_571_8e-34;
+
+/// [`SILVER_RATIO`](super::SILVER_RATIO), correctly rounded to `f32`.
+pub const SILVER_RATIO: f32 = 2.414_213_7;
+
+/// [`SPEED_OF_LIGHT`][c], correctly rounded to `f32`.
+///
+/// [c]: super::SPEED_OF_LIGHT
+pub const SPEED_OF_LIGHT: f32 = 2.997_924_5e8;
+
+/// [`SQRT2`](super::SQRT2), correctly rounded to `f32`.
+pub const SQRT2: f32 = core::f32::consts::SQRT_2;
+
+/// [`SQRT3`](super::SQRT3), correctly rounded to `f32`.
+pub const SQRT3: f32 = 1.732_050_8;
+
+/// [`SQRT5`](super::SQRT5), correctly rounded to `f32`.
+pub const SQRT5: f32 = 2.236_068;
+
+/// [`TAU`](super::TAU), correctly rounded to `f32`.
+pub const TAU: f32 = core::f32::consts::TAU;
+
+/// [`VACUUM_PERMEABILITY`][c], correctly rounded to `f32`.
+///
+/// [c]: super::VACUUM_PERMEABILITY
+pub const VACUUM_PERMEABILITY: f32 = 1.256_637e-6;
+
+/// [`VACUUM_PERMITTIVITY`][c], correctly rounded to `f32`.
+///
+/// [c]: super::VACUUM_PERMITTIVITY
+pub const VACUUM_PERMITTIVITY: f32 = 8.854_188e-12;
+
+/// [`LN_2`](super::LN_2), correctly rounded to `f32`.
+pub const LN_2: f32 = core::f32::consts::LN_2;
+
+/// [`LN_10`](super::LN_10), correctly rounded to `f32`.
+pub const LN_10: f32 = core::f32::consts::LN_10;
+
+/// [`LOG2_E`](super::LOG2_E), correctly rounded to `f32`.
+pub const LOG2_E: f32 = core::f32::consts::LOG2_E;
+
+/// [`LOG10_E`](super::LOG10_E), correctly rounded to `f32`.
+pub const LOG10_E: f32 = core::f32::consts::LOG10_E;
+
+/// [`FRAC_1_SQRT_2`](super::FRAC_1_SQRT_2), correctly rounded to `f32`.
+pub const FRAC_1_SQRT_2: f32 = core::f32::consts::FRAC_1_SQRT_2;
+
+/// [`FRAC_1_PI`](super::FRAC_1_PI), correctly rounded to `f32`.
+pub const FRAC_1_PI: f32 = core::f32::consts::FRAC_1_PI;
+
+/// [`FRAC_2_PI`](super::FRAC_2_PI), correctly rounded to `f32`.
+pub const FRAC_2_PI: f32 = core::f32::consts::FRAC_2_PI;
+
+/// [`FRAC_2_SQRT_PI`][c], correctly rounded to `f32`.
+///
+/// [c]: super::FRAC_2_SQRT_PI
+pub const FRAC_2_SQRT_PI: f32 = core::f32::consts::FRAC_2_SQRT_PI;
+
+/// [`FRAC_PI_2`](super::FRAC_PI_2), correctly rounded to `f32`.
+pub const FRAC_PI_2: f32 = core::f32::consts::FRAC_PI_2;
+
+/// [`FRAC_PI_3`](super::FRAC_PI_3), correctly rounded to `f32`.
+pub const FRAC_PI_3: f32 = core::f32::consts::FRAC_PI_3;
+
+/// [`FRAC_PI_4`](super::FRAC_PI_4), correctly rounded to `f32`.
+pub const FRAC_PI_4: f32 = core::f32::consts::FRAC_PI_4;
+
+/// [`FRAC_PI_6`](super::FRAC_PI_6), correctly rounded to `f32`.
+pub const FRAC_PI_6: f32 = core::f32::consts::FRAC_PI_6;
+
+/// [`FRAC_PI_8`](super::FRAC_PI_8), correctly rounded to `f32`.
+pub const FRAC_PI_8: f32 = core::f32::consts::FRAC_PI_8;
+
+/// [`ELEMENTARY_CHARGE`][c], correctly rounded to `f32`.
+///
+/// [c]: super::ELEMENTARY_CHARGE
+pub const ELEMENTARY_CHARGE: f32 = 1.602_176_6e-19;
+
+/// [`ELECTRON_MASS`](super::ELECTRON_MASS), correctly rounded to `f32`.
+pub const ELECTRON_MASS: f32 = 9.109_383e-31;
+
+/// [`PROTON_MASS`](super::PROTON_MASS), correctly rounded to `f32`.
+pub const PROTON_MASS: f32 = 1.672_622e-27;
+
+/// [`NEUTRON_MASS`](super::NEUTRON_MASS), correctly rounded to `f32`.
+pub const NEUTRON_MASS: f32 = 1.674_927_5e-27;
+
+/// [`STEFAN_BOLTZMANN`][c], correctly rounded to `f32`.
+///
+/// [c]: super::STEFAN_BOLTZMANN
+pub const STEFAN_BOLTZMANN: f32 = 5.670_374_4e-8;
+
+/// [`WIEN_DISPLACEMENT`][c], correctly rounded to `f32`.
+///
+/// [c]: super::WIEN_DISPLACEMENT
+pub const WIEN_DISPLACEMENT: f32 = 0.002_897_772;
+
+/// [`STANDARD_GRAVITY`][c], correctly rounded to `f32`.
+///
+/// [c]: super::STANDARD_GRAVITY
+pub const STANDARD_GRAVITY: f32 = 9.806_65;
+
+/// [`STANDARD_ATMOSPHERE`][c], correctly rounded to `f32`.
+///
+/// [c]: super::STANDARD_ATMOSPHERE
+pub const STANDARD_ATMOSPHERE: f32 = 101_325.0;
+
+/// [`ATOMIC_MASS_UNIT`][c], correctly rounded to `f32`.
+///
+/// [c]: super::ATOMIC_MASS_UNIT
+pub const ATOMIC_MASS_UNIT: f32 = 1.660_539_1e-27;
+
+/// [`BOHR_RADIUS`](super::BOHR_RADIUS), correctly rounded to `f32`.
+pub const BOHR_RADIUS: f32 = 5.291_772_2e-11;
+
+/// [`FINE_STRUCTURE`][c], correctly rounded to `f32`.
+///
+/// [c]: super::FINE_STRUCTURE
+pub const FINE_STRUCTURE: f32 = 0.007_297_352_4;
+
+/// [`RYDBERG`](super::RYDBERG), correctly rounded to `f32`.
+pub const RYDBERG: f32 = 10_973_732.0;
+
+/// [`MAGNETIC_FLUX_QUANTUM`][c], correctly rounded to `f32`.
+///
+/// [c]: super::MAGNETIC_FLUX_QUANTUM
+pub const MAGNETIC_FLUX_QUANTUM: f32 = 2.067_833_8e-15;
+
+/// [`CONDUCTANCE_QUANTUM`][c], correctly rounded to `f32`.
+///
+/// [c]: super::CONDUCTANCE_QUANTUM
+pub const CONDUCTANCE_QUANTUM: f32 = 7.748_091_6e-5;
+
+/// [`MUON_MASS`](super::MUON_MASS), correctly rounded to `f32`.
+pub const MUON_MASS: f32 = 1.883_531_7e-28;
+
+/// [`TAU_PARTICLE_MASS`][c], correctly rounded to `f32`.
+///
+/// [c]: super::TAU_PARTICLE_MASS
+pub const TAU_PARTICLE_MASS: f32 = 3.167_54e-27;
+
+/// [`DEUTERON_MASS`](super::DEUTERON_MASS), correctly rounded to `f32`.
+pub const DEUTERON_MASS: f32 = 3.343_584e-27;
+
+/// [`TRITON_MASS`](super::TRITON_MASS), correctly rounded to `f32`.
+pub const TRITON_MASS: f32 = 5.007_356_6e-27;
+
+/// [`HELION_MASS`](super::HELION_MASS), correctly rounded to `f32`.
+pub const HELION_MASS: f32 = 5.006_413e-27;
+
+/// [`ALPHA_PARTICLE_MASS`][c], correctly rounded to `f32`.
+///
+/// [c]: super::ALPHA_PARTICLE_MASS
+pub const ALPHA_PARTICLE_MASS: f32 = 6.644_657e-27;
+
+/// [`ELECTRON_PROTON_MASS_RATIO`][c], correctly rounded to `f32`.
+///
+/// [c]: super::ELECTRON_PROTON_MASS_RATIO
+pub const ELECTRON_PROTON_MASS_RATIO: f32 = 0.000_544_617;
+
+/// [`PROTON_ELECTRON_MASS_RATIO`][c], correctly rounded to `f32`.
+///
+/// [c]: super::PROTON_ELECTRON_MASS_RATIO
+pub const PROTON_ELECTRON_MASS_RATIO: f32 = 1_836.152_7;
+
+/// [`MUON_ELECTRON_MASS_RATIO`][c], correctly rounded to `f32`.
+///
+/// [c]: super::MUON_ELECTRON_MASS_RATIO
+pub const MUON_ELECTRON_MASS_RATIO: f32 = 206.768_28;
+
+/// [`NEUTRON_PROTON_MASS_RATIO`][c], correctly rounded to `f32`.
+///
+/// [c]: super::NEUTRON_PROTON_MASS_RATIO
+pub const NEUTRON_PROTON_MASS_RATIO: f32 = 1.001_378_4;
+
+/// [`DEUTERON_PROTON_MASS_RATIO`][c], correctly rounded to `f32`.
+///
+/// [c]: super::DEUTERON_PROTON_MASS_RATIO
+pub const DEUTERON_PROTON_MASS_RATIO: f32 = 1.999_007_5;
+
+/// [`BOHR_MAGNETON`](super::BOHR_MAGNETON), correctly rounded to `f32`.
+pub const BOHR_MAGNETON: f32 = 9.274_01e-24;
+
+/// [`NUCLEAR_MAGNETON`][c], correctly rounded to `f32`.
+///
+/// [c]: super::NUCLEAR_MAGNETON
+pub const NUCLEAR_MAGNETON: f32 = 5.050_783_6e-27;
+
+/// [`ELECTRON_MAGNETIC_MOMENT`][c], correctly rounded to `f32`.
+///
+/// [c]: super::ELECTRON_MAGNETIC_MOMENT
+pub const ELECTRON_MAGNETIC_MOMENT: f32 = -9.284_765e-24;
+
+/// [`PROTON_MAGNETIC_MOMENT`][c], correctly rounded to `f32`.
+///
+/// [c]: super::PROTON_MAGNETIC_MOMENT
+pub const PROTON_MAGNETIC_MOMENT: f32 = 1.410_606_9e-26;
+
+/// [`NEUTRON_MAGNETIC_MOMENT`][c], correctly rounded to `f32`.
+///
+/// [c]: super::NEUTRON_MAGNETIC_MOMENT
+pub const NEUTRON_MAGNETIC_MOMENT: f32 = -9.662_365e-27;
+
+/// [`ELECTRON_G_FACTOR`][c], correctly rounded to `f32`.
+///
+/// [c]: super::ELECTRON_G_FACTOR
+pub const ELECTRON_G_FACTOR: f32 = -2.002_319_3;
+
+/// [`PROTON_G_FACTOR`][c], correctly rounded to `f32`.
+///
+/// [c]: super::PROTON_G_FACTOR
+pub const PROTON_G_FACTOR: f32 = 5.585_695;
+
+/// [`ELECTRON_VOLT`](super::ELECTRON_VOLT), correctly rounded to `f32`.
+pub const ELECTRON_VOLT: f32 = 1.602_176_6e-19;
+
+/// [`EV_TO_KG`](super::EV_TO_KG), correctly rounded to `f32`.
+pub const EV_TO_KG: f32 = 1.782_661_9e-36;
+
+/// [`EV_TO_AMU`](super::EV_TO_AMU), correctly rounded to `f32`.
+pub const EV_TO_AMU: f32 = 1.073_544_1e-9;
+
+/// [`EV_TO_HZ`](super::EV_TO_HZ), correctly rounded to `f32`.
+pub const EV_TO_HZ: f32 = 2.417_989_2e14;
+
+/// [`EV_TO_KELVIN`](super::EV_TO_KELVIN), correctly rounded to `f32`.
+pub const EV_TO_KELVIN: f32 = 11_604.519;
+
+/// [`EV_TO_INVERSE_METER`][c], correctly rounded to `f32`.
+///
+/// [c]: super::EV_TO_INVERSE_METER
+pub const EV_TO_INVERSE_METER: f32 = 806_554.4;
+
+/// [`CLASSICAL_ELECTRON_RADIUS`][c], correctly rounded to `f32`.
+///
+/// [c]: super::CLASSICAL_ELECTRON_RADIUS
+pub const CLASSICAL_ELECTRON_RADIUS: f32 = 2.817_940_3e-15;
+
+/// [`ELECTRON_COMPTON_WAVELENGTH`][c], correctly rounded to `f32`.
+///
+/// [c]: super::ELECTRON_COMPTON_WAVELENGTH
+pub const ELECTRON_COMPTON_WAVELENGTH: f32 = 2.426_310_2e-12;
+
+/// [`PROTON_COMPTON_WAVELENGTH`][c], correctly rounded to `f32`.
+///
+/// [c]: super::PROTON_COMPTON_WAVELENGTH
+pub const PROTON_COMPTON_WAVELENGTH: f32 = 1.321_409_8e-15;
+
+/// [`NEUTRON_COMPTON_WAVELENGTH`][c], correctly rounded to `f32`.
+///
+/// [c]: super::NEUTRON_COMPTON_WAVELENGTH
+pub const NEUTRON_COMPTON_WAVELENGTH: f32 = 1.319_590_9e-15;
+
+/// [`THOMSON_CROSS_SECTION`][c], correctly rounded to `f32`.
+///
+/// [c]: super::THOMSON_CROSS_SECTION
+pub const THOMSON_CROSS_SECTION: f32 = 6.652_459e-29;
+
+/// [`FIRST_RADIATION_CONSTANT`][c], correctly rounded to `f32`.
+///
+/// [c]: super::FIRST_RADIATION_CONSTANT
+pub const FIRST_RADIATION_CONSTANT: f32 = 3.741_771_8e-16;
+
+/// [`SECOND_RADIATION_CONSTANT`][c], correctly rounded to `f32`.
+///
+/// [c]: super::SECOND_RADIATION_CONSTANT
+pub const SECOND_RADIATION_CONSTANT: f32 = 0.014_387_769;
+
+/// [`JOSEPHSON_CONSTANT`][c], correctly rounded to `f32`.
+///
+/// [c]: super::JOSEPHSON_CONSTANT
+pub const JOSEPHSON_CONSTANT: f32 = 4.835_978_5e14;
+
+/// [`VON_KLITZING_CONSTANT`][c], correctly rounded to `f32`.
+///
+/// [c]: super::VON_KLITZING_CONSTANT
+pub const VON_KLITZING_CONSTANT: f32 = 25_812.807;
+
+/// [`HARTREE_ENERGY`][c], correctly rounded to `f32`.
+///
+/// [c]: super::HARTREE_ENERGY
+pub const HARTREE_ENERGY: f32 = 4.359_744_8e-18;
+
+/// [`HARTREE_ENERGY_EV`][c], correctly rounded to `f32`.
+///
+/// [c]: super::HARTREE_ENERGY_EV
+pub const HARTREE_ENERGY_EV: f32 = 27.211_386;
+
+/// [`PLANCK_MASS`](super::PLANCK_MASS), correctly rounded to `f32`.
+pub const PLANCK_MASS: f32 = 2.176_434e-8;
+
+/// [`PLANCK_LENGTH`](super::PLANCK_LENGTH), correctly rounded to `f32`.
+pub const PLANCK_LENGTH: f32 = 1.616_255e-35;
+
+/// [`PLANCK_TIME`](super::PLANCK_TIME), correctly rounded to `f32`.
+///
+/// This is an `f32` subnormal, so it keeps fewer than 24
+/// significant bits.
+pub const PLANCK_TIME: f32 = 5.3e-44;
+
+/// [`PLANCK_TEMPERATURE`][c], correctly rounded to `f32`.
+///
+/// [c]: super::PLANCK_TEMPERATURE
+pub const PLANCK_TEMPERATURE: f32 = 1.416_784e32;
+
+/// [`PLANCK_CHARGE`](super::PLANCK_CHARGE), correctly rounded to `f32`.
+pub const PLANCK_CHARGE: f32 = 1.875_546e-18;
+
+/// [`MOLAR_MASS_CONSTANT`][c], correctly rounded to `f32`.
+///
+/// [c]: super::MOLAR_MASS_CONSTANT
+pub const MOLAR_MASS_CONSTANT: f32 = 0.001;
+
+/// [`MOLAR_PLANCK_CONSTANT`][c], correctly rounded to `f32`.
+///
+/// [c]: super::MOLAR_PLANCK_CONSTANT
+pub const MOLAR_PLANCK_CONSTANT: f32 = 3.990_312_8e-10;
+
+/// [`LOSCHMIDT_CONSTANT`][c], correctly rounded to `f32`.
+///
+/// [c]: super::LOSCHMIDT_CONSTANT
+pub const LOSCHMIDT_CONSTANT: f32 = 2.686_78e25;
+
+/// [`MOLAR_VOLUME_IDEAL_GAS`][c], correctly rounded to `f32`.
+///
+/// [c]: super::MOLAR_VOLUME_IDEAL_GAS
+pub const MOLAR_VOLUME_IDEAL_GAS: f32 = 0.022_710_95;
+
+/// [`SACKUR_TETRODE_CONSTANT`][c], correctly rounded to `f32`.
+///
+/// [c]: super::SACKUR_TETRODE_CONSTANT
+pub const SACKUR_TETRODE_CONSTANT: f32 = -1.151_707_5;
+
+/// [`IMPEDANCE_OF_FREE_SPACE`][c], correctly rounded to `f32`.
+///
+/// [c]: super::IMPEDANCE_OF_FREE_SPACE
+pub const IMPEDANCE_OF_FREE_SPACE: f32 = 376.730_32;
+
+/// [`INVERSE_FINE_STRUCTURE`][c], correctly rounded to `f32`.
+///
+/// [c]: super::INVERSE_FINE_STRUCTURE
+pub const INVERSE_FINE_STRUCTURE: f32 = 137.036;
+
+/// [`ELECTRON_CHARGE_TO_MASS`][c], correctly rounded to `f32`.
+///
+/// [c]: super::ELECTRON_CHARGE_TO_MASS
+pub const ELECTRON_CHARGE_TO_MASS: f32 = -1.758_82e11;
+
+/// [`PROTON_CHARGE_TO_MASS`][c], correctly rounded to `f32`.
+///
+/// [c]: super::PROTON_CHARGE_TO_MASS
+pub const PROTON_CHARGE_TO_MASS: f32 = 95_788_340.0;
+
+/// [`ATOMIC_UNIT_OF_LENGTH`][c], correctly rounded to `f32`.
+///
+/// [c]: super::ATOMIC_UNIT_OF_LENGTH
+pub const ATOMIC_UNIT_OF_LENGTH: f32 = 5.291_772_2e-11;
+
+/// [`ATOMIC_UNIT_OF_TIME`][c], correctly rounded to `f32`.
+///
+/// [c]: super::ATOMIC_UNIT_OF_TIME
+pub const ATOMIC_UNIT_OF_TIME: f32 = 2.418_884_4e-17;
+
+/// [`ATOMIC_UNIT_OF_VELOCITY`][c], correctly rounded to `f32`.
+///
+/// [c]: super::ATOMIC_UNIT_OF_VELOCITY
+pub const ATOMIC_UNIT_OF_VELOCITY: f32 = 2_187_691.3;
+
+/// [`ATOMIC_UNIT_OF_FORCE`][c], correctly rounded to `f32`.
+///
+/// [c]: super::ATOMIC_UNIT_OF_FORCE
+pub const ATOMIC_UNIT_OF_FORCE: f32 = 8.238_724e-8;
+
+/// [`ATOMIC_UNIT_OF_ELECTRIC_FIELD`][c], correctly rounded to `f32`.
+///
+/// [c]: super::ATOMIC_UNIT_OF_ELECTRIC_FIELD
+pub const ATOMIC_UNIT_OF_ELECTRIC_FIELD: f32 = 5.142_207e11;
+
+/// [`ATOMIC_UNIT_OF_POLARIZABILITY`][c], correctly rounded to `f32`.
+///
+/// This is an `f32` subnormal, so it keeps fewer than 24
+/// significant bits.
+///
+/// [c]: super::ATOMIC_UNIT_OF_POLARIZABILITY
+pub const ATOMIC_UNIT_OF_POLARIZABILITY: f32 = 1.648_8e-41;
+
+/// [`W_BOSON_MASS_GEV`][c], correctly rounded to `f32`.
+///
+/// [c]: super::W_BOSON_MASS_GEV
+pub const W_BOSON_MASS_GEV: f32 = 80.377;
+
+/// [`Z_BOSON_MASS_GEV`][c], correctly rounded to `f32`.
+///
+/// [c]: super::Z_BOSON_MASS_GEV
+pub const Z_BOSON_MASS_GEV: f32 = 91.187_6;
+
+/// [`HIGGS_BOSON_MASS_GEV`][c], correctly rounded to `f32`.
+///
+/// [c]: super::HIGGS_BOSON_MASS_GEV
+pub const HIGGS_BOSON_MASS_GEV: f32 = 125.25;
+
+/// [`ELECTRON_MASS_MEV`][c], correctly rounded to `f32`.
+///
+/// [c]: super::ELECTRON_MASS_MEV
+pub const ELECTRON_MASS_MEV: f32 = 0.510_998_96;
+
+/// [`PROTON_MASS_MEV`][c], correctly rounded to `f32`.
+///
+/// [c]: super::PROTON_MASS_MEV
+pub const PROTON_MASS_MEV: f32 = 938.272_1;
+
+/// [`NEUTRON_MASS_MEV`][c], correctly rounded to `f32`.
+///
+/// [c]: super::NEUTRON_MASS_MEV
+pub const NEUTRON_MASS_MEV: f32 = 939.565_4;
+
+/// [`MUON_MASS_MEV`](super::MUON_MASS_MEV), correctly rounded to `f32`.
+pub const MUON_MASS_MEV: f32 = 105.658_38;
+
+/// [`ELECTRON_REDUCED_COMPTON`][c], correctly rounded to `f32`.
+///
+/// [c]: super::ELECTRON_REDUCED_COMPTON
+pub const ELECTRON_REDUCED_COMPTON: f32 = 3.861_592_6e-13;
+
+/// [`PROTON_REDUCED_COMPTON`][c], correctly rounded to `f32`.
+///
+/// [c]: super::PROTON_REDUCED_COMPTON
+pub const PROTON_REDUCED_COMPTON: f32 = 2.103_089_1e-16;
+
+/// [`NEUTRON_REDUCED_COMPTON`][c], correctly rounded to `f32`.
+///
+/// [c]: super::NEUTRON_REDUCED_COMPTON
+pub const NEUTRON_REDUCED_COMPTON: f32 = 2.100_194_1e-16;
+
+/// [`GAS_CONSTANT_L_ATM`][c], correctly rounded to `f32`.
+///
+/// [c]: super::GAS_CONSTANT_L_ATM
+pub const GAS_CONSTANT_L_ATM: f32 = 0.082_057_364;
+
+/// Every `f32` constant with its CODATA 2018 value, in the row order
+/// of [`CONSTANTS_TABLE`](super::CONSTANTS_TABLE). Non-float entries
+/// such as `HASH_COST` are omitted.
+pub const TABLE: &[(&str, f32)] = &[
+    ("APERY", APERY),
+    ("AVOGADRO", AVOGADRO),
+    ("BOLTZMANN", BOLTZMANN),
+    ("CATALAN", CATALAN),
+    ("COULOMB", COULOMB),
+    ("EULER", EULER),
+    ("FARADAY", FARADAY),
+    ("GAMMA", GAMMA),
+    ("GAS_CONSTANT", GAS_CONSTANT),
+    ("GLAISHER_KINKELIN", GLAISHER_KINKELIN),
+    ("GRAVITATIONAL_CONSTANT", GRAVITATIONAL_CONSTANT),
+    ("KHINCHIN", KHINCHIN),
+    ("PHI", PHI),
+    ("PI", PI),
+    ("PLANCK", PLANCK),
+    ("PLANCK_REDUCED", PLANCK_REDUCED),
+    ("SILVER_RATIO", SILVER_RATIO),
+    ("SPEED_OF_LIGHT", SPEED_OF_LIGHT),
+    ("SQRT2", SQRT2),
+    ("SQRT3", SQRT3),
+    ("SQRT5", SQRT5),
+    ("TAU", TAU),
+    ("VACUUM_PERMEABILITY", VACUUM_PERMEABILITY),
+    ("VACUUM_PERMITTIVITY", VACUUM_PERMITTIVITY),
+    ("LN_2", LN_2),
+    ("LN_10", LN_10),
+    ("LOG2_E", LOG2_E),
+    ("LOG10_E", LOG10_E),
+    ("FRAC_1_SQRT_2", FRAC_1_SQRT_2),
+    ("FRAC_1_PI", FRAC_1_PI),
+    ("FRAC_2_PI", FRAC_2_PI),
+    ("FRAC_2_SQRT_PI", FRAC_2_SQRT_PI),
+    ("FRAC_PI_2", FRAC_PI_2),
+    ("FRAC_PI_3", FRAC_PI_3),
+    ("FRAC_PI_4", FRAC_PI_4),
+    ("FRAC_PI_6", FRAC_PI_6),
+    ("FRAC_PI_8", FRAC_PI_8),
+    ("ELEMENTARY_CHARGE", ELEMENTARY_CHARGE),
+    ("ELECTRON_MASS", ELECTRON_MASS),
+    ("PROTON_MASS", PROTON_MASS),
+    ("NEUTRON_MASS", NEUTRON_MASS),
+    ("STEFAN_BOLTZMANN", STEFAN_BOLTZMANN),
+    ("WIEN_DISPLACEMENT", WIEN_DISPLACEMENT),
+    ("STANDARD_GRAVITY", STANDARD_GRAVITY),
+    ("STANDARD_ATMOSPHERE", STANDARD_ATMOSPHERE),
+    ("ATOMIC_MASS_UNIT", ATOMIC_MASS_UNIT),
+    ("BOHR_RADIUS", BOHR_RADIUS),
+    ("FINE_STRUCTURE", FINE_STRUCTURE),
+    ("RYDBERG", RYDBERG),
+    ("MAGNETIC_FLUX_QUANTUM", MAGNETIC_FLUX_QUANTUM),
+    ("CONDUCTANCE_QUANTUM", CONDUCTANCE_QUANTUM),
+    ("MUON_MASS", MUON_MASS),
+    ("TAU_PARTICLE_MASS", TAU_PARTICLE_MASS),
+    ("DEUTERON_MASS", DEUTERON_MASS),
+    ("TRITON_MASS", TRITON_MASS),
+    ("HELION_MASS", HELION_MASS),
+    ("ALPHA_PARTICLE_MASS", ALPHA_PARTICLE_MASS),
+    ("ELECTRON_PROTON_MASS_RATIO", ELECTRON_PROTON_MASS_RATIO),
+    ("PROTON_ELECTRON_MASS_RATIO", PROTON_ELECTRON_MASS_RATIO),
+    ("MUON_ELECTRON_MASS_RATIO", MUON_ELECTRON_MASS_RATIO),
+    ("NEUTRON_PROTON_MASS_RATIO", NEUTRON_PROTON_MASS_RATIO),
+    ("DEUTERON_PROTON_MASS_RATIO", DEUTERON_PROTON_MASS_RATIO),
+    ("BOHR_MAGNETON", BOHR_MAGNETON),
+    ("NUCLEAR_MAGNETON", NUCLEAR_MAGNETON),
+    ("ELECTRON_MAGNETIC_MOMENT", ELECTRON_MAGNETIC_MOMENT),
+    ("PROTON_MAGNETIC_MOMENT", PROTON_MAGNETIC_MOMENT),
+    ("NEUTRON_MAGNETIC_MOMENT", NEUTRON_MAGNETIC_MOMENT),
+    ("ELECTRON_G_FACTOR", ELECTRON_G_FACTOR),
+    ("PROTON_G_FACTOR", PROTON_G_FACTOR),
+    ("ELECTRON_VOLT", ELECTRON_VOLT),
+    ("EV_TO_KG", EV_TO_KG),
+    ("EV_TO_AMU", EV_TO_AMU),
+    ("EV_TO_HZ", EV_TO_HZ),
+    ("EV_TO_KELVIN", EV_TO_KELVIN),
+    ("EV_TO_INVERSE_METER", EV_TO_INVERSE_METER),
+    ("CLASSICAL_ELECTRON_RADIUS", CLASSICAL_ELECTRON_RADIUS),
+    ("ELECTRON_COMPTON_WAVELENGTH", ELECTRON_COMPTON_WAVELENGTH),
+    ("PROTON_COMPTON_WAVELENGTH", PROTON_COMPTON_WAVELENGTH),
+    ("NEUTRON_COMPTON_WAVELENGTH", NEUTRON_COMPTON_WAVELENGTH),
+    ("THOMSON_CROSS_SECTION", THOMSON_CROSS_SECTION),
+    ("FIRST_RADIATION_CONSTANT", FIRST_RADIATION_CONSTANT),
+    ("SECOND_RADIATION_CONSTANT", SECOND_RADIATION_CONSTANT),
+    ("JOSEPHSON_CONSTANT", JOSEPHSON_CONSTANT),
+    ("VON_KLITZING_CONSTANT", VON_KLITZING_CONSTANT),
+    ("HARTREE_ENERGY", HARTREE_ENERGY),
+    ("HARTREE_ENERGY_EV", HARTREE_ENERGY_EV),
+    ("PLANCK_MASS", PLANCK_MASS),
+    ("PLANCK_LENGTH", PLANCK_LENGTH),
+    ("PLANCK_TIME", PLANCK_TIME),
+    ("PLANCK_TEMPERATURE", PLANCK_TEMPERATURE),
+    ("PLANCK_CHARGE", PLANCK_CHARGE),
+    ("MOLAR_MASS_CONSTANT", MOLAR_MASS_CONSTANT),
+    ("MOLAR_PLANCK_CONSTANT", MOLAR_PLANCK_CONSTANT),
+    ("LOSCHMIDT_CONSTANT", LOSCHMIDT_CONSTANT),
+    ("MOLAR_VOLUME_IDEAL_GAS", MOLAR_VOLUME_IDEAL_GAS),
+    ("SACKUR_TETRODE_CONSTANT", SACKUR_TETRODE_CONSTANT),
+    ("IMPEDANCE_OF_FREE_SPACE", IMPEDANCE_OF_FREE_SPACE),
+    ("INVERSE_FINE_STRUCTURE", INVERSE_FINE_STRUCTURE),
+    ("ELECTRON_CHARGE_TO_MASS", ELECTRON_CHARGE_TO_MASS),
+    ("PROTON_CHARGE_TO_MASS", PROTON_CHARGE_TO_MASS),
+    ("ATOMIC_UNIT_OF_LENGTH", ATOMIC_UNIT_OF_LENGTH),
+    ("ATOMIC_UNIT_OF_TIME", ATOMIC_UNIT_OF_TIME),
+    ("ATOMIC_UNIT_OF_VELOCITY", ATOMIC_UNIT_OF_VELOCITY),
+    ("ATOMIC_UNIT_OF_FORCE", ATOMIC_UNIT_OF_FORCE),
+    (
+        "ATOMIC_UNIT_OF_ELECTRIC_FIELD",
+        ATOMIC_UNIT_OF_ELECTRIC_FIELD,
+    ),
+    (
+        "ATOMIC_UNIT_OF_POLARIZABILITY",
+        ATOMIC_UNIT_OF_POLARIZABILITY,
+    ),
+    ("W_BOSON_MASS_GEV", W_BOSON_MASS_GEV),
+    ("Z_BOSON_MASS_GEV", Z_BOSON_MASS_GEV),
+    ("HIGGS_BOSON_MASS_GEV", HIGGS_BOSON_MASS_GEV),
+    ("ELECTRON_MASS_MEV", ELECTRON_MASS_MEV),
+    ("PROTON_MASS_MEV", PROTON_MASS_MEV),
+    ("NEUTRON_MASS_MEV", NEUTRON_MASS_MEV),
+    ("MUON_MASS_MEV", MUON_MASS_MEV),
+    ("ELECTRON_REDUCED_COMPTON", ELECTRON_REDUCED_COMPTON),
+    ("PROTON_REDUCED_COMPTON", PROTON_REDUCED_COMPTON),
+    ("NEUTRON_REDUCED_COMPTON", NEUTRON_REDUCED_COMPTON),
+    ("GAS_CONSTANT_L_ATM", GAS_CONSTANT_L_ATM),
+];
+
+/// The constants whose CODATA 2022 values differ from CODATA 2018,
+/// correctly rounded to `f32`. See
+/// [`codata2022::ADJUSTED`](super::codata2022::ADJUSTED).
+pub const CODATA2022_ADJUSTED: &[(&str, f32)] = &[
+    ("VACUUM_PERMEABILITY", 1.256_637e-6),
+    ("VACUUM_PERMITTIVITY", 8.854_188e-12),
+    ("COULOMB", 8.987_552e9),
+    ("ELECTRON_MASS", 9.109_383e-31),
+    ("PROTON_MASS", 1.672_622e-27),
+    ("NEUTRON_MASS", 1.674_927_5e-27),
+    ("ATOMIC_MASS_UNIT", 1.660_539_1e-27),
+    ("BOHR_RADIUS", 5.291_772_2e-11),
+    ("FINE_STRUCTURE", 0.007_297_352_4),
+    ("RYDBERG", 10_973_732.0),
+    ("DEUTERON_MASS", 3.343_584e-27),
+    ("TRITON_MASS", 5.007_356_6e-27),
+    ("HELION_MASS", 5.006_413e-27),
+    ("ALPHA_PARTICLE_MASS", 6.644_657e-27),
+    ("ELECTRON_PROTON_MASS_RATIO", 0.000_544_617),
+    ("PROTON_ELECTRON_MASS_RATIO", 1_836.152_7),
+    ("MUON_ELECTRON_MASS_RATIO", 206.768_28),
+    ("NEUTRON_PROTON_MASS_RATIO", 1.001_378_4),
+    ("DEUTERON_PROTON_MASS_RATIO", 1.999_007_5),
+    ("BOHR_MAGNETON", 9.274_01e-24),
+    ("NUCLEAR_MAGNETON", 5.050_783_6e-27),
+    ("ELECTRON_MAGNETIC_MOMENT", -9.284_765e-24),
+    ("PROTON_MAGNETIC_MOMENT", 1.410_606_9e-26),
+    ("NEUTRON_MAGNETIC_MOMENT", -9.662_365e-27),
+    ("ELECTRON_G_FACTOR", -2.002_319_3),
+    ("EV_TO_AMU", 1.073_544_1e-9),
+    ("CLASSICAL_ELECTRON_RADIUS", 2.817_940_3e-15),
+    ("ELECTRON_COMPTON_WAVELENGTH", 2.426_310_2e-12),
+    ("PROTON_COMPTON_WAVELENGTH", 1.321_409_8e-15),
+    ("NEUTRON_COMPTON_WAVELENGTH", 1.319_590_9e-15),
+    ("THOMSON_CROSS_SECTION", 6.652_459e-29),
+    ("HARTREE_ENERGY", 4.359_744_8e-18),
+    ("HARTREE_ENERGY_EV", 27.211_386),
+    ("MOLAR_MASS_CONSTANT", 0.001),
+    ("SACKUR_TETRODE_CONSTANT", -1.151_707_5),
+    ("IMPEDANCE_OF_FREE_SPACE", 376.730_32),
+    ("INVERSE_FINE_STRUCTURE", 137.036),
+    ("ELECTRON_CHARGE_TO_MASS", -1.758_82e11),
+    ("PROTON_CHARGE_TO_MASS", 95_788_330.0),
+    ("ATOMIC_UNIT_OF_LENGTH", 5.291_772_2e-11),
+    ("ATOMIC_UNIT_OF_TIME", 2.418_884_4e-17),
+    ("ATOMIC_UNIT_OF_VELOCITY", 2_187_691.3),
+    ("ATOMIC_UNIT_OF_FORCE", 8.238_724e-8),
+    ("ATOMIC_UNIT_OF_ELECTRIC_FIELD", 5.142_207e11),
+    ("ATOMIC_UNIT_OF_POLARIZABILITY", 1.648_8e-41),
+    ("ELECTRON_MASS_MEV", 0.510_998_96),
+    ("PROTON_MASS_MEV", 938.272_1),
+    ("NEUTRON_MASS_MEV", 939.565_4),
+    ("ELECTRON_REDUCED_COMPTON", 3.861_592_6e-13),
+    ("PROTON_REDUCED_COMPTON", 2.103_089_1e-16),
+    ("NEUTRON_REDUCED_COMPTON", 2.100_194_1e-16),
+];
+
+/// Returns the named constant as an `f32` from
+/// [`Dataset::DEFAULT`], or `None` if the name is unknown or the
+/// constant is not a float.
+pub fn get(name: &str) -> Option<f32> {
+    get_in(Dataset::DEFAULT, name)
+}
+
+/// Returns the named constant as an `f32` from `dataset`, or `None`
+/// if the name is unknown or the constant is not a float.
+pub fn get_in(dataset: Dataset, name: &str) -> Option<f32> {
+    let find = |table: &[(&str, f32)]| {
+        table.iter().find(|(n, _)| *n == name).map(|&(_, v)| v)
+    };
+    match dataset {
+        Dataset::Codata2018 => find(TABLE),
+        Dataset::Codata2022 => {
+            find(CODATA2022_ADJUSTED).or_else(|| find(TABLE))
+        }
+    }
+}
@@ -0,0 +1,737 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Loaders for user-defined constants in JSON, TOML and CSV.
+//!
+//! Every format lists constants with the same fields:
+//!
+//! - `name` (required): unique across the file and the target.
+//! - `value` (required): a finite number, expressed in `unit`.
+//! - `unit`: any symbol [`units::parse`] reads, such as `V`, `mA`,
+//!   `J/K` or `kg m^-3`, or the unit of a built-in constant;
+//!   dimensionless if absent or empty.
+//! - `uncertainty`: the absolute standard uncertainty; exact if
+//!   absent, empty or `0`.
+//! - `category`: `Mathematical`, `Physical` (the default) or
+//!   `Cryptographic`, ignoring case.
+//! - `description`: free text.
+//!
+//! The formats:
+//!
+//! - JSON: `{ "constants": [{ "name": ..., "value": ... }, ...] }`
+//! - TOML: one `[[constants]]` table per constant. Requires the
+//!   `toml` feature.
+//! - CSV: a header row naming the columns in any order, then one
+//!   constant per row. Quoted cells may contain commas, newlines
+//!   and `""` escapes.
+//!
+//! Other top-level keys are ignored; unknown fields are rejected.
+//! Every [`LoadError`] carries the line it was found on. Requires
+//! the `std` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use cmn::constants::loader::{Format, LoadErrorKind};
+//! use cmn::constants::ConstantRegistry;
+//! use cmn::units::METER;
+//!
+//! let csv = "\
+//! name,value,unit,uncertainty,category
+//! LAB_BASELINE,12.5,m,0.002,physical
+//! LAB_GAIN,2.5,,,
+//! ";
+//! let mut registry = ConstantRegistry::new();
+//! registry.load(Format::Csv, csv).unwrap();
+//! assert_eq!(registry.unit("LAB_BASELINE"), Some(METER));
+//! assert_eq!(registry.get::<f64>("LAB_GAIN"), Ok(2.5));
+//!
+//! // Loading the same file again clashes on its first row.
+//! let error = registry.load(Format::Csv, csv).unwrap_err();
+//! assert_eq!(error.line, 2);
+//! assert_eq!(
+//!     error.kind,
+//!     LoadErrorKind::Duplicate("LAB_BASELINE".to_string())
+//! );
+//! assert_eq!(
+//!     error.to_string(),
+//!     "line 2: duplicate constant `LAB_BASELINE`"
+//! );
+//! ```
+
+use super::registry::{resolve_unit, Definition};
+use super::{Category, Uncertainty};
+use crate::units::{self, ConversionError, Unit};
+use core::cell::RefCell;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{
+    self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess,
+    SeqAccess, Visitor,
+};
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+/// A file format accepted by [`load`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Format {
+    /// A `constants` array of objects.
+    Json,
+    /// `[[constants]]` tables. Requires the `toml` feature.
+    #[cfg(feature = "toml")]
+    Toml,
+    /// A header row, then one constant per row.
+    Csv,
+}
+
+impl Format {
+    /// Returns the format for a file extension such as `"csv"`,
+    /// ignoring case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::loader::Format;
+    ///
+    /// assert_eq!(Format::from_extension("JSON"), Some(Format::Json));
+    /// assert_eq!(Format::from_extension("xlsx"), None);
+    /// ```
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            #[cfg(feature = "toml")]
+            "toml" => Some(Self::Toml),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// Error returned by [`load`], with the line it was found on.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoadError {
+    /// The 1-based line of the offending field or record.
+    pub line: usize,
+    /// What went wrong.
+    pub kind: LoadErrorKind,
+}
+
+/// What a [`LoadError`] reports.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub enum LoadErrorKind {
+    /// The document or a record is malformed.
+    Syntax(String),
+    /// A constant has an empty name.
+    EmptyName,
+    /// The name appears earlier in the file or is already defined.
+    Duplicate(String),
+    /// The named field (`"value"` or `"uncertainty"`) is NaN or
+    /// infinite.
+    NonFinite(&'static str),
+    /// The uncertainty is negative.
+    NegativeUncertainty,
+    /// The unit names no known unit; see [`units::parse`].
+    UnknownUnit(String),
+    /// The unit is not a well-formed product of units; see
+    /// [`units::parse`].
+    MalformedUnit(String),
+    /// The category is not one of [`Category`]'s variants.
+    UnknownCategory(String),
+}
+
+impl fmt::Display for LoadErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax(message) => f.write_str(message),
+            Self::EmptyName => write!(f, "constant name is empty"),
+            Self::Duplicate(name) => {
+                write!(f, "duplicate constant `{name}`")
+            }
+            Self::NonFinite(field) => {
+                write!(f, "{field} is not finite")
+            }
+            Self::NegativeUncertainty => {
+                write!(f, "uncertainty is negative")
+            }
+            Self::UnknownUnit(symbol) => {
+                write!(f, "unknown unit `{symbol}`")
+            }
+            Self::MalformedUnit(symbol) => {
+                write!(f, "malformed unit `{symbol}`")
+            }
+            Self::UnknownCategory(name) => {
+                write!(f, "unknown category `{name}`")
+            }
+        }
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.kind)
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// Parses the constants in `text`.
+///
+/// Names only need to be unique within the file; use
+/// [`ConstantRegistry::load`](super::ConstantRegistry::load) or
+/// [`Constants::load`](super::Constants::load) to also reject names
+/// that are already defined.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::loader::{load, Format, LoadErrorKind};
+///
+/// let json = r#"{
+///     "constants": [
+///         { "name": "LAB_GAIN", "value": 2.5 },
+///         { "name": "LAB_OFFSET", "value": 0.1, "unit": "furlong" }
+///     ]
+/// }"#;
+/// let error = load(Format::Json, json).unwrap_err();
+/// assert_eq!(error.line, 4);
+/// assert_eq!(
+///     error.kind,
+///     LoadErrorKind::UnknownUnit("furlong".to_string())
+/// );
+/// ```
+pub fn load(
+    format: Format,
+    text: &str,
+) -> Result<Vec<Definition>, LoadError> {
+    parse(format, text, &|_| false)
+}
+
+/// Parses the constants in `text`, rejecting names for which
+/// `taken` returns `true`.
+pub(crate) fn parse(
+    format: Format,
+    text: &str,
+    taken: &dyn Fn(&str) -> bool,
+) -> Result<Vec<Definition>, LoadError> {
+    let checker = Checker {
+        taken,
+        seen: RefCell::default(),
+        failure: RefCell::default(),
+    };
+    match format {
+        Format::Json => json(text, &checker),
+        #[cfg(feature = "toml")]
+        Format::Toml => toml(text, &checker),
+        Format::Csv => csv(text, &checker),
+    }
+}
+
+const FIELDS: &[&str] = &[
+    "name",
+    "value",
+    "unit",
+    "uncertainty",
+    "category",
+    "description",
+];
+
+/// Validates fields and remembers the names seen so far.
+struct Checker<'a> {
+    taken: &'a dyn Fn(&str) -> bool,
+    seen: RefCell<HashSet<String>>,
+    /// The error behind the last failed [`Field`], which serde
+    /// only reports as a message.
+    failure: RefCell<Option<LoadErrorKind>>,
+}
+
+impl Checker<'_> {
+    fn name(&self, name: String) -> Result<String, LoadErrorKind> {
+        if name.is_empty() {
+            return Err(LoadErrorKind::EmptyName);
+        }
+        if (self.taken)(&name)
+            || !self.seen.borrow_mut().insert(name.clone())
+        {
+            return Err(LoadErrorKind::Duplicate(name));
+        }
+        Ok(name)
+    }
+
+    fn field<T, F>(&self, convert: F) -> Field<'_, T, F> {
+        Field {
+            failure: &self.failure,
+            convert,
+            raw: PhantomData,
+        }
+    }
+
+    fn error(&self, line: usize, message: String) -> LoadError {
+        LoadError {
+            line: line.max(1),
+            kind: self
+                .failure
+                .borrow_mut()
+                .take()
+                .unwrap_or(LoadErrorKind::Syntax(message)),
+        }
+    }
+}
+
+fn value(value: f64) -> Result<f64, LoadErrorKind> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(LoadErrorKind::NonFinite("value"))
+    }
+}
+
+fn uncertainty(sigma: f64) -> Result<Uncertainty, LoadErrorKind> {
+    if !sigma.is_finite() {
+        Err(LoadErrorKind::NonFinite("uncertainty"))
+    } else if sigma < 0.0 {
+        Err(LoadErrorKind::NegativeUncertainty)
+    } else if sigma == 0.0 {
+        Ok(Uncertainty::Exact)
+    } else {
+        Ok(Uncertainty::Standard(sigma))
+    }
+}
+
+fn unit(
+    symbol: &str,
+) -> Result<(Unit, Cow<'static, str>), LoadErrorKind> {
+    let symbol = symbol.trim();
+    resolve_unit(symbol).map_err(|error| match error {
+        ConversionError::Malformed => {
+            LoadErrorKind::MalformedUnit(symbol.to_string())
+        }
+        _ => LoadErrorKind::UnknownUnit(symbol.to_string()),
+    })
+}
+
+fn category(name: &str) -> Result<Category, LoadErrorKind> {
+    match name.trim().to_ascii_lowercase().as_str() {
+        "" | "physical" => Ok(Category::Physical),
+        "mathematical" => Ok(Category::Mathematical),
+        "cryptographic" => Ok(Category::Cryptographic),
+        _ => Err(LoadErrorKind::UnknownCategory(name.to_string())),
+    }
+}
+
+/// The fields of one constant, as they are read.
+#[derive(Default)]
+struct Record {
+    name: Option<String>,
+    value: Option<f64>,
+    unit: Option<(Unit, Cow<'static, str>)>,
+    uncertainty: Option<Uncertainty>,
+    category: Option<Category>,
+    description: Option<String>,
+}
+
+impl Record {
+    /// Builds the definition, or returns the missing field.
+    fn finish(self) -> Result<Definition, &'static str> {
+        let name = self.name.ok_or("name")?;
+        let value = self.value.ok_or("value")?;
+        let category = self.category.unwrap_or(Category::Physical);
+        let unit = self
+            .unit
+            .unwrap_or((units::ONE, Cow::Borrowed(units::ONE.symbol)));
+        Ok(Definition::new(name, value, category)
+            .with_resolved_unit(unit)
+            .with_uncertainty(
+                self.uncertainty.unwrap_or(Uncertainty::Exact),
+            )
+            .with_description(self.description.unwrap_or_default()))
+    }
+}
+
+// ---------------------------------------------------------------
+// JSON and TOML
+// ---------------------------------------------------------------
+
+/// Deserializes a `T` and converts it, recording a failed
+/// conversion in [`Checker::failure`] so the error is raised at the
+/// field's position.
+struct Field<'a, T, F> {
+    failure: &'a RefCell<Option<LoadErrorKind>>,
+    convert: F,
+    raw: PhantomData<fn() -> T>,
+}
+
+impl<'de, T, U, F> DeserializeSeed<'de> for Field<'_, T, F>
+where
+    T: Deserialize<'de>,
+    F: FnOnce(T) -> Result<U, LoadErrorKind>,
+{
+    type Value = U;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<U, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = T::deserialize(deserializer)?;
+        (self.convert)(raw).map_err(|kind| {
+            let error = de::Error::custom(&kind);
+            *self.failure.borrow_mut() = Some(kind);
+            error
+        })
+    }
+}
+
+/// The top-level table holding the `constants` list.
+struct Document<'a>(&'a Checker<'a>);
+
+impl<'de> DeserializeSeed<'de> for Document<'_> {
+    type Value = Vec<Definition>;
+
+    fn deserialize<D>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Document<'_> {
+    type Value = Vec<Definition>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a table with a `constants` list")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut constants = None;
+        while let Some(key) = map.next_key::<String>()? {
+            if key != "constants" {
+                let _ = map.next_value::<IgnoredAny>()?;
+            } else if constants.is_some() {
+                return Err(de::Error::duplicate_field("constants"));
+            } else {
+                constants = Some(map.next_value_seed(Records(self.0))?);
+            }
+        }
+        constants.ok_or_else(|| de::Error::missing_field("constants"))
+    }
+}
+
+/// The `constants` list.
+struct Records<'a>(&'a Checker<'a>);
+
+impl<'de> DeserializeSeed<'de> for Records<'_> {
+    type Value = Vec<Definition>;
+
+    fn deserialize<D>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Records<'_> {
+    type Value = Vec<Definition>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a list of constants")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut definitions = Vec::new();
+        while let Some(definition) =
+            seq.next_element_seed(RecordSeed(self.0))?
+        {
+            definitions.push(definition);
+        }
+        Ok(definitions)
+    }
+}
+
+/// A single constant of the `constants` list.
+struct RecordSeed<'a>(&'a Checker<'a>);
+
+impl<'de> DeserializeSeed<'de> for RecordSeed<'_> {
+    type Value = Definition;
+
+    fn deserialize<D>(
+        self,
+        deserializer: D,
+    ) -> Result<Definition, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for RecordSeed<'_> {
+    type Value = Definition;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a constant with a name and a value")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Definition, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let checker = self.0;
+        let mut record = Record::default();
+        while let Some(key) = map.next_key::<String>()? {
+            let field =
+                FIELDS.iter().find(|&&field| field == key).ok_or_else(
+                    || de::Error::unknown_field(&key, FIELDS),
+                )?;
+            match *field {
+                "name" if record.name.is_none() => {
+                    record.name = Some(map.next_value_seed(
+                        checker.field(|name| checker.name(name)),
+                    )?);
+                }
+                "value" if record.value.is_none() => {
+                    record.value = Some(
+                        map.next_value_seed(checker.field(value))?,
+                    );
+                }
+                "unit" if record.unit.is_none() => {
+                    record.unit = Some(map.next_value_seed(
+                        checker.field(|symbol: String| unit(&symbol)),
+                    )?);
+                }
+                "uncertainty" if record.uncertainty.is_none() => {
+                    record.uncertainty =
+                        Some(map.next_value_seed(
+                            checker.field(uncertainty),
+                        )?);
+                }
+                "category" if record.category.is_none() => {
+                    record.category = Some(map.next_value_seed(
+                        checker.field(|name: String| category(&name)),
+                    )?);
+                }
+                "description" if record.description.is_none() => {
+                    record.description = Some(map.next_value()?);
+                }
+                _ => return Err(de::Error::duplicate_field(field)),
+            }
+        }
+        record.finish().map_err(de::Error::missing_field)
+    }
+}
+
+fn json(
+    text: &str,
+    checker: &Checker<'_>,
+) -> Result<Vec<Definition>, LoadError> {
+    let mut deserializer = serde_json::Deserializer::from_str(text);
+    Document(checker)
+        .deserialize(&mut deserializer)
+        .and_then(|definitions| {
+            deserializer.end()?;
+            Ok(definitions)
+        })
+        .map_err(|error| {
+            // Drop the " at line L column C" suffix; the line is
+            // reported separately.
+            let message = error.to_string();
+            let message = message
+                .rsplit_once(" at line ")
+                .map_or(message.as_str(), |(message, _)| message);
+            checker.error(error.line(), message.to_string())
+        })
+}
+
+#[cfg(feature = "toml")]
+fn toml(
+    text: &str,
+    checker: &Checker<'_>,
+) -> Result<Vec<Definition>, LoadError> {
+    Document(checker)
+        .deserialize(toml::Deserializer::new(text))
+        .map_err(|error| {
+            let line = error.span().map_or(1, |span| {
+                text.as_bytes()[..span.start]
+                    .iter()
+                    .filter(|&&byte| byte == b'\n')
+                    .count()
+                    + 1
+            });
+            checker.error(line, error.message().to_string())
+        })
+}
+
+// ---------------------------------------------------------------
+// CSV
+// ---------------------------------------------------------------
+
+fn csv(
+    text: &str,
+    checker: &Checker<'_>,
+) -> Result<Vec<Definition>, LoadError> {
+    let syntax = |line, message: String| LoadError {
+        line,
+        kind: LoadErrorKind::Syntax(message),
+    };
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut rows = csv_rows(text)?.into_iter();
+    let (line, header) = rows
+        .next()
+        .ok_or_else(|| syntax(1, "missing header row".to_string()))?;
+    let mut columns: Vec<&'static str> = Vec::new();
+    for title in &header {
+        let title = title.trim();
+        let field = FIELDS
+            .iter()
+            .find(|field| field.eq_ignore_ascii_case(title))
+            .ok_or_else(|| {
+                syntax(line, format!("unknown column `{title}`"))
+            })?;
+        if columns.contains(field) {
+            return Err(syntax(
+                line,
+                format!("duplicate column `{field}`"),
+            ));
+        }
+        columns.push(field);
+    }
+    for required in ["name", "value"] {
+        if !columns.contains(&required) {
+            return Err(syntax(
+                line,
+                format!("missing column `{required}`"),
+            ));
+        }
+    }
+
+    let mut definitions = Vec::new();
+    for (line, cells) in rows {
+        if cells.iter().all(|cell| cell.trim().is_empty()) {
+            continue;
+        }
+        if cells.len() != columns.len() {
+            return Err(syntax(
+                line,
+                format!(
+                    "expected {} fields, found {}",
+                    columns.len(),
+                    cells.len()
+                ),
+            ));
+        }
+        let mut record = Record::default();
+        for (&field, cell) in columns.iter().zip(cells) {
+            let cell = cell.trim();
+            if cell.is_empty() && field != "name" {
+                continue;
+            }
+            let number = || {
+                cell.parse::<f64>().map_err(|_| {
+                    LoadErrorKind::Syntax(format!(
+                        "invalid {field} `{cell}`"
+                    ))
+                })
+            };
+            let parsed = match field {
+                "name" => checker
+                    .name(cell.to_string())
+                    .map(|name| record.name = Some(name)),
+                "value" => number()
+                    .and_then(value)
+                    .map(|value| record.value = Some(value)),
+                "unit" => {
+                    unit(cell).map(|unit| record.unit = Some(unit))
+                }
+                "uncertainty" => number()
+                    .and_then(uncertainty)
+                    .map(|sigma| record.uncertainty = Some(sigma)),
+                "category" => category(cell)
+                    .map(|category| record.category = Some(category)),
+                _ => {
+                    record.description = Some(cell.to_string());
+                    Ok(())
+                }
+            };
+            parsed.map_err(|kind| LoadError { line, kind })?;
+        }
+        let definition = record.finish().map_err(|field| {
+            syntax(line, format!("missing field `{field}`"))
+        })?;
+        definitions.push(definition);
+    }
+    Ok(definitions)
+}
+
+/// Splits CSV text into rows of unquoted cells, each with the line
+/// it starts on.
+fn csv_rows(
+    text: &str,
+) -> Result<Vec<(usize, Vec<String>)>, LoadError> {
+    let mut rows = Vec::new();
+    let mut chars = text.chars().peekable();
+    let mut line = 1;
+    while chars.peek().is_some() {
+        let start = line;
+        let mut row = Vec::new();
+        let mut cell = String::new();
+        let mut quoted = false;
+        loop {
+            match chars.next() {
+                None if quoted => {
+                    return Err(LoadError {
+                        line: start,
+                        kind: LoadErrorKind::Syntax(
+                            "unterminated quoted field".to_string(),
+                        ),
+                    });
+                }
+                None => {
+                    row.push(cell);
+                    break;
+                }
+                Some('"') if quoted => {
+                    if chars.peek() == Some(&'"') {
+                        let _ = chars.next();
+                        cell.push('"');
+                    } else {
+                        quoted = false;
+                    }
+                }
+                Some('"') if cell.is_empty() => quoted = true,
+                Some(',') if !quoted => {
+                    row.push(core::mem::take(&mut cell));
+                }
+                Some('\r')
+                    if !quoted && chars.peek() == Some(&'\n') => {}
+                Some('\n') if !quoted => {
+                    line += 1;
+                    row.push(cell);
+                    break;
+                }
+                Some(c) => {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    cell.push(c);
+                }
+            }
+        }
+        rows.push((start, row));
+    }
+    Ok(rows)
+}
@@ -0,0 +1,590 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! User-defined constants alongside the built-in set.
+//!
+//! A [`ConstantRegistry`] starts from every row of a [`Dataset`]
+//! and accepts further [`Definition`]s carrying the same metadata
+//! as a built-in row: category, unit and standard uncertainty.
+//! Names are unique; registering a name that is already taken
+//! fails with [`RegistryError::Conflict`]. Requires the `std`
+//! feature.
+//!
+//! # Example
+//!
+//! ```
+//! use cmn::constants::registry::{Definition, RegistryError};
+//! use cmn::constants::{Category, ConstantRegistry, Uncertainty};
+//! use cmn::units::METER;
+//!
+//! let mut registry = ConstantRegistry::new();
+//! registry
+//!     .register(
+//!         Definition::new("LAB_BASELINE", 12.5, Category::Physical)
+//!             .with_unit(METER)
+//!             .with_uncertainty(Uncertainty::Standard(0.002))
+//!             .with_description("Interferometer baseline"),
+//!     )
+//!     .unwrap();
+//!
+//! assert_eq!(registry.get::<f64>("LAB_BASELINE"), Ok(12.5));
+//! assert_eq!(registry.unit("LAB_BASELINE"), Some(METER));
+//! assert_eq!(registry.get::<f64>("PI"), Ok(core::f64::consts::PI));
+//!
+//! let clash = Definition::new("PI", 3.0, Category::Mathematical);
+//! assert!(matches!(
+//!     registry.register(clash),
+//!     Err(RegistryError::Conflict { builtin: true, .. })
+//! ));
+//! ```
+//!
+//! The registry serializes only the user-defined constants, and
+//! deserializing re-registers them, so a document that redefines
+//! a built-in name is rejected:
+//!
+//! ```
+//! use cmn::constants::ConstantRegistry;
+//!
+//! let json = r#"{
+//!     "dataset": "Codata2022",
+//!     "constants": [{
+//!         "name": "LAB_BASELINE",
+//!         "value": { "Float": 12.5 },
+//!         "category": "Physical",
+//!         "unit": "m",
+//!         "uncertainty": { "Standard": 0.002 }
+//!     }]
+//! }"#;
+//! let registry: ConstantRegistry =
+//!     serde_json::from_str(json).unwrap();
+//! assert_eq!(registry.user_defined().len(), 1);
+//!
+//! let clash = json.replace("LAB_BASELINE", "PLANCK");
+//! let error = serde_json::from_str::<ConstantRegistry>(&clash);
+//! assert!(error.unwrap_err().to_string().contains("PLANCK"));
+//! ```
+
+use super::loader::{self, Format, LoadError};
+use super::{
+    aliases, search, Category, Constant, ConstantEntry, ConstantError,
+    ConstantValue, Constants, Dataset, StandardUncertainty,
+    Uncertainty, CONSTANTS_TABLE,
+};
+use crate::units::{self, ConversionError, Unit};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// A named constant with its value and metadata, as held by a
+/// [`ConstantRegistry`].
+///
+/// Built-in constants borrow their name and description; user
+/// constants may own them.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(into = "Fields", try_from = "Fields")]
+pub struct Definition {
+    /// The name of the constant, e.g. `"PLANCK"`.
+    pub name: Cow<'static, str>,
+    /// The value of the constant, expressed in
+    /// [`Definition::unit`].
+    pub value: ConstantValue,
+    /// The category of the constant.
+    pub category: Category,
+    /// The unit of [`Definition::value`]. Serialized as its symbol,
+    /// which deserializes with [`units::parse`]. A unit read from
+    /// text that is not in [`units::REGISTRY`], such as `kg m^-3`,
+    /// has an empty `symbol`; see [`Definition::unit_symbol`].
+    pub unit: Unit,
+    /// The standard uncertainty of [`Definition::value`].
+    pub uncertainty: Uncertainty,
+    /// A short human description. Empty when not given.
+    pub description: Cow<'static, str>,
+    /// The symbol of a unit whose `symbol` is empty.
+    unit_symbol: Cow<'static, str>,
+}
+
+impl Definition {
+    /// Creates a dimensionless, exact definition with no
+    /// description.
+    pub fn new(
+        name: impl Into<Cow<'static, str>>,
+        value: impl Into<ConstantValue>,
+        category: Category,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            category,
+            unit: units::ONE,
+            uncertainty: Uncertainty::Exact,
+            description: Cow::Borrowed(""),
+            unit_symbol: Cow::Borrowed(""),
+        }
+    }
+
+    /// Sets the unit of the value.
+    pub fn with_unit(mut self, unit: Unit) -> Self {
+        self.unit = unit;
+        self.unit_symbol = Cow::Borrowed("");
+        self
+    }
+
+    /// Sets the unit of the value from its symbol, as written, such
+    /// as `"mV"` or `"kg m^-3"`; see [`units::parse`]. An empty
+    /// symbol is dimensionless.
+    ///
+    /// # Errors
+    ///
+    /// The [`ConversionError`] if the symbol names no unit or is
+    /// malformed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::registry::Definition;
+    /// use cmn::constants::Category;
+    ///
+    /// let density = Definition::new("RHO", 1.2, Category::Physical)
+    ///     .with_unit_symbol(&String::from("kg m^-3"))
+    ///     .unwrap();
+    /// assert_eq!(density.unit_symbol(), "kg m^-3");
+    /// assert_eq!(density.unit.dimension.to_string(), "m^-3 kg");
+    /// ```
+    pub fn with_unit_symbol(
+        self,
+        symbol: &str,
+    ) -> Result<Self, ConversionError> {
+        Ok(self.with_resolved_unit(resolve_unit(symbol)?))
+    }
+
+    /// Sets a unit returned by [`resolve_unit`].
+    pub(crate) fn with_resolved_unit(
+        mut self,
+        (unit, symbol): (Unit, Cow<'static, str>),
+    ) -> Self {
+        self.unit = unit;
+        self.unit_symbol = if unit.symbol.is_empty() {
+            symbol
+        } else {
+            Cow::Borrowed("")
+        };
+        self
+    }
+
+    /// Returns the symbol of the unit as it was written.
+    pub fn unit_symbol(&self) -> &str {
+        if self.unit.symbol.is_empty() {
+            &self.unit_symbol
+        } else {
+            self.unit.symbol
+        }
+    }
+
+    /// Sets the standard uncertainty of the value.
+    pub fn with_uncertainty(
+        mut self,
+        uncertainty: Uncertainty,
+    ) -> Self {
+        self.uncertainty = uncertainty;
+        self
+    }
+
+    /// Sets the description.
+    pub fn with_description(
+        mut self,
+        description: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Returns the absolute and relative standard uncertainty of
+    /// this definition, or `None` if the value is not a float.
+    pub fn standard_uncertainty(&self) -> Option<StandardUncertainty> {
+        match self.value {
+            ConstantValue::Float(value) => Some(StandardUncertainty {
+                absolute: self.uncertainty.absolute(),
+                relative: self.uncertainty.relative(value),
+                exact: self.uncertainty.is_exact(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl From<&'static ConstantEntry> for Definition {
+    fn from(entry: &'static ConstantEntry) -> Self {
+        Self {
+            name: Cow::Borrowed(entry.name),
+            value: entry.value.into(),
+            category: entry.category,
+            unit: entry.unit,
+            uncertainty: entry.uncertainty,
+            description: Cow::Borrowed(entry.info.description),
+            unit_symbol: Cow::Borrowed(""),
+        }
+    }
+}
+
+const fn exact() -> Uncertainty {
+    Uncertainty::Exact
+}
+
+/// Resolves a unit symbol to the unit of a built-in constant or
+/// the unit [`units::evaluate`] reads from it, with the symbol to
+/// keep. Empty symbols are dimensionless.
+pub(crate) fn resolve_unit(
+    symbol: &str,
+) -> Result<(Unit, Cow<'static, str>), ConversionError> {
+    let symbol = symbol.trim();
+    if symbol.is_empty() {
+        return Ok((units::ONE, Cow::Borrowed(units::ONE.symbol)));
+    }
+    let unit = match CONSTANTS_TABLE
+        .iter()
+        .map(|entry| entry.unit)
+        .find(|unit| unit.symbol == symbol)
+    {
+        Some(unit) => unit,
+        None => units::evaluate(symbol)?,
+    };
+    let kept = if unit.symbol.is_empty() {
+        Cow::Owned(symbol.to_string())
+    } else {
+        Cow::Borrowed(unit.symbol)
+    };
+    Ok((unit, kept))
+}
+
+/// The serialized form of a [`Definition`], with its unit as a
+/// symbol.
+#[derive(Serialize, Deserialize)]
+struct Fields {
+    name: Cow<'static, str>,
+    value: ConstantValue,
+    category: Category,
+    #[serde(default)]
+    unit: Cow<'static, str>,
+    #[serde(default = "exact")]
+    uncertainty: Uncertainty,
+    #[serde(default)]
+    description: Cow<'static, str>,
+}
+
+impl From<Definition> for Fields {
+    fn from(definition: Definition) -> Self {
+        Self {
+            unit: Cow::Owned(definition.unit_symbol().to_string()),
+            name: definition.name,
+            value: definition.value,
+            category: definition.category,
+            uncertainty: definition.uncertainty,
+            description: definition.description,
+        }
+    }
+}
+
+impl TryFrom<Fields> for Definition {
+    type Error = String;
+
+    fn try_from(fields: Fields) -> Result<Self, String> {
+        let definition =
+            Self::new(fields.name, fields.value, fields.category)
+                .with_unit_symbol(&fields.unit)
+                .map_err(|error| {
+                    format!("{error} `{}`", fields.unit)
+                })?;
+        Ok(definition
+            .with_uncertainty(fields.uncertainty)
+            .with_description(fields.description))
+    }
+}
+
+/// Error returned by [`ConstantRegistry::register`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegistryError {
+    /// The definition has an empty name.
+    EmptyName,
+    /// A constant with the same name is already registered.
+    Conflict {
+        /// The conflicting name.
+        name: String,
+        /// `true` if the existing constant is a built-in one.
+        builtin: bool,
+    },
+}
+
+impl core::fmt::Display for RegistryError {
+    fn fmt(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        match self {
+            Self::EmptyName => write!(f, "constant name is empty"),
+            Self::Conflict {
+                name,
+                builtin: true,
+            } => write!(f, "`{name}` is a built-in constant"),
+            Self::Conflict {
+                name,
+                builtin: false,
+            } => write!(f, "`{name}` is already registered"),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+/// The built-in constants of a [`Dataset`] extended with
+/// user-defined [`Definition`]s.
+///
+/// Serializes to `{ "dataset": ..., "constants": [...] }` listing
+/// the user-defined constants only.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(try_from = "Document<'static>")]
+pub struct ConstantRegistry {
+    dataset: Dataset,
+    /// The built-in rows, followed by the user-defined ones.
+    definitions: Vec<Definition>,
+    builtins: usize,
+}
+
+impl ConstantRegistry {
+    /// Creates a registry holding the built-in constants of
+    /// [`Dataset::DEFAULT`].
+    pub fn new() -> Self {
+        Self::with_dataset(Dataset::DEFAULT)
+    }
+
+    /// Creates a registry holding the built-in constants of
+    /// `dataset`.
+    pub fn with_dataset(dataset: Dataset) -> Self {
+        let definitions: Vec<_> =
+            dataset.table().iter().map(Definition::from).collect();
+        Self {
+            dataset,
+            builtins: definitions.len(),
+            definitions,
+        }
+    }
+
+    /// Returns the CODATA edition of the built-in constants.
+    pub fn dataset(&self) -> Dataset {
+        self.dataset
+    }
+
+    /// Adds a user-defined constant.
+    ///
+    /// Fails if the name is empty or already taken by a built-in or
+    /// previously registered constant; the registry is left
+    /// unchanged.
+    pub fn register(
+        &mut self,
+        definition: Definition,
+    ) -> Result<(), RegistryError> {
+        if definition.name.is_empty() {
+            return Err(RegistryError::EmptyName);
+        }
+        if let Some(index) = self
+            .definitions
+            .iter()
+            .position(|d| d.name == definition.name)
+        {
+            return Err(RegistryError::Conflict {
+                name: definition.name.into_owned(),
+                builtin: index < self.builtins,
+            });
+        }
+        self.definitions.push(definition);
+        Ok(())
+    }
+
+    /// Registers the constants defined in `text`; see [`loader`]
+    /// for the accepted formats and fields.
+    ///
+    /// Fails without changing the registry if any definition is
+    /// invalid or its name is already taken.
+    pub fn load(
+        &mut self,
+        format: Format,
+        text: &str,
+    ) -> Result<(), LoadError> {
+        let definitions = loader::parse(format, text, &|name| {
+            self.definition(name).is_some()
+        })?;
+        self.definitions.extend(definitions);
+        Ok(())
+    }
+
+    /// Returns every constant: the built-in ones first, then the
+    /// user-defined ones in registration order.
+    pub fn definitions(&self) -> &[Definition] {
+        &self.definitions
+    }
+
+    /// Returns the user-defined constants in registration order.
+    pub fn user_defined(&self) -> &[Definition] {
+        &self.definitions[self.builtins..]
+    }
+
+    /// Looks up a constant by exact name.
+    pub fn definition(&self, name: &str) -> Option<&Definition> {
+        self.definitions.iter().find(|d| d.name == name)
+    }
+
+    /// Returns the value of the named constant converted to `T`.
+    /// See [`Constants::get`].
+    pub fn get<T>(&self, name: &str) -> Result<T, ConstantError>
+    where
+        T: TryFrom<ConstantValue, Error = ConstantError>,
+    {
+        self.definition(name)
+            .ok_or(ConstantError::NotFound)
+            .and_then(|d| T::try_from(d.value.clone()))
+    }
+
+    /// Returns the unit of the named constant, or `None` for
+    /// unknown names and non-float values. A unit read from text
+    /// outside [`units::REGISTRY`] has an empty symbol; see
+    /// [`Definition::unit_symbol`].
+    pub fn unit(&self, name: &str) -> Option<Unit> {
+        self.definition(name)
+            .filter(|d| matches!(d.value, ConstantValue::Float(_)))
+            .map(|d| d.unit)
+    }
+
+    /// Returns the absolute and relative standard uncertainty of
+    /// the named constant, or `None` for unknown names and
+    /// non-float values.
+    pub fn uncertainty(
+        &self,
+        name: &str,
+    ) -> Option<StandardUncertainty> {
+        self.definition(name)?.standard_uncertainty()
+    }
+
+    /// Returns the category of the named constant.
+    pub fn category(&self, name: &str) -> Option<Category> {
+        self.definition(name).map(|d| d.category)
+    }
+
+    /// Iterates over the constants in `category`.
+    pub fn by_category(
+        &self,
+        category: Category,
+    ) -> impl Iterator<Item = &Definition> {
+        self.definitions
+            .iter()
+            .filter(move |d| d.category == category)
+    }
+
+    /// Ranks constants by similarity to `query` across their names,
+    /// descriptions and, for built-in constants, symbols and
+    /// English names. See [`Constants::search`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::registry::Definition;
+    /// use cmn::constants::{Category, ConstantRegistry};
+    ///
+    /// let mut registry = ConstantRegistry::new();
+    /// registry
+    ///     .register(
+    ///         Definition::new("LAB_GAIN", 2.5, Category::Physical)
+    ///             .with_description("Detector gain"),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let hits = registry.search("detector gain");
+    /// assert_eq!(hits[0].name, "LAB_GAIN");
+    /// assert_eq!(registry.search("hbar")[0].name, "PLANCK_REDUCED");
+    /// ```
+    pub fn search(&self, query: &str) -> Vec<search::SearchMatch<'_>> {
+        let names =
+            self.definitions.iter().map(|d| (&*d.name, &*d.name));
+        let descriptions = self
+            .definitions
+            .iter()
+            .filter(|d| !d.description.is_empty())
+            .map(|d| (&*d.name, &*d.description));
+        let aliases = aliases::SYMBOLS
+            .iter()
+            .chain(aliases::NAMES)
+            .map(|&(alias, name)| (name, alias));
+        search::rank(query, names.chain(descriptions).chain(aliases))
+    }
+
+    /// Returns every constant as a [`Constants`] collection, so the
+    /// user-defined ones can be read and serialized like the
+    /// built-ins.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::constants::registry::Definition;
+    /// use cmn::constants::{Category, ConstantRegistry};
+    ///
+    /// let mut registry = ConstantRegistry::new();
+    /// registry
+    ///     .register(Definition::new("GAIN", 2.5, Category::Physical))
+    ///     .unwrap();
+    ///
+    /// let constants = registry.to_constants();
+    /// assert_eq!(constants.constants().len(), 122);
+    /// assert_eq!(constants.get::<f64>("GAIN"), Ok(2.5));
+    /// ```
+    pub fn to_constants(&self) -> Constants {
+        let constants = self
+            .definitions
+            .iter()
+            .map(|d| Constant {
+                name: d.name.clone(),
+                value: d.value.clone(),
+            })
+            .collect();
+        Constants {
+            constants,
+            dataset: self.dataset,
+        }
+    }
+}
+
+impl Default for ConstantRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The serialized form of a [`ConstantRegistry`].
+#[derive(Serialize, Deserialize)]
+struct Document<'a> {
+    #[serde(default)]
+    dataset: Dataset,
+    constants: Cow<'a, [Definition]>,
+}
+
+impl TryFrom<Document<'_>> for ConstantRegistry {
+    type Error = RegistryError;
+
+    fn try_from(document: Document<'_>) -> Result<Self, Self::Error> {
+        let mut registry = Self::with_dataset(document.dataset);
+        for definition in document.constants.into_owned() {
+            registry.register(definition)?;
+        }
+        Ok(registry)
+    }
+}
+
+impl Serialize for ConstantRegistry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        Document {
+            dataset: self.dataset,
+            constants: Cow::Borrowed(self.user_defined()),
+        }
+        .serialize(serializer)
+    }
+}
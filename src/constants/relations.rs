@@ -0,0 +1,738 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Identities between constants, used to catch inconsistent values.
+//!
+//! Many constants are defined in terms of others: ħ = h/(2π),
+//! φ = (1 + √5)/2, m_p/m_e = 1/(m_e/m_p). Each [`Relation`] in
+//! [`RELATIONS`] recomputes one constant from its inputs and accepts
+//! a relative difference up to its tolerance, so a mistyped digit in
+//! a hand-entered literal shows up as an [`Inconsistency`].
+//! Available in `no_std`.
+//!
+//! # Example
+//!
+//! ```
+//! use cmn::constants::{relations, Dataset};
+//!
+//! assert_eq!(relations::verify(Dataset::Codata2018).count(), 0);
+//!
+//! let hbar = relations::RELATIONS
+//!     .iter()
+//!     .find(|r| r.constant == "PLANCK_REDUCED")
+//!     .unwrap();
+//! let wrong = |name: &str| match name {
+//!     "PLANCK_REDUCED" => Some(1.054_571_718e-34),
+//!     "PLANCK" => Some(6.626_070_15e-34),
+//!     _ => None,
+//! };
+//! let err = hbar.check(wrong).unwrap().unwrap_err();
+//! assert!(err.relative_error() > hbar.tolerance);
+//! ```
+
+use super::{abs, sqrt, Dataset};
+use core::f64::consts::{PI, TAU};
+
+/// An identity that defines one constant in terms of others.
+#[derive(Clone, Copy, Debug)]
+pub struct Relation {
+    /// The name of the constant the relation recomputes.
+    pub constant: &'static str,
+    /// The identity in conventional notation, e.g. `"ħ = h/(2π)"`.
+    pub formula: &'static str,
+    /// The names of the constants `compute` takes, in order.
+    pub inputs: &'static [&'static str],
+    /// Computes the expected value of `constant` from the values of
+    /// `inputs`.
+    pub compute: fn(&[f64]) -> f64,
+    /// The largest accepted relative difference between the stored
+    /// and the recomputed value.
+    pub tolerance: f64,
+}
+
+/// A [`Relation`] that does not hold for a set of values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Inconsistency {
+    /// The violated relation.
+    pub relation: &'static Relation,
+    /// The value recomputed from the relation's inputs.
+    pub expected: f64,
+    /// The stored value of the constant.
+    pub actual: f64,
+}
+
+impl PartialEq for Relation {
+    fn eq(&self, other: &Self) -> bool {
+        self.constant == other.constant && self.formula == other.formula
+    }
+}
+
+impl Relation {
+    /// Recomputes the constant from the values returned by `value`,
+    /// or returns `None` if the constant or one of the inputs is
+    /// missing. At most eight inputs are supported.
+    pub fn evaluate(
+        &self,
+        value: impl Fn(&str) -> Option<f64>,
+    ) -> Option<f64> {
+        let mut args = [0.0; 8];
+        for (arg, name) in args.iter_mut().zip(self.inputs) {
+            *arg = value(name)?;
+        }
+        Some((self.compute)(&args[..self.inputs.len()]))
+    }
+
+    /// Checks the relation against the values returned by `value`.
+    ///
+    /// Returns `None` if a value is missing, `Some(Ok(()))` if the
+    /// relation holds within [`tolerance`](Self::tolerance), and the
+    /// [`Inconsistency`] otherwise.
+    pub fn check(
+        &'static self,
+        value: impl Fn(&str) -> Option<f64>,
+    ) -> Option<Result<(), Inconsistency>> {
+        let actual = value(self.constant)?;
+        let expected = self.evaluate(value)?;
+        let inconsistency = Inconsistency {
+            relation: self,
+            expected,
+            actual,
+        };
+        Some(if inconsistency.relative_error() <= self.tolerance {
+            Ok(())
+        } else {
+            Err(inconsistency)
+        })
+    }
+}
+
+impl Inconsistency {
+    /// Returns `|actual - expected| / |expected|`.
+    pub fn relative_error(&self) -> f64 {
+        let diff = self.actual - self.expected;
+        abs(diff) / abs(self.expected)
+    }
+}
+
+impl core::fmt::Display for Inconsistency {
+    fn fmt(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        write!(
+            f,
+            "{} = {:e} violates {} (expected {:e}, relative error \
+             {:.1e} > {:.0e})",
+            self.relation.constant,
+            self.actual,
+            self.relation.formula,
+            self.expected,
+            self.relative_error(),
+            self.relation.tolerance,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Inconsistency {}
+
+/// Checks every relation in [`RELATIONS`] against the values of
+/// `dataset` and yields the ones that do not hold.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::{relations, Dataset};
+///
+/// assert_eq!(relations::verify(Dataset::Codata2022).count(), 0);
+/// ```
+pub fn verify(dataset: Dataset) -> impl Iterator<Item = Inconsistency> {
+    RELATIONS.iter().filter_map(move |relation| {
+        relation
+            .check(|name| dataset.entry(name)?.value.as_f64())?
+            .err()
+    })
+}
+
+/// Identities between `f64` values, allowing a few ulps of rounding.
+const FLOAT: f64 = 1e-15;
+/// Relations between exact constants whose derived values are
+/// published to ten significant digits.
+const EXACT: f64 = 1e-9;
+/// Relations involving measured constants, limited by the digits
+/// CODATA publishes rather than by their uncertainty.
+const MEASURED: f64 = 1e-8;
+/// Values published to seven significant digits: the Planck units
+/// and the molar volume.
+const SEVEN_DIGITS: f64 = 1e-6;
+
+/// Standard temperature, 273.15 K.
+const T0: f64 = 273.15;
+/// Standard-state pressure, 100 kPa.
+const P0: f64 = 100_000.0;
+/// The root of `x = 5 (1 - e^-x)` fixing Wien's displacement law.
+const WIEN_X: f64 = 4.965_114_231_744_276;
+
+const fn relation(
+    constant: &'static str,
+    formula: &'static str,
+    inputs: &'static [&'static str],
+    compute: fn(&[f64]) -> f64,
+    tolerance: f64,
+) -> Relation {
+    Relation {
+        constant,
+        formula,
+        inputs,
+        compute,
+        tolerance,
+    }
+}
+
+/// Every identity checked by [`verify`] and
+/// `Constants::verify_relations`, grouped as in
+/// [`CONSTANTS_TABLE`](super::CONSTANTS_TABLE).
+///
+/// Square roots use the crate's own Newton iteration, as `core`
+/// has no `f64::sqrt`. Relations are written without logarithms,
+/// so the Sackur-Tetrode constant is not covered.
+pub const RELATIONS: &[Relation] = &[
+    // Mathematical
+    relation("TAU", "τ = 2π", &["PI"], |x| 2.0 * x[0], FLOAT),
+    relation(
+        "PHI",
+        "φ = (1 + √5)/2",
+        &["SQRT5"],
+        |x| (1.0 + x[0]) / 2.0,
+        FLOAT,
+    ),
+    relation(
+        "SILVER_RATIO",
+        "δ_S = 1 + √2",
+        &["SQRT2"],
+        |x| 1.0 + x[0],
+        FLOAT,
+    ),
+    relation(
+        "FRAC_1_SQRT_2",
+        "1/√2 = √2/2",
+        &["SQRT2"],
+        |x| x[0] / 2.0,
+        FLOAT,
+    ),
+    relation("FRAC_1_PI", "1/π", &["PI"], |x| 1.0 / x[0], FLOAT),
+    relation("FRAC_2_PI", "2/π", &["PI"], |x| 2.0 / x[0], FLOAT),
+    relation("FRAC_PI_2", "π/2", &["PI"], |x| x[0] / 2.0, FLOAT),
+    relation("FRAC_PI_3", "π/3", &["PI"], |x| x[0] / 3.0, FLOAT),
+    relation("FRAC_PI_4", "π/4", &["PI"], |x| x[0] / 4.0, FLOAT),
+    relation("FRAC_PI_6", "π/6", &["PI"], |x| x[0] / 6.0, FLOAT),
+    relation("FRAC_PI_8", "π/8", &["PI"], |x| x[0] / 8.0, FLOAT),
+    relation(
+        "LOG2_E",
+        "log₂ e = 1/ln 2",
+        &["LN_2"],
+        |x| 1.0 / x[0],
+        FLOAT,
+    ),
+    relation(
+        "LOG10_E",
+        "log₁₀ e = 1/ln 10",
+        &["LN_10"],
+        |x| 1.0 / x[0],
+        FLOAT,
+    ),
+    // Universal and electromagnetic
+    relation(
+        "PLANCK_REDUCED",
+        "ħ = h/(2π)",
+        &["PLANCK"],
+        |x| x[0] / TAU,
+        EXACT,
+    ),
+    relation(
+        "VACUUM_PERMEABILITY",
+        "μ₀ = 2αh/(e²c)",
+        &[
+            "FINE_STRUCTURE",
+            "PLANCK",
+            "ELEMENTARY_CHARGE",
+            "SPEED_OF_LIGHT",
+        ],
+        |x| 2.0 * x[0] * x[1] / (x[2] * x[2] * x[3]),
+        MEASURED,
+    ),
+    relation(
+        "VACUUM_PERMITTIVITY",
+        "ε₀ = 1/(μ₀c²)",
+        &["VACUUM_PERMEABILITY", "SPEED_OF_LIGHT"],
+        |x| 1.0 / (x[0] * x[1] * x[1]),
+        MEASURED,
+    ),
+    relation(
+        "COULOMB",
+        "k_e = 1/(4πε₀)",
+        &["VACUUM_PERMITTIVITY"],
+        |x| 1.0 / (4.0 * PI * x[0]),
+        MEASURED,
+    ),
+    relation(
+        "IMPEDANCE_OF_FREE_SPACE",
+        "Z₀ = μ₀c",
+        &["VACUUM_PERMEABILITY", "SPEED_OF_LIGHT"],
+        |x| x[0] * x[1],
+        MEASURED,
+    ),
+    relation(
+        "FINE_STRUCTURE",
+        "α = e²/(4πε₀ħc)",
+        &[
+            "ELEMENTARY_CHARGE",
+            "VACUUM_PERMITTIVITY",
+            "PLANCK_REDUCED",
+            "SPEED_OF_LIGHT",
+        ],
+        |x| x[0] * x[0] / (4.0 * PI * x[1] * x[2] * x[3]),
+        MEASURED,
+    ),
+    relation(
+        "INVERSE_FINE_STRUCTURE",
+        "α⁻¹ = 1/α",
+        &["FINE_STRUCTURE"],
+        |x| 1.0 / x[0],
+        MEASURED,
+    ),
+    relation(
+        "MAGNETIC_FLUX_QUANTUM",
+        "Φ₀ = h/(2e)",
+        &["PLANCK", "ELEMENTARY_CHARGE"],
+        |x| x[0] / (2.0 * x[1]),
+        EXACT,
+    ),
+    relation(
+        "CONDUCTANCE_QUANTUM",
+        "G₀ = 2e²/h",
+        &["ELEMENTARY_CHARGE", "PLANCK"],
+        |x| 2.0 * x[0] * x[0] / x[1],
+        EXACT,
+    ),
+    relation(
+        "JOSEPHSON_CONSTANT",
+        "K_J = 2e/h",
+        &["ELEMENTARY_CHARGE", "PLANCK"],
+        |x| 2.0 * x[0] / x[1],
+        EXACT,
+    ),
+    relation(
+        "VON_KLITZING_CONSTANT",
+        "R_K = h/e²",
+        &["PLANCK", "ELEMENTARY_CHARGE"],
+        |x| x[0] / (x[1] * x[1]),
+        EXACT,
+    ),
+    // Particle masses and ratios
+    relation(
+        "PROTON_ELECTRON_MASS_RATIO",
+        "m_p/m_e = 1/(m_e/m_p)",
+        &["ELECTRON_PROTON_MASS_RATIO"],
+        |x| 1.0 / x[0],
+        MEASURED,
+    ),
+    relation(
+        "PROTON_ELECTRON_MASS_RATIO",
+        "m_p/m_e",
+        &["PROTON_MASS", "ELECTRON_MASS"],
+        |x| x[0] / x[1],
+        MEASURED,
+    ),
+    relation(
+        "MUON_ELECTRON_MASS_RATIO",
+        "m_μ/m_e",
+        &["MUON_MASS", "ELECTRON_MASS"],
+        |x| x[0] / x[1],
+        MEASURED,
+    ),
+    relation(
+        "NEUTRON_PROTON_MASS_RATIO",
+        "m_n/m_p",
+        &["NEUTRON_MASS", "PROTON_MASS"],
+        |x| x[0] / x[1],
+        MEASURED,
+    ),
+    relation(
+        "DEUTERON_PROTON_MASS_RATIO",
+        "m_d/m_p",
+        &["DEUTERON_MASS", "PROTON_MASS"],
+        |x| x[0] / x[1],
+        MEASURED,
+    ),
+    relation(
+        "ELECTRON_MASS_MEV",
+        "m_e c²/(10⁶ e)",
+        &["ELECTRON_MASS", "SPEED_OF_LIGHT", "ELEMENTARY_CHARGE"],
+        |x| x[0] * x[1] * x[1] / (1e6 * x[2]),
+        MEASURED,
+    ),
+    relation(
+        "PROTON_MASS_MEV",
+        "m_p c²/(10⁶ e)",
+        &["PROTON_MASS", "SPEED_OF_LIGHT", "ELEMENTARY_CHARGE"],
+        |x| x[0] * x[1] * x[1] / (1e6 * x[2]),
+        MEASURED,
+    ),
+    relation(
+        "NEUTRON_MASS_MEV",
+        "m_n c²/(10⁶ e)",
+        &["NEUTRON_MASS", "SPEED_OF_LIGHT", "ELEMENTARY_CHARGE"],
+        |x| x[0] * x[1] * x[1] / (1e6 * x[2]),
+        MEASURED,
+    ),
+    relation(
+        "MUON_MASS_MEV",
+        "m_μ c²/(10⁶ e)",
+        &["MUON_MASS", "SPEED_OF_LIGHT", "ELEMENTARY_CHARGE"],
+        |x| x[0] * x[1] * x[1] / (1e6 * x[2]),
+        MEASURED,
+    ),
+    relation(
+        "MOLAR_MASS_CONSTANT",
+        "M_u = N_A m_u",
+        &["AVOGADRO", "ATOMIC_MASS_UNIT"],
+        |x| x[0] * x[1],
+        MEASURED,
+    ),
+    // Magnetic moments
+    relation(
+        "BOHR_MAGNETON",
+        "μ_B = eħ/(2m_e)",
+        &["ELEMENTARY_CHARGE", "PLANCK_REDUCED", "ELECTRON_MASS"],
+        |x| x[0] * x[1] / (2.0 * x[2]),
+        MEASURED,
+    ),
+    relation(
+        "NUCLEAR_MAGNETON",
+        "μ_N = eħ/(2m_p)",
+        &["ELEMENTARY_CHARGE", "PLANCK_REDUCED", "PROTON_MASS"],
+        |x| x[0] * x[1] / (2.0 * x[2]),
+        MEASURED,
+    ),
+    relation(
+        "ELECTRON_MAGNETIC_MOMENT",
+        "μ_e = g_e μ_B/2",
+        &["ELECTRON_G_FACTOR", "BOHR_MAGNETON"],
+        |x| x[0] * x[1] / 2.0,
+        MEASURED,
+    ),
+    relation(
+        "PROTON_MAGNETIC_MOMENT",
+        "μ_p = g_p μ_N/2",
+        &["PROTON_G_FACTOR", "NUCLEAR_MAGNETON"],
+        |x| x[0] * x[1] / 2.0,
+        MEASURED,
+    ),
+    // Electron-volt equivalents
+    relation(
+        "ELECTRON_VOLT",
+        "1 eV = e × 1 V",
+        &["ELEMENTARY_CHARGE"],
+        |x| x[0],
+        FLOAT,
+    ),
+    relation(
+        "EV_TO_KG",
+        "1 eV/c² = e/c²",
+        &["ELEMENTARY_CHARGE", "SPEED_OF_LIGHT"],
+        |x| x[0] / (x[1] * x[1]),
+        EXACT,
+    ),
+    relation(
+        "EV_TO_AMU",
+        "1 eV/c² = e/(c² m_u)",
+        &["ELEMENTARY_CHARGE", "SPEED_OF_LIGHT", "ATOMIC_MASS_UNIT"],
+        |x| x[0] / (x[1] * x[1] * x[2]),
+        MEASURED,
+    ),
+    relation(
+        "EV_TO_HZ",
+        "1 eV/h = e/h",
+        &["ELEMENTARY_CHARGE", "PLANCK"],
+        |x| x[0] / x[1],
+        EXACT,
+    ),
+    relation(
+        "EV_TO_KELVIN",
+        "1 eV/k = e/k",
+        &["ELEMENTARY_CHARGE", "BOLTZMANN"],
+        |x| x[0] / x[1],
+        EXACT,
+    ),
+    relation(
+        "EV_TO_INVERSE_METER",
+        "1 eV/(hc) = e/(hc)",
+        &["ELEMENTARY_CHARGE", "PLANCK", "SPEED_OF_LIGHT"],
+        |x| x[0] / (x[1] * x[2]),
+        EXACT,
+    ),
+    // Atomic and nuclear
+    relation(
+        "BOHR_RADIUS",
+        "a₀ = ħ/(α m_e c)",
+        &[
+            "PLANCK_REDUCED",
+            "FINE_STRUCTURE",
+            "ELECTRON_MASS",
+            "SPEED_OF_LIGHT",
+        ],
+        |x| x[0] / (x[1] * x[2] * x[3]),
+        MEASURED,
+    ),
+    relation(
+        "CLASSICAL_ELECTRON_RADIUS",
+        "r_e = α² a₀",
+        &["FINE_STRUCTURE", "BOHR_RADIUS"],
+        |x| x[0] * x[0] * x[1],
+        MEASURED,
+    ),
+    relation(
+        "THOMSON_CROSS_SECTION",
+        "σ_e = (8π/3) r_e²",
+        &["CLASSICAL_ELECTRON_RADIUS"],
+        |x| 8.0 * PI / 3.0 * x[0] * x[0],
+        MEASURED,
+    ),
+    relation(
+        "RYDBERG",
+        "R_∞ = α² m_e c/(2h)",
+        &[
+            "FINE_STRUCTURE",
+            "ELECTRON_MASS",
+            "SPEED_OF_LIGHT",
+            "PLANCK",
+        ],
+        |x| x[0] * x[0] * x[1] * x[2] / (2.0 * x[3]),
+        MEASURED,
+    ),
+    relation(
+        "HARTREE_ENERGY",
+        "E_h = 2R_∞ hc",
+        &["RYDBERG", "PLANCK", "SPEED_OF_LIGHT"],
+        |x| 2.0 * x[0] * x[1] * x[2],
+        MEASURED,
+    ),
+    relation(
+        "HARTREE_ENERGY_EV",
+        "E_h/e",
+        &["HARTREE_ENERGY", "ELEMENTARY_CHARGE"],
+        |x| x[0] / x[1],
+        MEASURED,
+    ),
+    relation(
+        "ELECTRON_COMPTON_WAVELENGTH",
+        "λ_C = h/(m_e c)",
+        &["PLANCK", "ELECTRON_MASS", "SPEED_OF_LIGHT"],
+        |x| x[0] / (x[1] * x[2]),
+        MEASURED,
+    ),
+    relation(
+        "PROTON_COMPTON_WAVELENGTH",
+        "λ_C,p = h/(m_p c)",
+        &["PLANCK", "PROTON_MASS", "SPEED_OF_LIGHT"],
+        |x| x[0] / (x[1] * x[2]),
+        MEASURED,
+    ),
+    relation(
+        "NEUTRON_COMPTON_WAVELENGTH",
+        "λ_C,n = h/(m_n c)",
+        &["PLANCK", "NEUTRON_MASS", "SPEED_OF_LIGHT"],
+        |x| x[0] / (x[1] * x[2]),
+        MEASURED,
+    ),
+    relation(
+        "ELECTRON_REDUCED_COMPTON",
+        "ƛ_C = λ_C/(2π)",
+        &["ELECTRON_COMPTON_WAVELENGTH"],
+        |x| x[0] / TAU,
+        MEASURED,
+    ),
+    relation(
+        "PROTON_REDUCED_COMPTON",
+        "ƛ_C,p = λ_C,p/(2π)",
+        &["PROTON_COMPTON_WAVELENGTH"],
+        |x| x[0] / TAU,
+        MEASURED,
+    ),
+    relation(
+        "NEUTRON_REDUCED_COMPTON",
+        "ƛ_C,n = λ_C,n/(2π)",
+        &["NEUTRON_COMPTON_WAVELENGTH"],
+        |x| x[0] / TAU,
+        MEASURED,
+    ),
+    // Thermodynamic and molar
+    relation(
+        "FARADAY",
+        "F = N_A e",
+        &["AVOGADRO", "ELEMENTARY_CHARGE"],
+        |x| x[0] * x[1],
+        EXACT,
+    ),
+    relation(
+        "GAS_CONSTANT",
+        "R = N_A k",
+        &["AVOGADRO", "BOLTZMANN"],
+        |x| x[0] * x[1],
+        EXACT,
+    ),
+    relation(
+        "GAS_CONSTANT_L_ATM",
+        "R in L atm/(mol K) = 1000 R/atm",
+        &["GAS_CONSTANT", "STANDARD_ATMOSPHERE"],
+        |x| 1000.0 * x[0] / x[1],
+        EXACT,
+    ),
+    relation(
+        "MOLAR_PLANCK_CONSTANT",
+        "N_A h",
+        &["AVOGADRO", "PLANCK"],
+        |x| x[0] * x[1],
+        EXACT,
+    ),
+    relation(
+        "LOSCHMIDT_CONSTANT",
+        "n₀ = p/(kT) at 273.15 K, 101.325 kPa",
+        &["STANDARD_ATMOSPHERE", "BOLTZMANN"],
+        |x| x[0] / (x[1] * T0),
+        EXACT,
+    ),
+    relation(
+        "MOLAR_VOLUME_IDEAL_GAS",
+        "V_m = RT/p at 273.15 K, 100 kPa",
+        &["GAS_CONSTANT"],
+        |x| x[0] * T0 / P0,
+        SEVEN_DIGITS,
+    ),
+    relation(
+        "STEFAN_BOLTZMANN",
+        "σ = 2π⁵k⁴/(15h³c²)",
+        &["BOLTZMANN", "PLANCK", "SPEED_OF_LIGHT"],
+        |x| {
+            let pi5 = PI * PI * PI * PI * PI;
+            let k4 = x[0] * x[0] * x[0] * x[0];
+            2.0 * pi5 * k4 / (15.0 * x[1] * x[1] * x[1] * x[2] * x[2])
+        },
+        EXACT,
+    ),
+    relation(
+        "FIRST_RADIATION_CONSTANT",
+        "c₁ = 2πhc²",
+        &["PLANCK", "SPEED_OF_LIGHT"],
+        |x| TAU * x[0] * x[1] * x[1],
+        EXACT,
+    ),
+    relation(
+        "SECOND_RADIATION_CONSTANT",
+        "c₂ = hc/k",
+        &["PLANCK", "SPEED_OF_LIGHT", "BOLTZMANN"],
+        |x| x[0] * x[1] / x[2],
+        EXACT,
+    ),
+    relation(
+        "WIEN_DISPLACEMENT",
+        "b = c₂/x, x = 5(1 - e⁻ˣ)",
+        &["SECOND_RADIATION_CONSTANT"],
+        |x| x[0] / WIEN_X,
+        EXACT,
+    ),
+    // Planck units
+    relation(
+        "PLANCK_MASS",
+        "m_P = √(ħc/G)",
+        &["PLANCK_REDUCED", "SPEED_OF_LIGHT", "GRAVITATIONAL_CONSTANT"],
+        |x| sqrt(x[0] * x[1] / x[2]),
+        SEVEN_DIGITS,
+    ),
+    relation(
+        "PLANCK_LENGTH",
+        "l_P = ħ/(m_P c)",
+        &["PLANCK_REDUCED", "PLANCK_MASS", "SPEED_OF_LIGHT"],
+        |x| x[0] / (x[1] * x[2]),
+        SEVEN_DIGITS,
+    ),
+    relation(
+        "PLANCK_TIME",
+        "t_P = l_P/c",
+        &["PLANCK_LENGTH", "SPEED_OF_LIGHT"],
+        |x| x[0] / x[1],
+        SEVEN_DIGITS,
+    ),
+    relation(
+        "PLANCK_TEMPERATURE",
+        "T_P = m_P c²/k",
+        &["PLANCK_MASS", "SPEED_OF_LIGHT", "BOLTZMANN"],
+        |x| x[0] * x[1] * x[1] / x[2],
+        SEVEN_DIGITS,
+    ),
+    relation(
+        "PLANCK_CHARGE",
+        "q_P = √(4πε₀ħc)",
+        &["VACUUM_PERMITTIVITY", "PLANCK_REDUCED", "SPEED_OF_LIGHT"],
+        |x| sqrt(4.0 * PI * x[0] * x[1] * x[2]),
+        SEVEN_DIGITS,
+    ),
+    // Charge-to-mass and atomic units
+    relation(
+        "ELECTRON_CHARGE_TO_MASS",
+        "-e/m_e",
+        &["ELEMENTARY_CHARGE", "ELECTRON_MASS"],
+        |x| -x[0] / x[1],
+        MEASURED,
+    ),
+    relation(
+        "PROTON_CHARGE_TO_MASS",
+        "e/m_p",
+        &["ELEMENTARY_CHARGE", "PROTON_MASS"],
+        |x| x[0] / x[1],
+        MEASURED,
+    ),
+    relation(
+        "ATOMIC_UNIT_OF_LENGTH",
+        "a₀",
+        &["BOHR_RADIUS"],
+        |x| x[0],
+        FLOAT,
+    ),
+    relation(
+        "ATOMIC_UNIT_OF_TIME",
+        "ħ/E_h",
+        &["PLANCK_REDUCED", "HARTREE_ENERGY"],
+        |x| x[0] / x[1],
+        MEASURED,
+    ),
+    relation(
+        "ATOMIC_UNIT_OF_VELOCITY",
+        "αc",
+        &["FINE_STRUCTURE", "SPEED_OF_LIGHT"],
+        |x| x[0] * x[1],
+        MEASURED,
+    ),
+    relation(
+        "ATOMIC_UNIT_OF_FORCE",
+        "E_h/a₀",
+        &["HARTREE_ENERGY", "BOHR_RADIUS"],
+        |x| x[0] / x[1],
+        MEASURED,
+    ),
+    relation(
+        "ATOMIC_UNIT_OF_ELECTRIC_FIELD",
+        "E_h/(e a₀)",
+        &["HARTREE_ENERGY", "ELEMENTARY_CHARGE", "BOHR_RADIUS"],
+        |x| x[0] / (x[1] * x[2]),
+        MEASURED,
+    ),
+    relation(
+        "ATOMIC_UNIT_OF_POLARIZABILITY",
+        "e²a₀²/E_h",
+        &["ELEMENTARY_CHARGE", "BOHR_RADIUS", "HARTREE_ENERGY"],
+        |x| x[0] * x[0] * x[1] * x[1] / x[2],
+        MEASURED,
+    ),
+];
@@ -0,0 +1,145 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Fuzzy constant search ranked by edit distance and token overlap.
+//!
+//! Used by [`Constants::search`](super::Constants::search) and the
+//! `cmn` CLI's "did you mean" suggestions. Requires the `std`
+//! feature.
+
+/// Minimum [`score`] for a candidate to be reported.
+pub const MIN_SCORE: f64 = 0.5;
+
+/// A single ranked search result.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SearchMatch<'a> {
+    /// The canonical constant name.
+    pub name: &'a str,
+    /// The key that scored best: the name itself, an alias or a
+    /// description.
+    pub matched: &'a str,
+    /// Similarity in `0.0..=1.0`; `1.0` is an exact match ignoring
+    /// case and separators.
+    pub score: f64,
+}
+
+/// Scores how well `key` matches `query`, in `0.0..=1.0`.
+///
+/// Both strings are split into lowercase tokens on spaces, hyphens,
+/// underscores and `,()/` punctuation. The score is the larger of:
+///
+/// - the edit-distance similarity of the joined tokens, and
+/// - the fraction of query tokens found in `key` (exactly, or
+///   within one edit for tokens of four or more characters),
+///   weighted by how much of `key` they cover.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::search::score;
+///
+/// assert_eq!(score("electron mass", "ELECTRON_MASS"), 1.0);
+/// assert!(score("PLANK", "PLANCK") > 0.8);
+/// assert!(score("PLANK", "AVOGADRO") < 0.5);
+/// ```
+pub fn score(query: &str, key: &str) -> f64 {
+    let q = tokens(query);
+    let k = tokens(key);
+    if q.is_empty() || k.is_empty() {
+        return 0.0;
+    }
+
+    let (qj, kj) = (q.concat(), k.concat());
+    let max_len = qj.chars().count().max(kj.chars().count());
+    let similarity =
+        1.0 - levenshtein(&qj, &kj) as f64 / max_len as f64;
+
+    let matched = q
+        .iter()
+        .filter(|qt| k.iter().any(|kt| token_matches(qt, kt)))
+        .count() as f64;
+    let overlap = matched / q.len() as f64
+        * (0.5 + 0.5 * matched.min(k.len() as f64) / k.len() as f64);
+
+    similarity.max(overlap)
+}
+
+/// Ranks `(name, key)` candidates against `query`.
+///
+/// Each name keeps its best-scoring key. Results with a score below
+/// [`MIN_SCORE`] are dropped; the rest are sorted by descending
+/// score, then by name.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::search::rank;
+///
+/// let candidates = [
+///     ("PLANCK", "PLANCK"),
+///     ("PLANCK", "h"),
+///     ("PLANCK_MASS", "PLANCK_MASS"),
+///     ("AVOGADRO", "AVOGADRO"),
+/// ];
+/// let hits = rank("plank", candidates);
+/// assert_eq!(hits[0].name, "PLANCK");
+/// assert!(hits.iter().all(|m| m.name != "AVOGADRO"));
+/// ```
+pub fn rank<'a, I>(query: &str, candidates: I) -> Vec<SearchMatch<'a>>
+where
+    I: IntoIterator<Item = (&'a str, &'a str)>,
+{
+    let mut best: Vec<SearchMatch<'a>> = Vec::new();
+    for (name, key) in candidates {
+        let score = score(query, key);
+        if score < MIN_SCORE {
+            continue;
+        }
+        match best.iter_mut().find(|m| m.name == name) {
+            Some(m) if m.score >= score => {}
+            Some(m) => {
+                m.matched = key;
+                m.score = score;
+            }
+            None => best.push(SearchMatch {
+                name,
+                matched: key,
+                score,
+            }),
+        }
+    }
+    best.sort_by(|a, b| {
+        b.score.total_cmp(&a.score).then_with(|| a.name.cmp(b.name))
+    });
+    best
+}
+
+fn tokens(s: &str) -> Vec<String> {
+    s.split([' ', '-', '_', ',', '(', ')', '/'])
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn token_matches(a: &str, b: &str) -> bool {
+    a == b
+        || (a.chars().count() >= 4
+            && b.chars().count() >= 4
+            && levenshtein(a, b) <= 1)
+}
+
+/// Character-level Levenshtein distance.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
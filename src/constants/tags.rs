@@ -0,0 +1,218 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Fine-grained classification of constants.
+//!
+//! Every [`Tag`] belongs to one broad [`Category`], and a constant
+//! may carry several tags: the proton mass ratio is both a
+//! [`Tag::ParticleMass`] and a [`Tag::AtomicNuclear`] constant.
+//! Tag sets are stored as a [`Tags`] bit set, so they can be built
+//! in `const` context. Available in `no_std`.
+
+use super::Category;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// A fine-grained classification of a constant.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::{Category, Tag};
+///
+/// assert_eq!(Tag::PlanckUnits.category(), Category::Physical);
+/// assert_eq!(Tag::PlanckUnits.to_string(), "Planck units");
+/// ```
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Tag {
+    /// π, τ and their reciprocals.
+    Circle,
+    /// π/2, π/3, π/4, π/6 and π/8.
+    TrigonometricFraction,
+    /// Natural and base-2/base-10 logarithms, and γ.
+    Logarithmic,
+    /// Square roots.
+    Root,
+    /// The golden and silver ratios.
+    MetallicMean,
+    /// Series and number-theoretic constants (ζ(3), Catalan, …).
+    NumberTheory,
+    /// The exact constants that define the SI (c, h, e, k, N_A).
+    SiDefining,
+    /// Universal constants of nature (c, h, ħ, G, μ₀, ε₀, Z₀).
+    Universal,
+    /// Electric and magnetic constants and quanta.
+    Electromagnetic,
+    /// Atomic and nuclear structure constants.
+    AtomicNuclear,
+    /// Particle masses and mass ratios.
+    ParticleMass,
+    /// Magnetic moments, magnetons and g-factors.
+    MagneticMoment,
+    /// Planck mass, length, time, temperature and charge.
+    PlanckUnits,
+    /// Hartree atomic units.
+    AtomicUnits,
+    /// Factors converting between units, e.g. eV to joules.
+    ConversionFactor,
+    /// Thermodynamic and radiation constants.
+    Thermodynamic,
+    /// Molar and physico-chemical constants.
+    PhysicoChemical,
+    /// Gravitational constants.
+    Gravitation,
+    /// Password hashing parameters.
+    Hashing,
+    /// Character sets.
+    CharacterSet,
+}
+
+impl Tag {
+    /// Every tag, in declaration order.
+    pub const ALL: [Self; 20] = [
+        Self::Circle,
+        Self::TrigonometricFraction,
+        Self::Logarithmic,
+        Self::Root,
+        Self::MetallicMean,
+        Self::NumberTheory,
+        Self::SiDefining,
+        Self::Universal,
+        Self::Electromagnetic,
+        Self::AtomicNuclear,
+        Self::ParticleMass,
+        Self::MagneticMoment,
+        Self::PlanckUnits,
+        Self::AtomicUnits,
+        Self::ConversionFactor,
+        Self::Thermodynamic,
+        Self::PhysicoChemical,
+        Self::Gravitation,
+        Self::Hashing,
+        Self::CharacterSet,
+    ];
+
+    /// Returns the broad category this tag belongs to.
+    pub const fn category(self) -> Category {
+        match self {
+            Self::Circle
+            | Self::TrigonometricFraction
+            | Self::Logarithmic
+            | Self::Root
+            | Self::MetallicMean
+            | Self::NumberTheory => Category::Mathematical,
+            Self::Hashing | Self::CharacterSet => {
+                Category::Cryptographic
+            }
+            _ => Category::Physical,
+        }
+    }
+
+    /// Returns the display name of the tag, e.g. `"Particle
+    /// masses"`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Circle => "Circle constants",
+            Self::TrigonometricFraction => "Trigonometric fractions",
+            Self::Logarithmic => "Logarithms",
+            Self::Root => "Roots",
+            Self::MetallicMean => "Metallic means",
+            Self::NumberTheory => "Number theory",
+            Self::SiDefining => "SI defining constants",
+            Self::Universal => "Universal constants",
+            Self::Electromagnetic => "Electromagnetic",
+            Self::AtomicNuclear => "Atomic & nuclear",
+            Self::ParticleMass => "Particle masses",
+            Self::MagneticMoment => "Magnetic moments",
+            Self::PlanckUnits => "Planck units",
+            Self::AtomicUnits => "Atomic units",
+            Self::ConversionFactor => "Conversion factors",
+            Self::Thermodynamic => "Thermodynamic",
+            Self::PhysicoChemical => "Physico-chemical",
+            Self::Gravitation => "Gravitation",
+            Self::Hashing => "Hashing",
+            Self::CharacterSet => "Character sets",
+        }
+    }
+
+    const fn bit(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+impl core::fmt::Display for Tag {
+    fn fmt(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A set of [`Tag`]s, stored as a bit set.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::{Tag, Tags};
+///
+/// let tags = Tags::of(&[Tag::ParticleMass, Tag::AtomicNuclear]);
+/// assert!(tags.contains(Tag::ParticleMass));
+/// assert!(!tags.contains(Tag::PlanckUnits));
+/// assert_eq!(tags.len(), 2);
+/// assert_eq!(
+///     tags.iter().collect::<Vec<_>>(),
+///     [Tag::AtomicNuclear, Tag::ParticleMass],
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Tags(u32);
+
+impl Tags {
+    /// The empty set.
+    pub const EMPTY: Self = Self(0);
+
+    /// Builds a set from a slice of tags.
+    pub const fn of(tags: &[Tag]) -> Self {
+        let mut bits = 0;
+        let mut i = 0;
+        while i < tags.len() {
+            bits |= tags[i].bit();
+            i += 1;
+        }
+        Self(bits)
+    }
+
+    /// Returns this set with `tag` added.
+    pub const fn with(self, tag: Tag) -> Self {
+        Self(self.0 | tag.bit())
+    }
+
+    /// Returns `true` if the set contains `tag`.
+    pub const fn contains(self, tag: Tag) -> bool {
+        self.0 & tag.bit() != 0
+    }
+
+    /// Returns `true` if the set has no tags.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the number of tags in the set.
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Iterates over the tags in the set, in [`Tag::ALL`] order.
+    pub fn iter(self) -> impl Iterator<Item = Tag> {
+        Tag::ALL.into_iter().filter(move |&tag| self.contains(tag))
+    }
+}
+
+impl From<Tag> for Tags {
+    fn from(tag: Tag) -> Self {
+        Self(tag.bit())
+    }
+}
@@ -0,0 +1,236 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Textbook physics formulas built on the constants.
+//!
+//! Every function takes and returns values in coherent SI units —
+//! metres, kilograms, seconds, kelvins, moles, joules and pascals —
+//! and uses the CODATA 2018 constants of [`crate::constants`].
+//!
+//! # Example
+//!
+//! ```
+//! use cmn::formulas::{
+//!     hydrogen_wavelength, photon_energy, wien_peak_wavelength,
+//! };
+//!
+//! // The Sun's photosphere (5772 K) peaks near 502 nm.
+//! let peak = wien_peak_wavelength(5772.0);
+//! assert!((peak - 502.0e-9).abs() < 1e-9);
+//!
+//! // H-alpha, the n = 3 → 2 Balmer line, is red: 656 nm, 1.89 eV.
+//! let h_alpha = hydrogen_wavelength(2, 3).unwrap();
+//! assert!((h_alpha - 656.47e-9).abs() < 0.01e-9);
+//! let ev = photon_energy(h_alpha) / cmn::constants::ELECTRON_VOLT;
+//! assert!((ev - 1.889).abs() < 1e-3);
+//! ```
+
+use crate::constants::{
+    ELECTRON_PROTON_MASS_RATIO, FIRST_RADIATION_CONSTANT, GAS_CONSTANT,
+    PI, PLANCK, RYDBERG, SECOND_RADIATION_CONSTANT, SPEED_OF_LIGHT,
+    WIEN_DISPLACEMENT,
+};
+
+/// The Rydberg constant for hydrogen, R_H = R∞/(1 + m_e/m_p), in
+/// m⁻¹: R∞ corrected for the finite mass of the proton.
+pub const RYDBERG_HYDROGEN: f64 =
+    RYDBERG / (1.0 + ELECTRON_PROTON_MASS_RATIO);
+
+// ---------------------------------------------------------------
+// Thermal radiation
+// ---------------------------------------------------------------
+
+/// Returns the spectral radiance of a black body at `temperature`
+/// (K) per unit wavelength, at `wavelength` (m), in W sr⁻¹ m⁻³.
+///
+/// Planck's law, B_λ = c₁ / (π λ⁵ (e^(c₂/λT) − 1)), with the first
+/// and second radiation constants.
+///
+/// # Example
+///
+/// ```
+/// use cmn::formulas::{spectral_radiance, wien_peak_wavelength};
+///
+/// // The radiance peaks at the Wien wavelength.
+/// let t = 3000.0;
+/// let peak = wien_peak_wavelength(t);
+/// let at = |w| spectral_radiance(w, t);
+/// assert!(at(peak) > at(0.99 * peak));
+/// assert!(at(peak) > at(1.01 * peak));
+/// ```
+pub fn spectral_radiance(wavelength: f64, temperature: f64) -> f64 {
+    let x = SECOND_RADIATION_CONSTANT / (wavelength * temperature);
+    FIRST_RADIATION_CONSTANT / (PI * wavelength.powi(5) * x.exp_m1())
+}
+
+/// Returns the spectral radiance of a black body at `temperature`
+/// (K) per unit frequency, at `frequency` (Hz), in
+/// W sr⁻¹ m⁻² Hz⁻¹.
+///
+/// Planck's law in frequency, B_ν = 2hν³ / (c² (e^(hν/kT) − 1)),
+/// written with the radiation constants c₁ = 2πhc² and
+/// c₂ = hc/k.
+pub fn spectral_radiance_frequency(
+    frequency: f64,
+    temperature: f64,
+) -> f64 {
+    let x = SECOND_RADIATION_CONSTANT * frequency
+        / (SPEED_OF_LIGHT * temperature);
+    FIRST_RADIATION_CONSTANT * frequency.powi(3)
+        / (PI * SPEED_OF_LIGHT.powi(4) * x.exp_m1())
+}
+
+/// Returns the wavelength (m) at which the spectral radiance per
+/// unit wavelength of a black body at `temperature` (K) peaks:
+/// Wien's displacement law, λ_max = b/T.
+pub fn wien_peak_wavelength(temperature: f64) -> f64 {
+    WIEN_DISPLACEMENT / temperature
+}
+
+// ---------------------------------------------------------------
+// Photons and matter waves
+// ---------------------------------------------------------------
+
+/// Returns the energy (J) of a photon of `wavelength` (m):
+/// E = hc/λ.
+pub fn photon_energy(wavelength: f64) -> f64 {
+    PLANCK * SPEED_OF_LIGHT / wavelength
+}
+
+/// Returns the wavelength (m) of a photon of `energy` (J):
+/// λ = hc/E.
+pub fn photon_wavelength(energy: f64) -> f64 {
+    PLANCK * SPEED_OF_LIGHT / energy
+}
+
+/// Returns the de Broglie wavelength (m) of a particle of `mass`
+/// (kg) moving at `speed` (m/s): λ = h/p, with the relativistic
+/// momentum p = γmv.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::ELECTRON_MASS;
+/// use cmn::formulas::de_broglie_wavelength;
+///
+/// // An electron at 1% of c has a wavelength of 243 pm.
+/// let v = 0.01 * cmn::constants::SPEED_OF_LIGHT;
+/// let lambda = de_broglie_wavelength(ELECTRON_MASS, v);
+/// assert!((lambda - 242.6e-12).abs() < 0.1e-12);
+/// ```
+pub fn de_broglie_wavelength(mass: f64, speed: f64) -> f64 {
+    PLANCK / (lorentz_factor(speed) * mass * speed)
+}
+
+/// Returns the Lorentz factor γ = 1/√(1 − v²/c²) for `speed`
+/// (m/s).
+///
+/// It is infinite at the speed of light and NaN beyond it.
+///
+/// # Example
+///
+/// ```
+/// use cmn::constants::SPEED_OF_LIGHT;
+/// use cmn::formulas::lorentz_factor;
+///
+/// assert_eq!(lorentz_factor(0.0), 1.0);
+/// let gamma = lorentz_factor(0.6 * SPEED_OF_LIGHT);
+/// assert!((gamma - 1.25).abs() < 1e-12);
+/// assert!(lorentz_factor(SPEED_OF_LIGHT).is_infinite());
+/// ```
+pub fn lorentz_factor(speed: f64) -> f64 {
+    let beta = speed / SPEED_OF_LIGHT;
+    1.0 / (1.0 - beta * beta).sqrt()
+}
+
+// ---------------------------------------------------------------
+// Gases
+// ---------------------------------------------------------------
+
+/// Returns the pressure (Pa) of `amount` (mol) of ideal gas at
+/// `temperature` (K) in `volume` (m³): p = nRT/V.
+///
+/// # Example
+///
+/// ```
+/// use cmn::formulas::ideal_gas_pressure;
+///
+/// // One mole at 0 °C in 22.414 L is one standard atmosphere.
+/// let p = ideal_gas_pressure(1.0, 273.15, 22.413_969_54e-3);
+/// assert!((p - 101_325.0).abs() < 1e-3);
+/// ```
+pub fn ideal_gas_pressure(
+    amount: f64,
+    temperature: f64,
+    volume: f64,
+) -> f64 {
+    amount * GAS_CONSTANT * temperature / volume
+}
+
+/// Returns the volume (m³) of `amount` (mol) of ideal gas at
+/// `temperature` (K) and `pressure` (Pa): V = nRT/p.
+pub fn ideal_gas_volume(
+    amount: f64,
+    temperature: f64,
+    pressure: f64,
+) -> f64 {
+    amount * GAS_CONSTANT * temperature / pressure
+}
+
+/// Returns the temperature (K) of `amount` (mol) of ideal gas at
+/// `pressure` (Pa) in `volume` (m³): T = pV/(nR).
+pub fn ideal_gas_temperature(
+    amount: f64,
+    pressure: f64,
+    volume: f64,
+) -> f64 {
+    pressure * volume / (amount * GAS_CONSTANT)
+}
+
+/// Returns the amount (mol) of ideal gas at `pressure` (Pa) and
+/// `temperature` (K) in `volume` (m³): n = pV/(RT).
+pub fn ideal_gas_amount(
+    pressure: f64,
+    temperature: f64,
+    volume: f64,
+) -> f64 {
+    pressure * volume / (GAS_CONSTANT * temperature)
+}
+
+// ---------------------------------------------------------------
+// Hydrogen spectrum
+// ---------------------------------------------------------------
+
+/// Returns the wavenumber (m⁻¹) of the hydrogen line emitted from
+/// level `upper` to level `lower`, by the Rydberg formula
+/// 1/λ = R_H (1/n₁² − 1/n₂²), or `None` unless
+/// `0 < lower < upper`.
+pub fn hydrogen_wavenumber(lower: u32, upper: u32) -> Option<f64> {
+    if lower == 0 || upper <= lower {
+        return None;
+    }
+    let term = |n: u32| 1.0 / f64::from(n).powi(2);
+    Some(RYDBERG_HYDROGEN * (term(lower) - term(upper)))
+}
+
+/// Returns the vacuum wavelength (m) of the hydrogen line emitted
+/// from level `upper` to level `lower`, or `None` unless
+/// `0 < lower < upper`.
+///
+/// `lower` selects the series: 1 for Lyman, 2 for Balmer, 3 for
+/// Paschen. The formula ignores fine structure and the Lamb shift,
+/// which move the lines by parts in 10⁵.
+///
+/// # Example
+///
+/// ```
+/// use cmn::formulas::hydrogen_wavelength;
+///
+/// // Lyman-alpha is at 121.6 nm.
+/// let lyman_alpha = hydrogen_wavelength(1, 2).unwrap();
+/// assert!((lyman_alpha - 121.568e-9).abs() < 0.001e-9);
+/// assert_eq!(hydrogen_wavelength(2, 2), None);
+/// ```
+pub fn hydrogen_wavelength(lower: u32, upper: u32) -> Option<f64> {
+    hydrogen_wavenumber(lower, upper).map(f64::recip)
+}
@@ -13,8 +13,8 @@
 //!
 //! `std::f64::consts` provides 11 mathematical constants. CMN
 //! extends that with 110 additional physical, cryptographic,
-//! and series constants — plus a runtime lookup API, 15
-//! utility macros (7 `no_std` + 8 `std`), a datetime module,
+//! and series constants — plus a runtime lookup API, 16
+//! utility macros (8 `no_std` + 8 `std`), a datetime module,
 //! and a built-in word-list. Every constant resolves at
 //! compile time. Works in `no_std` and WASM environments.
 //!
@@ -24,13 +24,24 @@
 //!   Planck, W/Z/Higgs boson, etc.) with `Category` filtering.
 //!   With `std`: runtime `Constants` lookup API +
 //!   `ConstantValue` typed enum.
-//! - **[`macros`]** — 15 utility macros: 7 `no_std`
+//! - **[`units`]** — `Unit` and `Dimension` types: the display
+//!   symbol and SI base exponents of every constant.
+//! - **[`quantities`]** — `Quantity<Length>`, `Quantity<Energy>`,
+//!   …: dimension-checked arithmetic and typed physical constants.
+//! - **[`unit_systems`]** — Planck, Hartree atomic, natural and
+//!   Gaussian CGS units to and from SI.
+//! - **[`particles`]** — masses, charges, spins and magnetic
+//!   moments of the electron, muon, proton, W boson, ….
+//! - **[`macros`]** — 16 utility macros: 8 `no_std`
 //!   (`cmn_max!`, `cmn_min!`, `cmn_in_range!`, `cmn_assert!`,
-//!   `cmn_contains!`, `cmn_to_num!`, `cmn_constants!`) + 8
+//!   `cmn_contains!`, `cmn_to_num!`, `cmn_constants!`,
+//!   `cmn_const!`) + 8
 //!   `std` (`cmn_vec!`, `cmn_map!`, `cmn_join!`, `cmn_split!`,
 //!   `cmn_print!`, `cmn_print_vec!`, `cmn!`, `cmn_parse!`).
-//! - With `std` feature: **[`words`]** module, **[`datetime`]**
-//!   utilities, and **`Common`** JSON bridge.
+//! - With `std` feature: **[`measured`]** uncertainty
+//!   propagation, **[`formulas`]** built on the constants,
+//!   **[`words`]** module, **[`datetime`]** utilities, and
+//!   **`Common`** JSON bridge.
 //!
 //! ## Quick Start
 //!
@@ -62,6 +73,7 @@
 //! | Feature | Default | Enables |
 //! |---------|---------|---------|
 //! | `std`   | Yes     | `Constants` struct, `ConstantValue`, `Words`, `Common`, `datetime`, 8 std macros, serde |
+//! | `codata2022` | No | Resolve `CONSTANTS_TABLE` and `Constants::new()` against CODATA 2022 instead of CODATA 2018 |
 //!
 //! For `no_std`, disable default features:
 //! ```toml
@@ -86,7 +98,7 @@
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
-/// 15 utility macros: 7 `no_std`-compatible + 8 `std`-only.
+/// 16 utility macros: 8 `no_std`-compatible + 8 `std`-only.
 /// See the [`macros`] module for the full list.
 pub mod macros;
 
@@ -98,6 +110,37 @@ pub mod constants;
 #[cfg(feature = "std")]
 pub use constants::Constants;
 
+/// Machine-readable SI units and dimensions for every entry in
+/// [`constants::CONSTANTS_TABLE`]. Available in `no_std`.
+pub mod units;
+
+/// Zero-cost `Quantity<D>` newtypes whose SI dimension is checked
+/// at compile time, with a typed twin of every physical constant.
+/// Available in `no_std`.
+pub mod quantities;
+
+/// Converters between SI and the Planck, Hartree atomic, natural
+/// (ħ = c = 1, GeV) and Gaussian CGS unit systems, scaled by the
+/// constants. Available in `no_std`.
+pub mod unit_systems;
+
+/// A `Particle` catalogue of leptons, nucleons, light nuclei and
+/// bosons with their masses, charge, spin, g-factor, magnetic
+/// moment and Compton wavelength. Available in `no_std`.
+pub mod particles;
+
+/// `Measured` values with a standard uncertainty, propagated to
+/// first order through arithmetic and common functions, and
+/// seeded from the constants. Requires `std`.
+#[cfg(feature = "std")]
+pub mod measured;
+
+/// Textbook formulas built on the constants: Planck's law, Wien's
+/// law, photon energy, de Broglie wavelength, the ideal gas law,
+/// hydrogen lines and the Lorentz factor. Requires `std`.
+#[cfg(feature = "std")]
+pub mod formulas;
+
 /// A word-list module for passphrase generation and text
 /// processing. Backed by `HashSet<String>` for O(1) lookups
 /// with a curated built-in `WORD_LIST`. Requires `std`.
@@ -137,9 +180,7 @@ impl Common {
     }
     /// Returns the `Constants` instance.
     pub fn constants(&self) -> Constants {
-        Constants {
-            ..Default::default()
-        }
+        Constants::default()
     }
     /// Returns a new instance of the `Words` structure.
     ///
@@ -191,14 +232,76 @@ impl Default for Common {
 /// Requires `std`.
 #[cfg(feature = "std")]
 pub fn run() -> Result<(), Box<dyn std::error::Error>> {
+    run_with_args(std::iter::empty::<&str>())
+}
+
+/// Runs the `cmn` command line with the given arguments (without
+/// the program name). Requires `std`.
+///
+/// With no arguments, prints the welcome banner. Otherwise each
+/// argument is resolved as a constant name, symbol or English name
+/// and printed as `NAME = value [unit]`. An unknown name fails with
+/// up to three "did you mean" suggestions.
+///
+/// # Example
+///
+/// ```
+/// assert!(cmn::run_with_args(["hbar"]).is_ok());
+///
+/// let err = cmn::run_with_args(["PLANK"]).unwrap_err();
+/// assert!(err.to_string().contains("did you mean PLANCK"));
+/// ```
+#[cfg(feature = "std")]
+pub fn run_with_args<I, S>(
+    args: I,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
     if std::env::var("CMN_TEST_MODE").unwrap_or_default() == "1" {
         return Err("Simulated error".into());
     }
-    let name = "cmn";
-    println!("Welcome to `{}` 👋!", { name }.to_uppercase());
-    println!(
-        "A Rust library for accessing a collection of \
-         mathematical and cryptographic constants."
-    );
+    let mut args = args.into_iter().peekable();
+    if args.peek().is_none() {
+        let name = "cmn";
+        println!("Welcome to `{}` 👋!", { name }.to_uppercase());
+        println!(
+            "A Rust library for accessing a collection of \
+             mathematical and cryptographic constants."
+        );
+        return Ok(());
+    }
+
+    let constants = Constants::new();
+    for arg in args {
+        let query = arg.as_ref();
+        let Some((constant, _)) = constants.resolve(query) else {
+            let suggestions = constants.suggest(query, 3);
+            return Err(if suggestions.is_empty() {
+                format!("unknown constant `{query}`").into()
+            } else {
+                format!(
+                    "unknown constant `{query}`; did you mean {}?",
+                    suggestions.join(", ")
+                )
+                .into()
+            });
+        };
+        let value = match constant.value {
+            constants::ConstantValue::Float(v)
+                if v != 0.0 && !(1e-3..1e7).contains(&v.abs()) =>
+            {
+                format!("{v:e}")
+            }
+            ref other => other.to_string(),
+        };
+        match constants.unit(&constant.name) {
+            Some(unit) if !unit.dimension.is_dimensionless() => {
+                println!("{} = {value} {unit}", constant.name);
+            }
+            _ => println!("{} = {value}", constant.name),
+        }
+    }
     Ok(())
 }
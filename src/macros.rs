@@ -17,6 +17,7 @@
 //! | `cmn_min` | no_std | Returns the minimum of the given values. |
 //! | `cmn_to_num` | no_std | Converts the given string to a number. |
 //! | `cmn_constants` | no_std | Defines a set of `f64` constants. |
+//! | `cmn_const` | no_std | Looks up a constant at compile time. |
 //! | `cmn` | std | Parses arguments via `Common::parse`. |
 //! | `cmn_parse` | std | Parses the given input into a Rust value. |
 //! | `cmn_join` | std | Joins strings together. |
@@ -27,7 +28,7 @@
 //! | `cmn_print_vec` | std | Prints a vector of elements to the console. |
 
 // ---------------------------------------------------------------
-// no_std-compatible macros (6)
+// no_std-compatible macros (8)
 // ---------------------------------------------------------------
 
 /// Asserts that the given condition is true.
@@ -100,6 +101,38 @@ macro_rules! cmn_constants {
     };
 }
 
+/// Resolves a float constant by name at compile time.
+///
+/// Expands to the `f64` value of the named constant in
+/// [`Dataset::DEFAULT`](crate::constants::Dataset::DEFAULT). The
+/// lookup runs in a `const` item, so an unknown name (or a
+/// non-float constant such as `HASH_COST`) fails the build.
+///
+/// # Example
+///
+/// ```
+/// use cmn::cmn_const;
+///
+/// const H: f64 = cmn_const!("PLANCK");
+/// assert_eq!(H, cmn::constants::PLANCK);
+/// ```
+///
+/// ```compile_fail
+/// let h = cmn::cmn_const!("PLANK");
+/// ```
+#[macro_export]
+macro_rules! cmn_const {
+    ($name:literal) => {{
+        const VALUE: f64 = match $crate::constants::by_name($name) {
+            Some(value) => value,
+            None => {
+                panic!(concat!("no float constant named `", $name, "`"))
+            }
+        };
+        VALUE
+    }};
+}
+
 // ---------------------------------------------------------------
 // std-only macros (8) — require String, Vec, HashMap, println
 // ---------------------------------------------------------------
@@ -3,8 +3,9 @@
 
 //! This is the main entry point for the cmn application.
 fn main() {
-    // Call the `run()` function from the `Common (CMN)` module.
-    if let Err(err) = cmn::run() {
+    // Call `run_with_args()` with the command-line arguments, minus
+    // the program name.
+    if let Err(err) = cmn::run_with_args(std::env::args().skip(1)) {
         eprintln!("Error running cmn: {}", err);
         std::process::exit(1);
     }
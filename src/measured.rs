@@ -0,0 +1,404 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Values with a standard uncertainty and first-order propagation.
+//!
+//! A [`Measured`] pairs a value with its standard uncertainty
+//! `sigma`. Arithmetic and the common functions propagate the
+//! uncertainty linearly: for `f(x, y)`,
+//!
+//! ```text
+//! σ_f² = (∂f/∂x σ_x)² + (∂f/∂y σ_y)² + 2 (∂f/∂x)(∂f/∂y) cov(x, y)
+//! ```
+//!
+//! The operators `+`, `-`, `*` and `/` assume the operands are
+//! uncorrelated (`cov = 0`). When they are not — the same constant
+//! on both sides, or CODATA values adjusted together — use the
+//! covariance-aware `*_with_covariance` methods, which take
+//! `cov(x, y)` explicitly; `Measured::covariance` builds it from a
+//! correlation coefficient.
+//!
+//! [`Measured`]: crate::measured::Measured
+//!
+//! # Example
+//!
+//! ```
+//! use cmn::measured::Measured;
+//!
+//! // Surface gravity of the Earth, g = GM / r², with G from
+//! // CODATA and a made-up mass and radius.
+//! let g = Measured::from_constant("GRAVITATIONAL_CONSTANT").unwrap();
+//! let mass = Measured::new(5.9722e24, 6e20);
+//! let radius = Measured::new(6.371e6, 10.0);
+//! let surface = g * mass / radius.powi(2);
+//! assert!((surface.value - 9.82).abs() < 0.01);
+//! assert!(surface.relative() > g.relative());
+//!
+//! // x − x is exactly zero once the full correlation is declared.
+//! let x = Measured::new(2.0, 0.1);
+//! assert_eq!((x - x).sigma, 0.1 * 2f64.sqrt());
+//! assert_eq!(x.sub_with_covariance(x, x.sigma * x.sigma).sigma, 0.0);
+//! ```
+
+use crate::constants::Dataset;
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A value with a standard uncertainty.
+///
+/// `sigma` is the absolute standard uncertainty, in the unit of
+/// `value`, and is never negative.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Measured {
+    /// The best estimate.
+    pub value: f64,
+    /// The absolute standard uncertainty.
+    pub sigma: f64,
+}
+
+impl Measured {
+    /// Creates a value with standard uncertainty `sigma`. The sign
+    /// of `sigma` is ignored.
+    pub fn new(value: f64, sigma: f64) -> Self {
+        Self {
+            value,
+            sigma: sigma.abs(),
+        }
+    }
+
+    /// Creates a value with no uncertainty.
+    pub const fn exact(value: f64) -> Self {
+        Self { value, sigma: 0.0 }
+    }
+
+    /// Returns the value and standard uncertainty of the named
+    /// constant in [`Dataset::DEFAULT`], or `None` if the name is
+    /// unknown or the constant is not a float.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::measured::Measured;
+    ///
+    /// let g = Measured::from_constant("GRAVITATIONAL_CONSTANT");
+    /// let g = g.unwrap();
+    /// assert_eq!(g.value, cmn::constants::GRAVITATIONAL_CONSTANT);
+    /// assert!(g.sigma > 0.0);
+    ///
+    /// let h = Measured::from_constant("PLANCK").unwrap();
+    /// assert!(h.is_exact());
+    /// assert!(Measured::from_constant("HASH_COST").is_none());
+    /// ```
+    pub fn from_constant(name: &str) -> Option<Self> {
+        Self::from_constant_in(Dataset::DEFAULT, name)
+    }
+
+    /// Returns the value and standard uncertainty of the named
+    /// constant in `dataset`.
+    pub fn from_constant_in(
+        dataset: Dataset,
+        name: &str,
+    ) -> Option<Self> {
+        let entry = dataset.entry(name)?;
+        let value = entry.value.as_f64()?;
+        Some(Self::new(value, entry.uncertainty.absolute()))
+    }
+
+    /// Returns the relative standard uncertainty `sigma / |value|`:
+    /// zero for any exact value, including zero, and infinite for an
+    /// uncertain zero.
+    pub fn relative(&self) -> f64 {
+        if self.sigma == 0.0 {
+            0.0
+        } else {
+            self.sigma / self.value.abs()
+        }
+    }
+
+    /// Returns `true` if the uncertainty is zero.
+    pub fn is_exact(&self) -> bool {
+        self.sigma == 0.0
+    }
+
+    /// Returns the covariance of `self` and `other` for the
+    /// correlation coefficient `correlation` (between -1 and 1).
+    pub fn covariance(&self, other: Self, correlation: f64) -> f64 {
+        correlation * self.sigma * other.sigma
+    }
+
+    /// Applies a function of one variable with value `value` and
+    /// derivative `derivative` at `self.value`.
+    fn map(self, value: f64, derivative: f64) -> Self {
+        Self::new(value, scaled(derivative, self.sigma))
+    }
+
+    /// Applies a function of two variables with value `value` and
+    /// partial derivatives `da` and `db`.
+    fn combine(
+        self,
+        other: Self,
+        covariance: f64,
+        value: f64,
+        da: f64,
+        db: f64,
+    ) -> Self {
+        let (a, b) = (scaled(da, self.sigma), scaled(db, other.sigma));
+        let cross = scaled(2.0 * da * db, covariance);
+        let variance = a * a + b * b + cross;
+        Self::new(value, variance.max(0.0).sqrt())
+    }
+
+    /// Adds `other`, given the covariance of the operands.
+    pub fn add_with_covariance(
+        self,
+        other: Self,
+        covariance: f64,
+    ) -> Self {
+        self.combine(
+            other,
+            covariance,
+            self.value + other.value,
+            1.0,
+            1.0,
+        )
+    }
+
+    /// Subtracts `other`, given the covariance of the operands.
+    pub fn sub_with_covariance(
+        self,
+        other: Self,
+        covariance: f64,
+    ) -> Self {
+        self.combine(
+            other,
+            covariance,
+            self.value - other.value,
+            1.0,
+            -1.0,
+        )
+    }
+
+    /// Multiplies by `other`, given the covariance of the operands.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::measured::Measured;
+    ///
+    /// // A value fully correlated with itself: m × m is m², with
+    /// // twice the relative uncertainty.
+    /// let m = Measured::from_constant("ELECTRON_MASS").unwrap();
+    /// let squared = m.mul_with_covariance(m, m.covariance(m, 1.0));
+    /// let ratio = squared.relative() / m.relative();
+    /// assert!((ratio - 2.0).abs() < 1e-12);
+    /// assert_eq!(squared, m.powi(2));
+    /// ```
+    pub fn mul_with_covariance(
+        self,
+        other: Self,
+        covariance: f64,
+    ) -> Self {
+        self.combine(
+            other,
+            covariance,
+            self.value * other.value,
+            other.value,
+            self.value,
+        )
+    }
+
+    /// Divides by `other`, given the covariance of the operands.
+    pub fn div_with_covariance(
+        self,
+        other: Self,
+        covariance: f64,
+    ) -> Self {
+        let quotient = self.value / other.value;
+        self.combine(
+            other,
+            covariance,
+            quotient,
+            1.0 / other.value,
+            -quotient / other.value,
+        )
+    }
+
+    /// Raises to the power `exponent`, given the covariance of the
+    /// base and the exponent.
+    pub fn pow_with_covariance(
+        self,
+        exponent: Self,
+        covariance: f64,
+    ) -> Self {
+        let value = self.value.powf(exponent.value);
+        self.combine(
+            exponent,
+            covariance,
+            value,
+            exponent.value * self.value.powf(exponent.value - 1.0),
+            value * self.value.ln(),
+        )
+    }
+
+    /// Raises to the uncertain power `exponent`, assuming the two
+    /// are uncorrelated.
+    pub fn pow(self, exponent: Self) -> Self {
+        self.pow_with_covariance(exponent, 0.0)
+    }
+
+    /// Raises to an integer power. `x⁰` is exactly one.
+    pub fn powi(self, n: i32) -> Self {
+        if n == 0 {
+            return Self::exact(1.0);
+        }
+        self.map(
+            self.value.powi(n),
+            f64::from(n) * self.value.powi(n - 1),
+        )
+    }
+
+    /// Raises to an exact floating-point power. `x⁰` is exactly
+    /// one.
+    pub fn powf(self, p: f64) -> Self {
+        if p == 0.0 {
+            return Self::exact(1.0);
+        }
+        self.map(self.value.powf(p), p * self.value.powf(p - 1.0))
+    }
+
+    /// Returns the square root.
+    pub fn sqrt(self) -> Self {
+        let root = self.value.sqrt();
+        self.map(root, 0.5 / root)
+    }
+
+    /// Returns the cube root.
+    pub fn cbrt(self) -> Self {
+        let root = self.value.cbrt();
+        self.map(root, 1.0 / (3.0 * root * root))
+    }
+
+    /// Returns the reciprocal `1/x`.
+    pub fn recip(self) -> Self {
+        let inverse = self.value.recip();
+        self.map(inverse, inverse * inverse)
+    }
+
+    /// Returns the absolute value.
+    pub fn abs(self) -> Self {
+        Self::new(self.value.abs(), self.sigma)
+    }
+
+    /// Returns `e^x`.
+    pub fn exp(self) -> Self {
+        let value = self.value.exp();
+        self.map(value, value)
+    }
+
+    /// Returns the natural logarithm.
+    pub fn ln(self) -> Self {
+        self.map(self.value.ln(), 1.0 / self.value)
+    }
+
+    /// Returns the base-10 logarithm.
+    pub fn log10(self) -> Self {
+        self.map(
+            self.value.log10(),
+            1.0 / (self.value * core::f64::consts::LN_10),
+        )
+    }
+
+    /// Returns the sine of an angle in radians.
+    pub fn sin(self) -> Self {
+        self.map(self.value.sin(), self.value.cos())
+    }
+
+    /// Returns the cosine of an angle in radians.
+    pub fn cos(self) -> Self {
+        self.map(self.value.cos(), self.value.sin())
+    }
+
+    /// Returns the tangent of an angle in radians.
+    pub fn tan(self) -> Self {
+        let cos = self.value.cos();
+        self.map(self.value.tan(), 1.0 / (cos * cos))
+    }
+}
+
+/// Returns `derivative * sigma`, taking an exact input (`sigma`
+/// of zero) as contributing nothing even where the derivative is
+/// infinite or undefined.
+fn scaled(derivative: f64, sigma: f64) -> f64 {
+    if sigma == 0.0 {
+        0.0
+    } else {
+        derivative * sigma
+    }
+}
+
+impl From<f64> for Measured {
+    fn from(value: f64) -> Self {
+        Self::exact(value)
+    }
+}
+
+impl fmt::Display for Measured {
+    /// Formats as `value ± sigma`, applying any precision to both.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(p) => {
+                write!(f, "{:.p$} ± {:.p$}", self.value, self.sigma)
+            }
+            None => write!(f, "{} ± {}", self.value, self.sigma),
+        }
+    }
+}
+
+impl Neg for Measured {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.value, self.sigma)
+    }
+}
+
+/// Implements an operator between uncorrelated `Measured` values
+/// and with exact `f64` scalars on either side.
+macro_rules! impl_measured_op {
+    ($trait:ident, $method:ident, $with:ident) => {
+        impl $trait for Measured {
+            type Output = Self;
+
+            fn $method(self, other: Self) -> Self {
+                self.$with(other, 0.0)
+            }
+        }
+
+        impl $trait<f64> for Measured {
+            type Output = Self;
+
+            fn $method(self, other: f64) -> Self {
+                self.$with(Self::exact(other), 0.0)
+            }
+        }
+
+        impl $trait<Measured> for f64 {
+            type Output = Measured;
+
+            fn $method(self, other: Measured) -> Measured {
+                Measured::exact(self).$with(other, 0.0)
+            }
+        }
+    };
+}
+
+impl_measured_op!(Add, add, add_with_covariance);
+impl_measured_op!(Sub, sub, sub_with_covariance);
+impl_measured_op!(Mul, mul, mul_with_covariance);
+impl_measured_op!(Div, div, div_with_covariance);
+
+impl core::iter::Sum for Measured {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::exact(0.0), Add::add)
+    }
+}
@@ -0,0 +1,289 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A catalogue of particle properties.
+//!
+//! Each [`Particle`] in [`PARTICLES`] gathers the mass (in kg,
+//! MeV/c² and u), charge, spin, g-factor, magnetic moment and
+//! Compton wavelength of one particle. Every value comes from the
+//! constants of [`crate::constants`] — CODATA 2018 for the leptons
+//! (the tau included), nucleons and light nuclei, PDG 2022 for the
+//! W, Z and Higgs bosons —
+//! or is derived from them, such as the Compton wavelength h/(mc)
+//! where no constant tabulates it. Properties without a constant,
+//! like the muon g-factor, are `None`.
+//!
+//! Antiparticles are not listed: they have the same mass and spin
+//! and the opposite charge and magnetic moment. Available in
+//! `no_std`.
+//!
+//! [`Particle`]: crate::particles::Particle
+//! [`PARTICLES`]: crate::particles::PARTICLES
+//!
+//! # Example
+//!
+//! ```
+//! use cmn::particles::particle;
+//!
+//! let muon = particle("mu-").unwrap();
+//! assert_eq!(muon.name, "muon");
+//! assert_eq!(muon.charge, -1);
+//! assert_eq!(muon.spin, 0.5);
+//! assert!((muon.mass_mev - 105.658).abs() < 1e-3);
+//!
+//! // Names match ignoring case, spaces, hyphens and underscores.
+//! let z = particle("z boson").unwrap();
+//! assert_eq!(particle("Z0"), Some(z));
+//! assert!((z.mass_mev - 91_187.6).abs() < 0.1);
+//! ```
+
+use crate::constants::aliases::normalized_eq;
+use crate::constants::{
+    ALPHA_PARTICLE_MASS, ATOMIC_MASS_UNIT, DEUTERON_MASS,
+    ELECTRON_COMPTON_WAVELENGTH, ELECTRON_G_FACTOR,
+    ELECTRON_MAGNETIC_MOMENT, ELECTRON_MASS, ELECTRON_MASS_MEV,
+    ELECTRON_VOLT, EV_TO_KG, HELION_MASS, HIGGS_BOSON_MASS_GEV,
+    MUON_MASS, MUON_MASS_MEV, NEUTRON_COMPTON_WAVELENGTH,
+    NEUTRON_MAGNETIC_MOMENT, NEUTRON_MASS, NEUTRON_MASS_MEV,
+    NUCLEAR_MAGNETON, PLANCK, PROTON_COMPTON_WAVELENGTH,
+    PROTON_G_FACTOR, PROTON_MAGNETIC_MOMENT, PROTON_MASS,
+    PROTON_MASS_MEV, SPEED_OF_LIGHT, TAU_PARTICLE_MASS, TRITON_MASS,
+    W_BOSON_MASS_GEV, Z_BOSON_MASS_GEV,
+};
+
+/// The properties of one particle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Particle {
+    /// The English name, e.g. `"muon"`.
+    pub name: &'static str,
+    /// The conventional symbol, e.g. `"μ⁻"`.
+    pub symbol: &'static str,
+    /// Other symbols it is known by, in ASCII where possible, e.g.
+    /// `"mu-"`.
+    pub aliases: &'static [&'static str],
+    /// The rest mass, in kg.
+    pub mass: f64,
+    /// The rest mass, in MeV/c².
+    pub mass_mev: f64,
+    /// The rest mass, in unified atomic mass units (u).
+    pub mass_u: f64,
+    /// The electric charge, in units of the elementary charge.
+    pub charge: i8,
+    /// The spin, in units of ħ.
+    pub spin: f64,
+    /// The g-factor, if a constant gives it. Negative when the
+    /// magnetic moment is antiparallel to the spin.
+    pub g_factor: Option<f64>,
+    /// The magnetic moment, in J/T, if a constant gives it. Zero
+    /// for spin-0 particles.
+    pub magnetic_moment: Option<f64>,
+    /// The Compton wavelength h/(mc), in m.
+    pub compton_wavelength: f64,
+}
+
+/// One MeV, in joules.
+const MEV: f64 = 1e6 * ELECTRON_VOLT;
+const C2: f64 = SPEED_OF_LIGHT * SPEED_OF_LIGHT;
+
+// The PDG boson masses, in kg.
+const W_MASS: f64 = W_BOSON_MASS_GEV * 1e9 * EV_TO_KG;
+const Z_MASS: f64 = Z_BOSON_MASS_GEV * 1e9 * EV_TO_KG;
+const HIGGS_MASS: f64 = HIGGS_BOSON_MASS_GEV * 1e9 * EV_TO_KG;
+
+/// Every particle in the catalogue: leptons, nucleons, light nuclei
+/// and bosons.
+pub const PARTICLES: &[Particle] = &[
+    // Leptons
+    Particle {
+        name: "electron",
+        symbol: "e⁻",
+        aliases: &["e-", "e"],
+        mass: ELECTRON_MASS,
+        mass_mev: ELECTRON_MASS_MEV,
+        mass_u: ELECTRON_MASS / ATOMIC_MASS_UNIT,
+        charge: -1,
+        spin: 0.5,
+        g_factor: Some(ELECTRON_G_FACTOR),
+        magnetic_moment: Some(ELECTRON_MAGNETIC_MOMENT),
+        compton_wavelength: ELECTRON_COMPTON_WAVELENGTH,
+    },
+    Particle {
+        name: "muon",
+        symbol: "μ⁻",
+        aliases: &["mu-", "mu", "μ"],
+        mass: MUON_MASS,
+        mass_mev: MUON_MASS_MEV,
+        mass_u: MUON_MASS / ATOMIC_MASS_UNIT,
+        charge: -1,
+        spin: 0.5,
+        g_factor: None,
+        magnetic_moment: None,
+        compton_wavelength: PLANCK / (MUON_MASS * SPEED_OF_LIGHT),
+    },
+    Particle {
+        name: "tau",
+        symbol: "τ⁻",
+        aliases: &["tau-", "τ"],
+        mass: TAU_PARTICLE_MASS,
+        mass_mev: TAU_PARTICLE_MASS * C2 / MEV,
+        mass_u: TAU_PARTICLE_MASS / ATOMIC_MASS_UNIT,
+        charge: -1,
+        spin: 0.5,
+        g_factor: None,
+        magnetic_moment: None,
+        compton_wavelength: PLANCK
+            / (TAU_PARTICLE_MASS * SPEED_OF_LIGHT),
+    },
+    // Nucleons
+    Particle {
+        name: "proton",
+        symbol: "p",
+        aliases: &["p+"],
+        mass: PROTON_MASS,
+        mass_mev: PROTON_MASS_MEV,
+        mass_u: PROTON_MASS / ATOMIC_MASS_UNIT,
+        charge: 1,
+        spin: 0.5,
+        g_factor: Some(PROTON_G_FACTOR),
+        magnetic_moment: Some(PROTON_MAGNETIC_MOMENT),
+        compton_wavelength: PROTON_COMPTON_WAVELENGTH,
+    },
+    Particle {
+        name: "neutron",
+        symbol: "n",
+        aliases: &["n0"],
+        mass: NEUTRON_MASS,
+        mass_mev: NEUTRON_MASS_MEV,
+        mass_u: NEUTRON_MASS / ATOMIC_MASS_UNIT,
+        charge: 0,
+        spin: 0.5,
+        // μ_n = (g_n/2) μ_N
+        g_factor: Some(
+            2.0 * NEUTRON_MAGNETIC_MOMENT / NUCLEAR_MAGNETON,
+        ),
+        magnetic_moment: Some(NEUTRON_MAGNETIC_MOMENT),
+        compton_wavelength: NEUTRON_COMPTON_WAVELENGTH,
+    },
+    // Light nuclei
+    Particle {
+        name: "deuteron",
+        symbol: "d",
+        aliases: &["d+"],
+        mass: DEUTERON_MASS,
+        mass_mev: DEUTERON_MASS * C2 / MEV,
+        mass_u: DEUTERON_MASS / ATOMIC_MASS_UNIT,
+        charge: 1,
+        spin: 1.0,
+        g_factor: None,
+        magnetic_moment: None,
+        compton_wavelength: PLANCK / (DEUTERON_MASS * SPEED_OF_LIGHT),
+    },
+    Particle {
+        name: "triton",
+        symbol: "t",
+        aliases: &["t+"],
+        mass: TRITON_MASS,
+        mass_mev: TRITON_MASS * C2 / MEV,
+        mass_u: TRITON_MASS / ATOMIC_MASS_UNIT,
+        charge: 1,
+        spin: 0.5,
+        g_factor: None,
+        magnetic_moment: None,
+        compton_wavelength: PLANCK / (TRITON_MASS * SPEED_OF_LIGHT),
+    },
+    Particle {
+        name: "helion",
+        symbol: "h",
+        aliases: &["h2+"],
+        mass: HELION_MASS,
+        mass_mev: HELION_MASS * C2 / MEV,
+        mass_u: HELION_MASS / ATOMIC_MASS_UNIT,
+        charge: 2,
+        spin: 0.5,
+        g_factor: None,
+        magnetic_moment: None,
+        compton_wavelength: PLANCK / (HELION_MASS * SPEED_OF_LIGHT),
+    },
+    Particle {
+        name: "alpha particle",
+        symbol: "α",
+        aliases: &["alpha", "He2+"],
+        mass: ALPHA_PARTICLE_MASS,
+        mass_mev: ALPHA_PARTICLE_MASS * C2 / MEV,
+        mass_u: ALPHA_PARTICLE_MASS / ATOMIC_MASS_UNIT,
+        charge: 2,
+        spin: 0.0,
+        g_factor: None,
+        magnetic_moment: Some(0.0),
+        compton_wavelength: PLANCK
+            / (ALPHA_PARTICLE_MASS * SPEED_OF_LIGHT),
+    },
+    // Bosons
+    Particle {
+        name: "W boson",
+        symbol: "W⁺",
+        aliases: &["W+", "W"],
+        mass: W_MASS,
+        mass_mev: W_BOSON_MASS_GEV * 1e3,
+        mass_u: W_MASS / ATOMIC_MASS_UNIT,
+        charge: 1,
+        spin: 1.0,
+        g_factor: None,
+        magnetic_moment: None,
+        compton_wavelength: PLANCK / (W_MASS * SPEED_OF_LIGHT),
+    },
+    Particle {
+        name: "Z boson",
+        symbol: "Z⁰",
+        aliases: &["Z0", "Z"],
+        mass: Z_MASS,
+        mass_mev: Z_BOSON_MASS_GEV * 1e3,
+        mass_u: Z_MASS / ATOMIC_MASS_UNIT,
+        charge: 0,
+        spin: 1.0,
+        g_factor: None,
+        magnetic_moment: None,
+        compton_wavelength: PLANCK / (Z_MASS * SPEED_OF_LIGHT),
+    },
+    Particle {
+        name: "Higgs boson",
+        symbol: "H⁰",
+        aliases: &["H0", "H"],
+        mass: HIGGS_MASS,
+        mass_mev: HIGGS_BOSON_MASS_GEV * 1e3,
+        mass_u: HIGGS_MASS / ATOMIC_MASS_UNIT,
+        charge: 0,
+        spin: 0.0,
+        g_factor: None,
+        magnetic_moment: Some(0.0),
+        compton_wavelength: PLANCK / (HIGGS_MASS * SPEED_OF_LIGHT),
+    },
+];
+
+/// Looks up a particle by symbol or alias (exactly, e.g. `"μ⁻"`,
+/// `"mu-"` or `"p"`) or by name (ignoring case, spaces, hyphens
+/// and underscores, e.g. `"Alpha_Particle"`).
+///
+/// Symbols are case-sensitive, so `"h"` is the helion and `"H"`
+/// the Higgs boson.
+///
+/// # Example
+///
+/// ```
+/// use cmn::particles::particle;
+///
+/// let electron = particle("e-").unwrap();
+/// assert_eq!(electron.symbol, "e⁻");
+/// assert_eq!(particle("Electron"), Some(electron));
+/// assert_eq!(particle("h").unwrap().name, "helion");
+/// assert_eq!(particle("H").unwrap().name, "Higgs boson");
+/// assert_eq!(particle("quark"), None);
+/// ```
+pub fn particle(query: &str) -> Option<&'static Particle> {
+    PARTICLES
+        .iter()
+        .find(|p| p.symbol == query || p.aliases.contains(&query))
+        .or_else(|| {
+            PARTICLES.iter().find(|p| normalized_eq(p.name, query))
+        })
+}
@@ -0,0 +1,687 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Dimensioned quantities checked at compile time.
+//!
+//! A [`Quantity<D>`](crate::quantities::Quantity) is an `f64` in
+//! coherent SI units tagged with its dimension `D`, a
+//! [`Dim`](crate::quantities::Dim) whose const parameters are the
+//! exponents of the seven SI base units. Adding or subtracting
+//! quantities of different dimensions does not compile, and
+//! multiplying or dividing any two of them yields the dimension of
+//! the result, named or not. The wrapper is `#[repr(transparent)]`,
+//! so it has no runtime cost.
+//!
+//! Every physical constant of [`crate::constants`] has a typed
+//! twin here with the same name. Constants tabulated in non-SI
+//! units (eV, MeV/c², u, …) are converted to SI. Everything here is
+//! `const` and available in `no_std`.
+//!
+//! # Example
+//!
+//! ```
+//! use cmn::quantities::{
+//!     Acceleration, Energy, Length, Mass, Quantity, Time,
+//!     ELECTRON_MASS, GRAVITATIONAL_CONSTANT, SPEED_OF_LIGHT,
+//! };
+//!
+//! let rest_energy: Quantity<Energy> =
+//!     ELECTRON_MASS * SPEED_OF_LIGHT * SPEED_OF_LIGHT;
+//! assert!((rest_energy.value() - 8.187e-14).abs() < 1e-16);
+//!
+//! // g = GM/r² at the surface of the Earth.
+//! let earth = Quantity::<Mass>::new(5.972e24);
+//! let radius = Quantity::<Length>::new(6.371e6);
+//! let g: Quantity<Acceleration> =
+//!     GRAVITATIONAL_CONSTANT * earth / (radius * radius);
+//! assert!((g.value() - 9.82).abs() < 0.01);
+//!
+//! let t = Quantity::<Time>::new(2.0);
+//! let distance: Quantity<Length> = SPEED_OF_LIGHT * t;
+//! assert_eq!(distance.value(), 2.0 * 299_792_458.0);
+//! assert_eq!(distance.to_string(), "599584916 m");
+//! ```
+//!
+//! Mixing dimensions is a compile error:
+//!
+//! ```compile_fail
+//! use cmn::quantities::{PLANCK, SPEED_OF_LIGHT};
+//!
+//! let nonsense = SPEED_OF_LIGHT + PLANCK;
+//! ```
+
+use crate::constants;
+use crate::units::{self, Dimension, Unit};
+use core::fmt;
+use core::iter::Sum;
+use core::marker::PhantomData;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+mod products;
+
+/// A type-level SI dimension.
+///
+/// The parameters are the exponents of the metre, kilogram,
+/// second, ampere, kelvin, mole and candela, in that order (see
+/// [`Dimension`]). Named aliases such as [`Length`] and
+/// [`Energy`] cover the dimensions of every constant.
+#[derive(
+    Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd,
+)]
+pub struct Dim<
+    const L: i8,
+    const M: i8,
+    const T: i8,
+    const I: i8,
+    const K: i8,
+    const N: i8,
+    const J: i8,
+>;
+
+/// A type that stands for an SI dimension.
+pub trait Dimensioned: Copy {
+    /// The runtime form of the dimension.
+    const DIMENSION: Dimension;
+}
+
+impl<
+        const L: i8,
+        const M: i8,
+        const T: i8,
+        const I: i8,
+        const K: i8,
+        const N: i8,
+        const J: i8,
+    > Dimensioned for Dim<L, M, T, I, K, N, J>
+{
+    const DIMENSION: Dimension = Dimension::new(L, M, T, I, K, N, J);
+}
+
+/// A type-level exponent of one SI base unit.
+///
+/// The `Mul` and `Div` impls of [`Quantity`] add and subtract the
+/// [`Dim`] parameters through [`AddExponent`] and [`SubExponent`]
+/// on this type, which are implemented for results between -12 and
+/// 12.
+#[derive(
+    Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd,
+)]
+pub struct Exponent<const N: i8>;
+
+/// Type-level addition of [`Exponent`]s.
+pub trait AddExponent<Rhs> {
+    /// The sum.
+    type Output;
+}
+
+/// Type-level subtraction of [`Exponent`]s.
+pub trait SubExponent<Rhs> {
+    /// The difference.
+    type Output;
+}
+
+// ---------------------------------------------------------------
+// Named dimensions
+// ---------------------------------------------------------------
+
+/// A dimensionless ratio.
+pub type Dimensionless = Dim<0, 0, 0, 0, 0, 0, 0>;
+/// Length (m).
+pub type Length = Dim<1, 0, 0, 0, 0, 0, 0>;
+/// Mass (kg).
+pub type Mass = Dim<0, 1, 0, 0, 0, 0, 0>;
+/// Time (s).
+pub type Time = Dim<0, 0, 1, 0, 0, 0, 0>;
+/// Electric current (A).
+pub type Current = Dim<0, 0, 0, 1, 0, 0, 0>;
+/// Thermodynamic temperature (K).
+pub type Temperature = Dim<0, 0, 0, 0, 1, 0, 0>;
+/// Amount of substance (mol).
+pub type Amount = Dim<0, 0, 0, 0, 0, 1, 0>;
+/// Luminous intensity (cd).
+pub type LuminousIntensity = Dim<0, 0, 0, 0, 0, 0, 1>;
+/// Area (m^2).
+pub type Area = Dim<2, 0, 0, 0, 0, 0, 0>;
+/// Volume (m^3).
+pub type Volume = Dim<3, 0, 0, 0, 0, 0, 0>;
+/// Wavenumber (m^-1).
+pub type Wavenumber = Dim<-1, 0, 0, 0, 0, 0, 0>;
+/// Number density (m^-3).
+pub type NumberDensity = Dim<-3, 0, 0, 0, 0, 0, 0>;
+/// Frequency (Hz).
+pub type Frequency = Dim<0, 0, -1, 0, 0, 0, 0>;
+/// Velocity (m s^-1).
+pub type Velocity = Dim<1, 0, -1, 0, 0, 0, 0>;
+/// Acceleration (m s^-2).
+pub type Acceleration = Dim<1, 0, -2, 0, 0, 0, 0>;
+/// Momentum (kg m s^-1).
+pub type Momentum = Dim<1, 1, -1, 0, 0, 0, 0>;
+/// Force (N).
+pub type Force = Dim<1, 1, -2, 0, 0, 0, 0>;
+/// Pressure (Pa).
+pub type Pressure = Dim<-1, 1, -2, 0, 0, 0, 0>;
+/// Energy (J).
+pub type Energy = Dim<2, 1, -2, 0, 0, 0, 0>;
+/// Power (W).
+pub type Power = Dim<2, 1, -3, 0, 0, 0, 0>;
+/// Action or angular momentum (J s).
+pub type Action = Dim<2, 1, -1, 0, 0, 0, 0>;
+/// Mass density (kg m^-3).
+pub type MassDensity = Dim<-3, 1, 0, 0, 0, 0, 0>;
+/// Electric charge (C).
+pub type Charge = Dim<0, 0, 1, 1, 0, 0, 0>;
+/// Electric potential difference (V).
+pub type Voltage = Dim<2, 1, -3, -1, 0, 0, 0>;
+/// Electric resistance (Ω).
+pub type Resistance = Dim<2, 1, -3, -2, 0, 0, 0>;
+/// Electric conductance (S).
+pub type Conductance = Dim<-2, -1, 3, 2, 0, 0, 0>;
+/// Capacitance (F).
+pub type Capacitance = Dim<-2, -1, 4, 2, 0, 0, 0>;
+/// Inductance (H).
+pub type Inductance = Dim<2, 1, -2, -2, 0, 0, 0>;
+/// Magnetic flux (Wb).
+pub type MagneticFlux = Dim<2, 1, -2, -1, 0, 0, 0>;
+/// Magnetic flux density (T).
+pub type MagneticFluxDensity = Dim<0, 1, -2, -1, 0, 0, 0>;
+/// Electric field strength (V m^-1).
+pub type ElectricField = Dim<1, 1, -3, -1, 0, 0, 0>;
+/// Magnetic moment (J T^-1).
+pub type MagneticMoment = Dim<2, 0, 0, 1, 0, 0, 0>;
+/// Permittivity (F m^-1).
+pub type Permittivity = Dim<-3, -1, 4, 2, 0, 0, 0>;
+/// Permeability (N A^-2).
+pub type Permeability = Dim<1, 1, -2, -2, 0, 0, 0>;
+/// Inverse permittivity (N m^2 C^-2).
+pub type InversePermittivity = Dim<3, 1, -4, -2, 0, 0, 0>;
+/// Electric polarizability (C^2 m^2 J^-1).
+pub type Polarizability = Dim<0, -1, 4, 2, 0, 0, 0>;
+/// Charge-to-mass ratio (C kg^-1).
+pub type SpecificCharge = Dim<0, -1, 1, 1, 0, 0, 0>;
+/// Frequency per voltage (Hz V^-1).
+pub type FrequencyPerVoltage = Dim<-2, -1, 2, 1, 0, 0, 0>;
+/// Entropy or heat capacity (J K^-1).
+pub type Entropy = Dim<2, 1, -2, 0, -1, 0, 0>;
+/// Length times temperature (m K).
+pub type LengthTemperature = Dim<1, 0, 0, 0, 1, 0, 0>;
+/// Power times area (W m^2).
+pub type PowerArea = Dim<4, 1, -3, 0, 0, 0, 0>;
+/// Radiant exitance per kelvin to the fourth (W m^-2 K^-4).
+pub type PowerPerAreaKelvin4 = Dim<0, 1, -3, 0, -4, 0, 0>;
+/// Unit of the gravitational constant (m^3 kg^-1 s^-2).
+pub type GravitationalCoupling = Dim<3, -1, -2, 0, 0, 0, 0>;
+/// Reciprocal amount of substance (mol^-1).
+pub type InverseAmount = Dim<0, 0, 0, 0, 0, -1, 0>;
+/// Molar mass (kg mol^-1).
+pub type MolarMass = Dim<0, 1, 0, 0, 0, -1, 0>;
+/// Molar volume (m^3 mol^-1).
+pub type MolarVolume = Dim<3, 0, 0, 0, 0, -1, 0>;
+/// Molar charge (C mol^-1).
+pub type MolarCharge = Dim<0, 0, 1, 1, 0, -1, 0>;
+/// Molar entropy or heat capacity (J mol^-1 K^-1).
+pub type MolarEntropy = Dim<2, 1, -2, 0, -1, -1, 0>;
+/// Molar action (J s mol^-1).
+pub type MolarAction = Dim<2, 1, -1, 0, 0, -1, 0>;
+
+/// A value in coherent SI units with dimension `D`.
+///
+/// # Example
+///
+/// ```
+/// use cmn::quantities::{Energy, Quantity};
+/// use cmn::units::{ELECTRONVOLT, JOULE, METER};
+///
+/// let e = Quantity::<Energy>::from_unit(1.0, ELECTRONVOLT).unwrap();
+/// assert_eq!(e.value(), cmn::constants::ELECTRON_VOLT);
+/// assert_eq!(e.to_unit(JOULE), Some(e.value()));
+/// assert!(Quantity::<Energy>::from_unit(1.0, METER).is_none());
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Quantity<D> {
+    value: f64,
+    dimension: PhantomData<D>,
+}
+
+impl<D> Quantity<D> {
+    /// Wraps a value expressed in coherent SI units.
+    pub const fn new(value: f64) -> Self {
+        Self {
+            value,
+            dimension: PhantomData,
+        }
+    }
+
+    /// Returns the value in coherent SI units.
+    pub const fn value(self) -> f64 {
+        self.value
+    }
+}
+
+impl<D: Dimensioned> Quantity<D> {
+    /// Returns the runtime form of `D`.
+    pub fn dimension(self) -> Dimension {
+        D::DIMENSION
+    }
+
+    /// Converts `value`, expressed in `unit`, to a quantity. Returns
+    /// `None` if `unit` does not measure dimension `D`.
+    pub fn from_unit(value: f64, unit: Unit) -> Option<Self> {
+        (unit.dimension == D::DIMENSION)
+            .then(|| Self::new(unit.to_si(value)))
+    }
+
+    /// Expresses the quantity in `unit`. Returns `None` if `unit`
+    /// does not measure dimension `D`.
+    pub fn to_unit(self, unit: Unit) -> Option<f64> {
+        (unit.dimension == D::DIMENSION)
+            .then(|| unit.from_si(self.value))
+    }
+}
+
+impl<D> Add for Quantity<D> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.value + rhs.value)
+    }
+}
+
+impl<D> Sub for Quantity<D> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.value - rhs.value)
+    }
+}
+
+impl<D> Neg for Quantity<D> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.value)
+    }
+}
+
+impl<D> AddAssign for Quantity<D> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.value += rhs.value;
+    }
+}
+
+impl<D> SubAssign for Quantity<D> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value -= rhs.value;
+    }
+}
+
+impl<D> Mul<f64> for Quantity<D> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: f64) -> Self {
+        Self::new(self.value * rhs)
+    }
+}
+
+impl<D> Mul<Quantity<D>> for f64 {
+    type Output = Quantity<D>;
+
+    #[inline]
+    fn mul(self, rhs: Quantity<D>) -> Quantity<D> {
+        Quantity::new(self * rhs.value)
+    }
+}
+
+impl<D> Div<f64> for Quantity<D> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: f64) -> Self {
+        Self::new(self.value / rhs)
+    }
+}
+
+impl<D> MulAssign<f64> for Quantity<D> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64) {
+        self.value *= rhs;
+    }
+}
+
+impl<D> DivAssign<f64> for Quantity<D> {
+    #[inline]
+    fn div_assign(&mut self, rhs: f64) {
+        self.value /= rhs;
+    }
+}
+
+impl<D> Sum for Quantity<D> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(0.0), Add::add)
+    }
+}
+
+impl From<f64> for Quantity<Dimensionless> {
+    fn from(value: f64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<Quantity<Dimensionless>> for f64 {
+    fn from(quantity: Quantity<Dimensionless>) -> Self {
+        quantity.value
+    }
+}
+
+impl<D: Dimensioned> fmt::Display for Quantity<D> {
+    /// Formats the value followed by its SI base units, e.g.
+    /// `299792458 m s^-1`. Dimensionless values print bare.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        if D::DIMENSION.is_dimensionless() {
+            return Ok(());
+        }
+        write!(f, " {}", D::DIMENSION)
+    }
+}
+
+// ---------------------------------------------------------------
+// Typed physical constants
+// ---------------------------------------------------------------
+
+/// Avogadro constant.
+pub const AVOGADRO: Quantity<InverseAmount> =
+    Quantity::new(constants::AVOGADRO);
+/// Boltzmann constant.
+pub const BOLTZMANN: Quantity<Entropy> =
+    Quantity::new(constants::BOLTZMANN);
+/// Coulomb constant.
+pub const COULOMB: Quantity<InversePermittivity> =
+    Quantity::new(constants::COULOMB);
+/// Faraday constant.
+pub const FARADAY: Quantity<MolarCharge> =
+    Quantity::new(constants::FARADAY);
+/// Molar gas constant.
+pub const GAS_CONSTANT: Quantity<MolarEntropy> =
+    Quantity::new(constants::GAS_CONSTANT);
+/// Newtonian constant of gravitation.
+pub const GRAVITATIONAL_CONSTANT: Quantity<GravitationalCoupling> =
+    Quantity::new(constants::GRAVITATIONAL_CONSTANT);
+/// Planck constant.
+pub const PLANCK: Quantity<Action> = Quantity::new(constants::PLANCK);
+/// Reduced Planck constant.
+pub const PLANCK_REDUCED: Quantity<Action> =
+    Quantity::new(constants::PLANCK_REDUCED);
+/// Speed of light in vacuum.
+pub const SPEED_OF_LIGHT: Quantity<Velocity> =
+    Quantity::new(constants::SPEED_OF_LIGHT);
+/// Vacuum magnetic permeability.
+pub const VACUUM_PERMEABILITY: Quantity<Permeability> =
+    Quantity::new(constants::VACUUM_PERMEABILITY);
+/// Vacuum electric permittivity.
+pub const VACUUM_PERMITTIVITY: Quantity<Permittivity> =
+    Quantity::new(constants::VACUUM_PERMITTIVITY);
+/// Elementary charge.
+pub const ELEMENTARY_CHARGE: Quantity<Charge> =
+    Quantity::new(constants::ELEMENTARY_CHARGE);
+/// Electron mass.
+pub const ELECTRON_MASS: Quantity<Mass> =
+    Quantity::new(constants::ELECTRON_MASS);
+/// Proton mass.
+pub const PROTON_MASS: Quantity<Mass> =
+    Quantity::new(constants::PROTON_MASS);
+/// Neutron mass.
+pub const NEUTRON_MASS: Quantity<Mass> =
+    Quantity::new(constants::NEUTRON_MASS);
+/// Stefan–Boltzmann constant.
+pub const STEFAN_BOLTZMANN: Quantity<PowerPerAreaKelvin4> =
+    Quantity::new(constants::STEFAN_BOLTZMANN);
+/// Wien wavelength displacement law constant.
+pub const WIEN_DISPLACEMENT: Quantity<LengthTemperature> =
+    Quantity::new(constants::WIEN_DISPLACEMENT);
+/// Standard acceleration of gravity.
+pub const STANDARD_GRAVITY: Quantity<Acceleration> =
+    Quantity::new(constants::STANDARD_GRAVITY);
+/// Standard atmosphere.
+pub const STANDARD_ATMOSPHERE: Quantity<Pressure> =
+    Quantity::new(constants::STANDARD_ATMOSPHERE);
+/// Atomic mass constant (dalton).
+pub const ATOMIC_MASS_UNIT: Quantity<Mass> =
+    Quantity::new(constants::ATOMIC_MASS_UNIT);
+/// Bohr radius.
+pub const BOHR_RADIUS: Quantity<Length> =
+    Quantity::new(constants::BOHR_RADIUS);
+/// Fine-structure constant.
+pub const FINE_STRUCTURE: Quantity<Dimensionless> =
+    Quantity::new(constants::FINE_STRUCTURE);
+/// Rydberg constant.
+pub const RYDBERG: Quantity<Wavenumber> =
+    Quantity::new(constants::RYDBERG);
+/// Magnetic flux quantum.
+pub const MAGNETIC_FLUX_QUANTUM: Quantity<MagneticFlux> =
+    Quantity::new(constants::MAGNETIC_FLUX_QUANTUM);
+/// Conductance quantum.
+pub const CONDUCTANCE_QUANTUM: Quantity<Conductance> =
+    Quantity::new(constants::CONDUCTANCE_QUANTUM);
+/// Muon mass.
+pub const MUON_MASS: Quantity<Mass> =
+    Quantity::new(constants::MUON_MASS);
+/// Tau lepton mass.
+pub const TAU_PARTICLE_MASS: Quantity<Mass> =
+    Quantity::new(constants::TAU_PARTICLE_MASS);
+/// Deuteron mass.
+pub const DEUTERON_MASS: Quantity<Mass> =
+    Quantity::new(constants::DEUTERON_MASS);
+/// Triton mass.
+pub const TRITON_MASS: Quantity<Mass> =
+    Quantity::new(constants::TRITON_MASS);
+/// Helion (helium-3 nucleus) mass.
+pub const HELION_MASS: Quantity<Mass> =
+    Quantity::new(constants::HELION_MASS);
+/// Alpha particle mass.
+pub const ALPHA_PARTICLE_MASS: Quantity<Mass> =
+    Quantity::new(constants::ALPHA_PARTICLE_MASS);
+/// Electron-to-proton mass ratio.
+pub const ELECTRON_PROTON_MASS_RATIO: Quantity<Dimensionless> =
+    Quantity::new(constants::ELECTRON_PROTON_MASS_RATIO);
+/// Proton-to-electron mass ratio.
+pub const PROTON_ELECTRON_MASS_RATIO: Quantity<Dimensionless> =
+    Quantity::new(constants::PROTON_ELECTRON_MASS_RATIO);
+/// Muon-to-electron mass ratio.
+pub const MUON_ELECTRON_MASS_RATIO: Quantity<Dimensionless> =
+    Quantity::new(constants::MUON_ELECTRON_MASS_RATIO);
+/// Neutron-to-proton mass ratio.
+pub const NEUTRON_PROTON_MASS_RATIO: Quantity<Dimensionless> =
+    Quantity::new(constants::NEUTRON_PROTON_MASS_RATIO);
+/// Deuteron-to-proton mass ratio.
+pub const DEUTERON_PROTON_MASS_RATIO: Quantity<Dimensionless> =
+    Quantity::new(constants::DEUTERON_PROTON_MASS_RATIO);
+/// Bohr magneton.
+pub const BOHR_MAGNETON: Quantity<MagneticMoment> =
+    Quantity::new(constants::BOHR_MAGNETON);
+/// Nuclear magneton.
+pub const NUCLEAR_MAGNETON: Quantity<MagneticMoment> =
+    Quantity::new(constants::NUCLEAR_MAGNETON);
+/// Electron magnetic moment.
+pub const ELECTRON_MAGNETIC_MOMENT: Quantity<MagneticMoment> =
+    Quantity::new(constants::ELECTRON_MAGNETIC_MOMENT);
+/// Proton magnetic moment.
+pub const PROTON_MAGNETIC_MOMENT: Quantity<MagneticMoment> =
+    Quantity::new(constants::PROTON_MAGNETIC_MOMENT);
+/// Neutron magnetic moment.
+pub const NEUTRON_MAGNETIC_MOMENT: Quantity<MagneticMoment> =
+    Quantity::new(constants::NEUTRON_MAGNETIC_MOMENT);
+/// Electron g-factor.
+pub const ELECTRON_G_FACTOR: Quantity<Dimensionless> =
+    Quantity::new(constants::ELECTRON_G_FACTOR);
+/// Proton g-factor.
+pub const PROTON_G_FACTOR: Quantity<Dimensionless> =
+    Quantity::new(constants::PROTON_G_FACTOR);
+/// Electron volt.
+pub const ELECTRON_VOLT: Quantity<Energy> =
+    Quantity::new(constants::ELECTRON_VOLT);
+/// Electron volt–kilogram relationship.
+pub const EV_TO_KG: Quantity<Mass> = Quantity::new(constants::EV_TO_KG);
+/// Electron volt–atomic mass unit relationship, converted from `u`
+/// to SI.
+pub const EV_TO_AMU: Quantity<Mass> =
+    Quantity::new(constants::EV_TO_AMU * units::DALTON.scale);
+/// Electron volt–hertz relationship.
+pub const EV_TO_HZ: Quantity<Frequency> =
+    Quantity::new(constants::EV_TO_HZ);
+/// Electron volt–kelvin relationship.
+pub const EV_TO_KELVIN: Quantity<Temperature> =
+    Quantity::new(constants::EV_TO_KELVIN);
+/// Electron volt–inverse metre relationship.
+pub const EV_TO_INVERSE_METER: Quantity<Wavenumber> =
+    Quantity::new(constants::EV_TO_INVERSE_METER);
+/// Classical electron radius.
+pub const CLASSICAL_ELECTRON_RADIUS: Quantity<Length> =
+    Quantity::new(constants::CLASSICAL_ELECTRON_RADIUS);
+/// Electron Compton wavelength.
+pub const ELECTRON_COMPTON_WAVELENGTH: Quantity<Length> =
+    Quantity::new(constants::ELECTRON_COMPTON_WAVELENGTH);
+/// Proton Compton wavelength.
+pub const PROTON_COMPTON_WAVELENGTH: Quantity<Length> =
+    Quantity::new(constants::PROTON_COMPTON_WAVELENGTH);
+/// Neutron Compton wavelength.
+pub const NEUTRON_COMPTON_WAVELENGTH: Quantity<Length> =
+    Quantity::new(constants::NEUTRON_COMPTON_WAVELENGTH);
+/// Thomson cross section.
+pub const THOMSON_CROSS_SECTION: Quantity<Area> =
+    Quantity::new(constants::THOMSON_CROSS_SECTION);
+/// First radiation constant.
+pub const FIRST_RADIATION_CONSTANT: Quantity<PowerArea> =
+    Quantity::new(constants::FIRST_RADIATION_CONSTANT);
+/// Second radiation constant.
+pub const SECOND_RADIATION_CONSTANT: Quantity<LengthTemperature> =
+    Quantity::new(constants::SECOND_RADIATION_CONSTANT);
+/// Josephson constant.
+pub const JOSEPHSON_CONSTANT: Quantity<FrequencyPerVoltage> =
+    Quantity::new(constants::JOSEPHSON_CONSTANT);
+/// Von Klitzing constant.
+pub const VON_KLITZING_CONSTANT: Quantity<Resistance> =
+    Quantity::new(constants::VON_KLITZING_CONSTANT);
+/// Hartree energy.
+pub const HARTREE_ENERGY: Quantity<Energy> =
+    Quantity::new(constants::HARTREE_ENERGY);
+/// Hartree energy in electron volts, converted from `eV` to SI.
+pub const HARTREE_ENERGY_EV: Quantity<Energy> = Quantity::new(
+    constants::HARTREE_ENERGY_EV * units::ELECTRONVOLT.scale,
+);
+/// Planck mass.
+pub const PLANCK_MASS: Quantity<Mass> =
+    Quantity::new(constants::PLANCK_MASS);
+/// Planck length.
+pub const PLANCK_LENGTH: Quantity<Length> =
+    Quantity::new(constants::PLANCK_LENGTH);
+/// Planck time.
+pub const PLANCK_TIME: Quantity<Time> =
+    Quantity::new(constants::PLANCK_TIME);
+/// Planck temperature.
+pub const PLANCK_TEMPERATURE: Quantity<Temperature> =
+    Quantity::new(constants::PLANCK_TEMPERATURE);
+/// Planck charge.
+pub const PLANCK_CHARGE: Quantity<Charge> =
+    Quantity::new(constants::PLANCK_CHARGE);
+/// Molar mass constant.
+pub const MOLAR_MASS_CONSTANT: Quantity<MolarMass> =
+    Quantity::new(constants::MOLAR_MASS_CONSTANT);
+/// Molar Planck constant.
+pub const MOLAR_PLANCK_CONSTANT: Quantity<MolarAction> =
+    Quantity::new(constants::MOLAR_PLANCK_CONSTANT);
+/// Loschmidt constant (273.15 K, 101.325 kPa).
+pub const LOSCHMIDT_CONSTANT: Quantity<NumberDensity> =
+    Quantity::new(constants::LOSCHMIDT_CONSTANT);
+/// Molar volume of an ideal gas (273.15 K, 100 kPa).
+pub const MOLAR_VOLUME_IDEAL_GAS: Quantity<MolarVolume> =
+    Quantity::new(constants::MOLAR_VOLUME_IDEAL_GAS);
+/// Sackur–Tetrode constant (1 K, 101.325 kPa).
+pub const SACKUR_TETRODE_CONSTANT: Quantity<Dimensionless> =
+    Quantity::new(constants::SACKUR_TETRODE_CONSTANT);
+/// Characteristic impedance of vacuum.
+pub const IMPEDANCE_OF_FREE_SPACE: Quantity<Resistance> =
+    Quantity::new(constants::IMPEDANCE_OF_FREE_SPACE);
+/// Inverse fine-structure constant.
+pub const INVERSE_FINE_STRUCTURE: Quantity<Dimensionless> =
+    Quantity::new(constants::INVERSE_FINE_STRUCTURE);
+/// Electron charge-to-mass quotient.
+pub const ELECTRON_CHARGE_TO_MASS: Quantity<SpecificCharge> =
+    Quantity::new(constants::ELECTRON_CHARGE_TO_MASS);
+/// Proton charge-to-mass quotient.
+pub const PROTON_CHARGE_TO_MASS: Quantity<SpecificCharge> =
+    Quantity::new(constants::PROTON_CHARGE_TO_MASS);
+/// Atomic unit of length.
+pub const ATOMIC_UNIT_OF_LENGTH: Quantity<Length> =
+    Quantity::new(constants::ATOMIC_UNIT_OF_LENGTH);
+/// Atomic unit of time.
+pub const ATOMIC_UNIT_OF_TIME: Quantity<Time> =
+    Quantity::new(constants::ATOMIC_UNIT_OF_TIME);
+/// Atomic unit of velocity.
+pub const ATOMIC_UNIT_OF_VELOCITY: Quantity<Velocity> =
+    Quantity::new(constants::ATOMIC_UNIT_OF_VELOCITY);
+/// Atomic unit of force.
+pub const ATOMIC_UNIT_OF_FORCE: Quantity<Force> =
+    Quantity::new(constants::ATOMIC_UNIT_OF_FORCE);
+/// Atomic unit of electric field.
+pub const ATOMIC_UNIT_OF_ELECTRIC_FIELD: Quantity<ElectricField> =
+    Quantity::new(constants::ATOMIC_UNIT_OF_ELECTRIC_FIELD);
+/// Atomic unit of electric polarizability.
+pub const ATOMIC_UNIT_OF_POLARIZABILITY: Quantity<Polarizability> =
+    Quantity::new(constants::ATOMIC_UNIT_OF_POLARIZABILITY);
+/// W boson mass, converted from `GeV/c^2` to SI.
+pub const W_BOSON_MASS_GEV: Quantity<Mass> = Quantity::new(
+    constants::W_BOSON_MASS_GEV * units::GIGAELECTRONVOLT_PER_C2.scale,
+);
+/// Z boson mass, converted from `GeV/c^2` to SI.
+pub const Z_BOSON_MASS_GEV: Quantity<Mass> = Quantity::new(
+    constants::Z_BOSON_MASS_GEV * units::GIGAELECTRONVOLT_PER_C2.scale,
+);
+/// Higgs boson mass, converted from `GeV/c^2` to SI.
+pub const HIGGS_BOSON_MASS_GEV: Quantity<Mass> = Quantity::new(
+    constants::HIGGS_BOSON_MASS_GEV
+        * units::GIGAELECTRONVOLT_PER_C2.scale,
+);
+/// Electron mass energy equivalent in MeV, converted from `MeV/c^2`
+/// to SI.
+pub const ELECTRON_MASS_MEV: Quantity<Mass> = Quantity::new(
+    constants::ELECTRON_MASS_MEV * units::MEGAELECTRONVOLT_PER_C2.scale,
+);
+/// Proton mass energy equivalent in MeV, converted from `MeV/c^2`
+/// to SI.
+pub const PROTON_MASS_MEV: Quantity<Mass> = Quantity::new(
+    constants::PROTON_MASS_MEV * units::MEGAELECTRONVOLT_PER_C2.scale,
+);
+/// Neutron mass energy equivalent in MeV, converted from `MeV/c^2`
+/// to SI.
+pub const NEUTRON_MASS_MEV: Quantity<Mass> = Quantity::new(
+    constants::NEUTRON_MASS_MEV * units::MEGAELECTRONVOLT_PER_C2.scale,
+);
+/// Muon mass energy equivalent in MeV, converted from `MeV/c^2` to
+/// SI.
+pub const MUON_MASS_MEV: Quantity<Mass> = Quantity::new(
+    constants::MUON_MASS_MEV * units::MEGAELECTRONVOLT_PER_C2.scale,
+);
+/// Reduced electron Compton wavelength.
+pub const ELECTRON_REDUCED_COMPTON: Quantity<Length> =
+    Quantity::new(constants::ELECTRON_REDUCED_COMPTON);
+/// Reduced proton Compton wavelength.
+pub const PROTON_REDUCED_COMPTON: Quantity<Length> =
+    Quantity::new(constants::PROTON_REDUCED_COMPTON);
+/// Reduced neutron Compton wavelength.
+pub const NEUTRON_REDUCED_COMPTON: Quantity<Length> =
+    Quantity::new(constants::NEUTRON_REDUCED_COMPTON);
+/// Molar gas constant in litre-atmospheres, converted from `L atm
+/// mol^-1 K^-1` to SI.
+pub const GAS_CONSTANT_L_ATM: Quantity<MolarEntropy> = Quantity::new(
+    constants::GAS_CONSTANT_L_ATM
+        * units::LITER_ATMOSPHERE_PER_MOLE_KELVIN.scale,
+);
@@ -0,0 +1,136 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! `Mul` and `Div` between any two [`Quantity`] dimensions.
+//!
+//! Stable Rust cannot add const generic parameters, so the
+//! exponents of the result are looked up in a table of
+//! [`AddExponent`] and [`SubExponent`] impls covering every sum and
+//! difference between -12 and 12. A product or quotient whose
+//! exponents leave that range does not compile.
+
+use super::{AddExponent, Dim, Exponent, Quantity, SubExponent};
+use core::ops::{Div, Mul};
+
+macro_rules! exponents {
+    ($($a:literal + [$($b:literal)*] = [$($sum:literal)*];)*) => {$($(
+        impl AddExponent<Exponent<$b>> for Exponent<$a> {
+            type Output = Exponent<$sum>;
+        }
+
+        impl SubExponent<Exponent<$b>> for Exponent<$sum> {
+            type Output = Exponent<$a>;
+        }
+    )*)*};
+}
+
+macro_rules! arithmetic {
+    ($op:ident, $method:ident, $exponent:ident, $symbol:tt) => {
+        impl<
+                const L1: i8,
+                const M1: i8,
+                const T1: i8,
+                const I1: i8,
+                const K1: i8,
+                const N1: i8,
+                const J1: i8,
+                const L2: i8,
+                const M2: i8,
+                const T2: i8,
+                const I2: i8,
+                const K2: i8,
+                const N2: i8,
+                const J2: i8,
+                const L: i8,
+                const M: i8,
+                const T: i8,
+                const I: i8,
+                const K: i8,
+                const N: i8,
+                const J: i8,
+            > $op<Quantity<Dim<L2, M2, T2, I2, K2, N2, J2>>>
+            for Quantity<Dim<L1, M1, T1, I1, K1, N1, J1>>
+        where
+            Exponent<L1>: $exponent<Exponent<L2>, Output = Exponent<L>>,
+            Exponent<M1>: $exponent<Exponent<M2>, Output = Exponent<M>>,
+            Exponent<T1>: $exponent<Exponent<T2>, Output = Exponent<T>>,
+            Exponent<I1>: $exponent<Exponent<I2>, Output = Exponent<I>>,
+            Exponent<K1>: $exponent<Exponent<K2>, Output = Exponent<K>>,
+            Exponent<N1>: $exponent<Exponent<N2>, Output = Exponent<N>>,
+            Exponent<J1>: $exponent<Exponent<J2>, Output = Exponent<J>>,
+        {
+            type Output = Quantity<Dim<L, M, T, I, K, N, J>>;
+
+            #[inline]
+            fn $method(
+                self,
+                rhs: Quantity<Dim<L2, M2, T2, I2, K2, N2, J2>>,
+            ) -> Self::Output {
+                Quantity::new(self.value $symbol rhs.value)
+            }
+        }
+    };
+}
+
+arithmetic!(Mul, mul, AddExponent, *);
+arithmetic!(Div, div, SubExponent, /);
+
+exponents! {
+    -12 + [0 1 2 3 4 5 6 7 8 9 10 11 12]
+        = [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0];
+    -11 + [-1 0 1 2 3 4 5 6 7 8 9 10 11 12]
+        = [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1];
+    -10 + [-2 -1 0 1 2 3 4 5 6 7 8 9 10 11 12]
+        = [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2];
+    -9 + [-3 -2 -1 0 1 2 3 4 5 6 7 8 9 10 11 12]
+        = [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3];
+    -8 + [-4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10 11 12]
+        = [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4];
+    -7 + [-5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10 11 12]
+        = [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5];
+    -6 + [-6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10 11 12]
+        = [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6];
+    -5 + [-7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10 11 12]
+        = [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7];
+    -4 + [-8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10 11 12]
+        = [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8];
+    -3 + [-9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10 11 12]
+        = [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9];
+    -2 + [-10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10 11 12]
+        = [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9
+            10];
+    -1 + [-11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10 11
+        12]
+        = [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10
+            11];
+    0 + [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10
+        11 12]
+        = [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10
+            11 12];
+    1 + [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10
+        11]
+        = [-11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10 11
+            12];
+    2 + [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10]
+        = [-10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10 11 12];
+    3 + [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9]
+        = [-9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10 11 12];
+    4 + [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8]
+        = [-8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10 11 12];
+    5 + [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7]
+        = [-7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10 11 12];
+    6 + [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6]
+        = [-6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10 11 12];
+    7 + [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5]
+        = [-5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10 11 12];
+    8 + [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4]
+        = [-4 -3 -2 -1 0 1 2 3 4 5 6 7 8 9 10 11 12];
+    9 + [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3]
+        = [-3 -2 -1 0 1 2 3 4 5 6 7 8 9 10 11 12];
+    10 + [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1 2]
+        = [-2 -1 0 1 2 3 4 5 6 7 8 9 10 11 12];
+    11 + [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0 1]
+        = [-1 0 1 2 3 4 5 6 7 8 9 10 11 12];
+    12 + [-12 -11 -10 -9 -8 -7 -6 -5 -4 -3 -2 -1 0]
+        = [0 1 2 3 4 5 6 7 8 9 10 11 12];
+}
@@ -0,0 +1,559 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Conversions between SI and the Planck, Hartree atomic, natural
+//! and Gaussian CGS unit systems.
+//!
+//! A [`UnitSystem`] expresses a quantity as a multiple of its own
+//! unit for the quantity's SI [`Dimension`]. The unit is built from
+//! the system's base scales, all taken from the CODATA 2018 values
+//! in [`crate::constants`], whether or not the `codata2022` feature
+//! is enabled:
+//!
+//! | System | Length | Mass | Time | Charge | Temperature |
+//! |:---|:---|:---|:---|:---|:---|
+//! | Planck | l_P | m_P | t_P | q_P | T_P |
+//! | Hartree atomic | a₀ | m_e | ħ/E_h | e | E_h/k |
+//! | Natural | ħc/GeV | GeV/c² | ħ/GeV | √(ε₀ħc) | GeV/k |
+//! | Gaussian CGS | cm | g | s | statC | K |
+//!
+//! So ħ, c and k are 1 in Planck and natural units, G too in Planck
+//! units, and ħ, m_e, e, a₀ and k in atomic units. The Planck and
+//! atomic systems are unrationalised (4πε₀ = 1); natural units are
+//! the Heaviside-Lorentz units of particle physics (ε₀ = 1, so
+//! e = √(4πα)), with every quantity a power of the GeV given by
+//! [`natural_power`]. An amount of substance becomes a number of
+//! particles in all three.
+//!
+//! Gaussian electromagnetic units are not products of base units:
+//! the field equations change form, and B, H and M differ from
+//! their SI counterparts by more than a scale. They are listed per
+//! [`Electromagnetic`] quantity instead.
+//!
+//! [`UnitSystem`]: crate::unit_systems::UnitSystem
+//! [`Dimension`]: crate::units::Dimension
+//! [`natural_power`]: crate::unit_systems::natural_power
+//! [`Electromagnetic`]: crate::unit_systems::Electromagnetic
+//!
+//! # Example
+//!
+//! ```
+//! use cmn::constants::{ELECTRON_VOLT, PROTON_MASS, SPEED_OF_LIGHT};
+//! use cmn::unit_systems::UnitSystem;
+//! use cmn::units::{Dimension, JOULE, KILOGRAM};
+//!
+//! // The speed of light is 1/α in atomic units.
+//! let c = UnitSystem::Atomic.from_si(
+//!     SPEED_OF_LIGHT,
+//!     Dimension::new(1, 0, -1, 0, 0, 0, 0),
+//! );
+//! assert!((c.unwrap() - 137.036).abs() < 1e-3);
+//!
+//! // The proton mass is 0.938 GeV in natural units.
+//! let natural = UnitSystem::Natural;
+//! let m_p = natural.from_si(PROTON_MASS, KILOGRAM.dimension);
+//! assert!((m_p.unwrap() - 0.938_272).abs() < 1e-6);
+//!
+//! // One electron volt is 1.602 × 10⁻¹² erg.
+//! let gaussian = UnitSystem::Gaussian;
+//! let erg = gaussian.from_si(ELECTRON_VOLT, JOULE.dimension);
+//! assert!((erg.unwrap() / 1.602_176_634e-12 - 1.0).abs() < 1e-12);
+//! ```
+
+use crate::constants::{
+    sqrt, ATOMIC_UNIT_OF_LENGTH, ATOMIC_UNIT_OF_TIME, AVOGADRO,
+    BOLTZMANN, ELECTRON_MASS, ELECTRON_VOLT, ELEMENTARY_CHARGE,
+    HARTREE_ENERGY, PI, PLANCK_CHARGE, PLANCK_LENGTH, PLANCK_MASS,
+    PLANCK_REDUCED, PLANCK_TEMPERATURE, PLANCK_TIME, SPEED_OF_LIGHT,
+    VACUUM_PERMEABILITY, VACUUM_PERMITTIVITY,
+};
+use crate::quantities::{Dimensioned, Quantity};
+use crate::units::Dimension;
+use core::fmt;
+
+/// A system of units other than SI.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum UnitSystem {
+    /// Planck units: G = ħ = c = k = 4πε₀ = 1.
+    Planck,
+    /// Hartree atomic units: ħ = m_e = e = a₀ = k = 4πε₀ = 1.
+    Atomic,
+    /// Particle-physics natural units: ħ = c = k = ε₀ = 1, with
+    /// energies in GeV.
+    Natural,
+    /// Gaussian CGS: centimetre, gram, second and statcoulomb.
+    Gaussian,
+}
+
+impl UnitSystem {
+    /// Every system, in declaration order.
+    pub const ALL: [Self; 4] =
+        [Self::Planck, Self::Atomic, Self::Natural, Self::Gaussian];
+
+    /// Returns the display name, e.g. `"Hartree atomic"`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Planck => "Planck",
+            Self::Atomic => "Hartree atomic",
+            Self::Natural => "natural",
+            Self::Gaussian => "Gaussian CGS",
+        }
+    }
+
+    /// Returns the SI value of this system's unit of `dimension`.
+    ///
+    /// # Errors
+    ///
+    /// [`UnitSystemError::Unsupported`] if the system has no unit
+    /// for the dimension, and [`UnitSystemError::Ambiguous`] if
+    /// Gaussian units depend on which quantity of that dimension
+    /// is meant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::unit_systems::{Electromagnetic, UnitSystem};
+    /// use cmn::units::JOULE;
+    ///
+    /// // Derived units agree with the tabulated constants to the
+    /// // precision of their published digits.
+    /// let hartree = UnitSystem::Atomic.unit(JOULE.dimension);
+    /// let e_h = cmn::constants::HARTREE_ENERGY;
+    /// assert!((hartree.unwrap() / e_h - 1.0).abs() < 1e-10);
+    ///
+    /// // One gauss is 10⁻⁴ T.
+    /// let b = Electromagnetic::MagneticFluxDensity.dimension();
+    /// let gauss = UnitSystem::Gaussian.unit(b).unwrap();
+    /// assert!((gauss / 1e-4 - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn unit(
+        self,
+        dimension: Dimension,
+    ) -> Result<f64, UnitSystemError> {
+        let unsupported = UnitSystemError::Unsupported {
+            system: self,
+            dimension,
+        };
+        let base = match self {
+            Self::Planck => PLANCK,
+            Self::Atomic => ATOMIC,
+            Self::Natural => natural(),
+            Self::Gaussian => return gaussian(dimension),
+        };
+        if dimension.luminous_intensity != 0 {
+            return Err(unsupported);
+        }
+        Ok(base.unit(dimension))
+    }
+
+    /// Converts `value`, in SI units of `dimension`, to this
+    /// system.
+    ///
+    /// # Errors
+    ///
+    /// As for [`UnitSystem::unit`].
+    pub fn from_si(
+        self,
+        value: f64,
+        dimension: Dimension,
+    ) -> Result<f64, UnitSystemError> {
+        Ok(value / self.unit(dimension)?)
+    }
+
+    /// Converts `value`, in this system's unit of `dimension`, to
+    /// SI.
+    ///
+    /// # Errors
+    ///
+    /// As for [`UnitSystem::unit`].
+    pub fn to_si(
+        self,
+        value: f64,
+        dimension: Dimension,
+    ) -> Result<f64, UnitSystemError> {
+        Ok(value * self.unit(dimension)?)
+    }
+
+    /// Expresses a typed SI quantity in this system.
+    ///
+    /// # Errors
+    ///
+    /// As for [`UnitSystem::unit`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::quantities::{Length, Quantity, PLANCK_LENGTH};
+    /// use cmn::unit_systems::UnitSystem;
+    ///
+    /// let l = UnitSystem::Planck.from_quantity(PLANCK_LENGTH);
+    /// assert_eq!(l, Ok(1.0));
+    ///
+    /// // 1 GeV⁻¹ is 0.197 fm.
+    /// let length: Quantity<Length> =
+    ///     UnitSystem::Natural.to_quantity(1.0).unwrap();
+    /// assert!((length.value() / 1.973_27e-16 - 1.0).abs() < 1e-5);
+    /// ```
+    pub fn from_quantity<D: Dimensioned>(
+        self,
+        quantity: Quantity<D>,
+    ) -> Result<f64, UnitSystemError> {
+        self.from_si(quantity.value(), D::DIMENSION)
+    }
+
+    /// Converts `value`, in this system's unit of `D`, to a typed
+    /// SI quantity.
+    ///
+    /// # Errors
+    ///
+    /// As for [`UnitSystem::unit`].
+    pub fn to_quantity<D: Dimensioned>(
+        self,
+        value: f64,
+    ) -> Result<Quantity<D>, UnitSystemError> {
+        self.to_si(value, D::DIMENSION).map(Quantity::new)
+    }
+}
+
+impl fmt::Display for UnitSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Error returned when a quantity has no unit in a system.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnitSystemError {
+    /// The system has no unit for the dimension, e.g. the candela
+    /// in Planck units.
+    Unsupported {
+        /// The target system.
+        system: UnitSystem,
+        /// The SI dimension of the quantity.
+        dimension: Dimension,
+    },
+    /// Several electromagnetic quantities share the SI dimension
+    /// but have different Gaussian units (D and P, H and M); use
+    /// [`Electromagnetic`] to say which is meant.
+    Ambiguous {
+        /// The SI dimension of the quantity.
+        dimension: Dimension,
+    },
+}
+
+impl fmt::Display for UnitSystemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsupported { system, dimension } => {
+                write!(f, "no {system} unit for {dimension}")
+            }
+            Self::Ambiguous { dimension } => write!(
+                f,
+                "the Gaussian unit for {dimension} depends on \
+                 the quantity"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnitSystemError {}
+
+/// Returns the power of the GeV that a quantity of `dimension`
+/// has in natural units, or `None` for the candela.
+///
+/// With ħ = c = k = ε₀ = 1, lengths and times go as GeV⁻¹,
+/// masses, temperatures and currents as GeV, and charge and amount
+/// of substance are pure numbers.
+///
+/// # Example
+///
+/// ```
+/// use cmn::unit_systems::natural_power;
+/// use cmn::units::{JOULE_SECOND, METER, NEWTON};
+///
+/// assert_eq!(natural_power(JOULE_SECOND.dimension), Some(0));
+/// assert_eq!(natural_power(METER.dimension), Some(-1));
+/// assert_eq!(natural_power(NEWTON.dimension), Some(2));
+/// ```
+pub const fn natural_power(dimension: Dimension) -> Option<i8> {
+    if dimension.luminous_intensity != 0 {
+        return None;
+    }
+    Some(
+        dimension.mass - dimension.length - dimension.time
+            + dimension.current
+            + dimension.temperature,
+    )
+}
+
+/// The SI values of a system's base units.
+struct Base {
+    length: f64,
+    mass: f64,
+    time: f64,
+    charge: f64,
+    temperature: f64,
+    /// One particle, in moles.
+    amount: f64,
+}
+
+impl Base {
+    fn unit(&self, dimension: Dimension) -> f64 {
+        powi(self.length, dimension.length)
+            * powi(self.mass, dimension.mass)
+            * powi(self.time, dimension.time)
+            * powi(self.charge / self.time, dimension.current)
+            * powi(self.temperature, dimension.temperature)
+            * powi(self.amount, dimension.amount)
+    }
+}
+
+// The scales use the CODATA 2018 constants, not Dataset::DEFAULT.
+const PLANCK: Base = Base {
+    length: PLANCK_LENGTH,
+    mass: PLANCK_MASS,
+    time: PLANCK_TIME,
+    charge: PLANCK_CHARGE,
+    temperature: PLANCK_TEMPERATURE,
+    amount: 1.0 / AVOGADRO,
+};
+
+const ATOMIC: Base = Base {
+    length: ATOMIC_UNIT_OF_LENGTH,
+    mass: ELECTRON_MASS,
+    time: ATOMIC_UNIT_OF_TIME,
+    charge: ELEMENTARY_CHARGE,
+    temperature: HARTREE_ENERGY / BOLTZMANN,
+    amount: 1.0 / AVOGADRO,
+};
+
+/// One GeV, in joules.
+const GEV: f64 = 1e9 * ELECTRON_VOLT;
+
+fn natural() -> Base {
+    Base {
+        length: PLANCK_REDUCED * SPEED_OF_LIGHT / GEV,
+        mass: GEV / (SPEED_OF_LIGHT * SPEED_OF_LIGHT),
+        time: PLANCK_REDUCED / GEV,
+        charge: sqrt(
+            VACUUM_PERMITTIVITY * PLANCK_REDUCED * SPEED_OF_LIGHT,
+        ),
+        temperature: GEV / BOLTZMANN,
+        amount: 1.0 / AVOGADRO,
+    }
+}
+
+/// Returns the SI value of the Gaussian unit of `dimension`: the
+/// centimetre, gram and second for mechanics, the kelvin, mole and
+/// candela unchanged, and the [`Electromagnetic`] unit of the same
+/// SI dimension for the rest.
+fn gaussian(dimension: Dimension) -> Result<f64, UnitSystemError> {
+    let mechanical =
+        powi(1e-2, dimension.length) * powi(1e-3, dimension.mass);
+    if dimension.current == 0 {
+        return Ok(mechanical);
+    }
+    let electromagnetic = Dimension {
+        temperature: 0,
+        amount: 0,
+        luminous_intensity: 0,
+        ..dimension
+    };
+    let mut matches = Electromagnetic::ALL
+        .into_iter()
+        .filter(|kind| kind.dimension() == electromagnetic);
+    match (matches.next(), matches.next()) {
+        (Some(kind), None) => Ok(kind.gaussian_unit()),
+        (Some(_), Some(_)) => {
+            Err(UnitSystemError::Ambiguous { dimension })
+        }
+        (None, _) => Err(UnitSystemError::Unsupported {
+            system: UnitSystem::Gaussian,
+            dimension,
+        }),
+    }
+}
+
+/// Returns `x` raised to the integer power `n`; `core` has no
+/// `f64::powi`.
+fn powi(x: f64, n: i8) -> f64 {
+    let mut result = 1.0;
+    for _ in 0..n.unsigned_abs() {
+        result *= x;
+    }
+    if n < 0 {
+        1.0 / result
+    } else {
+        result
+    }
+}
+
+/// An electromagnetic quantity, for conversions to and from
+/// Gaussian CGS units.
+///
+/// Gaussian units follow the usual correspondence (Jackson,
+/// *Classical Electrodynamics*, appendix): charge scales by
+/// √(4πε₀), fields by 1/√(4πε₀) or √(μ₀/4π), and D and H carry an
+/// extra 4π.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Electromagnetic {
+    /// Electric charge: statcoulomb.
+    Charge,
+    /// Electric current: statampere.
+    Current,
+    /// Charge density: statcoulomb per cm³.
+    ChargeDensity,
+    /// Current density: statampere per cm².
+    CurrentDensity,
+    /// Electric potential: statvolt.
+    ElectricPotential,
+    /// Electric field E: statvolt per cm.
+    ElectricField,
+    /// Electric displacement D.
+    ElectricDisplacement,
+    /// Polarization P: statcoulomb per cm².
+    Polarization,
+    /// Electric dipole moment: statcoulomb centimetre.
+    ElectricDipoleMoment,
+    /// Magnetic flux density B: gauss.
+    MagneticFluxDensity,
+    /// Magnetic field strength H: oersted.
+    MagneticFieldStrength,
+    /// Magnetization M: emu per cm³.
+    Magnetization,
+    /// Magnetic flux: maxwell.
+    MagneticFlux,
+    /// Magnetic vector potential A: gauss centimetre.
+    VectorPotential,
+    /// Magnetic dipole moment: erg per gauss.
+    MagneticMoment,
+    /// Resistance: second per centimetre.
+    Resistance,
+    /// Conductance: centimetre per second.
+    Conductance,
+    /// Capacitance: centimetre.
+    Capacitance,
+    /// Inductance: second² per centimetre.
+    Inductance,
+    /// Conductivity: per second.
+    Conductivity,
+    /// Permittivity: dimensionless, 1 in vacuum.
+    Permittivity,
+    /// Permeability: dimensionless, 1 in vacuum.
+    Permeability,
+}
+
+impl Electromagnetic {
+    /// Every quantity, in declaration order.
+    pub const ALL: [Self; 22] = [
+        Self::Charge,
+        Self::Current,
+        Self::ChargeDensity,
+        Self::CurrentDensity,
+        Self::ElectricPotential,
+        Self::ElectricField,
+        Self::ElectricDisplacement,
+        Self::Polarization,
+        Self::ElectricDipoleMoment,
+        Self::MagneticFluxDensity,
+        Self::MagneticFieldStrength,
+        Self::Magnetization,
+        Self::MagneticFlux,
+        Self::VectorPotential,
+        Self::MagneticMoment,
+        Self::Resistance,
+        Self::Conductance,
+        Self::Capacitance,
+        Self::Inductance,
+        Self::Conductivity,
+        Self::Permittivity,
+        Self::Permeability,
+    ];
+
+    /// Returns the SI dimension of the quantity.
+    pub const fn dimension(self) -> Dimension {
+        let (length, mass, time, current) = match self {
+            Self::Charge => (0, 0, 1, 1),
+            Self::Current => (0, 0, 0, 1),
+            Self::ChargeDensity => (-3, 0, 1, 1),
+            Self::CurrentDensity => (-2, 0, 0, 1),
+            Self::ElectricPotential => (2, 1, -3, -1),
+            Self::ElectricField => (1, 1, -3, -1),
+            Self::ElectricDisplacement | Self::Polarization => {
+                (-2, 0, 1, 1)
+            }
+            Self::ElectricDipoleMoment => (1, 0, 1, 1),
+            Self::MagneticFluxDensity => (0, 1, -2, -1),
+            Self::MagneticFieldStrength | Self::Magnetization => {
+                (-1, 0, 0, 1)
+            }
+            Self::MagneticFlux => (2, 1, -2, -1),
+            Self::VectorPotential => (1, 1, -2, -1),
+            Self::MagneticMoment => (2, 0, 0, 1),
+            Self::Resistance => (2, 1, -3, -2),
+            Self::Conductance => (-2, -1, 3, 2),
+            Self::Capacitance => (-2, -1, 4, 2),
+            Self::Inductance => (2, 1, -2, -2),
+            Self::Conductivity => (-3, -1, 3, 2),
+            Self::Permittivity => (-3, -1, 4, 2),
+            Self::Permeability => (1, 1, -2, -2),
+        };
+        Dimension::new(length, mass, time, current, 0, 0, 0)
+    }
+
+    /// Returns the SI value of the quantity's Gaussian unit, from
+    /// ε₀ and μ₀.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::unit_systems::Electromagnetic;
+    ///
+    /// // 1 statC = 3.336 × 10⁻¹⁰ C and 1 Oe = 1000/(4π) A/m.
+    /// let statc = Electromagnetic::Charge.gaussian_unit();
+    /// assert!((statc / 3.335_64e-10 - 1.0).abs() < 1e-5);
+    /// let h = Electromagnetic::MagneticFieldStrength;
+    /// let oersted = 1e3 / (4.0 * core::f64::consts::PI);
+    /// assert!((h.gaussian_unit() / oersted - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn gaussian_unit(self) -> f64 {
+        // Each Gaussian unit is √(4πε₀) or √(μ₀/4π), to the power
+        // that links the two systems, times the CGS-to-SI factor of
+        // its mechanical dimension.
+        let e = 4.0 * PI * VACUUM_PERMITTIVITY;
+        let m = VACUUM_PERMEABILITY / (4.0 * PI);
+        let statcoulomb = sqrt(1e-9 * e);
+        match self {
+            Self::Charge | Self::Current => statcoulomb,
+            Self::ChargeDensity => 1e6 * statcoulomb,
+            Self::CurrentDensity => 1e4 * statcoulomb,
+            Self::ElectricPotential => sqrt(1e-5 / e),
+            Self::ElectricField => sqrt(0.1 / e),
+            Self::ElectricDisplacement => sqrt(0.1 * e) / (4.0 * PI),
+            Self::Polarization => sqrt(0.1 * e),
+            Self::ElectricDipoleMoment => 1e-2 * statcoulomb,
+            Self::MagneticFluxDensity => sqrt(0.1 * m),
+            Self::MagneticFieldStrength => sqrt(0.1 / m) / (4.0 * PI),
+            Self::Magnetization => sqrt(0.1 / m),
+            Self::MagneticFlux => sqrt(1e-9 * m),
+            Self::VectorPotential => sqrt(1e-5 * m),
+            Self::MagneticMoment => sqrt(1e-13 / m),
+            Self::Resistance | Self::Inductance => 1e2 / e,
+            Self::Conductance | Self::Capacitance => 1e-2 * e,
+            Self::Conductivity => e,
+            Self::Permittivity => VACUUM_PERMITTIVITY,
+            Self::Permeability => VACUUM_PERMEABILITY,
+        }
+    }
+
+    /// Converts `value`, in SI units, to Gaussian units.
+    pub fn to_gaussian(self, value: f64) -> f64 {
+        value / self.gaussian_unit()
+    }
+
+    /// Converts `value`, in Gaussian units, to SI.
+    pub fn from_gaussian(self, value: f64) -> f64 {
+        value * self.gaussian_unit()
+    }
+}
@@ -0,0 +1,1015 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Machine-readable SI units for the constants in
+//! [`crate::constants`].
+//!
+//! A [`Unit`](crate::units::Unit) pairs a display symbol with a
+//! [`Dimension`](crate::units::Dimension) (the exponents of the seven
+//! SI base units) and the factor that converts one of it into
+//! coherent SI, plus an offset for temperature scales such as the
+//! degree Celsius. Everything here is `const` and available in
+//! `no_std`.
+//!
+//! # Example
+//!
+//! ```
+//! use cmn::units::{Dimension, JOULE_SECOND};
+//!
+//! assert_eq!(JOULE_SECOND.symbol, "J s");
+//! assert_eq!(
+//!     JOULE_SECOND.dimension,
+//!     Dimension::new(2, 1, -1, 0, 0, 0, 0)
+//! );
+//! assert_eq!(JOULE_SECOND.dimension.to_string(), "m^2 kg s^-1");
+//! ```
+//!
+//! [`convert`](crate::units::convert) converts between unit symbols,
+//! including across dimensions related by a physical constant
+//! (energy, mass, frequency, temperature, wavenumber and molar
+//! energy):
+//!
+//! ```
+//! use cmn::units::convert;
+//!
+//! assert_eq!(convert(1.0, "eV", "J"), Ok(1.602_176_634e-19));
+//! let kcal = convert(1.0, "hartree", "kcal/mol").unwrap();
+//! assert!((kcal - 627.509_474).abs() < 1e-5);
+//! ```
+
+use crate::constants::{
+    ATOMIC_MASS_UNIT, ATOMIC_UNIT_OF_LENGTH, ATOMIC_UNIT_OF_TIME,
+    AVOGADRO, BOLTZMANN, ELECTRON_MASS, ELECTRON_VOLT, EV_TO_KG,
+    HARTREE_ENERGY, PLANCK, SPEED_OF_LIGHT, STANDARD_ATMOSPHERE,
+    STANDARD_GRAVITY,
+};
+use core::fmt;
+
+/// Exponents of the seven SI base units.
+///
+/// Field order follows the SI brochure: length (m), mass (kg),
+/// time (s), electric current (A), thermodynamic temperature (K),
+/// amount of substance (mol) and luminous intensity (cd).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Dimension {
+    /// Exponent of the metre.
+    pub length: i8,
+    /// Exponent of the kilogram.
+    pub mass: i8,
+    /// Exponent of the second.
+    pub time: i8,
+    /// Exponent of the ampere.
+    pub current: i8,
+    /// Exponent of the kelvin.
+    pub temperature: i8,
+    /// Exponent of the mole.
+    pub amount: i8,
+    /// Exponent of the candela.
+    pub luminous_intensity: i8,
+}
+
+impl Dimension {
+    /// A dimensionless quantity (all exponents zero).
+    pub const NONE: Self = Self::new(0, 0, 0, 0, 0, 0, 0);
+
+    /// Creates a dimension from its seven base-unit exponents.
+    pub const fn new(
+        length: i8,
+        mass: i8,
+        time: i8,
+        current: i8,
+        temperature: i8,
+        amount: i8,
+        luminous_intensity: i8,
+    ) -> Self {
+        Self {
+            length,
+            mass,
+            time,
+            current,
+            temperature,
+            amount,
+            luminous_intensity,
+        }
+    }
+
+    /// Returns `true` if every exponent is zero.
+    pub const fn is_dimensionless(&self) -> bool {
+        self.length == 0
+            && self.mass == 0
+            && self.time == 0
+            && self.current == 0
+            && self.temperature == 0
+            && self.amount == 0
+            && self.luminous_intensity == 0
+    }
+
+    const fn exponents(&self) -> [(&'static str, i8); 7] {
+        [
+            ("m", self.length),
+            ("kg", self.mass),
+            ("s", self.time),
+            ("A", self.current),
+            ("K", self.temperature),
+            ("mol", self.amount),
+            ("cd", self.luminous_intensity),
+        ]
+    }
+}
+
+impl fmt::Display for Dimension {
+    /// Formats the dimension in SI base units, e.g. `m^2 kg s^-1`.
+    /// Dimensionless quantities are written as `1`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_dimensionless() {
+            return write!(f, "1");
+        }
+        let mut first = true;
+        for (symbol, exponent) in self.exponents() {
+            if exponent == 0 {
+                continue;
+            }
+            if !first {
+                write!(f, " ")?;
+            }
+            first = false;
+            if exponent == 1 {
+                write!(f, "{symbol}")?;
+            } else {
+                write!(f, "{symbol}^{exponent}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A unit of measurement: display symbol, SI dimension and the
+/// affine map that converts a value in this unit to coherent SI.
+///
+/// Coherent SI units such as the joule have a `scale` of `1.0`;
+/// non-SI units accepted for use with SI (the electron volt, the
+/// dalton, …) carry their SI value as the scale. Only temperature
+/// scales whose zero is not absolute zero have a non-zero `offset`:
+/// `x` in the unit is `x * scale + offset` in coherent SI.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Unit {
+    /// Display symbol, e.g. `"J s"` or `"eV"`. `"1"` when
+    /// dimensionless.
+    pub symbol: &'static str,
+    /// Exponents of the SI base units.
+    pub dimension: Dimension,
+    /// Value of one of this unit in coherent SI units.
+    pub scale: f64,
+    /// Value of zero in this unit in coherent SI units, e.g.
+    /// `273.15` for the degree Celsius.
+    pub offset: f64,
+}
+
+impl Unit {
+    /// Creates a coherent SI unit (scale `1.0`).
+    pub const fn si(
+        symbol: &'static str,
+        dimension: Dimension,
+    ) -> Self {
+        Self {
+            symbol,
+            dimension,
+            scale: 1.0,
+            offset: 0.0,
+        }
+    }
+
+    /// Creates a scaled unit whose value in coherent SI is `scale`.
+    pub const fn scaled(
+        symbol: &'static str,
+        dimension: Dimension,
+        scale: f64,
+    ) -> Self {
+        Self {
+            symbol,
+            dimension,
+            scale,
+            offset: 0.0,
+        }
+    }
+
+    /// Creates a unit whose value `x` is `x * scale + offset` in
+    /// coherent SI, such as a temperature scale.
+    pub const fn affine(
+        symbol: &'static str,
+        dimension: Dimension,
+        scale: f64,
+        offset: f64,
+    ) -> Self {
+        Self {
+            symbol,
+            dimension,
+            scale,
+            offset,
+        }
+    }
+
+    /// Returns `true` if both units measure the same dimension.
+    pub fn is_compatible(&self, other: &Self) -> bool {
+        self.dimension == other.dimension
+    }
+
+    /// Converts a value expressed in this unit to coherent SI.
+    pub fn to_si(&self, value: f64) -> f64 {
+        value * self.scale + self.offset
+    }
+
+    /// Converts a value in coherent SI to this unit.
+    pub fn from_si(&self, si: f64) -> f64 {
+        (si - self.offset) / self.scale
+    }
+
+    /// Converts a value expressed in this unit to `to`.
+    ///
+    /// Units of the same dimension convert by their scales and
+    /// offsets, so `°C` to `K` adds 273.15. Units
+    /// of different dimensions convert through their energy
+    /// equivalent when both are related to energy by an exact
+    /// constant: `E = mc²` (mass), `E = hν` (frequency), `E = kT`
+    /// (temperature), `E = hc/λ` (wavenumber) and `E = E_m/N_A`
+    /// (molar energy).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cmn::units::{
+    ///     ConversionError, ELECTRONVOLT, JOULE, KELVIN, METER,
+    /// };
+    ///
+    /// let e = ELECTRONVOLT.convert(2.0, &JOULE);
+    /// assert_eq!(e, Ok(3.204_353_268e-19));
+    /// let t = ELECTRONVOLT.convert(1.0, &KELVIN).unwrap();
+    /// assert!((t - 11_604.518).abs() < 1e-3);
+    /// assert!(matches!(
+    ///     METER.convert(1.0, &KELVIN),
+    ///     Err(ConversionError::Incompatible { .. })
+    /// ));
+    /// ```
+    pub fn convert(
+        &self,
+        value: f64,
+        to: &Self,
+    ) -> Result<f64, ConversionError> {
+        let si = self.to_si(value);
+        if self.is_compatible(to) {
+            return Ok(to.from_si(si));
+        }
+        let incompatible = ConversionError::Incompatible {
+            from: self.dimension,
+            to: to.dimension,
+        };
+        let from_joules =
+            joules_per_si(self.dimension).ok_or(incompatible)?;
+        let to_joules =
+            joules_per_si(to.dimension).ok_or(incompatible)?;
+        Ok(to.from_si(si * from_joules / to_joules))
+    }
+}
+
+/// Error returned by [`parse`], [`convert`] and [`Unit::convert`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConversionError {
+    /// A unit symbol is neither in the registry nor a prefixed SI
+    /// unit; see [`parse`].
+    UnknownUnit,
+    /// A unit expression is not a product of units, such as `m/s/`
+    /// or the ambiguous `J/mol K`; see [`parse`].
+    Malformed,
+    /// The units measure dimensions that are not related by a
+    /// physical constant.
+    Incompatible {
+        /// Dimension of the source unit.
+        from: Dimension,
+        /// Dimension of the target unit.
+        to: Dimension,
+    },
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownUnit => write!(f, "unknown unit"),
+            Self::Malformed => write!(f, "malformed unit"),
+            Self::Incompatible { from, to } => {
+                write!(f, "cannot convert {from} to {to}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol)
+    }
+}
+
+const fn dim(
+    length: i8,
+    mass: i8,
+    time: i8,
+    current: i8,
+    temperature: i8,
+    amount: i8,
+) -> Dimension {
+    Dimension::new(length, mass, time, current, temperature, amount, 0)
+}
+
+// ---------------------------------------------------------------
+// Base and named derived SI units
+// ---------------------------------------------------------------
+
+/// Dimensionless (`1`).
+pub const ONE: Unit = Unit::si("1", Dimension::NONE);
+/// Metre (`m`).
+pub const METER: Unit = Unit::si("m", dim(1, 0, 0, 0, 0, 0));
+/// Kilogram (`kg`).
+pub const KILOGRAM: Unit = Unit::si("kg", dim(0, 1, 0, 0, 0, 0));
+/// Second (`s`).
+pub const SECOND: Unit = Unit::si("s", dim(0, 0, 1, 0, 0, 0));
+/// Ampere (`A`).
+pub const AMPERE: Unit = Unit::si("A", dim(0, 0, 0, 1, 0, 0));
+/// Kelvin (`K`).
+pub const KELVIN: Unit = Unit::si("K", dim(0, 0, 0, 0, 1, 0));
+/// Mole (`mol`).
+pub const MOLE: Unit = Unit::si("mol", dim(0, 0, 0, 0, 0, 1));
+/// Candela (`cd`).
+pub const CANDELA: Unit =
+    Unit::si("cd", Dimension::new(0, 0, 0, 0, 0, 0, 1));
+/// Coulomb (`C`).
+pub const COULOMB: Unit = Unit::si("C", dim(0, 0, 1, 1, 0, 0));
+/// Hertz (`Hz`).
+pub const HERTZ: Unit = Unit::si("Hz", dim(0, 0, -1, 0, 0, 0));
+/// Newton (`N`).
+pub const NEWTON: Unit = Unit::si("N", dim(1, 1, -2, 0, 0, 0));
+/// Pascal (`Pa`).
+pub const PASCAL: Unit = Unit::si("Pa", dim(-1, 1, -2, 0, 0, 0));
+/// Joule (`J`).
+pub const JOULE: Unit = Unit::si("J", dim(2, 1, -2, 0, 0, 0));
+/// Watt (`W`).
+pub const WATT: Unit = Unit::si("W", dim(2, 1, -3, 0, 0, 0));
+/// Volt (`V`).
+pub const VOLT: Unit = Unit::si("V", dim(2, 1, -3, -1, 0, 0));
+/// Farad (`F`).
+pub const FARAD: Unit = Unit::si("F", dim(-2, -1, 4, 2, 0, 0));
+/// Henry (`H`).
+pub const HENRY: Unit = Unit::si("H", dim(2, 1, -2, -2, 0, 0));
+/// Tesla (`T`).
+pub const TESLA: Unit = Unit::si("T", dim(0, 1, -2, -1, 0, 0));
+/// Weber (`Wb`).
+pub const WEBER: Unit = Unit::si("Wb", dim(2, 1, -2, -1, 0, 0));
+/// Siemens (`S`).
+pub const SIEMENS: Unit = Unit::si("S", dim(-2, -1, 3, 2, 0, 0));
+/// Ohm (`Ω`).
+pub const OHM: Unit = Unit::si("Ω", dim(2, 1, -3, -2, 0, 0));
+
+// ---------------------------------------------------------------
+// Compound SI units used by the constants table
+// ---------------------------------------------------------------
+
+/// Square metre (`m^2`).
+pub const SQUARE_METER: Unit = Unit::si("m^2", dim(2, 0, 0, 0, 0, 0));
+/// Reciprocal metre (`m^-1`).
+pub const PER_METER: Unit = Unit::si("m^-1", dim(-1, 0, 0, 0, 0, 0));
+/// Reciprocal cubic metre (`m^-3`).
+pub const PER_CUBIC_METER: Unit =
+    Unit::si("m^-3", dim(-3, 0, 0, 0, 0, 0));
+/// Reciprocal mole (`mol^-1`).
+pub const PER_MOLE: Unit = Unit::si("mol^-1", dim(0, 0, 0, 0, 0, -1));
+/// Metre per second (`m s^-1`).
+pub const METER_PER_SECOND: Unit =
+    Unit::si("m s^-1", dim(1, 0, -1, 0, 0, 0));
+/// Metre per second squared (`m s^-2`).
+pub const METER_PER_SECOND_SQUARED: Unit =
+    Unit::si("m s^-2", dim(1, 0, -2, 0, 0, 0));
+/// Metre kelvin (`m K`).
+pub const METER_KELVIN: Unit = Unit::si("m K", dim(1, 0, 0, 0, 1, 0));
+/// Joule second (`J s`).
+pub const JOULE_SECOND: Unit = Unit::si("J s", dim(2, 1, -1, 0, 0, 0));
+/// Joule per kelvin (`J K^-1`).
+pub const JOULE_PER_KELVIN: Unit =
+    Unit::si("J K^-1", dim(2, 1, -2, 0, -1, 0));
+/// Joule per tesla (`J T^-1`).
+pub const JOULE_PER_TESLA: Unit =
+    Unit::si("J T^-1", dim(2, 0, 0, 1, 0, 0));
+/// Joule per mole kelvin (`J mol^-1 K^-1`).
+pub const JOULE_PER_MOLE_KELVIN: Unit =
+    Unit::si("J mol^-1 K^-1", dim(2, 1, -2, 0, -1, -1));
+/// Joule second per mole (`J s mol^-1`).
+pub const JOULE_SECOND_PER_MOLE: Unit =
+    Unit::si("J s mol^-1", dim(2, 1, -1, 0, 0, -1));
+/// Coulomb per mole (`C mol^-1`).
+pub const COULOMB_PER_MOLE: Unit =
+    Unit::si("C mol^-1", dim(0, 0, 1, 1, 0, -1));
+/// Coulomb per kilogram (`C kg^-1`).
+pub const COULOMB_PER_KILOGRAM: Unit =
+    Unit::si("C kg^-1", dim(0, -1, 1, 1, 0, 0));
+/// Kilogram per mole (`kg mol^-1`).
+pub const KILOGRAM_PER_MOLE: Unit =
+    Unit::si("kg mol^-1", dim(0, 1, 0, 0, 0, -1));
+/// Cubic metre per mole (`m^3 mol^-1`).
+pub const CUBIC_METER_PER_MOLE: Unit =
+    Unit::si("m^3 mol^-1", dim(3, 0, 0, 0, 0, -1));
+/// Unit of the gravitational constant (`m^3 kg^-1 s^-2`).
+pub const CUBIC_METER_PER_KILOGRAM_SECOND_SQUARED: Unit =
+    Unit::si("m^3 kg^-1 s^-2", dim(3, -1, -2, 0, 0, 0));
+/// Unit of the Coulomb constant (`N m^2 C^-2`).
+pub const NEWTON_SQUARE_METER_PER_COULOMB_SQUARED: Unit =
+    Unit::si("N m^2 C^-2", dim(3, 1, -4, -2, 0, 0));
+/// Newton per ampere squared (`N A^-2`).
+pub const NEWTON_PER_AMPERE_SQUARED: Unit =
+    Unit::si("N A^-2", dim(1, 1, -2, -2, 0, 0));
+/// Farad per metre (`F m^-1`).
+pub const FARAD_PER_METER: Unit =
+    Unit::si("F m^-1", dim(-3, -1, 4, 2, 0, 0));
+/// Volt per metre (`V m^-1`).
+pub const VOLT_PER_METER: Unit =
+    Unit::si("V m^-1", dim(1, 1, -3, -1, 0, 0));
+/// Hertz per volt (`Hz V^-1`).
+pub const HERTZ_PER_VOLT: Unit =
+    Unit::si("Hz V^-1", dim(-2, -1, 2, 1, 0, 0));
+/// Watt square metre (`W m^2`).
+pub const WATT_SQUARE_METER: Unit =
+    Unit::si("W m^2", dim(4, 1, -3, 0, 0, 0));
+/// Watt per square metre per kelvin to the fourth (`W m^-2 K^-4`).
+pub const WATT_PER_SQUARE_METER_KELVIN_FOURTH: Unit =
+    Unit::si("W m^-2 K^-4", dim(0, 1, -3, 0, -4, 0));
+/// Unit of electric polarizability (`C^2 m^2 J^-1`).
+pub const COULOMB_SQUARED_SQUARE_METER_PER_JOULE: Unit =
+    Unit::si("C^2 m^2 J^-1", dim(0, -1, 4, 2, 0, 0));
+
+/// Joule per mole (`J mol^-1`).
+pub const JOULE_PER_MOLE: Unit =
+    Unit::si("J mol^-1", dim(2, 1, -2, 0, 0, -1));
+
+// ---------------------------------------------------------------
+// Non-SI units accepted for use with SI
+// ---------------------------------------------------------------
+
+/// Electron volt (`eV`).
+pub const ELECTRONVOLT: Unit =
+    Unit::scaled("eV", JOULE.dimension, ELECTRON_VOLT);
+/// Dalton / unified atomic mass unit (`u`).
+pub const DALTON: Unit =
+    Unit::scaled("u", KILOGRAM.dimension, ATOMIC_MASS_UNIT);
+/// Mega-electron volt per c² (`MeV/c^2`), a unit of mass.
+pub const MEGAELECTRONVOLT_PER_C2: Unit =
+    Unit::scaled("MeV/c^2", KILOGRAM.dimension, 1e6 * EV_TO_KG);
+/// Giga-electron volt per c² (`GeV/c^2`), a unit of mass.
+pub const GIGAELECTRONVOLT_PER_C2: Unit =
+    Unit::scaled("GeV/c^2", KILOGRAM.dimension, 1e9 * EV_TO_KG);
+/// Litre atmosphere per mole kelvin (`L atm mol^-1 K^-1`).
+pub const LITER_ATMOSPHERE_PER_MOLE_KELVIN: Unit = Unit::scaled(
+    "L atm mol^-1 K^-1",
+    JOULE_PER_MOLE_KELVIN.dimension,
+    1e-3 * STANDARD_ATMOSPHERE,
+);
+
+// ---------------------------------------------------------------
+// Atomic, chemical and practical units
+// ---------------------------------------------------------------
+
+/// Hartree (`Eh`), the atomic unit of energy.
+pub const HARTREE: Unit =
+    Unit::scaled("Eh", JOULE.dimension, HARTREE_ENERGY);
+/// Rydberg (`Ry`), half a hartree.
+pub const RYDBERG: Unit =
+    Unit::scaled("Ry", JOULE.dimension, HARTREE_ENERGY / 2.0);
+/// Thermochemical calorie (`cal`), exactly 4.184 J.
+pub const CALORIE: Unit = Unit::scaled("cal", JOULE.dimension, 4.184);
+/// Kilocalorie per mole (`kcal/mol`).
+pub const KILOCALORIE_PER_MOLE: Unit =
+    Unit::scaled("kcal/mol", JOULE_PER_MOLE.dimension, 4184.0);
+/// Kilojoule per mole (`kJ/mol`).
+pub const KILOJOULE_PER_MOLE: Unit =
+    Unit::scaled("kJ/mol", JOULE_PER_MOLE.dimension, 1e3);
+/// Electron mass (`m_e`), the atomic unit of mass.
+pub const ELECTRON_MASS_UNIT: Unit =
+    Unit::scaled("m_e", KILOGRAM.dimension, ELECTRON_MASS);
+/// Bohr (`a0`), the atomic unit of length.
+pub const BOHR: Unit =
+    Unit::scaled("a0", METER.dimension, ATOMIC_UNIT_OF_LENGTH);
+/// Ångström (`Å`).
+pub const ANGSTROM: Unit = Unit::scaled("Å", METER.dimension, 1e-10);
+/// Atomic unit of time (`ħ/Eh`).
+pub const ATOMIC_TIME: Unit =
+    Unit::scaled("ħ/Eh", SECOND.dimension, ATOMIC_UNIT_OF_TIME);
+/// Standard atmosphere (`atm`).
+pub const ATMOSPHERE: Unit =
+    Unit::scaled("atm", PASCAL.dimension, STANDARD_ATMOSPHERE);
+/// Bar (`bar`).
+pub const BAR: Unit = Unit::scaled("bar", PASCAL.dimension, 1e5);
+/// Torr (`Torr`), 1/760 of a standard atmosphere.
+pub const TORR: Unit =
+    Unit::scaled("Torr", PASCAL.dimension, STANDARD_ATMOSPHERE / 760.0);
+/// Pound-force per square inch (`psi`).
+pub const PSI: Unit = Unit::scaled(
+    "psi",
+    PASCAL.dimension,
+    0.453_592_37 * STANDARD_GRAVITY / (0.0254 * 0.0254),
+);
+/// Reciprocal centimetre (`cm^-1`).
+pub const PER_CENTIMETER: Unit =
+    Unit::scaled("cm^-1", PER_METER.dimension, 1e2);
+/// Degree Celsius (`°C`): `0 °C` is `273.15 K`.
+pub const DEGREE_CELSIUS: Unit =
+    Unit::affine("°C", KELVIN.dimension, 1.0, 273.15);
+/// Degree Fahrenheit (`°F`): `0 °F` is `459.67 × 5/9 K`.
+pub const DEGREE_FAHRENHEIT: Unit =
+    Unit::affine("°F", KELVIN.dimension, 5.0 / 9.0, 459.67 * 5.0 / 9.0);
+
+// ---------------------------------------------------------------
+// Conversion
+// ---------------------------------------------------------------
+
+/// Named unit symbols accepted by [`parse`] and [`convert`], which
+/// also accept their prefixed and compound forms.
+///
+/// SI-prefixed forms such as `cm`, `ns` and `GHz` are derived by the
+/// parser and not listed; only those it cannot build (`kcal`,
+/// `cal/mol`, `cm^-1`, ...) are. Lookup is case-sensitive (`mK` is
+/// not `MK`); common spellings such as `"hartree"` and `"angstrom"`
+/// are listed as aliases.
+pub const REGISTRY: &[(&str, Unit)] = &[
+    // Energy
+    ("J", JOULE),
+    ("eV", ELECTRONVOLT),
+    (
+        "meV",
+        Unit::scaled("meV", JOULE.dimension, 1e-3 * ELECTRON_VOLT),
+    ),
+    (
+        "keV",
+        Unit::scaled("keV", JOULE.dimension, 1e3 * ELECTRON_VOLT),
+    ),
+    (
+        "MeV",
+        Unit::scaled("MeV", JOULE.dimension, 1e6 * ELECTRON_VOLT),
+    ),
+    (
+        "GeV",
+        Unit::scaled("GeV", JOULE.dimension, 1e9 * ELECTRON_VOLT),
+    ),
+    ("Eh", HARTREE),
+    ("Ha", HARTREE),
+    ("hartree", HARTREE),
+    ("Ry", RYDBERG),
+    ("rydberg", RYDBERG),
+    ("cal", CALORIE),
+    ("kcal", Unit::scaled("kcal", JOULE.dimension, 4184.0)),
+    ("erg", Unit::scaled("erg", JOULE.dimension, 1e-7)),
+    // Molar energy
+    ("J/mol", JOULE_PER_MOLE),
+    ("kJ/mol", KILOJOULE_PER_MOLE),
+    (
+        "cal/mol",
+        Unit::scaled("cal/mol", JOULE_PER_MOLE.dimension, 4.184),
+    ),
+    ("kcal/mol", KILOCALORIE_PER_MOLE),
+    // Mass
+    ("kg", KILOGRAM),
+    ("u", DALTON),
+    ("Da", DALTON),
+    ("m_e", ELECTRON_MASS_UNIT),
+    (
+        "eV/c^2",
+        Unit::scaled("eV/c^2", KILOGRAM.dimension, EV_TO_KG),
+    ),
+    ("MeV/c^2", MEGAELECTRONVOLT_PER_C2),
+    ("GeV/c^2", GIGAELECTRONVOLT_PER_C2),
+    // Length
+    ("m", METER),
+    ("Å", ANGSTROM),
+    ("angstrom", ANGSTROM),
+    ("a0", BOHR),
+    ("bohr", BOHR),
+    // Time
+    ("s", SECOND),
+    ("ħ/Eh", ATOMIC_TIME),
+    ("au_time", ATOMIC_TIME),
+    // Temperature
+    ("K", KELVIN),
+    ("°C", DEGREE_CELSIUS),
+    ("degC", DEGREE_CELSIUS),
+    ("°F", DEGREE_FAHRENHEIT),
+    ("degF", DEGREE_FAHRENHEIT),
+    // Frequency and wavenumber
+    ("Hz", HERTZ),
+    ("m^-1", PER_METER),
+    ("cm^-1", PER_CENTIMETER),
+    // Pressure
+    ("Pa", PASCAL),
+    ("bar", BAR),
+    ("atm", ATMOSPHERE),
+    ("Torr", TORR),
+    ("psi", PSI),
+    // Force, power and amount
+    ("N", NEWTON),
+    ("W", WATT),
+    ("mol", MOLE),
+    ("cd", CANDELA),
+    // Electromagnetism
+    ("A", AMPERE),
+    ("C", COULOMB),
+    ("V", VOLT),
+    ("Ω", OHM),
+    ("ohm", OHM),
+    ("S", SIEMENS),
+    ("F", FARAD),
+    ("H", HENRY),
+    ("Wb", WEBER),
+    ("T", TESLA),
+];
+
+/// Parses a unit symbol.
+///
+/// A symbol of [`REGISTRY`] returns its entry. Anything else is
+/// read as a product of units: SI units with an optional prefix
+/// (`mV`, `kΩ`, `µmol`, `GeV`), joined by spaces, `·` or `*`, divided
+/// by `/`, grouped in parentheses and raised to integer powers with
+/// `^n` or superscripts (`kg m^-3`, `W·m⁻²`, `J/(mol K)`). The
+/// result keeps `symbol` for display. A product after a `/` must be
+/// parenthesised, as `J/mol K` could mean either `J K/mol` or
+/// `J/(mol K)`. Inside a product, `°C` and `°F` are temperature
+/// intervals: `J/°C` is `J/K`.
+///
+/// # Errors
+///
+/// [`ConversionError::UnknownUnit`] if a symbol names no unit and
+/// [`ConversionError::Malformed`] if the expression does not parse.
+///
+/// # Example
+///
+/// ```
+/// use cmn::units::{parse, ConversionError, Dimension, JOULE};
+///
+/// assert_eq!(parse("J"), Ok(JOULE));
+/// assert_eq!(parse("hartree"), parse("Eh"));
+/// let density = parse("kg m^-3").unwrap();
+/// assert_eq!(density.symbol, "kg m^-3");
+/// assert_eq!(density.dimension, Dimension::new(-3, 1, 0, 0, 0, 0, 0));
+/// assert_eq!(parse("mV").unwrap().scale, 1e-3);
+/// assert_eq!(parse("furlong"), Err(ConversionError::UnknownUnit));
+/// assert_eq!(parse("m/s/"), Err(ConversionError::Malformed));
+/// ```
+pub fn parse(symbol: &'static str) -> Result<Unit, ConversionError> {
+    let unit = evaluate(symbol)?;
+    if unit.symbol.is_empty() {
+        Ok(Unit {
+            symbol: symbol.trim(),
+            ..unit
+        })
+    } else {
+        Ok(unit)
+    }
+}
+
+/// Converts `value` from the unit named `from` to the unit named
+/// `to`. See [`Unit::convert`] for the cross-dimension rules and
+/// [`parse`] for the accepted symbols.
+///
+/// # Example
+///
+/// ```
+/// use cmn::units::{convert, ConversionError};
+///
+/// let mev = convert(1.0, "u", "MeV").unwrap();
+/// assert!((mev - 931.494_102_42).abs() < 1e-6);
+/// let a0 = convert(1.0, "bohr", "Å").unwrap();
+/// assert!((a0 - 0.529_177).abs() < 1e-6);
+/// assert_eq!(convert(1.0, "atm", "Pa"), Ok(101_325.0));
+/// assert_eq!(
+///     convert(1.0, "eV", "furlong"),
+///     Err(ConversionError::UnknownUnit)
+/// );
+/// ```
+pub fn convert(
+    value: f64,
+    from: &str,
+    to: &str,
+) -> Result<f64, ConversionError> {
+    evaluate(from)?.convert(value, &evaluate(to)?)
+}
+
+/// Energy in joules per coherent SI unit of each dimension with an
+/// exact energy equivalent.
+const ENERGY_EQUIVALENTS: [(Dimension, f64); 6] = [
+    (JOULE.dimension, 1.0),
+    (KILOGRAM.dimension, SPEED_OF_LIGHT * SPEED_OF_LIGHT),
+    (HERTZ.dimension, PLANCK),
+    (KELVIN.dimension, BOLTZMANN),
+    (PER_METER.dimension, PLANCK * SPEED_OF_LIGHT),
+    (JOULE_PER_MOLE.dimension, 1.0 / AVOGADRO),
+];
+
+fn joules_per_si(dimension: Dimension) -> Option<f64> {
+    ENERGY_EQUIVALENTS
+        .iter()
+        .find(|(d, _)| *d == dimension)
+        .map(|&(_, joules)| joules)
+}
+
+// ---------------------------------------------------------------
+// Unit expressions
+// ---------------------------------------------------------------
+
+/// SI prefixes and the powers of ten they stand for, `da` first so
+/// that it is not read as deci. `u` spells micro in ASCII.
+const PREFIXES: [(&str, i32); 26] = [
+    ("da", 1),
+    ("Q", 30),
+    ("R", 27),
+    ("Y", 24),
+    ("Z", 21),
+    ("E", 18),
+    ("P", 15),
+    ("T", 12),
+    ("G", 9),
+    ("M", 6),
+    ("k", 3),
+    ("h", 2),
+    ("d", -1),
+    ("c", -2),
+    ("m", -3),
+    ("µ", -6),
+    ("μ", -6),
+    ("u", -6),
+    ("n", -9),
+    ("p", -12),
+    ("f", -15),
+    ("a", -18),
+    ("z", -21),
+    ("y", -24),
+    ("r", -27),
+    ("q", -30),
+];
+
+/// Units that take an SI prefix, with the power of ten between the
+/// unprefixed symbol and the listed unit (`g` is `1e-3 kg`).
+const PREFIXABLE: [(&str, Unit, i32); 25] = [
+    ("m", METER, 0),
+    ("g", KILOGRAM, -3),
+    ("s", SECOND, 0),
+    ("A", AMPERE, 0),
+    ("K", KELVIN, 0),
+    ("mol", MOLE, 0),
+    ("cd", CANDELA, 0),
+    ("Hz", HERTZ, 0),
+    ("N", NEWTON, 0),
+    ("Pa", PASCAL, 0),
+    ("J", JOULE, 0),
+    ("W", WATT, 0),
+    ("C", COULOMB, 0),
+    ("V", VOLT, 0),
+    ("F", FARAD, 0),
+    ("Ω", OHM, 0),
+    ("ohm", OHM, 0),
+    ("S", SIEMENS, 0),
+    ("Wb", WEBER, 0),
+    ("T", TESLA, 0),
+    ("H", HENRY, 0),
+    ("L", Unit::si("m^3", dim(3, 0, 0, 0, 0, 0)), -3),
+    ("bar", PASCAL, 5),
+    ("eV", ELECTRONVOLT, 0),
+    ("Da", DALTON, 0),
+];
+
+/// How deeply parentheses may nest in a unit expression.
+const MAX_DEPTH: u8 = 8;
+
+/// Dimension and scale of a product of units. Powers of ten are
+/// kept apart from `scale` so that prefixes combine exactly.
+#[derive(Clone, Copy)]
+struct Term {
+    dimension: Dimension,
+    decade: i32,
+    scale: f64,
+}
+
+impl Term {
+    const ONE: Self = Self {
+        dimension: Dimension::NONE,
+        decade: 0,
+        scale: 1.0,
+    };
+
+    fn of(unit: Unit, decade: i32) -> Self {
+        Self {
+            dimension: unit.dimension,
+            decade,
+            scale: unit.scale,
+        }
+    }
+
+    /// Returns `self × other^power`, or `None` if an exponent
+    /// overflows.
+    fn times(self, other: Self, power: i8) -> Option<Self> {
+        let (a, b) = (self.dimension, other.dimension);
+        let add = |x: i8, y: i8| y.checked_mul(power)?.checked_add(x);
+        Some(Self {
+            dimension: Dimension::new(
+                add(a.length, b.length)?,
+                add(a.mass, b.mass)?,
+                add(a.time, b.time)?,
+                add(a.current, b.current)?,
+                add(a.temperature, b.temperature)?,
+                add(a.amount, b.amount)?,
+                add(a.luminous_intensity, b.luminous_intensity)?,
+            ),
+            decade: self.decade + other.decade * i32::from(power),
+            scale: self.scale * powi(other.scale, i32::from(power)),
+        })
+    }
+}
+
+/// `x` raised to the integer power `n`.
+fn powi(x: f64, n: i32) -> f64 {
+    let mut result = 1.0;
+    for _ in 0..n.unsigned_abs() {
+        result *= x;
+    }
+    if n < 0 {
+        1.0 / result
+    } else {
+        result
+    }
+}
+
+/// Returns the entry of [`REGISTRY`] for `expression` or, for any
+/// other well-formed expression, the unit it denotes with an empty
+/// symbol.
+pub(crate) fn evaluate(
+    expression: &str,
+) -> Result<Unit, ConversionError> {
+    let expression = expression.trim();
+    if let Some(unit) = named(expression) {
+        return Ok(unit);
+    }
+    let mut input = expression;
+    let term = quotient(&mut input, 0)?;
+    if !input.is_empty() {
+        return Err(ConversionError::Malformed);
+    }
+    Ok(Unit::scaled(
+        "",
+        term.dimension,
+        powi(10.0, term.decade) * term.scale,
+    ))
+}
+
+fn named(symbol: &str) -> Option<Unit> {
+    REGISTRY
+        .iter()
+        .find(|(name, _)| *name == symbol)
+        .map(|&(_, unit)| unit)
+}
+
+/// Parses factors joined by products and quotients, up to the end
+/// of `input` or an unmatched `)`.
+fn quotient(
+    input: &mut &str,
+    depth: u8,
+) -> Result<Term, ConversionError> {
+    let malformed = ConversionError::Malformed;
+    let mut term = factor(input, depth)?;
+    let mut divided = false;
+    loop {
+        let trimmed = input.trim_start();
+        let spaced = trimmed.len() < input.len();
+        *input = trimmed;
+        let mut chars = input.chars();
+        let power = match chars.next() {
+            None | Some(')') => return Ok(term),
+            Some('/') => {
+                *input = chars.as_str();
+                divided = true;
+                -1
+            }
+            Some('·' | '⋅' | '*') if !divided => {
+                *input = chars.as_str();
+                1
+            }
+            Some(_) if spaced && !divided => 1,
+            Some(_) => return Err(malformed),
+        };
+        term = term
+            .times(factor(input, depth)?, power)
+            .ok_or(malformed)?;
+    }
+}
+
+/// Parses a unit or a parenthesised quotient, raised to an optional
+/// power.
+fn factor(
+    input: &mut &str,
+    depth: u8,
+) -> Result<Term, ConversionError> {
+    let malformed = ConversionError::Malformed;
+    *input = input.trim_start();
+    let base = if let Some(rest) = input.strip_prefix('(') {
+        if depth == MAX_DEPTH {
+            return Err(malformed);
+        }
+        *input = rest;
+        let group = quotient(input, depth + 1)?;
+        *input = input.strip_prefix(')').ok_or(malformed)?;
+        group
+    } else {
+        let end = input.find(is_delimiter).unwrap_or(input.len());
+        if end == 0 {
+            return Err(malformed);
+        }
+        let (symbol, rest) = input.split_at(end);
+        *input = rest;
+        atom(symbol).ok_or(ConversionError::UnknownUnit)?
+    };
+    Term::ONE.times(base, exponent(input)?).ok_or(malformed)
+}
+
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace()
+        || matches!(c, '/' | '·' | '⋅' | '*' | '(' | ')' | '^' | '⁻')
+        || superscript_digit(c).is_some()
+}
+
+/// Resolves a single unit symbol, with or without an SI prefix.
+fn atom(symbol: &str) -> Option<Term> {
+    if symbol == "1" {
+        return Some(Term::ONE);
+    }
+    if let Some(unit) = named(symbol) {
+        return Some(Term::of(unit, 0));
+    }
+    let prefixable = |symbol: &str, power: i32| {
+        PREFIXABLE
+            .iter()
+            .find(|(name, ..)| *name == symbol)
+            .map(|&(_, unit, decade)| Term::of(unit, decade + power))
+    };
+    prefixable(symbol, 0).or_else(|| {
+        PREFIXES.iter().find_map(|&(prefix, power)| {
+            prefixable(symbol.strip_prefix(prefix)?, power)
+        })
+    })
+}
+
+/// Parses an optional `^n` or superscript exponent; `1` if absent.
+fn exponent(input: &mut &str) -> Result<i8, ConversionError> {
+    let (negative, superscript) = if let Some(rest) =
+        input.strip_prefix('^')
+    {
+        let rest = rest.strip_prefix('+').unwrap_or(rest);
+        let minus = rest.strip_prefix(['-', '−']);
+        *input = minus.unwrap_or(rest);
+        (minus.is_some(), false)
+    } else if let Some(rest) = input.strip_prefix('⁻') {
+        *input = rest;
+        (true, true)
+    } else if input.starts_with(|c| superscript_digit(c).is_some()) {
+        (false, true)
+    } else {
+        return Ok(1);
+    };
+    let digit = |c: char| {
+        if superscript {
+            superscript_digit(c)
+        } else {
+            c.to_digit(10)
+        }
+    };
+    let end = input.find(|c| digit(c).is_none()).unwrap_or(input.len());
+    let (digits, rest) = input.split_at(end);
+    *input = rest;
+    let power = digits
+        .chars()
+        .filter_map(digit)
+        .try_fold(0_i8, |power, d| {
+            power.checked_mul(10)?.checked_add(i8::try_from(d).ok()?)
+        })
+        .filter(|_| !digits.is_empty())
+        .ok_or(ConversionError::Malformed)?;
+    Ok(if negative { -power } else { power })
+}
+
+fn superscript_digit(c: char) -> Option<u32> {
+    match c {
+        '⁰' => Some(0),
+        '¹' => Some(1),
+        '²' => Some(2),
+        '³' => Some(3),
+        '⁴' => Some(4),
+        '⁵' => Some(5),
+        '⁶' => Some(6),
+        '⁷' => Some(7),
+        '⁸' => Some(8),
+        '⁹' => Some(9),
+        _ => None,
+    }
+}
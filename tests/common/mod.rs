@@ -0,0 +1,10 @@
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Helpers shared by the integration tests.
+
+/// Whether `a` and `b` agree to within `tolerance`, relative to the
+/// larger magnitude.
+pub(crate) fn near(a: f64, b: f64, tolerance: f64) -> bool {
+    (a - b).abs() <= tolerance * a.abs().max(b.abs())
+}
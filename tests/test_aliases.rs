@@ -0,0 +1,174 @@
+#![allow(missing_docs)]
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod tests {
+    use cmn::constants::aliases::{
+        resolve, MatchKind, Resolved, NAMES, SYMBOLS,
+    };
+    use cmn::constants::{lookup, Constants, CONSTANTS_TABLE};
+
+    fn name_of(query: &str) -> &'static str {
+        resolve(query)
+            .unwrap_or_else(|| panic!("{query:?} did not resolve"))
+            .name
+    }
+
+    // ---------------------------------------------------------------
+    // Registry integrity
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn every_alias_targets_a_table_entry() {
+        for (alias, name) in SYMBOLS.iter().chain(NAMES) {
+            assert!(lookup(name).is_some(), "{alias:?} -> {name}");
+        }
+    }
+
+    #[test]
+    fn every_alias_resolves_to_its_target() {
+        for (alias, name) in SYMBOLS.iter().chain(NAMES) {
+            assert_eq!(name_of(alias), *name, "{alias:?}");
+        }
+    }
+
+    #[test]
+    fn symbols_are_unique() {
+        for (i, (a, _)) in SYMBOLS.iter().enumerate() {
+            assert!(
+                SYMBOLS[i + 1..].iter().all(|(b, _)| a != b),
+                "duplicate symbol {a:?}"
+            );
+        }
+    }
+
+    // ---------------------------------------------------------------
+    // Match kinds
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn exact_name_wins() {
+        let r = resolve("PLANCK").unwrap();
+        assert_eq!(
+            r,
+            Resolved {
+                name: "PLANCK",
+                matched: "PLANCK",
+                kind: MatchKind::Exact,
+            }
+        );
+    }
+
+    #[test]
+    fn requested_symbols_resolve() {
+        for (query, name) in [
+            ("c", "SPEED_OF_LIGHT"),
+            ("h", "PLANCK"),
+            ("hbar", "PLANCK_REDUCED"),
+            ("ħ", "PLANCK_REDUCED"),
+            ("k_B", "BOLTZMANN"),
+            ("N_A", "AVOGADRO"),
+            ("alpha", "FINE_STRUCTURE"),
+            ("α", "FINE_STRUCTURE"),
+        ] {
+            let r = resolve(query).unwrap();
+            assert_eq!(r.name, name, "{query}");
+            assert_eq!(r.kind, MatchKind::Symbol, "{query}");
+            assert_eq!(r.matched, query);
+        }
+    }
+
+    #[test]
+    fn symbols_are_case_sensitive() {
+        assert_eq!(name_of("G"), "GRAVITATIONAL_CONSTANT");
+        assert_eq!(name_of("g"), "STANDARD_GRAVITY");
+        assert_eq!(name_of("m_h"), "HELION_MASS");
+        assert_eq!(name_of("m_H"), "HIGGS_BOSON_MASS_GEV");
+    }
+
+    #[test]
+    fn canonical_name_is_case_and_separator_insensitive() {
+        for query in [
+            "speed of light",
+            "Speed Of Light",
+            "speed_of_light",
+            "speed-of-light",
+            "SpeedOfLight",
+        ] {
+            let r = resolve(query).unwrap();
+            assert_eq!(r.name, "SPEED_OF_LIGHT", "{query}");
+            assert_eq!(r.kind, MatchKind::Normalized, "{query}");
+        }
+    }
+
+    #[test]
+    fn non_float_names_are_normalized_too() {
+        let r = resolve("hash cost").unwrap();
+        assert_eq!(r.name, "HASH_COST");
+        assert_eq!(r.kind, MatchKind::Normalized);
+    }
+
+    #[test]
+    fn english_names_resolve() {
+        let r = resolve("Planck Constant").unwrap();
+        assert_eq!(r.name, "PLANCK");
+        assert_eq!(r.matched, "planck constant");
+        assert_eq!(r.kind, MatchKind::Name);
+        assert_eq!(name_of("golden ratio"), "PHI");
+        assert_eq!(
+            name_of("Fine Structure Constant"),
+            "FINE_STRUCTURE"
+        );
+    }
+
+    #[test]
+    fn normalized_symbol_needs_a_unique_match() {
+        let r = resolve("K_B").unwrap();
+        assert_eq!(r.name, "BOLTZMANN");
+        assert_eq!(r.kind, MatchKind::SymbolNormalized);
+        assert_eq!(r.matched, "k_B");
+        // "M_H" folds to both "m_h" (helion) and "m_H" (Higgs).
+        assert!(resolve("M_H").is_none());
+    }
+
+    #[test]
+    fn unknown_queries_return_none() {
+        for query in ["", " ", "_", "not a constant", "PLANCKK"] {
+            assert!(resolve(query).is_none(), "{query:?}");
+        }
+    }
+
+    #[test]
+    fn resolved_entry_is_the_table_entry() {
+        let entry = resolve("N_A").unwrap().entry().unwrap();
+        let expected =
+            CONSTANTS_TABLE.iter().find(|e| e.name == "AVOGADRO");
+        assert_eq!(Some(entry), expected);
+    }
+
+    // ---------------------------------------------------------------
+    // Constants::resolve
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn constants_resolve_returns_constant_and_match() {
+        let c = Constants::new();
+        let (constant, how) = c.resolve("speed of light").unwrap();
+        assert_eq!(constant, c.constant("SPEED_OF_LIGHT").unwrap());
+        assert_eq!(how.kind, MatchKind::Normalized);
+    }
+
+    #[test]
+    fn constants_resolve_exact_covers_non_table_constants() {
+        let c = Constants::new();
+        let (constant, how) = c.resolve("HASH_COST").unwrap();
+        assert_eq!(constant.name, "HASH_COST");
+        assert_eq!(how.kind, MatchKind::Exact);
+    }
+
+    #[test]
+    fn constants_resolve_unknown_is_none() {
+        assert!(Constants::new().resolve("nope").is_none());
+    }
+}
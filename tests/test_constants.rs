@@ -74,7 +74,7 @@ mod tests {
     fn constants_contains_all_expected_names() {
         let c = Constants::new();
         let names: Vec<&str> =
-            c.constants().iter().map(|c| c.name).collect();
+            c.constants().iter().map(|c| &*c.name).collect();
         let expected = [
             "APERY",
             "ATOMIC_MASS_UNIT",
@@ -263,6 +263,73 @@ mod tests {
         assert!(c.get_value("DOES_NOT_EXIST").is_none());
     }
 
+    // ---------------------------------------------------------------
+    // Constants::get — typed accessor
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn get_returns_each_supported_type() {
+        let c = Constants::new();
+        assert_eq!(c.get::<f64>("PI").unwrap(), PI);
+        assert_eq!(c.get::<u32>("HASH_COST").unwrap(), HASH_COST);
+        assert_eq!(c.get::<usize>("HASH_LENGTH").unwrap(), HASH_LENGTH);
+        assert_eq!(
+            c.get::<String>("HASH_ALGORITHM").unwrap(),
+            HASH_ALGORITHM
+        );
+        assert_eq!(
+            c.get::<&[char]>("SPECIAL_CHARS").unwrap(),
+            SPECIAL_CHARS
+        );
+    }
+
+    #[test]
+    fn get_reports_type_mismatch() {
+        use cmn::constants::ConstantError;
+        let c = Constants::new();
+        let err = c.get::<usize>("HASH_COST").unwrap_err();
+        assert_eq!(
+            err,
+            ConstantError::TypeMismatch {
+                expected: "usize",
+                found: "u32",
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "type mismatch: expected usize, found u32"
+        );
+    }
+
+    #[test]
+    fn get_reports_not_found() {
+        use cmn::constants::ConstantError;
+        let c = Constants::new();
+        let err = c.get::<f64>("DOES_NOT_EXIST").unwrap_err();
+        assert_eq!(err, ConstantError::NotFound);
+        assert_eq!(err.to_string(), "unknown constant");
+    }
+
+    #[test]
+    fn every_constant_has_a_typed_value() {
+        let c = Constants::new();
+        for constant in c.constants() {
+            let expected = match &*constant.name {
+                "HASH_ALGORITHM" => "String",
+                "HASH_COST" => "u32",
+                "HASH_LENGTH" => "usize",
+                "SPECIAL_CHARS" => "&[char]",
+                _ => "f64",
+            };
+            assert_eq!(
+                constant.value.type_name(),
+                expected,
+                "{}",
+                constant.name
+            );
+        }
+    }
+
     // ---------------------------------------------------------------
     // Constants::is_valid
     // ---------------------------------------------------------------
@@ -334,16 +401,23 @@ mod tests {
         let value: serde_json::Value =
             serde_json::from_str(&json).unwrap();
         assert_eq!(value["name"], "PI");
-        assert!(!value["value"].as_str().unwrap().is_empty());
+        assert_eq!(value["value"]["Float"], std::f64::consts::PI);
     }
 
     #[test]
     fn constant_deserialize_from_json() {
-        // Use a leaked string to satisfy the 'static lifetime on name
-        let json: &'static str = r#"{"name":"TEST","value":"42"}"#;
+        let json: &'static str =
+            r#"{"name":"TEST","value":{"U32":42}}"#;
         let c: Constant = serde_json::from_str(json).unwrap();
         assert_eq!(c.name, "TEST");
-        assert_eq!(c.value, "42");
+        assert_eq!(c.value, ConstantValue::U32(42));
+    }
+
+    #[test]
+    fn constant_deserialize_rejects_char_array() {
+        let json: &'static str =
+            r#"{"name":"TEST","value":{"CharArray":["a"]}}"#;
+        assert!(serde_json::from_str::<Constant>(json).is_err());
     }
 
     // ---------------------------------------------------------------
@@ -372,6 +446,86 @@ mod tests {
         assert!(json.contains("PI"));
     }
 
+    // ---------------------------------------------------------------
+    // Constants struct — Deserialize and JSON schema
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn constants_json_round_trip() {
+        use cmn::constants::Dataset;
+        let original = Constants::with_dataset(Dataset::Codata2022);
+        let json = serde_json::to_string(&original).unwrap();
+        let restored: Constants = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.constants, original.constants);
+        assert_eq!(restored.dataset(), Dataset::Codata2022);
+        assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+    }
+
+    #[test]
+    fn constants_deserialize_from_owned_input() {
+        let json = String::from(
+            r#"{"constants":[{"name":"GAIN","value":{"Float":2.5}}]}"#,
+        );
+        let set: Constants = serde_json::from_str(&json).unwrap();
+        drop(json);
+        assert_eq!(set.constants[0].name, "GAIN");
+        assert_eq!(set.get::<f64>("GAIN"), Ok(2.5));
+        assert_eq!(set.dataset(), cmn::constants::Dataset::DEFAULT);
+        assert!(set.unit("GAIN").is_none());
+    }
+
+    #[test]
+    fn constants_deserialize_rejects_missing_fields() {
+        assert!(serde_json::from_str::<Constants>("{}").is_err());
+        assert!(serde_json::from_str::<Constants>(
+            r#"{"constants":[{"name":"X"}]}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<Constants>(
+            r#"{"constants":[],"dataset":"Codata1998"}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn char_array_deserializes_to_the_built_in_set() {
+        let json =
+            serde_json::to_string(&get("SPECIAL_CHARS")).unwrap();
+        let c: Constant = serde_json::from_str(&json).unwrap();
+        assert_eq!(c.value, ConstantValue::CharArray(SPECIAL_CHARS));
+    }
+
+    #[test]
+    fn json_schema_describes_the_document() {
+        use cmn::constants::JSON_SCHEMA;
+        let schema: serde_json::Value =
+            serde_json::from_str(JSON_SCHEMA).unwrap();
+        let variants: Vec<_> = schema["$defs"]["value"]["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v["required"][0].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            variants,
+            ["Float", "String", "U32", "Usize", "CharArray"]
+        );
+        // Every built-in value serializes to one of the variants.
+        for constant in Constants::new().constants() {
+            let value = serde_json::to_value(&constant.value).unwrap();
+            let key = value.as_object().unwrap().keys().next().unwrap();
+            assert!(variants.contains(&key.as_str()), "{key}");
+        }
+        let datasets = &schema["properties"]["dataset"]["enum"];
+        for dataset in [
+            cmn::constants::Dataset::Codata2018,
+            cmn::constants::Dataset::Codata2022,
+        ] {
+            let name = serde_json::to_value(dataset).unwrap();
+            assert!(datasets.as_array().unwrap().contains(&name));
+        }
+    }
+
     // ---------------------------------------------------------------
     // ConstantValue — Clone, Debug, Serialize for each variant
     // ---------------------------------------------------------------
@@ -603,6 +757,59 @@ mod tests {
         assert!(NEUTRON_MASS > PROTON_MASS);
     }
 
+    // ---------------------------------------------------------------
+    // ConstantValue — Eq, Ord, Hash
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn constant_value_float_equality_is_bitwise() {
+        assert_eq!(
+            ConstantValue::Float(1.5),
+            ConstantValue::Float(1.5)
+        );
+        assert_eq!(
+            ConstantValue::Float(f64::NAN),
+            ConstantValue::Float(f64::NAN)
+        );
+        assert_ne!(
+            ConstantValue::Float(0.0),
+            ConstantValue::Float(-0.0)
+        );
+        assert_ne!(ConstantValue::Float(1.0), ConstantValue::U32(1));
+    }
+
+    #[test]
+    fn constant_value_ordering_is_total() {
+        let mut values = vec![
+            ConstantValue::U32(2),
+            ConstantValue::Float(2.0),
+            ConstantValue::String("b".into()),
+            ConstantValue::Float(1.0),
+            ConstantValue::String("a".into()),
+        ];
+        values.sort();
+        assert_eq!(
+            values,
+            vec![
+                ConstantValue::Float(1.0),
+                ConstantValue::Float(2.0),
+                ConstantValue::String("a".into()),
+                ConstantValue::String("b".into()),
+                ConstantValue::U32(2),
+            ]
+        );
+    }
+
+    #[test]
+    fn constant_value_hash_matches_equality() {
+        use std::collections::HashSet;
+        let mut set = HashSet::new();
+        let _ = set.insert(ConstantValue::Float(PI));
+        let _ = set.insert(ConstantValue::Float(PI));
+        let _ = set.insert(ConstantValue::CharArray(SPECIAL_CHARS));
+        assert_eq!(set.len(), 2);
+    }
+
     // ---------------------------------------------------------------
     // ConstantValue Display
     // ---------------------------------------------------------------
@@ -642,9 +849,62 @@ mod tests {
     // ---------------------------------------------------------------
 
     #[test]
-    fn constants_table_has_117_float_entries() {
+    fn constants_table_has_121_entries() {
+        use cmn::constants::CONSTANTS_TABLE;
+        assert_eq!(CONSTANTS_TABLE.len(), 121);
+    }
+
+    #[test]
+    fn constants_table_matches_constants_new() {
         use cmn::constants::CONSTANTS_TABLE;
-        assert_eq!(CONSTANTS_TABLE.len(), 117);
+        let c = Constants::new();
+        assert_eq!(c.constants().len(), CONSTANTS_TABLE.len());
+        for (constant, entry) in
+            c.constants().iter().zip(CONSTANTS_TABLE)
+        {
+            assert_eq!(constant.name, entry.name);
+            assert_eq!(
+                constant.value,
+                ConstantValue::from(entry.value)
+            );
+        }
+    }
+
+    #[test]
+    fn constants_table_includes_cryptographic_entries() {
+        use cmn::constants::{by_category, Category, ConstValue};
+        let crypto: Vec<_> = by_category(Category::Cryptographic)
+            .map(|e| (e.name, e.value))
+            .collect();
+        assert_eq!(
+            crypto,
+            [
+                ("HASH_ALGORITHM", ConstValue::Str(HASH_ALGORITHM)),
+                ("HASH_COST", ConstValue::U32(HASH_COST)),
+                ("HASH_LENGTH", ConstValue::Usize(HASH_LENGTH)),
+                ("SPECIAL_CHARS", ConstValue::Chars(SPECIAL_CHARS)),
+            ]
+        );
+    }
+
+    #[test]
+    fn const_value_accessors_and_display() {
+        use cmn::constants::ConstValue;
+        assert_eq!(ConstValue::Float(1.5).as_f64(), Some(1.5));
+        assert!(ConstValue::Float(1.5).is_float());
+        assert_eq!(ConstValue::U32(8).as_f64(), None);
+        assert!(!ConstValue::Str("x").is_float());
+        assert_eq!(ConstValue::Str("Blake3").to_string(), "Blake3");
+        assert_eq!(ConstValue::Usize(32).to_string(), "32");
+        assert_eq!(ConstValue::Chars(&['a', 'b']).to_string(), "ab");
+        for entry in cmn::constants::CONSTANTS_TABLE {
+            assert_eq!(
+                entry.value.type_name(),
+                ConstantValue::from(entry.value).type_name(),
+                "{}",
+                entry.name
+            );
+        }
     }
 
     #[test]
@@ -652,13 +912,475 @@ mod tests {
         use cmn::constants::{Category, CONSTANTS_TABLE};
         let math: Vec<_> = CONSTANTS_TABLE
             .iter()
-            .filter(|(_, _, c)| *c == Category::Mathematical)
+            .filter(|e| e.category == Category::Mathematical)
             .collect();
         let phys: Vec<_> = CONSTANTS_TABLE
             .iter()
-            .filter(|(_, _, c)| *c == Category::Physical)
+            .filter(|e| e.category == Category::Physical)
             .collect();
         assert!(math.len() > 20);
         assert!(phys.len() > 20);
     }
+
+    // ---------------------------------------------------------------
+    // constants::lookup — sorted-index name lookup
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn lookup_finds_every_table_entry() {
+        use cmn::constants::{lookup, CONSTANTS_TABLE};
+        for entry in CONSTANTS_TABLE {
+            assert_eq!(
+                lookup(entry.name),
+                Some(entry),
+                "{}",
+                entry.name
+            );
+        }
+    }
+
+    #[test]
+    fn lookup_misses_return_none() {
+        use cmn::constants::lookup;
+        for name in ["", "pi", "PI ", "A", "ZZZZ", "PLANCK_", "\u{0}"] {
+            assert!(lookup(name).is_none(), "{name:?}");
+        }
+    }
+
+    #[test]
+    fn dataset_entry_uses_same_index_for_every_edition() {
+        use cmn::constants::Dataset;
+        for dataset in Dataset::ALL {
+            for entry in dataset.table() {
+                assert_eq!(dataset.entry(entry.name), Some(entry));
+            }
+        }
+    }
+
+    #[test]
+    fn by_name_resolves_in_const_contexts() {
+        use cmn::constants::{
+            by_name, lookup, Dataset, CONSTANTS_TABLE,
+        };
+        const H: Option<f64> = by_name("PLANCK");
+        const G: Option<f64> =
+            Dataset::Codata2018.by_name("GRAVITATIONAL_CONSTANT");
+        const _: () = assert!(lookup("PLANK").is_none());
+        assert_eq!(H, Some(PLANCK));
+        assert_eq!(G, Some(6.67430e-11));
+        for entry in CONSTANTS_TABLE {
+            assert_eq!(by_name(entry.name), entry.value.as_f64());
+        }
+        assert_eq!(by_name("HASH_COST"), None);
+        assert_eq!(by_name("pi"), None);
+    }
+
+    // ---------------------------------------------------------------
+    // Units on CONSTANTS_TABLE and Constants::unit
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn constants_table_every_entry_has_a_unit_symbol() {
+        use cmn::constants::CONSTANTS_TABLE;
+        for entry in CONSTANTS_TABLE {
+            assert!(
+                !entry.unit.symbol.is_empty(),
+                "{} has no unit symbol",
+                entry.name
+            );
+        }
+    }
+
+    #[test]
+    fn constants_table_mathematical_entries_are_dimensionless() {
+        use cmn::constants::{Category, CONSTANTS_TABLE};
+        for entry in CONSTANTS_TABLE
+            .iter()
+            .filter(|e| e.category == Category::Mathematical)
+        {
+            assert!(
+                entry.unit.dimension.is_dimensionless(),
+                "{} should be dimensionless",
+                entry.name
+            );
+        }
+    }
+
+    #[test]
+    fn constants_unit_known_and_unknown() {
+        use cmn::units::Dimension;
+        let c = Constants::new();
+        let g = c.unit("GRAVITATIONAL_CONSTANT").unwrap();
+        assert_eq!(g.symbol, "m^3 kg^-1 s^-2");
+        assert_eq!(g.dimension, Dimension::new(3, -1, -2, 0, 0, 0, 0));
+        assert!(c.unit("HASH_ALGORITHM").is_none());
+        assert!(c.unit("DOES_NOT_EXIST").is_none());
+    }
+
+    #[test]
+    fn constants_unit_scaled_mass_units_share_kg_dimension() {
+        let c = Constants::new();
+        let kg = c.unit("ELECTRON_MASS").unwrap();
+        let mev = c.unit("ELECTRON_MASS_MEV").unwrap();
+        assert!(kg.is_compatible(&mev));
+        let si = mev.to_si(cmn::constants::ELECTRON_MASS_MEV);
+        assert!((si - ELECTRON_MASS).abs() / ELECTRON_MASS < 1e-9);
+    }
+
+    // ---------------------------------------------------------------
+    // Standard uncertainty metadata
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn uncertainty_links_existing_uncertainty_consts() {
+        use cmn::constants::{
+            Dataset, BOHR_RADIUS_UNCERTAINTY,
+            GRAVITATIONAL_CONSTANT_UNCERTAINTY,
+        };
+        let c = Constants::with_dataset(Dataset::Codata2018);
+        let a0 = c.uncertainty("BOHR_RADIUS").unwrap();
+        assert_eq!(a0.absolute, BOHR_RADIUS_UNCERTAINTY);
+        assert_eq!(a0.relative, BOHR_RADIUS_UNCERTAINTY / BOHR_RADIUS);
+        assert!(!a0.exact);
+        let g = c.uncertainty("GRAVITATIONAL_CONSTANT").unwrap();
+        assert_eq!(g.absolute, GRAVITATIONAL_CONSTANT_UNCERTAINTY);
+    }
+
+    #[test]
+    fn uncertainty_si_defining_constants_are_exact() {
+        use cmn::constants::uncertainty;
+        for name in [
+            "PLANCK",
+            "SPEED_OF_LIGHT",
+            "ELEMENTARY_CHARGE",
+            "BOLTZMANN",
+            "AVOGADRO",
+        ] {
+            let u = uncertainty(name).unwrap();
+            assert!(u.exact, "{name} should be exact");
+            assert_eq!(u.absolute, 0.0);
+            assert_eq!(u.relative, 0.0);
+        }
+    }
+
+    #[test]
+    fn uncertainty_mathematical_constants_are_exact() {
+        use cmn::constants::{Category, CONSTANTS_TABLE};
+        for entry in CONSTANTS_TABLE
+            .iter()
+            .filter(|e| e.category == Category::Mathematical)
+        {
+            assert!(entry.uncertainty.is_exact(), "{}", entry.name);
+        }
+    }
+
+    #[test]
+    fn uncertainty_measured_constants_are_small_and_positive() {
+        use cmn::constants::CONSTANTS_TABLE;
+        for entry in CONSTANTS_TABLE {
+            let u = entry.standard_uncertainty();
+            if !u.exact {
+                assert!(u.absolute > 0.0, "{}", entry.name);
+                assert!(u.relative < 1e-2, "{}", entry.name);
+            }
+        }
+    }
+
+    #[test]
+    fn uncertainty_unknown_name_returns_none() {
+        assert!(cmn::constants::uncertainty("NOPE").is_none());
+        assert!(Constants::new().uncertainty("HASH_COST").is_none());
+    }
+
+    #[test]
+    fn uncertainty_enum_accessors() {
+        use cmn::constants::Uncertainty;
+        let s = Uncertainty::Standard(0.5);
+        assert!(!s.is_exact());
+        assert_eq!(s.absolute(), 0.5);
+        assert_eq!(s.relative(-2.0), 0.25);
+        assert!(Uncertainty::Exact.is_exact());
+        assert_eq!(Uncertainty::Exact.absolute(), 0.0);
+        assert_eq!(Uncertainty::Exact.relative(2.0), 0.0);
+    }
+
+    // ---------------------------------------------------------------
+    // CODATA datasets
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn dataset_default_follows_feature() {
+        use cmn::constants::{Dataset, CONSTANTS_TABLE};
+        let expected = if cfg!(feature = "codata2022") {
+            Dataset::Codata2022
+        } else {
+            Dataset::Codata2018
+        };
+        assert_eq!(Dataset::DEFAULT, expected);
+        assert_eq!(Dataset::default(), expected);
+        assert_eq!(Constants::new().dataset(), expected);
+        assert_eq!(CONSTANTS_TABLE, expected.table());
+    }
+
+    #[test]
+    fn dataset_tables_share_names_and_order() {
+        use cmn::constants::Dataset;
+        let old = Dataset::Codata2018.table();
+        let new = Dataset::Codata2022.table();
+        assert_eq!(old.len(), new.len());
+        for (a, b) in old.iter().zip(new) {
+            assert_eq!(a.name, b.name);
+            assert_eq!(a.category, b.category);
+            assert_eq!(a.unit, b.unit);
+        }
+    }
+
+    #[test]
+    fn dataset_codata2022_updates_adjusted_values() {
+        use cmn::constants::{codata2022, Dataset};
+        let entry = Dataset::Codata2022.entry("ELECTRON_MASS").unwrap();
+        assert_eq!(
+            entry.value.as_f64(),
+            Some(codata2022::ELECTRON_MASS)
+        );
+        assert_ne!(entry.value.as_f64(), Some(ELECTRON_MASS));
+        assert_eq!(
+            entry.uncertainty.absolute(),
+            codata2022::ELECTRON_MASS_UNCERTAINTY
+        );
+        for &(name, value, sigma) in Dataset::Codata2022.adjusted() {
+            let e = Dataset::Codata2022.entry(name).unwrap();
+            assert_eq!(e.value.as_f64(), Some(value), "{name}");
+            assert_eq!(e.uncertainty.absolute(), sigma, "{name}");
+        }
+    }
+
+    #[test]
+    fn dataset_exact_constants_match_across_editions() {
+        use cmn::constants::Dataset;
+        for entry in Dataset::Codata2018.table() {
+            if entry.uncertainty.is_exact() {
+                assert_eq!(
+                    Some(entry),
+                    Dataset::Codata2022.entry(entry.name),
+                    "{}",
+                    entry.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn constants_with_dataset_resolves_values_and_uncertainty() {
+        use cmn::constants::{codata2022, Dataset};
+        let c = Constants::with_dataset(Dataset::Codata2022);
+        assert_eq!(c.dataset(), Dataset::Codata2022);
+        assert!(matches!(
+            c.get_value("ELECTRON_MASS"),
+            Some(ConstantValue::Float(v)) if v == codata2022::ELECTRON_MASS
+        ));
+        assert_eq!(
+            c.uncertainty("ELECTRON_MASS").unwrap().absolute,
+            codata2022::ELECTRON_MASS_UNCERTAINTY
+        );
+        let c = Constants::with_dataset(Dataset::Codata2018);
+        assert!(matches!(
+            c.get_value("ELECTRON_MASS"),
+            Some(ConstantValue::Float(v)) if v == ELECTRON_MASS
+        ));
+    }
+
+    #[test]
+    fn dataset_display_and_lookup_miss() {
+        use cmn::constants::Dataset;
+        assert_eq!(Dataset::Codata2018.to_string(), "CODATA 2018");
+        assert_eq!(Dataset::Codata2022.to_string(), "CODATA 2022");
+        assert_eq!(Dataset::ALL.len(), 2);
+        assert!(Dataset::Codata2022.entry("NOT_A_CONSTANT").is_none());
+    }
+
+    // ---------------------------------------------------------------
+    // ConstantInfo metadata
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn info_every_table_entry_is_described() {
+        use cmn::constants::CONSTANTS_TABLE;
+        for entry in CONSTANTS_TABLE {
+            let info = entry.info;
+            assert!(!info.description.is_empty(), "{}", entry.name);
+            if !entry.value.is_float() {
+                continue;
+            }
+            assert!(!info.symbol.is_empty(), "{}", entry.name);
+            assert!(!info.latex.is_empty(), "{}", entry.name);
+            if let Some(formula) = info.formula {
+                assert!(!formula.is_empty(), "{}", entry.name);
+            }
+        }
+    }
+
+    #[test]
+    fn info_sources_follow_category() {
+        use cmn::constants::{Category, Source, CONSTANTS_TABLE};
+        for entry in CONSTANTS_TABLE {
+            let math = entry.category == Category::Mathematical;
+            assert_eq!(
+                entry.info.source == Source::Mathematics,
+                math,
+                "{}",
+                entry.name
+            );
+        }
+        assert_eq!(
+            cmn::constants::info("HIGGS_BOSON_MASS_GEV")
+                .unwrap()
+                .source,
+            Source::Pdg2022
+        );
+    }
+
+    #[test]
+    fn info_codata2022_relabels_adjusted_sources_only() {
+        use cmn::constants::{Dataset, Source};
+        let old = Dataset::Codata2018.table();
+        let new = Dataset::Codata2022.table();
+        for (a, b) in old.iter().zip(new) {
+            let adjusted = Dataset::Codata2022
+                .adjusted()
+                .iter()
+                .any(|(name, ..)| *name == a.name);
+            if adjusted {
+                assert_eq!(
+                    b.info.source,
+                    Source::Codata2022,
+                    "{}",
+                    a.name
+                );
+            } else {
+                assert_eq!(a.info, b.info, "{}", a.name);
+            }
+            assert_eq!(a.info.description, b.info.description);
+        }
+    }
+
+    #[test]
+    fn info_covers_every_runtime_constant() {
+        let c = Constants::new();
+        for constant in c.constants() {
+            let info = c.info(&constant.name);
+            assert!(info.is_some(), "{}", constant.name);
+            assert_eq!(info, cmn::constants::info(&constant.name));
+        }
+        assert!(c.info("NOT_A_CONSTANT").is_none());
+    }
+
+    #[test]
+    fn info_formula_present_for_derived_constants() {
+        use cmn::constants::info;
+        assert_eq!(info("FARADAY").unwrap().formula, Some("N_A e"));
+        assert_eq!(info("PLANCK").unwrap().formula, None);
+        assert_eq!(info("PLANCK_REDUCED").unwrap().latex, r"\hbar");
+    }
+
+    #[test]
+    fn source_citation_and_display() {
+        use cmn::constants::Source;
+        assert_eq!(Source::Codata2018.to_string(), "CODATA 2018");
+        assert_eq!(Source::Codata2022.citation(), "CODATA 2022");
+        assert_eq!(Source::Pdg2022.to_string(), "PDG 2022");
+    }
+
+    #[test]
+    fn info_serializes_to_json() {
+        let info =
+            cmn::constants::info("MAGNETIC_FLUX_QUANTUM").unwrap();
+        let v = serde_json::to_value(info).unwrap();
+        assert_eq!(v["symbol"], "Φ₀");
+        assert_eq!(v["source"], "Codata2018");
+        assert_eq!(v["formula"], "h/(2e)");
+    }
+
+    #[test]
+    fn search_matches_descriptions() {
+        let hits =
+            Constants::new().search("wien wavelength displacement");
+        assert_eq!(hits[0].name, "WIEN_DISPLACEMENT");
+        assert_eq!(
+            hits[0].matched,
+            "Wien wavelength displacement law constant"
+        );
+    }
+
+    // ---------------------------------------------------------------
+    // Decimal expansions
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn digits_cover_every_mathematical_constant() {
+        use cmn::constants::{
+            digits, Category, CONSTANTS_TABLE, MAX_DIGITS,
+        };
+        for entry in CONSTANTS_TABLE {
+            let expansion = digits(entry.name, MAX_DIGITS);
+            if entry.category != Category::Mathematical {
+                assert!(expansion.is_none(), "{}", entry.name);
+                continue;
+            }
+            let expansion = expansion.unwrap();
+            let (int, frac) = expansion.split_once('.').unwrap();
+            assert_eq!(frac.len(), MAX_DIGITS, "{}", entry.name);
+            assert!(
+                int.chars()
+                    .chain(frac.chars())
+                    .all(|c| c.is_ascii_digit()),
+                "{}",
+                entry.name
+            );
+            // The f64 constant is the correctly rounded expansion.
+            assert_eq!(
+                expansion.parse::<f64>().ok(),
+                entry.value.as_f64(),
+                "{}",
+                entry.name
+            );
+        }
+    }
+
+    #[test]
+    fn digits_truncate_without_rounding() {
+        use cmn::constants::{digits, PHI_DIGITS, SQRT2_DIGITS};
+        // π = 3.14159 26535 89793 2384...
+        assert_eq!(digits("PI", 5), Some("3.14159"));
+        assert_eq!(digits("GAMMA", 3), Some("0.577"));
+        assert_eq!(digits("KHINCHIN", 0), Some("2"));
+        assert!(PHI_DIGITS.starts_with("1.6180339887498948482045868"));
+        assert!(SQRT2_DIGITS.starts_with("1.41421356237309504880168"));
+        assert!(digits("NOT_A_CONSTANT", 1).is_none());
+        assert!(digits("HASH_COST", 1).is_none());
+    }
+
+    #[test]
+    fn digits_agree_between_related_constants() {
+        use cmn::constants::digits;
+        // Spot-check well-known 50-digit prefixes.
+        assert_eq!(
+            digits("TAU", 50),
+            Some(
+                "6.28318530717958647692528676655900576839433879875021"
+            )
+        );
+        assert_eq!(
+            digits("APERY", 50),
+            Some(
+                "1.20205690315959428539973816151144999076498629234049"
+            )
+        );
+        assert_eq!(
+            digits("CATALAN", 50),
+            Some(
+                "0.91596559417721901505460351493238411077414937428167"
+            )
+        );
+    }
 }
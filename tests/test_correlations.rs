@@ -0,0 +1,208 @@
+#![allow(missing_docs)]
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod tests {
+    use cmn::constants::correlations::{
+        self, modelled_uncertainty, DEPENDENCES,
+    };
+    use cmn::constants::{
+        modelled_correlation, Dataset, CONSTANTS_TABLE,
+    };
+    use cmn::measured::Measured;
+
+    const DATASETS: [Dataset; 2] =
+        [Dataset::Codata2018, Dataset::Codata2022];
+
+    fn r(dataset: Dataset, a: &str, b: &str) -> f64 {
+        dataset.modelled_correlation(a, b).unwrap()
+    }
+
+    // ---------------------------------------------------------------
+    // Dependences
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn dependences_name_table_rows_after_their_inputs() {
+        let row = |name: &str| {
+            CONSTANTS_TABLE.iter().position(|e| e.name == name)
+        };
+        for (i, dependence) in DEPENDENCES.iter().enumerate() {
+            assert!(row(dependence.constant).is_some());
+            assert!(!dependence.formula.is_empty());
+            for &(input, _) in dependence.on {
+                assert!(row(input).is_some(), "{input}");
+                // An input is either independent or listed earlier.
+                let defined = DEPENDENCES
+                    .iter()
+                    .position(|d| d.constant == input);
+                assert!(defined.map_or(true, |j| j < i), "{input}");
+            }
+        }
+    }
+
+    #[test]
+    fn modelled_uncertainties_match_the_tables() {
+        // Only the rounding of two-digit uncertainties separates
+        // the modelled value from the tabulated one.
+        for dataset in DATASETS {
+            for entry in dataset.table() {
+                let Some(value) = entry.value.as_f64() else {
+                    assert_eq!(
+                        modelled_uncertainty(dataset, entry.name),
+                        None
+                    );
+                    continue;
+                };
+                let modelled =
+                    modelled_uncertainty(dataset, entry.name).unwrap();
+                let table = entry.uncertainty.absolute() / value.abs();
+                if table == 0.0 {
+                    assert_eq!(modelled, 0.0, "{}", entry.name);
+                } else {
+                    let ratio = modelled / table;
+                    assert!(
+                        (ratio - 1.0).abs() < 0.06,
+                        "{} {ratio}",
+                        entry.name
+                    );
+                }
+            }
+        }
+    }
+
+    // ---------------------------------------------------------------
+    // Coefficients
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn coefficients_are_symmetric_and_bounded() {
+        // Every modelled constant and input, plus an exact one.
+        let mut names = vec!["SPEED_OF_LIGHT"];
+        for dependence in DEPENDENCES {
+            names.push(dependence.constant);
+            names.extend(dependence.on.iter().map(|&(n, _)| n));
+        }
+        names.sort_unstable();
+        names.dedup();
+        for dataset in DATASETS {
+            for (i, a) in names.iter().enumerate() {
+                assert_eq!(
+                    dataset.modelled_correlation(a, a),
+                    Some(1.0)
+                );
+                for b in &names[i + 1..] {
+                    let ab = r(dataset, a, b);
+                    assert_eq!(ab, r(dataset, b, a), "{a} {b}");
+                    assert!((-1.0..=1.0).contains(&ab), "{a} {b}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn exact_and_unknown_constants() {
+        assert_eq!(
+            modelled_correlation("PLANCK", "SPEED_OF_LIGHT"),
+            Some(0.0)
+        );
+        assert_eq!(
+            modelled_correlation("PLANCK", "ELECTRON_MASS"),
+            Some(0.0)
+        );
+        assert_eq!(modelled_correlation("PI", "PI"), Some(1.0));
+        assert_eq!(modelled_correlation("NOPE", "PI"), None);
+        assert_eq!(modelled_correlation("PI", "HASH_LENGTH"), None);
+        assert_eq!(
+            Dataset::DEFAULT.modelled_covariance("PI", "NOPE"),
+            None
+        );
+    }
+
+    #[test]
+    fn signs_follow_the_exponents() {
+        for d in DATASETS {
+            assert!(r(d, "ELECTRON_MASS", "FINE_STRUCTURE") < -0.999);
+            assert!(r(d, "BOHR_MAGNETON", "ELECTRON_MASS") < -0.999);
+            // μ_e is negative, so it rises as μ_B does in magnitude.
+            let mu = r(d, "ELECTRON_MAGNETIC_MOMENT", "BOHR_MAGNETON");
+            assert!(mu < -0.999);
+            assert!(
+                r(d, "SACKUR_TETRODE_CONSTANT", "ATOMIC_MASS_UNIT")
+                    > 0.999
+            );
+            assert!(r(d, "PLANCK_LENGTH", "PLANCK_MASS") < -0.999);
+            assert!(r(d, "PROTON_MASS", "ELECTRON_MASS") > 0.9);
+        }
+    }
+
+    #[test]
+    fn independent_inputs_are_uncorrelated() {
+        // G, the τ mass and the PDG boson masses are measured
+        // apart from the adjustment of α, R∞ and the mass ratios.
+        for d in DATASETS {
+            assert_eq!(r(d, "GRAVITATIONAL_CONSTANT", "RYDBERG"), 0.0);
+            assert_eq!(r(d, "PLANCK_MASS", "ELECTRON_MASS"), 0.0);
+            for name in ["W_BOSON_MASS_GEV", "TAU_PARTICLE_MASS"] {
+                assert_eq!(r(d, name, "MUON_MASS"), 0.0);
+            }
+        }
+    }
+
+    // ---------------------------------------------------------------
+    // Matrix and covariance
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn matrix_has_a_unit_diagonal() {
+        let names = [
+            "FINE_STRUCTURE",
+            "ELECTRON_MASS",
+            "BOHR_RADIUS",
+            "PROTON_MASS",
+        ];
+        let m = correlations::modelled_matrix(Dataset::DEFAULT, names)
+            .unwrap();
+        for i in 0..names.len() {
+            assert_eq!(m[i][i], 1.0);
+            for j in 0..names.len() {
+                assert_eq!(m[i][j], m[j][i]);
+                assert_eq!(
+                    m[i][j],
+                    modelled_correlation(names[i], names[j]).unwrap()
+                );
+            }
+        }
+        let bad = ["FINE_STRUCTURE", "HASH_COST"];
+        assert_eq!(
+            correlations::modelled_matrix(Dataset::DEFAULT, bad),
+            None
+        );
+    }
+
+    #[test]
+    fn covariance_cancels_shared_uncertainty() {
+        // r_e = α² a₀: recomputing it with the covariance of α and
+        // a₀ recovers the tabulated uncertainty.
+        for d in DATASETS {
+            let get = |n| Measured::from_constant_in(d, n).unwrap();
+            let (alpha, a0) =
+                (get("FINE_STRUCTURE"), get("BOHR_RADIUS"));
+            let r_e = get("CLASSICAL_ELECTRON_RADIUS");
+            let alpha2 = alpha.powi(2);
+            let cov = 2.0
+                * alpha.value
+                * d.modelled_covariance(
+                    "FINE_STRUCTURE",
+                    "BOHR_RADIUS",
+                )
+                .unwrap();
+            let correlated = alpha2.mul_with_covariance(a0, cov);
+            let naive = alpha2 * a0;
+            let ratio = correlated.relative() / r_e.relative();
+            assert!((ratio - 1.0).abs() < 0.06, "{ratio}");
+            assert!(naive.relative() / r_e.relative() < 0.8);
+        }
+    }
+}
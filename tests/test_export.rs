@@ -0,0 +1,292 @@
+#![allow(missing_docs)]
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod tests {
+    use cmn::constants::export::{Catalogue, Format};
+    use cmn::constants::loader::{load, Format as LoadFormat};
+    use cmn::constants::registry::Definition;
+    use cmn::constants::{
+        Category, ConstantRegistry, Constants, Dataset, Uncertainty,
+        CONSTANTS_TABLE,
+    };
+    use cmn::units::{Dimension, Unit, DEGREE_CELSIUS, METER};
+
+    /// The electron mass and its uncertainty, formatted as the
+    /// exporters write them.
+    fn m_e() -> (String, String) {
+        let entry = Dataset::DEFAULT.entry("ELECTRON_MASS").unwrap();
+        let Uncertainty::Standard(sigma) = entry.uncertainty else {
+            unreachable!()
+        };
+        let value = entry.value.as_f64().unwrap();
+        (format!("{value:e}"), format!("{sigma:e}"))
+    }
+
+    fn crypto() -> Catalogue {
+        Catalogue::new().in_category(Category::Cryptographic)
+    }
+
+    // ---------------------------------------------------------------
+    // Catalogue
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn new_covers_the_whole_table() {
+        let catalogue = Catalogue::new();
+        assert_eq!(catalogue.rows().len(), CONSTANTS_TABLE.len());
+        assert_eq!(catalogue, Catalogue::default());
+        for (row, entry) in catalogue.rows().iter().zip(CONSTANTS_TABLE)
+        {
+            assert_eq!(row.name, entry.name);
+            assert_eq!(row.unit, entry.unit);
+            assert_eq!(row.category, Some(entry.category));
+        }
+    }
+
+    #[test]
+    fn in_category_keeps_matching_rows_in_order() {
+        let names: Vec<_> =
+            crypto().rows().iter().map(|r| r.name.clone()).collect();
+        assert_eq!(
+            names,
+            [
+                "HASH_ALGORITHM",
+                "HASH_COST",
+                "HASH_LENGTH",
+                "SPECIAL_CHARS"
+            ]
+        );
+        for category in [Category::Physical, Category::Mathematical] {
+            let catalogue = Catalogue::new().in_category(category);
+            assert!(!catalogue.rows().is_empty());
+            assert!(catalogue
+                .rows()
+                .iter()
+                .all(|row| row.category == Some(category)));
+        }
+    }
+
+    #[test]
+    fn from_constants_uses_the_dataset_metadata() {
+        let mut constants = Constants::new();
+        constants
+            .load(LoadFormat::Csv, "name,value\nLAB_GAIN,4\n")
+            .unwrap();
+        let catalogue = Catalogue::from_constants(&constants);
+        let rows = catalogue.rows();
+        assert_eq!(rows.len(), constants.constants().len());
+        let gain = rows.last().unwrap();
+        assert_eq!(gain.name, "LAB_GAIN");
+        assert_eq!(gain.category, None);
+        assert_eq!(gain.uncertainty, Uncertainty::Exact);
+        assert!(gain.symbol.is_empty());
+        let c = rows.iter().find(|r| r.name == "SPEED_OF_LIGHT");
+        assert_eq!(c.unwrap().symbol, "c");
+    }
+
+    #[test]
+    fn from_registry_includes_user_constants() {
+        let mut registry = ConstantRegistry::new();
+        registry
+            .register(
+                Definition::new(
+                    "LAB_BASELINE",
+                    12.5,
+                    Category::Physical,
+                )
+                .with_unit(METER)
+                .with_uncertainty(Uncertainty::Standard(0.002)),
+            )
+            .unwrap();
+        let catalogue = Catalogue::from_registry(&registry);
+        assert_eq!(catalogue.rows().len(), CONSTANTS_TABLE.len() + 1);
+        let csv = catalogue.render(Format::Csv);
+        assert!(
+            csv.ends_with("\nLAB_BASELINE,,12.5,m,0.002,Physical\n")
+        );
+
+        let density =
+            Definition::new("LAB_DENSITY", 1.2, Category::Physical)
+                .with_unit_symbol("kg m^-3")
+                .unwrap();
+        registry.register(density).unwrap();
+        let csv =
+            Catalogue::from_registry(&registry).render(Format::Csv);
+        let row = "\nLAB_DENSITY,,1.2,kg m^-3,,Physical\n";
+        assert!(csv.ends_with(row));
+    }
+
+    // ---------------------------------------------------------------
+    // Formats
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn csv_quotes_cells_that_need_it() {
+        let csv = crypto().render(Format::Csv);
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("name,symbol,value,unit,uncertainty,category")
+        );
+        assert_eq!(
+            lines.nth(3),
+            Some(
+                "SPECIAL_CHARS,,\"!@#$%^&*()_+=[]{}|;:\"\"<>,.?/~`\",\
+                 1,,Cryptographic"
+            )
+        );
+
+        let (value, sigma) = m_e();
+        let physical = Catalogue::new().render(Format::Csv);
+        assert!(physical.contains(&format!(
+            "\nELECTRON_MASS,mₑ,{value},kg,{sigma},Physical\n"
+        )));
+    }
+
+    #[test]
+    fn csv_rows_load_back_without_the_symbol() {
+        let mut registry = ConstantRegistry::new();
+        let definition =
+            Definition::new("ODD, \"NAME\"", 1e-12, Category::Physical)
+                .with_uncertainty(Uncertainty::Standard(3e-15));
+        registry.register(definition.clone()).unwrap();
+        let csv =
+            Catalogue::from_registry(&registry).render(Format::Csv);
+        let last = csv.lines().last().unwrap();
+        assert_eq!(
+            last,
+            "\"ODD, \"\"NAME\"\"\",,1e-12,1,3e-15,Physical"
+        );
+
+        let user = format!(
+            "name,value,unit,uncertainty,category\n{}\n",
+            last.replacen(",,", ",", 1)
+        );
+        assert_eq!(load(LoadFormat::Csv, &user).unwrap(), [definition]);
+    }
+
+    #[test]
+    fn markdown_escapes_pipes_and_backticks() {
+        let markdown = crypto().render(Format::Markdown);
+        let lines: Vec<_> = markdown.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[1], "|:---|:---|---:|:---|---:|:---|");
+        assert_eq!(
+            lines[3],
+            "| `HASH_COST` |  | 8 | 1 | exact | Cryptographic |"
+        );
+        assert_eq!(
+            lines[5],
+            "| `SPECIAL_CHARS` |  | \
+             `` !@#$%^&*()_+=[]{}\\|;:\"<>,.?/~` `` | \
+             1 | exact | Cryptographic |"
+        );
+        let (value, sigma) = m_e();
+        let physical = Catalogue::new().render(Format::Markdown);
+        assert!(physical.contains(&format!(
+            "| `ELECTRON_MASS` | mₑ | {value} | kg | {sigma} | \
+             Physical |\n"
+        )));
+        assert!(physical.contains("| `AVOGADRO` | N\\_A |"));
+    }
+
+    #[test]
+    fn latex_escapes_text_and_typesets_numbers() {
+        let latex = Catalogue::new().render(Format::Latex);
+        assert!(
+            latex.starts_with("\\begin{tabular}{llrlrl}\n\\hline\n")
+        );
+        assert!(latex.ends_with("\\hline\n\\end{tabular}\n"));
+        let (value, sigma) = m_e();
+        let tex = |text: String| {
+            let (mantissa, exponent) = text.split_once('e').unwrap();
+            format!("${mantissa} \\times 10^{{{exponent}}}$")
+        };
+        assert!(latex.contains(&format!(
+            "\\texttt{{ELECTRON\\_MASS}} & $m_\\mathrm{{e}}$ & {} & \
+             $\\mathrm{{kg}}$ & {} & Physical \\\\\n",
+            tex(value),
+            tex(sigma)
+        )));
+        assert!(latex.contains(
+            "\\texttt{!@\\#\\$\\%\\textasciicircum{}\\&*()\\_+=[]\\{\\}"
+        ));
+        assert!(latex.contains("$\\mathrm{J\\,K^{-1}}$"));
+    }
+
+    #[test]
+    fn latex_is_plain_ascii() {
+        // pdflatex rejects raw μ, ħ, Å or Ω, even in math mode.
+        let latex = Catalogue::new().render(Format::Latex);
+        for line in latex.lines() {
+            assert!(line.is_ascii(), "{line}");
+        }
+        assert!(latex.contains(
+            "\\texttt{VON\\_KLITZING\\_CONSTANT} & $R_\\mathrm{K}$"
+        ));
+        assert!(latex.contains("$\\mathrm{\\Omega }$"));
+    }
+
+    #[test]
+    fn latex_escapes_unit_symbols() {
+        let mut registry = ConstantRegistry::new();
+        let percent = Unit::scaled("% & #{x}~", Dimension::NONE, 0.01);
+        for (name, unit) in
+            [("LAB_TEMPERATURE", DEGREE_CELSIUS), ("LAB_GAIN", percent)]
+        {
+            let definition =
+                Definition::new(name, 1.0, Category::Physical)
+                    .with_unit(unit);
+            registry.register(definition).unwrap();
+        }
+        let flux = Definition::new("LAB_FLUX", 2.0, Category::Physical)
+            .with_unit_symbol("W·m⁻²")
+            .unwrap();
+        registry.register(flux).unwrap();
+        let latex =
+            Catalogue::from_registry(&registry).render(Format::Latex);
+        for line in latex.lines() {
+            assert!(line.is_ascii(), "{line}");
+        }
+        assert!(latex.contains(
+            "\\texttt{LAB\\_TEMPERATURE} &  & $1$ & \
+             $\\mathrm{^\\circ C}$ &"
+        ));
+        assert!(latex.contains(
+            "$\\mathrm{\\%\\,\\&\\,\\#\\{x\\}\\text{\\textasciitilde}}$"
+        ));
+        assert!(latex.contains("$\\mathrm{W\\cdot m^{-2}}$"));
+    }
+
+    #[test]
+    fn json_is_an_array_of_typed_values() {
+        let json: serde_json::Value = serde_json::from_str(
+            &Catalogue::new().render(Format::Json),
+        )
+        .unwrap();
+        let rows = json.as_array().unwrap();
+        assert_eq!(rows.len(), CONSTANTS_TABLE.len());
+        let electron = rows
+            .iter()
+            .find(|row| row["name"] == "ELECTRON_MASS")
+            .unwrap();
+        assert_eq!(electron["symbol"], "mₑ");
+        let (value, sigma) = m_e();
+        assert_eq!(electron["value"], value.parse::<f64>().unwrap());
+        assert_eq!(electron["unit"], "kg");
+        assert_eq!(
+            electron["uncertainty"],
+            sigma.parse::<f64>().unwrap()
+        );
+        assert_eq!(electron["category"], "Physical");
+
+        let special = rows
+            .iter()
+            .find(|row| row["name"] == "SPECIAL_CHARS")
+            .unwrap();
+        assert_eq!(special["uncertainty"], serde_json::Value::Null);
+        assert_eq!(special["value"], "!@#$%^&*()_+=[]{}|;:\"<>,.?/~`");
+    }
+}
@@ -0,0 +1,136 @@
+#![allow(missing_docs)]
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod tests {
+    use cmn::constants::{
+        self, codata2018, codata2022, digits, f32, Category, Dataset,
+        MAX_DIGITS,
+    };
+
+    // ---------------------------------------------------------------
+    // Table
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn table_mirrors_every_float_row() {
+        let floats: Vec<_> = codata2018::TABLE
+            .iter()
+            .filter(|e| e.value.is_float())
+            .map(|e| e.name)
+            .collect();
+        let names: Vec<_> =
+            f32::TABLE.iter().map(|(n, _)| *n).collect();
+        assert_eq!(names, floats);
+    }
+
+    #[test]
+    fn adjusted_mirrors_codata2022() {
+        let names: Vec<_> =
+            f32::CODATA2022_ADJUSTED.iter().map(|(n, _)| *n).collect();
+        let expected: Vec<_> =
+            codata2022::ADJUSTED.iter().map(|(n, ..)| *n).collect();
+        assert_eq!(names, expected);
+    }
+
+    // ---------------------------------------------------------------
+    // Rounding
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn mathematical_constants_round_from_their_expansions() {
+        // `str::parse` rounds correctly, so parsing 120 digits gives
+        // the f32 nearest the exact value.
+        for entry in codata2018::TABLE {
+            if entry.category != Category::Mathematical {
+                continue;
+            }
+            let exact = digits(entry.name, MAX_DIGITS).unwrap();
+            assert_eq!(
+                f32::get_in(Dataset::Codata2018, entry.name),
+                exact.parse().ok(),
+                "{}",
+                entry.name
+            );
+        }
+    }
+
+    #[test]
+    fn physical_constants_round_from_the_published_decimal() {
+        // The shortest f64 representation is the CODATA literal.
+        for entry in codata2018::TABLE {
+            if entry.category != Category::Physical {
+                continue;
+            }
+            let decimal = entry.value.as_f64().unwrap().to_string();
+            assert_eq!(
+                f32::get_in(Dataset::Codata2018, entry.name),
+                decimal.parse().ok(),
+                "{}",
+                entry.name
+            );
+        }
+        for &(name, value, _) in codata2022::ADJUSTED {
+            assert_eq!(
+                f32::get_in(Dataset::Codata2022, name),
+                value.to_string().parse().ok(),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn named_constants_match_the_table() {
+        assert_eq!(f32::PI, core::f32::consts::PI);
+        assert_eq!(f32::get("PI"), Some(f32::PI));
+        assert_eq!(f32::get("APERY"), Some(f32::APERY));
+        assert_eq!(f32::get("PLANCK"), Some(f32::PLANCK));
+        assert_eq!(f32::get("GAS_CONSTANT"), Some(f32::GAS_CONSTANT));
+    }
+
+    #[test]
+    fn tiny_constants_are_subnormal_not_zero() {
+        assert!(f32::PLANCK_TIME.is_subnormal());
+        assert!(f32::ATOMIC_UNIT_OF_POLARIZABILITY.is_subnormal());
+        assert!(f32::PLANCK_LENGTH.is_normal());
+    }
+
+    // ---------------------------------------------------------------
+    // Lookup
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn get_follows_the_default_dataset() {
+        for entry in constants::CONSTANTS_TABLE {
+            let Some(value) = entry.value.as_f64() else {
+                assert!(f32::get(entry.name).is_none());
+                continue;
+            };
+            // Within half an f32 ulp, or half the smallest subnormal.
+            let single = f64::from(f32::get(entry.name).unwrap());
+            let half_ulp = 2f64.powi(-24);
+            assert!(
+                (single - value).abs()
+                    <= value.abs() * half_ulp + 1e-45,
+                "{}",
+                entry.name
+            );
+        }
+    }
+
+    #[test]
+    fn get_in_switches_adjusted_values() {
+        // Most CODATA 2022 adjustments vanish at single precision;
+        // e/m_p is the exception.
+        assert_ne!(
+            f32::get_in(Dataset::Codata2018, "PROTON_CHARGE_TO_MASS"),
+            f32::get_in(Dataset::Codata2022, "PROTON_CHARGE_TO_MASS")
+        );
+        assert_eq!(
+            f32::get_in(Dataset::Codata2018, "PLANCK"),
+            f32::get_in(Dataset::Codata2022, "PLANCK")
+        );
+        assert!(f32::get_in(Dataset::Codata2022, "NOPE").is_none());
+    }
+}
@@ -0,0 +1,167 @@
+#![allow(missing_docs)]
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::near;
+    use cmn::constants::{
+        BOLTZMANN, ELECTRON_MASS, ELECTRON_VOLT,
+        MOLAR_VOLUME_IDEAL_GAS, PI, PLANCK, SPEED_OF_LIGHT,
+        STEFAN_BOLTZMANN,
+    };
+    use cmn::formulas::{
+        de_broglie_wavelength, hydrogen_wavelength,
+        hydrogen_wavenumber, ideal_gas_amount, ideal_gas_pressure,
+        ideal_gas_temperature, ideal_gas_volume, lorentz_factor,
+        photon_energy, photon_wavelength, spectral_radiance,
+        spectral_radiance_frequency, wien_peak_wavelength,
+        RYDBERG_HYDROGEN,
+    };
+
+    // ---------------------------------------------------------------
+    // Thermal radiation
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn radiance_integrates_to_stefan_boltzmann() {
+        // π ∫ B_λ dλ = σT⁴, by the midpoint rule on a log grid.
+        let t = 1500.0;
+        let (start, end, steps) = (1e-8f64, 1e-2f64, 20_000);
+        let ratio = (end / start).powf(1.0 / f64::from(steps));
+        let mut total = 0.0;
+        let mut low = start;
+        for _ in 0..steps {
+            let high = low * ratio;
+            let mid = (low * high).sqrt();
+            total += spectral_radiance(mid, t) * (high - low);
+            low = high;
+        }
+        let exitance = STEFAN_BOLTZMANN * t.powi(4);
+        assert!(near(PI * total, exitance, 1e-6));
+    }
+
+    #[test]
+    fn radiance_forms_agree_and_approach_rayleigh_jeans() {
+        let t = 6000.0;
+        for wavelength in [200e-9, 500e-9, 2e-6, 1e-3] {
+            let frequency = SPEED_OF_LIGHT / wavelength;
+            // B_λ = B_ν dν/dλ = B_ν c/λ².
+            let per_frequency =
+                spectral_radiance_frequency(frequency, t)
+                    * SPEED_OF_LIGHT
+                    / (wavelength * wavelength);
+            assert!(near(
+                spectral_radiance(wavelength, t),
+                per_frequency,
+                1e-12
+            ));
+        }
+        // hc/λ ≪ kT: B_λ → 2ckT/λ⁴.
+        let wavelength: f64 = 1.0;
+        let classical =
+            2.0 * SPEED_OF_LIGHT * BOLTZMANN * t / wavelength.powi(4);
+        assert!(near(
+            spectral_radiance(wavelength, t),
+            classical,
+            1e-5
+        ));
+    }
+
+    #[test]
+    fn wien_peak_is_the_maximum_of_planck_law() {
+        for t in [300.0, 5772.0, 1e5] {
+            let peak = wien_peak_wavelength(t);
+            let best = (-1000..=1000)
+                .map(|i| peak * (1.0 + f64::from(i) * 1e-5))
+                .max_by(|a, b| {
+                    spectral_radiance(*a, t)
+                        .total_cmp(&spectral_radiance(*b, t))
+                })
+                .unwrap();
+            assert!(near(best, peak, 2e-5));
+        }
+    }
+
+    // ---------------------------------------------------------------
+    // Photons and matter waves
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn photon_energy_and_wavelength_are_inverse() {
+        // 1 eV ↔ 1239.841 98 nm.
+        let lambda = photon_wavelength(ELECTRON_VOLT);
+        assert!(near(lambda, 1_239.841_984e-9, 1e-9));
+        assert!(near(photon_energy(lambda), ELECTRON_VOLT, 1e-15));
+        assert_eq!(photon_energy(1.0), PLANCK * SPEED_OF_LIGHT);
+    }
+
+    #[test]
+    fn lorentz_factor_matches_known_speeds() {
+        let c = SPEED_OF_LIGHT;
+        assert!(near(lorentz_factor(0.8 * c), 5.0 / 3.0, 1e-12));
+        assert_eq!(lorentz_factor(-0.8 * c), lorentz_factor(0.8 * c));
+        assert!(near(lorentz_factor(1e3), 1.0, 1e-11));
+        assert!(lorentz_factor(1.5 * c).is_nan());
+    }
+
+    #[test]
+    fn de_broglie_has_the_classical_limit() {
+        let slow = 1e3;
+        let classical = PLANCK / (ELECTRON_MASS * slow);
+        let lambda = de_broglie_wavelength(ELECTRON_MASS, slow);
+        assert!(near(lambda, classical, 1e-11));
+        // At γ = 5/3 the momentum is 4/3 m_e c.
+        let fast =
+            de_broglie_wavelength(ELECTRON_MASS, 0.8 * SPEED_OF_LIGHT);
+        let compton = PLANCK / (ELECTRON_MASS * SPEED_OF_LIGHT);
+        assert!(near(fast, 0.75 * compton, 1e-12));
+    }
+
+    // ---------------------------------------------------------------
+    // Gases
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn ideal_gas_law_round_trips() {
+        // The molar volume at 273.15 K and 100 kPa.
+        let v = ideal_gas_volume(1.0, 273.15, 100e3);
+        assert!(near(v, MOLAR_VOLUME_IDEAL_GAS, 1e-6));
+        let (n, t, volume) = (2.5, 310.0, 0.04);
+        let p = ideal_gas_pressure(n, t, volume);
+        assert!(near(ideal_gas_volume(n, t, p), volume, 1e-15));
+        assert!(near(ideal_gas_temperature(n, p, volume), t, 1e-15));
+        assert!(near(ideal_gas_amount(p, t, volume), n, 1e-15));
+    }
+
+    // ---------------------------------------------------------------
+    // Hydrogen spectrum
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn hydrogen_lines_follow_the_rydberg_formula() {
+        assert!(near(RYDBERG_HYDROGEN, 10_967_758.34, 1e-9));
+        // Balmer lines, vacuum wavelengths in nm.
+        for (upper, nm) in [(3, 656.47), (4, 486.27), (5, 434.17)] {
+            let lambda = hydrogen_wavelength(2, upper).unwrap();
+            assert!(near(lambda, nm * 1e-9, 2e-5), "{upper}");
+        }
+        // The series converges on R_H/4 (the Balmer limit).
+        let limit = hydrogen_wavenumber(2, 100_000).unwrap();
+        assert!(near(limit, RYDBERG_HYDROGEN / 4.0, 1e-9));
+        // Ionisation from the ground state: R_H hc = 13.598 eV.
+        let ionisation =
+            photon_energy(hydrogen_wavelength(1, u32::MAX).unwrap());
+        assert!(near(ionisation / ELECTRON_VOLT, 13.598_287, 1e-6));
+    }
+
+    #[test]
+    fn hydrogen_lines_need_ordered_levels() {
+        assert_eq!(hydrogen_wavenumber(0, 2), None);
+        assert_eq!(hydrogen_wavenumber(3, 3), None);
+        assert_eq!(hydrogen_wavelength(3, 2), None);
+        assert!(hydrogen_wavelength(1, 2).unwrap() < 122e-9);
+    }
+}
@@ -0,0 +1,297 @@
+#![allow(missing_docs)]
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod tests {
+    use cmn::constants::loader::{
+        load, Format, LoadError, LoadErrorKind,
+    };
+    use cmn::constants::{
+        Category, ConstantRegistry, Constants, Uncertainty,
+    };
+    use cmn::units::{self, Dimension, KELVIN, METER};
+
+    fn error(format: Format, text: &str) -> (usize, LoadErrorKind) {
+        let LoadError { line, kind } = load(format, text).unwrap_err();
+        (line, kind)
+    }
+
+    const JSON: &str = r#"{
+        "version": 3,
+        "constants": [
+            {
+                "name": "LAB_BASELINE",
+                "value": 12.5,
+                "unit": "m",
+                "uncertainty": 0.002,
+                "category": "Physical",
+                "description": "Interferometer baseline"
+            },
+            { "name": "LAB_GAIN", "value": 4,
+              "category": "mathematical" }
+        ]
+    }"#;
+
+    const CSV: &str = "\
+name,value,unit,uncertainty,category,description
+LAB_BASELINE,12.5,m,0.002,Physical,Interferometer baseline
+LAB_GAIN,4,,,mathematical,
+";
+
+    fn assert_loaded(registry: &ConstantRegistry) {
+        let baseline = registry.definition("LAB_BASELINE").unwrap();
+        assert_eq!(baseline.unit, METER);
+        assert_eq!(baseline.uncertainty, Uncertainty::Standard(0.002));
+        assert_eq!(baseline.category, Category::Physical);
+        assert_eq!(baseline.description, "Interferometer baseline");
+        let gain = registry.definition("LAB_GAIN").unwrap();
+        assert_eq!(registry.get::<f64>("LAB_GAIN"), Ok(4.0));
+        assert_eq!(gain.unit, units::ONE);
+        assert_eq!(gain.uncertainty, Uncertainty::Exact);
+        assert_eq!(gain.category, Category::Mathematical);
+        assert!(gain.description.is_empty());
+    }
+
+    // ---------------------------------------------------------------
+    // Formats
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn json_and_csv_load_the_same_definitions() {
+        assert_eq!(
+            load(Format::Json, JSON).unwrap(),
+            load(Format::Csv, CSV).unwrap()
+        );
+        for (format, text) in [(Format::Json, JSON), (Format::Csv, CSV)]
+        {
+            let mut registry = ConstantRegistry::new();
+            registry.load(format, text).unwrap();
+            assert_eq!(registry.user_defined().len(), 2);
+            assert_loaded(&registry);
+        }
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn toml_loads_the_same_definitions() {
+        let toml = r#"
+title = "Lab calibration"
+
+[[constants]]
+name = "LAB_BASELINE"
+value = 12.5
+unit = "m"
+uncertainty = 0.002
+category = "Physical"
+description = "Interferometer baseline"
+
+[[constants]]
+name = "LAB_GAIN"
+value = 4
+category = "mathematical"
+"#;
+        assert_eq!(
+            load(Format::Toml, toml).unwrap(),
+            load(Format::Json, JSON).unwrap()
+        );
+        assert_eq!(
+            error(Format::Toml, &toml.replace("12.5", "nan")),
+            (6, LoadErrorKind::NonFinite("value"))
+        );
+        assert_eq!(
+            error(Format::Toml, &toml.replace("GAIN", "BASELINE")),
+            (13, LoadErrorKind::Duplicate("LAB_BASELINE".to_string()))
+        );
+        let (line, kind) = error(Format::Toml, "[[constants]\n");
+        assert_eq!(line, 1);
+        assert!(matches!(kind, LoadErrorKind::Syntax(_)));
+    }
+
+    #[test]
+    fn csv_columns_may_be_reordered_and_quoted() {
+        let csv = "\u{feff}Value,NAME,description\r\n\
+                   300,ROOM,\"Room temperature, nominal\"\r\n\
+                   \r\n\
+                   1,NOTE,\"multi\nline \"\"quoted\"\"\"\r\n\
+                   ,,\r\n";
+        let definitions = load(Format::Csv, csv).unwrap();
+        assert_eq!(definitions.len(), 2);
+        assert_eq!(
+            definitions[0].description,
+            "Room temperature, nominal"
+        );
+        assert_eq!(
+            definitions[1].description,
+            "multi\nline \"quoted\""
+        );
+        assert_eq!(
+            error(Format::Csv, &format!("{csv}BAD,VALUE,x\n")),
+            (
+                7,
+                LoadErrorKind::Syntax(
+                    "invalid value `BAD`".to_string()
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn format_from_extension() {
+        assert_eq!(Format::from_extension("csv"), Some(Format::Csv));
+        assert_eq!(Format::from_extension("Json"), Some(Format::Json));
+        #[cfg(feature = "toml")]
+        assert_eq!(Format::from_extension("TOML"), Some(Format::Toml));
+        assert_eq!(Format::from_extension("xls"), None);
+    }
+
+    // ---------------------------------------------------------------
+    // Line-numbered errors
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn duplicates_are_reported_on_their_line() {
+        assert_eq!(
+            error(Format::Csv, "name,value\nA,1\nB,2\nA,3\n"),
+            (4, LoadErrorKind::Duplicate("A".to_string()))
+        );
+
+        let json = JSON.replace("LAB_GAIN", "LAB_BASELINE");
+        assert_eq!(
+            error(Format::Json, &json),
+            (12, LoadErrorKind::Duplicate("LAB_BASELINE".to_string()))
+        );
+
+        let mut registry = ConstantRegistry::new();
+        let clash = CSV.replace("LAB_GAIN", "BOLTZMANN");
+        let error = registry.load(Format::Csv, &clash).unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(
+            error.to_string(),
+            "line 3: duplicate constant `BOLTZMANN`"
+        );
+        assert!(registry.user_defined().is_empty());
+    }
+
+    #[test]
+    fn non_finite_values_are_rejected() {
+        assert_eq!(
+            error(Format::Csv, &CSV.replace("12.5", "inf")),
+            (2, LoadErrorKind::NonFinite("value"))
+        );
+        assert_eq!(
+            error(Format::Csv, &CSV.replace("0.002", "NaN")),
+            (2, LoadErrorKind::NonFinite("uncertainty"))
+        );
+        assert_eq!(
+            error(Format::Csv, &CSV.replace("0.002", "-0.002")),
+            (2, LoadErrorKind::NegativeUncertainty)
+        );
+        let (line, kind) =
+            error(Format::Json, &JSON.replace("12.5", "1e999"));
+        assert_eq!(line, 6);
+        assert!(matches!(kind, LoadErrorKind::Syntax(_)));
+    }
+
+    #[test]
+    fn malformed_units_and_categories_are_rejected() {
+        assert_eq!(
+            error(Format::Csv, &CSV.replace(",m,", ",metres,")),
+            (2, LoadErrorKind::UnknownUnit("metres".to_string()))
+        );
+        assert_eq!(
+            error(Format::Json, &JSON.replace("\"m\"", "\"m/s/s/\"")),
+            (7, LoadErrorKind::MalformedUnit("m/s/s/".to_string()))
+        );
+        assert_eq!(
+            error(Format::Csv, &CSV.replace(",m,", ",J/mol K,"))
+                .1
+                .to_string(),
+            "malformed unit `J/mol K`"
+        );
+        assert_eq!(
+            error(Format::Json, &JSON.replace("mathematical", "misc")),
+            (13, LoadErrorKind::UnknownCategory("misc".to_string()))
+        );
+        let kelvin = load(Format::Csv, &CSV.replace(",m,", ",K,"));
+        assert_eq!(kelvin.unwrap()[0].unit, KELVIN);
+    }
+
+    #[test]
+    fn prefixed_and_compound_units_are_loaded() {
+        for (symbol, dimension, scale) in [
+            ("V", units::VOLT.dimension, 1.0),
+            ("A", units::AMPERE.dimension, 1.0),
+            ("W", units::WATT.dimension, 1.0),
+            ("T", units::TESLA.dimension, 1.0),
+            ("mV", units::VOLT.dimension, 1e-3),
+            ("mA", units::AMPERE.dimension, 1e-3),
+            ("J/K", units::JOULE_PER_KELVIN.dimension, 1.0),
+            ("m/s", units::METER_PER_SECOND.dimension, 1.0),
+            ("s^-1", units::HERTZ.dimension, 1.0),
+            ("kg m^-3", Dimension::new(-3, 1, 0, 0, 0, 0, 0), 1.0),
+            ("°C", KELVIN.dimension, 1.0),
+        ] {
+            let csv = CSV.replace(",m,", &format!(",{symbol},"));
+            let loaded = load(Format::Csv, &csv).unwrap();
+            let unit = loaded[0].unit;
+            assert_eq!(loaded[0].unit_symbol(), symbol);
+            assert_eq!(unit.dimension, dimension, "{symbol}");
+            assert_eq!(unit.scale, scale, "{symbol}");
+            // The symbol survives a round trip through the registry.
+            let mut registry = ConstantRegistry::new();
+            registry.load(Format::Csv, &csv).unwrap();
+            let json = serde_json::to_string(&registry).unwrap();
+            let back: ConstantRegistry =
+                serde_json::from_str(&json).unwrap();
+            assert_eq!(
+                back.definition("LAB_BASELINE"),
+                Some(&loaded[0])
+            );
+        }
+    }
+
+    #[test]
+    fn malformed_documents_are_rejected() {
+        let cases = [
+            (Format::Json, JSON.replace("\"unit\"", "\"units\""), 7),
+            (Format::Json, JSON.replace("\"value\": 4,", ""), 13),
+            (Format::Json, "{}".to_string(), 1),
+            (Format::Json, "{ \"constants\": [ }".to_string(), 1),
+            (Format::Csv, String::new(), 1),
+            (Format::Csv, "name,value,colour\n".to_string(), 1),
+            (Format::Csv, "name,unit\n".to_string(), 1),
+            (Format::Csv, "name,value\nA,1,2\n".to_string(), 2),
+            (Format::Csv, "name,value\nA\n".to_string(), 2),
+            (Format::Csv, "name,value\nA,\n".to_string(), 2),
+            (Format::Csv, "name,value\n\"A,1\n".to_string(), 2),
+        ];
+        for (format, text, line) in cases {
+            let (found, kind) = error(format, &text);
+            assert_eq!(found, line, "{text}");
+            assert!(matches!(kind, LoadErrorKind::Syntax(_)), "{text}");
+        }
+        assert_eq!(
+            error(Format::Csv, "name,value\n,1\n"),
+            (2, LoadErrorKind::EmptyName)
+        );
+    }
+
+    // ---------------------------------------------------------------
+    // Constants
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn constants_load_merges_names_and_values() {
+        let mut constants = Constants::new();
+        constants.load(Format::Csv, CSV).unwrap();
+        assert_eq!(constants.constants().len(), 123);
+        assert_eq!(constants.get::<f64>("LAB_BASELINE"), Ok(12.5));
+
+        let clash = CSV.replace("LAB_GAIN", "PI");
+        let mut constants = Constants::new();
+        let error = constants.load(Format::Csv, &clash).unwrap_err();
+        assert_eq!(error.kind, LoadErrorKind::Duplicate("PI".into()));
+        assert_eq!(constants.constants().len(), 121);
+    }
+}
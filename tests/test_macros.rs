@@ -6,9 +6,10 @@
 mod tests {
     use cmn::Common;
     use cmn::{
-        cmn_assert, cmn_constants, cmn_contains, cmn_in_range,
-        cmn_join, cmn_map, cmn_max, cmn_min, cmn_parse, cmn_print,
-        cmn_print_vec, cmn_split, cmn_to_num, cmn_vec, constants::*,
+        cmn_assert, cmn_const, cmn_constants, cmn_contains,
+        cmn_in_range, cmn_join, cmn_map, cmn_max, cmn_min, cmn_parse,
+        cmn_print, cmn_print_vec, cmn_split, cmn_to_num, cmn_vec,
+        constants::*,
     };
 
     // ===============================================================
@@ -350,6 +351,24 @@ mod tests {
         assert_eq!(TEST_E, std::f64::consts::E);
     }
 
+    // ===============================================================
+    // cmn_const! macro
+    // ===============================================================
+
+    #[test]
+    fn cmn_const_resolves_names_at_compile_time() {
+        const H: f64 = cmn_const!("PLANCK");
+        const C: f64 = cmn_const!("SPEED_OF_LIGHT");
+        assert_eq!(H, PLANCK);
+        assert_eq!(C, SPEED_OF_LIGHT);
+        assert_eq!(cmn_const!("PI"), PI);
+        // Follows the default dataset, like `lookup`.
+        assert_eq!(
+            Some(cmn_const!("ELECTRON_MASS")),
+            Dataset::DEFAULT.by_name("ELECTRON_MASS")
+        );
+    }
+
     // ===============================================================
     // cmn! macro (top-level parse)
     // ===============================================================
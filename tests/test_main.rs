@@ -60,4 +60,54 @@ mod tests {
 
         assert!(output.status.success());
     }
+
+    // ---------------------------------------------------------------
+    // Binary execution — constant lookup and suggestions
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn binary_prints_resolved_constant() {
+        let output = Command::cargo_bin("cmn")
+            .unwrap()
+            .args(["hbar", "PI"])
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout
+            .contains("PLANCK_REDUCED = 1.0545718176461565e-34 J s"));
+        assert!(stdout.contains("PI = 3.141592653589793"));
+    }
+
+    #[test]
+    fn binary_unknown_constant_suggests_names() {
+        let output = Command::cargo_bin("cmn")
+            .unwrap()
+            .arg("PLANK")
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(!output.status.success());
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(
+            stderr.contains(
+                "unknown constant `PLANK`; did you mean PLANCK"
+            ),
+            "got: {stderr}"
+        );
+    }
+
+    #[test]
+    fn binary_unknown_constant_without_suggestions() {
+        let output = Command::cargo_bin("cmn")
+            .unwrap()
+            .arg("xyzzy")
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(!output.status.success());
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(stderr.contains("unknown constant `xyzzy`\n"));
+    }
 }
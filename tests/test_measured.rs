@@ -0,0 +1,227 @@
+#![allow(missing_docs)]
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::near;
+    use cmn::constants::{
+        Dataset, CONSTANTS_TABLE, GRAVITATIONAL_CONSTANT,
+        GRAVITATIONAL_CONSTANT_UNCERTAINTY,
+    };
+    use cmn::measured::Measured;
+
+    /// Central-difference derivative, to check the analytic ones.
+    fn slope(f: impl Fn(f64) -> f64, x: f64) -> f64 {
+        let h = 1e-6 * x.abs().max(1.0);
+        (f(x + h) - f(x - h)) / (2.0 * h)
+    }
+
+    // ---------------------------------------------------------------
+    // Constructors
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn from_constant_reads_value_and_uncertainty() {
+        let g = Measured::from_constant("GRAVITATIONAL_CONSTANT");
+        assert_eq!(
+            Measured::from_constant_in(
+                Dataset::Codata2018,
+                "GRAVITATIONAL_CONSTANT"
+            ),
+            Some(Measured::new(
+                GRAVITATIONAL_CONSTANT,
+                GRAVITATIONAL_CONSTANT_UNCERTAINTY
+            ))
+        );
+        assert!(g.unwrap().relative() > 1e-5);
+        for entry in CONSTANTS_TABLE {
+            let m = Measured::from_constant(entry.name);
+            assert_eq!(m.map(|m| m.value), entry.value.as_f64());
+            if let Some(m) = m {
+                assert_eq!(m.sigma, entry.uncertainty.absolute());
+                assert_eq!(m.is_exact(), entry.uncertainty.is_exact());
+            }
+        }
+        assert_eq!(Measured::from_constant("NOPE"), None);
+    }
+
+    #[test]
+    fn new_normalises_the_sign_of_sigma() {
+        assert_eq!(Measured::new(1.0, -0.5).sigma, 0.5);
+        assert_eq!(Measured::from(3.0), Measured::exact(3.0));
+        assert_eq!(Measured::exact(0.0).relative(), 0.0);
+        assert_eq!(Measured::new(-4.0, 0.5).relative(), 0.125);
+    }
+
+    // ---------------------------------------------------------------
+    // Uncorrelated arithmetic
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn sums_add_absolute_uncertainties_in_quadrature() {
+        let a = Measured::new(10.0, 3.0);
+        let b = Measured::new(5.0, 4.0);
+        assert_eq!(a + b, Measured::new(15.0, 5.0));
+        assert_eq!(a - b, Measured::new(5.0, 5.0));
+        assert_eq!(-a, Measured::new(-10.0, 3.0));
+        assert_eq!(a + 1.0, Measured::new(11.0, 3.0));
+        assert_eq!(1.0 - a, Measured::new(-9.0, 3.0));
+        let total: Measured = [a, b, a].into_iter().sum();
+        assert_eq!(total.value, 25.0);
+        assert!(near(total.sigma, 34f64.sqrt(), 1e-12));
+    }
+
+    #[test]
+    fn products_add_relative_uncertainties_in_quadrature() {
+        let a = Measured::new(10.0, 0.3);
+        let b = Measured::new(4.0, 0.16);
+        let product = a * b;
+        assert_eq!(product.value, 40.0);
+        assert!(near(product.relative(), 0.05, 1e-12));
+        let quotient = a / b;
+        assert_eq!(quotient.value, 2.5);
+        assert!(near(quotient.relative(), 0.05, 1e-12));
+        assert_eq!(2.0 * a, Measured::new(20.0, 0.6));
+        assert_eq!(a / 2.0, Measured::new(5.0, 0.15));
+        assert!(near((1.0 / b).relative(), 0.04, 1e-12));
+    }
+
+    // ---------------------------------------------------------------
+    // Covariance-aware arithmetic
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn full_correlation_cancels_and_compounds() {
+        let x = Measured::new(3.0, 0.2);
+        let var = x.covariance(x, 1.0);
+        assert_eq!(var, x.sigma * x.sigma);
+        assert_eq!(x.sub_with_covariance(x, var).sigma, 0.0);
+        assert!(near(x.add_with_covariance(x, var).sigma, 0.4, 1e-12));
+        assert_eq!(x.div_with_covariance(x, var), Measured::exact(1.0));
+        assert!(near(
+            x.mul_with_covariance(x, var).sigma,
+            x.powi(2).sigma,
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn anticorrelation_is_the_mirror_image() {
+        let a = Measured::new(2.0, 0.3);
+        let b = Measured::new(5.0, 0.4);
+        let cov = a.covariance(b, -1.0);
+        assert!(near(a.add_with_covariance(b, cov).sigma, 0.1, 1e-12));
+        assert!(near(a.sub_with_covariance(b, cov).sigma, 0.7, 1e-12));
+        let partial = a.add_with_covariance(b, a.covariance(b, 0.5));
+        assert!(near(
+            partial.sigma,
+            (0.09f64 + 0.16 + 0.12).sqrt(),
+            1e-12
+        ));
+        assert_eq!(a.add_with_covariance(b, 0.0), a + b);
+        assert_eq!(a.div_with_covariance(b, 0.0), a / b);
+    }
+
+    #[test]
+    fn reduced_planck_keeps_the_relative_uncertainty() {
+        // ħ = h / 2π with h exact in the SI, and G's relative
+        // uncertainty survives scaling and powers.
+        let h = Measured::from_constant("PLANCK").unwrap();
+        let hbar = h / (2.0 * core::f64::consts::PI);
+        assert!(hbar.is_exact());
+        let g =
+            Measured::from_constant("GRAVITATIONAL_CONSTANT").unwrap();
+        let planck_length = (hbar * g / 299_792_458f64.powi(3)).sqrt();
+        assert!(near(
+            planck_length.relative(),
+            g.relative() / 2.0,
+            1e-12
+        ));
+        let table = Measured::from_constant("PLANCK_LENGTH").unwrap();
+        assert!((planck_length.value / table.value - 1.0).abs() < 1e-5);
+    }
+
+    // ---------------------------------------------------------------
+    // Powers and functions
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn functions_scale_sigma_by_their_derivative() {
+        let x = Measured::new(0.7, 1e-3);
+        type Case = (fn(Measured) -> Measured, fn(f64) -> f64);
+        let cases: [Case; 11] = [
+            (|m| m.powi(3), |v| v.powi(3)),
+            (|m| m.powf(-1.5), |v| v.powf(-1.5)),
+            (Measured::sqrt, f64::sqrt),
+            (Measured::cbrt, f64::cbrt),
+            (Measured::recip, f64::recip),
+            (Measured::exp, f64::exp),
+            (Measured::ln, f64::ln),
+            (Measured::log10, f64::log10),
+            (Measured::sin, f64::sin),
+            (Measured::cos, f64::cos),
+            (Measured::tan, f64::tan),
+        ];
+        for (measured, plain) in cases {
+            let y = measured(x);
+            assert_eq!(y.value, plain(x.value));
+            let expected = slope(plain, x.value).abs() * x.sigma;
+            assert!((y.sigma / expected - 1.0).abs() < 1e-6);
+        }
+        assert_eq!(
+            Measured::new(-2.0, 0.1).abs(),
+            Measured::new(2.0, 0.1)
+        );
+    }
+
+    #[test]
+    fn uncertain_exponents_propagate_through_the_logarithm() {
+        let base = Measured::new(2.0, 0.0);
+        let exponent = Measured::new(3.0, 0.1);
+        let y = base.pow(exponent);
+        assert_eq!(y.value, 8.0);
+        assert!(near(y.sigma, 8.0 * 2f64.ln() * 0.1, 1e-12));
+        let x = Measured::new(2.0, 0.05);
+        assert!(near(
+            x.pow(Measured::exact(3.0)).sigma,
+            x.powi(3).sigma,
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn exact_inputs_never_produce_nan() {
+        assert_eq!(Measured::exact(0.0).sqrt(), Measured::exact(0.0));
+        assert_eq!(
+            Measured::exact(-8.0).pow(Measured::exact(2.0)),
+            Measured::exact(64.0)
+        );
+        let zero = Measured::exact(0.0).ln();
+        assert_eq!(zero.sigma, 0.0);
+    }
+
+    #[test]
+    fn zeroth_powers_are_exactly_one() {
+        for x in [Measured::new(0.0, 0.1), Measured::new(-2.0, 0.5)] {
+            assert_eq!(x.powi(0), Measured::exact(1.0));
+            assert_eq!(x.powf(0.0), Measured::exact(1.0));
+        }
+    }
+
+    #[test]
+    fn relative_uncertainty_of_zero() {
+        assert_eq!(Measured::exact(0.0).relative(), 0.0);
+        assert_eq!(Measured::new(0.0, 0.1).relative(), f64::INFINITY);
+        assert_eq!(Measured::new(-4.0, 0.1).relative(), 0.025);
+    }
+
+    #[test]
+    fn display_shows_value_and_sigma() {
+        let x = Measured::new(1.5, 0.25);
+        assert_eq!(x.to_string(), "1.5 ± 0.25");
+        assert_eq!(format!("{x:.3}"), "1.500 ± 0.250");
+    }
+}
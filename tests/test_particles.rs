@@ -0,0 +1,154 @@
+#![allow(missing_docs)]
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::near;
+    use cmn::constants::{
+        ATOMIC_MASS_UNIT, ELECTRON_VOLT, MUON_MASS, NUCLEAR_MAGNETON,
+        PLANCK, PROTON_MAGNETIC_MOMENT, PROTON_MASS_MEV,
+        SPEED_OF_LIGHT,
+    };
+    use cmn::particles::{particle, PARTICLES};
+
+    // ---------------------------------------------------------------
+    // Lookup
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn lookup_by_symbol_alias_and_name() {
+        for query in ["μ⁻", "mu-", "mu", "μ", "muon", "MUON", " Mu_on"]
+        {
+            assert_eq!(
+                particle(query).unwrap().name,
+                "muon",
+                "{query}"
+            );
+        }
+        assert_eq!(particle("alpha-particle").unwrap().symbol, "α");
+        assert_eq!(particle("He2+").unwrap().name, "alpha particle");
+        assert_eq!(particle("w_boson").unwrap().charge, 1);
+        assert_eq!(particle("positron"), None);
+        assert_eq!(particle(""), None);
+    }
+
+    #[test]
+    fn symbols_are_case_sensitive() {
+        assert_eq!(particle("h").unwrap().name, "helion");
+        assert_eq!(particle("H").unwrap().name, "Higgs boson");
+        assert_eq!(particle("n").unwrap().name, "neutron");
+        assert_eq!(particle("N"), None);
+    }
+
+    #[test]
+    fn names_symbols_and_aliases_are_unique() {
+        let mut keys: Vec<&str> = PARTICLES
+            .iter()
+            .flat_map(|p| {
+                [p.name, p.symbol]
+                    .into_iter()
+                    .chain(p.aliases.iter().copied())
+            })
+            .collect();
+        let count = keys.len();
+        keys.sort_unstable();
+        keys.dedup();
+        assert_eq!(keys.len(), count);
+        for p in PARTICLES {
+            assert_eq!(particle(p.name), Some(p));
+            assert_eq!(particle(p.symbol), Some(p));
+        }
+    }
+
+    // ---------------------------------------------------------------
+    // Values
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn masses_agree_across_units() {
+        let mev = 1e6 * ELECTRON_VOLT;
+        for p in PARTICLES {
+            let energy = p.mass * SPEED_OF_LIGHT * SPEED_OF_LIGHT;
+            assert!(near(energy / mev, p.mass_mev, 1e-8), "{}", p.name);
+            assert!(
+                near(p.mass / ATOMIC_MASS_UNIT, p.mass_u, 1e-15),
+                "{}",
+                p.name
+            );
+        }
+        assert_eq!(particle("p").unwrap().mass_mev, PROTON_MASS_MEV);
+        let tau = particle("tau").unwrap();
+        assert!(near(tau.mass_mev, 1_776.86, 1e-4));
+        assert!(near(
+            particle("proton").unwrap().mass_u,
+            1.007_276,
+            1e-6
+        ));
+        assert!(near(particle("W").unwrap().mass_mev, 80_377.0, 1e-3));
+    }
+
+    #[test]
+    fn compton_wavelengths_are_h_over_mc() {
+        for p in PARTICLES {
+            let lambda = PLANCK / (p.mass * SPEED_OF_LIGHT);
+            assert!(
+                near(p.compton_wavelength, lambda, 1e-9),
+                "{}",
+                p.name
+            );
+        }
+        let muon = particle("mu").unwrap();
+        assert_eq!(muon.mass, MUON_MASS);
+        assert!(near(muon.compton_wavelength, 1.173_444_11e-14, 1e-8));
+    }
+
+    #[test]
+    fn g_factors_give_the_magnetic_moments() {
+        let proton = particle("p").unwrap();
+        let g = proton.g_factor.unwrap();
+        assert!(near(g, 5.585_694_69, 1e-9));
+        assert!(near(
+            g / 2.0 * NUCLEAR_MAGNETON,
+            PROTON_MAGNETIC_MOMENT,
+            1e-9
+        ));
+        let neutron = particle("n").unwrap();
+        assert!(near(neutron.g_factor.unwrap(), -3.826_085_45, 1e-9));
+        assert!(neutron.magnetic_moment.unwrap() < 0.0);
+        let electron = particle("e").unwrap();
+        assert!(near(electron.g_factor.unwrap(), -2.002_319_304, 1e-9));
+        assert_eq!(particle("muon").unwrap().g_factor, None);
+    }
+
+    #[test]
+    fn charges_and_spins() {
+        let expected = [
+            ("electron", -1, 0.5),
+            ("muon", -1, 0.5),
+            ("tau", -1, 0.5),
+            ("proton", 1, 0.5),
+            ("neutron", 0, 0.5),
+            ("deuteron", 1, 1.0),
+            ("triton", 1, 0.5),
+            ("helion", 2, 0.5),
+            ("alpha particle", 2, 0.0),
+            ("W boson", 1, 1.0),
+            ("Z boson", 0, 1.0),
+            ("Higgs boson", 0, 0.0),
+        ];
+        assert_eq!(PARTICLES.len(), expected.len());
+        for (p, (name, charge, spin)) in PARTICLES.iter().zip(expected)
+        {
+            assert_eq!(
+                (p.name, p.charge, p.spin),
+                (name, charge, spin)
+            );
+            if p.spin == 0.0 {
+                assert_eq!(p.magnetic_moment, Some(0.0), "{name}");
+            }
+        }
+    }
+}
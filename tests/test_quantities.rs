@@ -0,0 +1,213 @@
+#![allow(missing_docs)]
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::near;
+    use cmn::constants;
+    use cmn::quantities::{
+        self, Acceleration, Area, Dim, Dimensioned, Dimensionless,
+        Energy, Force, Frequency, Length, Mass, Quantity, Time,
+        Velocity,
+    };
+    use cmn::units::{self, Dimension};
+
+    // ---------------------------------------------------------------
+    // Dimensions
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn named_dimensions_match_units() {
+        assert_eq!(Length::DIMENSION, units::METER.dimension);
+        assert_eq!(Energy::DIMENSION, units::JOULE.dimension);
+        assert_eq!(Force::DIMENSION, units::NEWTON.dimension);
+        assert_eq!(Dimensionless::DIMENSION, Dimension::NONE);
+        assert_eq!(
+            <Dim<0, 0, 0, 0, 0, 0, 1>>::DIMENSION,
+            Dimension::new(0, 0, 0, 0, 0, 0, 1)
+        );
+    }
+
+    // ---------------------------------------------------------------
+    // Arithmetic
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn same_dimension_arithmetic() {
+        let a = Quantity::<Length>::new(3.0);
+        let b = Quantity::<Length>::new(1.5);
+        assert_eq!((a + b).value(), 4.5);
+        assert_eq!((a - b).value(), 1.5);
+        assert_eq!((-a).value(), -3.0);
+        let mut c = a;
+        c += b;
+        c -= Quantity::new(0.5);
+        assert_eq!(c.value(), 4.0);
+        assert!(b < a);
+        let total: Quantity<Length> = [a, b, c].into_iter().sum();
+        assert_eq!(total.value(), 8.5);
+    }
+
+    #[test]
+    fn scalar_arithmetic_keeps_dimension() {
+        let m = Quantity::<Mass>::new(2.0);
+        assert_eq!((m * 3.0).value(), 6.0);
+        assert_eq!((3.0 * m).value(), 6.0);
+        assert_eq!((m / 4.0).value(), 0.5);
+        let mut n = m;
+        n *= 5.0;
+        n /= 2.0;
+        assert_eq!(n.value(), 5.0);
+    }
+
+    #[test]
+    fn products_and_quotients_change_dimension() {
+        let l = Quantity::<Length>::new(2.0);
+        let t = Quantity::<Time>::new(4.0);
+        let area: Quantity<Area> = l * l;
+        let v: Quantity<Velocity> = l / t;
+        let a: Quantity<Acceleration> = v / t;
+        let f: Quantity<Force> = Quantity::<Mass>::new(3.0) * a;
+        let e: Quantity<Energy> = f * l;
+        let hz: Quantity<Frequency> = v / l;
+        assert_eq!(area.value(), 4.0);
+        assert_eq!(v.value(), 0.5);
+        assert_eq!(e.value(), 0.75);
+        assert_eq!(hz.value(), 0.25);
+        let ratio: Quantity<Dimensionless> = l / l;
+        assert_eq!(f64::from(ratio), 1.0);
+    }
+
+    #[test]
+    fn unnamed_intermediate_dimensions_compose() {
+        use quantities::{
+            BOLTZMANN, ELEMENTARY_CHARGE, GRAVITATIONAL_CONSTANT,
+            PLANCK_REDUCED, SPEED_OF_LIGHT, VACUUM_PERMITTIVITY,
+        };
+        let c2 = SPEED_OF_LIGHT * SPEED_OF_LIGHT;
+        assert_eq!(
+            c2.dimension(),
+            Dimension::new(2, 0, -2, 0, 0, 0, 0)
+        );
+        assert_eq!(c2.to_string(), "89875517873681760 m^2 s^-2");
+        let m = Quantity::<Mass>::new(2.0);
+        let e: Quantity<Energy> = m * c2;
+        assert_eq!(e.value(), 2.0 * c2.value());
+        // GM/r² and the Planck length √(ħG/c³), squared.
+        let r = Quantity::<Length>::new(4.0);
+        let g: Quantity<Acceleration> =
+            GRAVITATIONAL_CONSTANT * m / (r * r);
+        assert!(near(
+            g.value(),
+            constants::GRAVITATIONAL_CONSTANT / 8.0,
+            1e-12
+        ));
+        let l_p2: Quantity<Area> = PLANCK_REDUCED
+            * GRAVITATIONAL_CONSTANT
+            / (SPEED_OF_LIGHT * SPEED_OF_LIGHT * SPEED_OF_LIGHT);
+        let l_p = constants::PLANCK_LENGTH;
+        assert!((l_p2.value() / (l_p * l_p) - 1.0).abs() < 1e-5);
+        // α = e²/(4πε₀ħc) is dimensionless.
+        let alpha: Quantity<Dimensionless> = ELEMENTARY_CHARGE
+            * ELEMENTARY_CHARGE
+            / (4.0
+                * core::f64::consts::PI
+                * VACUUM_PERMITTIVITY
+                * PLANCK_REDUCED
+                * SPEED_OF_LIGHT);
+        assert!(
+            (f64::from(alpha) / constants::FINE_STRUCTURE - 1.0).abs()
+                < 1e-9
+        );
+        // Exponents up to ±12 are supported: k_B⁶ has m¹² and s⁻¹².
+        let k = BOLTZMANN * BOLTZMANN * BOLTZMANN;
+        let k6 = k * k;
+        assert_eq!(
+            k6.dimension(),
+            Dimension::new(12, 6, -12, 0, -6, 0, 0)
+        );
+    }
+
+    // ---------------------------------------------------------------
+    // Units and display
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn unit_conversion_checks_dimension() {
+        let m =
+            Quantity::<Mass>::from_unit(1.0, units::DALTON).unwrap();
+        assert_eq!(m.value(), constants::ATOMIC_MASS_UNIT);
+        assert!(near(m.to_unit(units::DALTON).unwrap(), 1.0, 1e-12));
+        assert!(m.to_unit(units::METER).is_none());
+        assert!(
+            Quantity::<Mass>::from_unit(1.0, units::JOULE).is_none()
+        );
+    }
+
+    #[test]
+    fn display_appends_si_units() {
+        let h = quantities::PLANCK;
+        assert_eq!(h.dimension(), units::JOULE_SECOND.dimension);
+        assert!(h.to_string().ends_with(" m^2 kg s^-1"));
+        assert_eq!(
+            format!("{:.3}", quantities::FINE_STRUCTURE),
+            "0.007"
+        );
+    }
+
+    // ---------------------------------------------------------------
+    // Typed constants
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn typed_constants_match_the_table_in_si() {
+        let twins = [
+            ("SPEED_OF_LIGHT", quantities::SPEED_OF_LIGHT.value()),
+            ("PLANCK", quantities::PLANCK.value()),
+            ("ELECTRON_MASS", quantities::ELECTRON_MASS.value()),
+            ("BOLTZMANN", quantities::BOLTZMANN.value()),
+            (
+                "HARTREE_ENERGY_EV",
+                quantities::HARTREE_ENERGY_EV.value(),
+            ),
+            (
+                "ELECTRON_MASS_MEV",
+                quantities::ELECTRON_MASS_MEV.value(),
+            ),
+        ];
+        let table = constants::Dataset::Codata2018.table();
+        for (name, value) in twins {
+            let entry = table.iter().find(|e| e.name == name).unwrap();
+            let si = entry.unit.to_si(entry.value.as_f64().unwrap());
+            assert!(near(value, si, 1e-12), "{name}");
+        }
+    }
+
+    #[test]
+    fn typed_constants_compose() {
+        let mc2: Quantity<Energy> = quantities::ELECTRON_MASS
+            * quantities::SPEED_OF_LIGHT
+            * quantities::SPEED_OF_LIGHT;
+        // m_e c^2 = 0.51099895 MeV (CODATA 2018).
+        let mev = Quantity::<Energy>::from_unit(
+            0.510_998_950e6,
+            units::ELECTRONVOLT,
+        )
+        .unwrap();
+        assert!((mc2.value() / mev.value() - 1.0).abs() < 1e-9);
+
+        let ratio =
+            quantities::ELECTRON_MASS_MEV / quantities::ELECTRON_MASS;
+        assert!((f64::from(ratio) - 1.0).abs() < 1e-9);
+
+        let hbar = quantities::PLANCK / (2.0 * core::f64::consts::PI);
+        assert!(near(
+            hbar.value(),
+            quantities::PLANCK_REDUCED.value(),
+            1e-12
+        ));
+    }
+}
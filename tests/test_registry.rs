@@ -0,0 +1,258 @@
+#![allow(missing_docs)]
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod tests {
+    use cmn::constants::registry::{Definition, RegistryError};
+    use cmn::constants::{
+        Category, ConstantError, ConstantRegistry, ConstantValue,
+        Dataset, Uncertainty, CONSTANTS_TABLE,
+    };
+    use cmn::units::{KELVIN, METER};
+
+    fn baseline() -> Definition {
+        Definition::new("LAB_BASELINE", 12.5, Category::Physical)
+            .with_unit(METER)
+            .with_uncertainty(Uncertainty::Standard(0.002))
+            .with_description("Interferometer baseline")
+    }
+
+    fn registry() -> ConstantRegistry {
+        let mut registry = ConstantRegistry::new();
+        registry.register(baseline()).unwrap();
+        registry
+            .register(Definition::new(
+                "SITE_CODE",
+                String::from("LAB-7"),
+                Category::Cryptographic,
+            ))
+            .unwrap();
+        registry
+    }
+
+    // ---------------------------------------------------------------
+    // Built-in constants
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn starts_from_the_dataset_table() {
+        for dataset in [Dataset::Codata2018, Dataset::Codata2022] {
+            let registry = ConstantRegistry::with_dataset(dataset);
+            assert_eq!(registry.dataset(), dataset);
+            assert_eq!(registry.definitions().len(), 121);
+            assert!(registry.user_defined().is_empty());
+            let m_e = dataset.entry("ELECTRON_MASS").unwrap();
+            assert_eq!(
+                registry.get::<f64>("ELECTRON_MASS"),
+                Ok(m_e.value.as_f64().unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn builtin_definitions_carry_the_row_metadata() {
+        let registry = ConstantRegistry::new();
+        for entry in CONSTANTS_TABLE {
+            let d = registry.definition(entry.name).unwrap();
+            assert_eq!(d.category, entry.category, "{}", entry.name);
+            assert_eq!(d.unit, entry.unit, "{}", entry.name);
+            assert_eq!(d.uncertainty, entry.uncertainty);
+            assert_eq!(d.description, entry.info.description);
+        }
+    }
+
+    // ---------------------------------------------------------------
+    // Registration
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn registered_constants_are_looked_up_like_builtins() {
+        let registry = registry();
+        assert_eq!(registry.definitions().len(), 123);
+        assert_eq!(registry.user_defined()[0], baseline());
+        assert_eq!(registry.get::<f64>("LAB_BASELINE"), Ok(12.5));
+        assert_eq!(registry.unit("LAB_BASELINE"), Some(METER));
+        assert_eq!(
+            registry.category("LAB_BASELINE"),
+            Some(Category::Physical)
+        );
+        let u = registry.uncertainty("LAB_BASELINE").unwrap();
+        assert_eq!(u.absolute, 0.002);
+        assert!((u.relative - 1.6e-4).abs() < 1e-12);
+        assert!(!u.exact);
+
+        assert_eq!(
+            registry.get::<String>("SITE_CODE"),
+            Ok("LAB-7".to_string())
+        );
+        assert_eq!(registry.unit("SITE_CODE"), None);
+        assert_eq!(registry.uncertainty("SITE_CODE"), None);
+        assert_eq!(
+            registry.get::<u32>("LAB_BASELINE"),
+            Err(ConstantError::TypeMismatch {
+                expected: "u32",
+                found: "f64",
+            })
+        );
+        assert_eq!(
+            registry.get::<f64>("MISSING"),
+            Err(ConstantError::NotFound)
+        );
+    }
+
+    #[test]
+    fn conflicting_names_are_rejected() {
+        let mut registry = registry();
+        assert_eq!(
+            registry.register(Definition::new(
+                "BOLTZMANN",
+                1.0,
+                Category::Physical
+            )),
+            Err(RegistryError::Conflict {
+                name: "BOLTZMANN".to_string(),
+                builtin: true,
+            })
+        );
+        let error = registry
+            .register(baseline().with_unit(KELVIN))
+            .unwrap_err();
+        assert_eq!(
+            error,
+            RegistryError::Conflict {
+                name: "LAB_BASELINE".to_string(),
+                builtin: false,
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "`LAB_BASELINE` is already registered"
+        );
+        assert_eq!(
+            registry.register(Definition::new(
+                "",
+                1.0,
+                Category::Physical
+            )),
+            Err(RegistryError::EmptyName)
+        );
+        assert_eq!(registry.definitions().len(), 123);
+        assert_eq!(registry.unit("LAB_BASELINE"), Some(METER));
+    }
+
+    #[test]
+    fn by_category_includes_user_constants() {
+        let registry = registry();
+        let crypto: Vec<_> = registry
+            .by_category(Category::Cryptographic)
+            .map(|d| &*d.name)
+            .collect();
+        assert_eq!(crypto.len(), 5);
+        assert_eq!(crypto.last(), Some(&"SITE_CODE"));
+    }
+
+    #[test]
+    fn search_covers_names_descriptions_and_aliases() {
+        let registry = registry();
+        let hits = registry.search("interferometer baseline");
+        assert_eq!(hits[0].name, "LAB_BASELINE");
+        assert_eq!(hits[0].matched, "Interferometer baseline");
+        assert_eq!(registry.search("lab baseline")[0].score, 1.0);
+        assert_eq!(registry.search("k_B")[0].name, "BOLTZMANN");
+    }
+
+    // ---------------------------------------------------------------
+    // Serialization
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn to_constants_includes_user_constants() {
+        let constants = registry().to_constants();
+        assert_eq!(constants.constants().len(), 123);
+        assert_eq!(constants.get::<f64>("LAB_BASELINE"), Ok(12.5));
+        assert_eq!(constants.dataset(), Dataset::DEFAULT);
+    }
+
+    #[test]
+    fn json_round_trip_keeps_user_constants_only() {
+        let registry = registry();
+        let json = serde_json::to_value(&registry).unwrap();
+        assert_eq!(json["constants"].as_array().unwrap().len(), 2);
+        assert_eq!(json["constants"][0]["unit"], "m");
+        assert_eq!(
+            json["constants"][0]["uncertainty"]["Standard"],
+            0.002
+        );
+        let back: ConstantRegistry =
+            serde_json::from_value(json).unwrap();
+        assert_eq!(back, registry);
+    }
+
+    #[test]
+    fn optional_fields_default_when_deserializing() {
+        let json = r#"{ "constants": [{
+            "name": "GAIN",
+            "value": { "U32": 4 },
+            "category": "Cryptographic"
+        }] }"#;
+        let registry: ConstantRegistry =
+            serde_json::from_str(json).unwrap();
+        assert_eq!(registry.dataset(), Dataset::DEFAULT);
+        let gain = registry.definition("GAIN").unwrap();
+        assert_eq!(gain.value, ConstantValue::U32(4));
+        assert_eq!(gain.unit, cmn::units::ONE);
+        assert_eq!(gain.uncertainty, Uncertainty::Exact);
+        assert!(gain.description.is_empty());
+    }
+
+    #[test]
+    fn invalid_documents_are_rejected() {
+        let unit = r#"{ "constants": [{
+            "name": "X", "value": { "Float": 1.0 },
+            "category": "Physical", "unit": "furlong"
+        }] }"#;
+        let error =
+            serde_json::from_str::<ConstantRegistry>(unit).unwrap_err();
+        assert!(error.to_string().contains("unknown unit `furlong`"));
+        let malformed = unit.replace("furlong", "m^");
+        let error =
+            serde_json::from_str::<ConstantRegistry>(&malformed)
+                .unwrap_err();
+        assert!(error.to_string().contains("malformed unit `m^`"));
+
+        let duplicate = r#"{ "constants": [
+            { "name": "X", "value": { "Float": 1.0 },
+              "category": "Physical" },
+            { "name": "X", "value": { "Float": 2.0 },
+              "category": "Physical" }
+        ] }"#;
+        let error = serde_json::from_str::<ConstantRegistry>(duplicate)
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("`X` is already registered"));
+    }
+
+    #[test]
+    fn every_builtin_unit_round_trips() {
+        let mut registry = ConstantRegistry::new();
+        for entry in CONSTANTS_TABLE {
+            let name = format!("COPY_OF_{}", entry.name);
+            let definition = Definition::new(name, 1.0, entry.category)
+                .with_unit(entry.unit);
+            registry.register(definition).unwrap();
+        }
+        let json = serde_json::to_string(&registry).unwrap();
+        let back: ConstantRegistry =
+            serde_json::from_str(&json).unwrap();
+        for (a, b) in back.user_defined().iter().zip(CONSTANTS_TABLE) {
+            assert_eq!(a.unit.symbol, b.unit.symbol);
+            assert_eq!(
+                a.unit.dimension, b.unit.dimension,
+                "{}",
+                b.name
+            );
+        }
+    }
+}
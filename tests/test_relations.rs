@@ -0,0 +1,150 @@
+#![allow(missing_docs)]
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod tests {
+    use cmn::constants::relations::{self, Relation, RELATIONS};
+    use cmn::constants::{lookup, ConstantValue, Constants, Dataset};
+
+    fn relation(constant: &str, formula: &str) -> &'static Relation {
+        RELATIONS
+            .iter()
+            .find(|r| r.constant == constant && r.formula == formula)
+            .unwrap()
+    }
+
+    fn with_value(name: &str, value: f64) -> Constants {
+        let mut constants = Constants::new();
+        constants
+            .constants
+            .iter_mut()
+            .find(|c| c.name == name)
+            .unwrap()
+            .value = ConstantValue::Float(value);
+        constants
+    }
+
+    // ---------------------------------------------------------------
+    // Registry
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn every_relation_refers_to_float_constants() {
+        for relation in RELATIONS {
+            for name in
+                relation.inputs.iter().chain([&relation.constant])
+            {
+                let entry = lookup(name).unwrap_or_else(|| {
+                    panic!("{}: unknown {name}", relation.formula)
+                });
+                assert!(entry.value.is_float(), "{name}");
+            }
+            assert!(
+                !relation.inputs.is_empty(),
+                "{}",
+                relation.formula
+            );
+            assert!(relation.inputs.len() <= 8, "{}", relation.formula);
+            assert!(relation.tolerance > 0.0, "{}", relation.formula);
+        }
+    }
+
+    #[test]
+    fn requested_identities_are_registered() {
+        assert_eq!(
+            relation("PLANCK_REDUCED", "ħ = h/(2π)").inputs,
+            ["PLANCK"]
+        );
+        assert_eq!(relation("PHI", "φ = (1 + √5)/2").inputs, ["SQRT5"]);
+        assert_eq!(
+            relation(
+                "PROTON_ELECTRON_MASS_RATIO",
+                "m_p/m_e = 1/(m_e/m_p)"
+            )
+            .inputs,
+            ["ELECTRON_PROTON_MASS_RATIO"]
+        );
+        assert_eq!(
+            relation("EV_TO_KG", "1 eV/c² = e/c²").inputs,
+            ["ELEMENTARY_CHARGE", "SPEED_OF_LIGHT"]
+        );
+        assert_eq!(
+            relation("PLANCK_MASS", "m_P = √(ħc/G)").inputs,
+            [
+                "PLANCK_REDUCED",
+                "SPEED_OF_LIGHT",
+                "GRAVITATIONAL_CONSTANT"
+            ]
+        );
+        assert_eq!(
+            relation("PLANCK_CHARGE", "q_P = √(4πε₀ħc)").inputs,
+            ["VACUUM_PERMITTIVITY", "PLANCK_REDUCED", "SPEED_OF_LIGHT"]
+        );
+    }
+
+    // ---------------------------------------------------------------
+    // Verification
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn both_datasets_are_consistent() {
+        for dataset in [Dataset::Codata2018, Dataset::Codata2022] {
+            let errors: Vec<_> = relations::verify(dataset)
+                .map(|e| e.to_string())
+                .collect();
+            assert!(errors.is_empty(), "{dataset}: {errors:#?}");
+            assert!(Constants::with_dataset(dataset)
+                .verify_relations()
+                .is_ok());
+        }
+    }
+
+    #[test]
+    fn a_single_wrong_digit_is_reported() {
+        // a₀ = 5.29177210903e-11 m; change the fifth digit.
+        let errors = with_value("BOHR_RADIUS", 5.291_872_109_03e-11)
+            .verify_relations()
+            .unwrap_err();
+        let constants: Vec<_> =
+            errors.iter().map(|e| e.relation.constant).collect();
+        assert!(constants.contains(&"BOHR_RADIUS"));
+        assert!(constants.contains(&"CLASSICAL_ELECTRON_RADIUS"));
+        let bohr = errors
+            .iter()
+            .find(|e| e.relation.constant == "BOHR_RADIUS")
+            .unwrap();
+        assert_eq!(bohr.actual, 5.291_872_109_03e-11);
+        assert!((bohr.relative_error() - 1.9e-5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn a_rounded_ratio_is_reported() {
+        let errors = with_value("PROTON_ELECTRON_MASS_RATIO", 1_836.0)
+            .verify_relations()
+            .unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().all(
+            |e| e.relation.constant == "PROTON_ELECTRON_MASS_RATIO"
+        ));
+    }
+
+    #[test]
+    fn missing_constants_are_skipped() {
+        let mut constants = Constants::new();
+        constants.constants.retain(|c| c.name != "PLANCK");
+        assert!(constants.verify_relations().is_ok());
+        assert!(relation("PLANCK_REDUCED", "ħ = h/(2π)")
+            .check(|name| constants.get(name).ok())
+            .is_none());
+    }
+
+    #[test]
+    fn inconsistency_display_names_the_relation() {
+        let errors =
+            with_value("TAU", 6.0).verify_relations().unwrap_err();
+        let message = errors[0].to_string();
+        assert!(message.starts_with("TAU = 6e0 violates τ = 2π"));
+        assert!(message.contains("expected 6.283185307179586e0"));
+    }
+}
@@ -0,0 +1,123 @@
+#![allow(missing_docs)]
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod tests {
+    use cmn::constants::search::{rank, score, SearchMatch, MIN_SCORE};
+    use cmn::constants::Constants;
+
+    // ---------------------------------------------------------------
+    // score
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn score_is_one_for_normalized_equality() {
+        assert_eq!(score("speed of light", "SPEED_OF_LIGHT"), 1.0);
+        assert_eq!(score("Speed-Of-Light", "speed of light"), 1.0);
+    }
+
+    #[test]
+    fn score_rewards_small_edit_distance() {
+        let close = score("PLANK", "PLANCK");
+        let far = score("PLANK", "BOLTZMANN");
+        assert!(close > 0.8, "{close}");
+        assert!(far < MIN_SCORE, "{far}");
+    }
+
+    #[test]
+    fn score_rewards_token_overlap() {
+        // Every query token appears in the key.
+        let s = score("boson", "W_BOSON_MASS_GEV");
+        assert!(s >= MIN_SCORE, "{s}");
+        // Token typo within one edit still counts.
+        let s = score("electon mass", "ELECTRON_MASS_MEV");
+        assert!(s > 0.8, "{s}");
+    }
+
+    #[test]
+    fn score_empty_inputs_are_zero() {
+        assert_eq!(score("", "PI"), 0.0);
+        assert_eq!(score("PI", "___"), 0.0);
+    }
+
+    #[test]
+    fn score_is_within_unit_interval() {
+        for (q, k) in [("a", "PLANCK"), ("pi", "PI"), ("x y z", "X")] {
+            let s = score(q, k);
+            assert!((0.0..=1.0).contains(&s), "{q} / {k}: {s}");
+        }
+    }
+
+    // ---------------------------------------------------------------
+    // rank
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn rank_keeps_best_key_per_name_and_sorts() {
+        let hits = rank(
+            "plank",
+            [
+                ("PLANCK", "h"),
+                ("PLANCK", "PLANCK"),
+                ("PLANCK_MASS", "PLANCK_MASS"),
+            ],
+        );
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].name, "PLANCK");
+        assert_eq!(hits[0].matched, "PLANCK");
+        assert!(hits[0].score >= hits[1].score);
+    }
+
+    #[test]
+    fn rank_breaks_ties_by_name() {
+        let hits = rank("x", [("B", "x"), ("A", "x")]);
+        let names: Vec<_> = hits.iter().map(|m| m.name).collect();
+        assert_eq!(names, ["A", "B"]);
+    }
+
+    #[test]
+    fn rank_drops_low_scores() {
+        let hits: Vec<SearchMatch<'_>> =
+            rank("zzzz", [("PI", "PI"), ("TAU", "TAU")]);
+        assert!(hits.is_empty());
+    }
+
+    // ---------------------------------------------------------------
+    // Constants::search / Constants::suggest
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn search_electron_mass_ranks_exact_first() {
+        let hits = Constants::new().search("electron mass");
+        assert_eq!(hits[0].name, "ELECTRON_MASS");
+        assert_eq!(hits[0].score, 1.0);
+        assert!(hits.iter().any(|m| m.name == "ELECTRON_MASS_MEV"));
+        for pair in hits.windows(2) {
+            assert!(pair[0].score >= pair[1].score);
+        }
+    }
+
+    #[test]
+    fn search_matches_aliases() {
+        let hits = Constants::new().search("plancks constant");
+        assert_eq!(hits[0].name, "PLANCK");
+        assert_ne!(hits[0].matched, "PLANCK", "matched via an alias");
+    }
+
+    #[test]
+    fn search_covers_non_table_constants() {
+        let hits = Constants::new().search("hash cost");
+        assert_eq!(hits[0].name, "HASH_COST");
+    }
+
+    #[test]
+    fn suggest_returns_did_you_mean_names() {
+        let c = Constants::new();
+        assert!(c.constant("PLANK").is_none());
+        assert_eq!(c.suggest("PLANK", 1), ["PLANCK"]);
+        assert_eq!(c.suggest("BOLTZMAN", 1), ["BOLTZMANN"]);
+        assert!(c.suggest("PLANK", 3).len() <= 3);
+        assert!(c.suggest("xyzzy", 3).is_empty());
+    }
+}
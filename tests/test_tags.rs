@@ -0,0 +1,180 @@
+#![allow(missing_docs)]
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod tests {
+    use cmn::constants::{
+        by_category, by_tag, Category, Constants, Tag, Tags,
+        CONSTANTS_TABLE,
+    };
+
+    fn tagged(tag: Tag) -> Vec<&'static str> {
+        by_tag(tag).map(|e| e.name).collect()
+    }
+
+    // ---------------------------------------------------------------
+    // Tag and Tags
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn all_lists_every_tag_once() {
+        for (i, tag) in Tag::ALL.iter().enumerate() {
+            assert!(!Tag::ALL[i + 1..].contains(tag), "{tag:?}");
+        }
+    }
+
+    #[test]
+    fn tag_names_are_unique_and_non_empty() {
+        for (i, tag) in Tag::ALL.iter().enumerate() {
+            assert!(!tag.name().is_empty());
+            assert!(
+                Tag::ALL[i + 1..]
+                    .iter()
+                    .all(|t| t.name() != tag.name()),
+                "{tag:?}"
+            );
+        }
+        assert_eq!(Tag::AtomicNuclear.to_string(), "Atomic & nuclear");
+    }
+
+    #[test]
+    fn tags_set_operations() {
+        let tags = Tags::EMPTY.with(Tag::Root).with(Tag::Circle);
+        assert_eq!(tags, Tags::of(&[Tag::Circle, Tag::Root]));
+        assert_eq!(tags.len(), 2);
+        assert!(!tags.is_empty());
+        assert!(Tags::default().is_empty());
+        assert_eq!(Tags::from(Tag::Root).len(), 1);
+        assert_eq!(Tags::of(&Tag::ALL).len(), Tag::ALL.len());
+        assert_eq!(
+            Tags::of(&Tag::ALL).iter().collect::<Vec<_>>(),
+            Tag::ALL
+        );
+    }
+
+    // ---------------------------------------------------------------
+    // Table tagging
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn every_entry_has_tags_of_its_category() {
+        for entry in CONSTANTS_TABLE {
+            assert!(!entry.tags.is_empty(), "{}", entry.name);
+            assert!(
+                entry
+                    .tags
+                    .iter()
+                    .any(|t| t.category() == entry.category),
+                "{}",
+                entry.name
+            );
+        }
+    }
+
+    #[test]
+    fn requested_tags_are_populated() {
+        assert!(tagged(Tag::Electromagnetic).contains(&"COULOMB"));
+        assert!(tagged(Tag::AtomicNuclear).contains(&"BOHR_RADIUS"));
+        assert!(tagged(Tag::ParticleMass).contains(&"MUON_MASS"));
+        assert!(tagged(Tag::ConversionFactor).contains(&"EV_TO_KG"));
+        assert!(
+            tagged(Tag::Thermodynamic).contains(&"STEFAN_BOLTZMANN")
+        );
+        let mut planck = tagged(Tag::PlanckUnits);
+        planck.sort_unstable();
+        assert_eq!(
+            planck,
+            [
+                "PLANCK_CHARGE",
+                "PLANCK_LENGTH",
+                "PLANCK_MASS",
+                "PLANCK_TEMPERATURE",
+                "PLANCK_TIME",
+            ]
+        );
+        assert_eq!(tagged(Tag::TrigonometricFraction).len(), 5);
+        assert_eq!(tagged(Tag::SiDefining).len(), 5);
+    }
+
+    #[test]
+    fn constants_carry_multiple_tags() {
+        let ratio = CONSTANTS_TABLE
+            .iter()
+            .find(|e| e.name == "PROTON_ELECTRON_MASS_RATIO")
+            .unwrap();
+        assert!(ratio.tags.contains(Tag::ParticleMass));
+        assert!(ratio.tags.contains(Tag::AtomicNuclear));
+    }
+
+    #[test]
+    fn by_category_partitions_the_table() {
+        let total: usize = [
+            Category::Mathematical,
+            Category::Physical,
+            Category::Cryptographic,
+        ]
+        .into_iter()
+        .map(|c| by_category(c).count())
+        .sum();
+        assert_eq!(total, CONSTANTS_TABLE.len());
+        assert!(by_category(Category::Mathematical)
+            .all(|e| e.category == Category::Mathematical));
+    }
+
+    // ---------------------------------------------------------------
+    // Constants filters
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn constants_by_tag_includes_utility_constants() {
+        let constants = Constants::new();
+        let hashing: Vec<_> =
+            constants.by_tag(Tag::Hashing).map(|c| &*c.name).collect();
+        assert_eq!(
+            hashing,
+            ["HASH_ALGORITHM", "HASH_COST", "HASH_LENGTH"]
+        );
+        assert_eq!(
+            constants.tags("SPECIAL_CHARS"),
+            Some(Tags::from(Tag::CharacterSet))
+        );
+    }
+
+    #[test]
+    fn constants_by_category_covers_every_constant() {
+        let constants = Constants::new();
+        let total: usize = [
+            Category::Mathematical,
+            Category::Physical,
+            Category::Cryptographic,
+        ]
+        .into_iter()
+        .map(|c| constants.by_category(c).count())
+        .sum();
+        assert_eq!(total, constants.constants().len());
+    }
+
+    #[test]
+    fn constants_by_tag_matches_table() {
+        let constants = Constants::new();
+        for tag in Tag::ALL {
+            if tag.category() == Category::Cryptographic {
+                continue;
+            }
+            let mut names: Vec<_> =
+                constants.by_tag(tag).map(|c| &*c.name).collect();
+            let mut expected = tagged(tag);
+            names.sort_unstable();
+            expected.sort_unstable();
+            assert_eq!(names, expected, "{tag:?}");
+        }
+    }
+
+    #[test]
+    fn unknown_names_have_no_classification() {
+        let constants = Constants::new();
+        assert!(constants.tags("NOPE").is_none());
+        assert!(constants.category("NOPE").is_none());
+    }
+}
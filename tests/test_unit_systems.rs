@@ -0,0 +1,248 @@
+#![allow(missing_docs)]
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::near;
+    use cmn::constants::{
+        BOHR_MAGNETON, BOLTZMANN, ELECTRON_MASS, ELECTRON_VOLT,
+        ELEMENTARY_CHARGE, FINE_STRUCTURE, GRAVITATIONAL_CONSTANT, PI,
+        PLANCK_REDUCED, PROTON_MASS, SPEED_OF_LIGHT,
+        VACUUM_PERMEABILITY, VACUUM_PERMITTIVITY,
+    };
+    use cmn::quantities::{self, Energy, Quantity};
+    use cmn::unit_systems::{
+        natural_power, Electromagnetic, UnitSystem, UnitSystemError,
+    };
+    use cmn::units::{
+        Dimension, COULOMB, FARAD_PER_METER, JOULE, JOULE_PER_KELVIN,
+        JOULE_PER_TESLA, JOULE_SECOND, KILOGRAM, METER,
+        METER_PER_SECOND, NEWTON_PER_AMPERE_SQUARED, VOLT_PER_METER,
+    };
+
+    fn from_si(system: UnitSystem, value: f64, d: Dimension) -> f64 {
+        system.from_si(value, d).unwrap()
+    }
+
+    /// The dimension of G.
+    const GRAVITATION: Dimension =
+        Dimension::new(3, -1, -2, 0, 0, 0, 0);
+
+    // ---------------------------------------------------------------
+    // Systems
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn planck_units_set_the_constants_to_one() {
+        let p = UnitSystem::Planck;
+        let ones = [
+            (PLANCK_REDUCED, JOULE_SECOND.dimension),
+            (SPEED_OF_LIGHT, METER_PER_SECOND.dimension),
+            (BOLTZMANN, JOULE_PER_KELVIN.dimension),
+            (GRAVITATIONAL_CONSTANT, GRAVITATION),
+            (4.0 * PI * VACUUM_PERMITTIVITY, FARAD_PER_METER.dimension),
+        ];
+        // The tabulated Planck units carry six or seven digits.
+        for (value, dimension) in ones {
+            assert!(near(from_si(p, value, dimension), 1.0, 1e-5));
+        }
+    }
+
+    #[test]
+    fn atomic_units_set_the_constants_to_one() {
+        let a = UnitSystem::Atomic;
+        let ones = [
+            (PLANCK_REDUCED, JOULE_SECOND.dimension),
+            (ELECTRON_MASS, KILOGRAM.dimension),
+            (ELEMENTARY_CHARGE, COULOMB.dimension),
+            (4.0 * PI * VACUUM_PERMITTIVITY, FARAD_PER_METER.dimension),
+            (BOLTZMANN, JOULE_PER_KELVIN.dimension),
+        ];
+        for (value, dimension) in ones {
+            assert!(near(from_si(a, value, dimension), 1.0, 1e-9));
+        }
+        let c = from_si(a, SPEED_OF_LIGHT, METER_PER_SECOND.dimension);
+        assert!(near(c, 1.0 / FINE_STRUCTURE, 1e-9));
+        // μ_B = 1/2 and the atomic unit of field, 5.14 × 10¹¹ V/m.
+        let mu_b = from_si(a, BOHR_MAGNETON, JOULE_PER_TESLA.dimension);
+        assert!(near(mu_b, 0.5, 1e-9));
+        let field = a.unit(VOLT_PER_METER.dimension).unwrap();
+        assert!(near(
+            field,
+            cmn::constants::ATOMIC_UNIT_OF_ELECTRIC_FIELD,
+            1e-9
+        ));
+    }
+
+    #[test]
+    fn natural_units_measure_in_gev() {
+        let n = UnitSystem::Natural;
+        for (value, dimension) in [
+            (PLANCK_REDUCED, JOULE_SECOND.dimension),
+            (SPEED_OF_LIGHT, METER_PER_SECOND.dimension),
+            (BOLTZMANN, JOULE_PER_KELVIN.dimension),
+            (VACUUM_PERMITTIVITY, FARAD_PER_METER.dimension),
+        ] {
+            assert!(near(from_si(n, value, dimension), 1.0, 1e-12));
+        }
+        let gev = from_si(n, 1e9 * ELECTRON_VOLT, JOULE.dimension);
+        assert!(near(gev, 1.0, 1e-12));
+        let m_p = from_si(n, PROTON_MASS, KILOGRAM.dimension);
+        assert!(near(m_p, 0.938_272_088, 1e-8));
+        // e = √(4πα) in Heaviside-Lorentz units.
+        let e = from_si(n, ELEMENTARY_CHARGE, COULOMB.dimension);
+        assert!(near(e, (4.0 * PI * FINE_STRUCTURE).sqrt(), 1e-9));
+        // ħc = 0.197 327 GeV fm, so 1 fm = 5.068 GeV⁻¹.
+        let fm = from_si(n, 1e-15, METER.dimension);
+        assert!(near(fm, 1.0 / 0.197_326_980_4, 1e-9));
+    }
+
+    #[test]
+    fn natural_powers_count_gev() {
+        assert_eq!(natural_power(KILOGRAM.dimension), Some(1));
+        assert_eq!(natural_power(METER.dimension), Some(-1));
+        assert_eq!(natural_power(JOULE_SECOND.dimension), Some(0));
+        assert_eq!(natural_power(COULOMB.dimension), Some(0));
+        assert_eq!(natural_power(GRAVITATION), Some(-2));
+        let candela = Dimension::new(0, 0, 0, 0, 0, 0, 1);
+        assert_eq!(natural_power(candela), None);
+    }
+
+    #[test]
+    fn gaussian_mechanics_uses_centimetres_and_grams() {
+        let g = UnitSystem::Gaussian;
+        assert!(near(from_si(g, 1.0, JOULE.dimension), 1e7, 1e-15));
+        assert!(near(from_si(g, 1.0, METER.dimension), 100.0, 1e-15));
+        assert!(near(from_si(g, 1.0, KILOGRAM.dimension), 1e3, 1e-15));
+        let gravitation =
+            from_si(g, GRAVITATIONAL_CONSTANT, GRAVITATION);
+        assert!(near(gravitation, GRAVITATIONAL_CONSTANT * 1e3, 1e-15));
+    }
+
+    #[test]
+    fn gaussian_electromagnetism_follows_the_usual_table() {
+        let g = UnitSystem::Gaussian;
+        // e = 4.803 × 10⁻¹⁰ statC.
+        let e = from_si(g, ELEMENTARY_CHARGE, COULOMB.dimension);
+        assert!(near(e, 4.803_204_7e-10, 1e-7));
+        // 1 V/m = 1/29 979 statV/cm.
+        let field = from_si(g, 1.0, VOLT_PER_METER.dimension);
+        assert!(near(field, 1e-4 / 2.997_924_58, 1e-9));
+        // μ_B = 9.274 × 10⁻²¹ erg/G.
+        let mu_b = from_si(g, BOHR_MAGNETON, JOULE_PER_TESLA.dimension);
+        assert!(near(mu_b, 9.274_010_08e-21, 1e-8));
+        // ε₀ and μ₀ are both 1.
+        let eps =
+            from_si(g, VACUUM_PERMITTIVITY, FARAD_PER_METER.dimension);
+        let mu = from_si(
+            g,
+            VACUUM_PERMEABILITY,
+            NEWTON_PER_AMPERE_SQUARED.dimension,
+        );
+        assert!(near(eps, 1.0, 1e-15));
+        assert!(near(mu, 1.0, 1e-15));
+        // A molar charge keeps the mole.
+        let faraday = Dimension::new(0, 0, 1, 1, 0, -1, 0);
+        let statc = Electromagnetic::Charge.gaussian_unit();
+        assert_eq!(g.unit(faraday), Ok(statc));
+    }
+
+    #[test]
+    fn gaussian_units_of_each_electromagnetic_quantity() {
+        use Electromagnetic as E;
+        // Values of the Gaussian units in SI, from the
+        // conventional table, with c in units of 10⁸ m/s.
+        let c = SPEED_OF_LIGHT * 1e-8;
+        let expected = [
+            (E::Current, 1.0 / (c * 1e9)),
+            (E::ChargeDensity, 1e6 / (c * 1e9)),
+            (E::ElectricPotential, c * 100.0),
+            (E::ElectricDisplacement, 1e3 / (4.0 * PI * c * 1e8)),
+            (E::Polarization, 1e3 / (c * 1e8)),
+            (E::MagneticFluxDensity, 1e-4),
+            (E::Magnetization, 1e3),
+            (E::MagneticFlux, 1e-8),
+            (E::MagneticMoment, 1e-3),
+            (E::Resistance, c * c * 1e11),
+            (E::Capacitance, 1e-11 / (c * c)),
+            (E::Inductance, c * c * 1e11),
+            (E::Conductivity, 1e-9 / (c * c)),
+        ];
+        // μ₀ is no longer exactly 4π × 10⁻⁷ in the 2019 SI.
+        for (kind, unit) in expected {
+            assert!(near(kind.gaussian_unit(), unit, 1e-9), "{kind:?}");
+        }
+        for kind in E::ALL {
+            let si = 3.5;
+            let there = kind.to_gaussian(si);
+            assert!(near(kind.from_gaussian(there), si, 1e-15));
+        }
+    }
+
+    // ---------------------------------------------------------------
+    // Errors and round trips
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn ambiguous_and_unsupported_dimensions() {
+        let g = UnitSystem::Gaussian;
+        let d = Electromagnetic::Polarization.dimension();
+        assert_eq!(
+            Electromagnetic::ElectricDisplacement.dimension(),
+            d
+        );
+        assert_eq!(
+            g.from_si(1.0, d),
+            Err(UnitSystemError::Ambiguous { dimension: d })
+        );
+        let odd = Dimension::new(0, 0, 0, 3, 0, 0, 0);
+        assert_eq!(
+            g.unit(odd),
+            Err(UnitSystemError::Unsupported {
+                system: g,
+                dimension: odd
+            })
+        );
+        let candela = Dimension::new(0, 0, 0, 0, 0, 0, 1);
+        assert!(g.unit(candela).is_ok());
+        let planck = UnitSystem::Planck.unit(candela).unwrap_err();
+        assert_eq!(planck.to_string(), "no Planck unit for cd");
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        let dimensions = [
+            Dimension::NONE,
+            JOULE.dimension,
+            GRAVITATION,
+            COULOMB.dimension,
+            JOULE_PER_TESLA.dimension,
+            Dimension::new(0, 0, 0, 0, 0, 1, 0),
+            Dimension::new(-2, 1, 3, -1, 1, -1, 0),
+        ];
+        for system in UnitSystem::ALL {
+            for dimension in dimensions {
+                let Ok(value) = system.from_si(2.5, dimension) else {
+                    continue;
+                };
+                let back = system.to_si(value, dimension).unwrap();
+                assert!(near(back, 2.5, 1e-14), "{system} {dimension}");
+            }
+            assert_eq!(system.from_si(7.0, Dimension::NONE), Ok(7.0));
+        }
+    }
+
+    #[test]
+    fn typed_quantities_convert() {
+        let n = UnitSystem::Natural;
+        let m_e = n.from_quantity(quantities::ELECTRON_MASS).unwrap();
+        assert!(near(m_e, 0.510_998_950e-3, 1e-8));
+        let e: Quantity<Energy> = n.to_quantity(m_e).unwrap();
+        let rest = ELECTRON_MASS * SPEED_OF_LIGHT * SPEED_OF_LIGHT;
+        assert!(near(e.value(), rest, 1e-14));
+        assert_eq!(UnitSystem::Atomic.to_string(), "Hartree atomic");
+    }
+}
@@ -0,0 +1,370 @@
+#![allow(missing_docs)]
+// Copyright © 2023-2026 Common (CMN) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::near;
+    use cmn::constants::{
+        ATOMIC_MASS_UNIT, ELECTRON_VOLT, EV_TO_HZ, EV_TO_INVERSE_METER,
+        EV_TO_KELVIN, EV_TO_KG, GAS_CONSTANT, GAS_CONSTANT_L_ATM,
+        HARTREE_ENERGY_EV,
+    };
+    use cmn::units::{
+        convert, parse, ConversionError, Dimension, Unit, AMPERE,
+        COULOMB, DALTON, DEGREE_CELSIUS, DEGREE_FAHRENHEIT,
+        ELECTRONVOLT, FARAD, FARAD_PER_METER, HENRY, JOULE,
+        JOULE_PER_TESLA, KELVIN, KILOGRAM, METER, MOLE, OHM, ONE,
+        PASCAL, REGISTRY, SECOND, SIEMENS, SQUARE_METER, TESLA, VOLT,
+        WATT, WEBER,
+    };
+
+    /// Dimension of `a / b`.
+    fn quotient(a: Unit, b: Unit) -> Dimension {
+        let (a, b) = (a.dimension, b.dimension);
+        Dimension::new(
+            a.length - b.length,
+            a.mass - b.mass,
+            a.time - b.time,
+            a.current - b.current,
+            a.temperature - b.temperature,
+            a.amount - b.amount,
+            a.luminous_intensity - b.luminous_intensity,
+        )
+    }
+
+    // ---------------------------------------------------------------
+    // Dimension
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn dimension_none_is_dimensionless() {
+        assert!(Dimension::NONE.is_dimensionless());
+        assert_eq!(Dimension::default(), Dimension::NONE);
+    }
+
+    #[test]
+    fn dimension_display_dimensionless_is_one() {
+        assert_eq!(Dimension::NONE.to_string(), "1");
+    }
+
+    #[test]
+    fn dimension_display_orders_base_units() {
+        assert_eq!(JOULE.dimension.to_string(), "m^2 kg s^-2");
+        assert_eq!(SIEMENS.dimension.to_string(), "m^-2 kg^-1 s^3 A^2");
+    }
+
+    #[test]
+    fn dimension_display_omits_unit_exponent() {
+        assert_eq!(METER.dimension.to_string(), "m");
+    }
+
+    // ---------------------------------------------------------------
+    // Unit
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn si_units_have_unit_scale() {
+        for unit in [ONE, METER, KILOGRAM, JOULE, OHM] {
+            assert_eq!(unit.scale, 1.0, "{unit}");
+            assert_eq!(unit.offset, 0.0, "{unit}");
+        }
+    }
+
+    #[test]
+    fn named_derived_units_have_si_dimensions() {
+        assert_eq!(quotient(JOULE, SECOND), WATT.dimension);
+        assert_eq!(quotient(WATT, AMPERE), VOLT.dimension);
+        assert_eq!(quotient(VOLT, AMPERE), OHM.dimension);
+        assert_eq!(quotient(COULOMB, VOLT), FARAD.dimension);
+        assert_eq!(quotient(WEBER, AMPERE), HENRY.dimension);
+        assert_eq!(quotient(WEBER, SQUARE_METER), TESLA.dimension);
+        assert_eq!(quotient(JOULE, TESLA), JOULE_PER_TESLA.dimension);
+        assert_eq!(quotient(FARAD, METER), FARAD_PER_METER.dimension);
+        for (symbol, unit) in
+            [("V", VOLT), ("A", AMPERE), ("W", WATT), ("T", TESLA)]
+        {
+            assert_eq!(parse(symbol), Ok(unit));
+        }
+    }
+
+    #[test]
+    fn scaled_units_convert_to_si() {
+        assert_eq!(ELECTRONVOLT.to_si(1.0), ELECTRON_VOLT);
+        assert_eq!(DALTON.to_si(2.0), 2.0 * ATOMIC_MASS_UNIT);
+    }
+
+    #[test]
+    fn compatibility_compares_dimensions_only() {
+        assert!(ELECTRONVOLT.is_compatible(&JOULE));
+        assert!(DALTON.is_compatible(&KILOGRAM));
+        assert!(!JOULE.is_compatible(&KILOGRAM));
+    }
+
+    #[test]
+    fn unit_display_is_symbol() {
+        assert_eq!(OHM.to_string(), "Ω");
+        assert_eq!(ONE.to_string(), "1");
+    }
+
+    #[test]
+    fn unit_constructors() {
+        let d = Dimension::new(1, 0, -1, 0, 0, 0, 0);
+        let si = Unit::si("m s^-1", d);
+        let knot = Unit::scaled("kn", d, 1852.0 / 3600.0);
+        assert_eq!(si.scale, 1.0);
+        assert!(si.is_compatible(&knot));
+        assert!((knot.to_si(1.0) - 0.514_444).abs() < 1e-6);
+        let rankine =
+            Unit::affine("°R", KELVIN.dimension, 5.0 / 9.0, 0.0);
+        assert_eq!(
+            rankine,
+            Unit::scaled("°R", KELVIN.dimension, 5.0 / 9.0)
+        );
+    }
+
+    #[test]
+    fn temperature_scales_are_affine() {
+        assert_eq!(DEGREE_CELSIUS.to_si(0.0), 273.15);
+        assert_eq!(DEGREE_CELSIUS.from_si(273.15), 0.0);
+        assert_eq!(convert(0.0, "°C", "K"), Ok(273.15));
+        assert_eq!(convert(1.0, "K", "degC"), Ok(1.0 - 273.15));
+        assert!(near(DEGREE_FAHRENHEIT.to_si(32.0), 273.15, 1e-15));
+        assert!(near(
+            convert(212.0, "°F", "°C").unwrap(),
+            100.0,
+            1e-12
+        ));
+        assert!(near(
+            convert(-40.0, "degF", "degC").unwrap(),
+            -40.0,
+            1e-12
+        ));
+        // Energy equivalents see the absolute temperature.
+        assert_eq!(
+            convert(25.0, "°C", "eV"),
+            convert(298.15, "K", "eV")
+        );
+        let back = convert(1.0, "eV", "°C").unwrap();
+        assert!(near(back + 273.15, 11_604.518_12, 1e-9));
+    }
+
+    // ---------------------------------------------------------------
+    // Conversion engine
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn registry_symbols_are_unique_and_parse() {
+        for (i, (name, unit)) in REGISTRY.iter().enumerate() {
+            assert!(
+                REGISTRY[i + 1..].iter().all(|(n, _)| n != name),
+                "duplicate {name}"
+            );
+            assert_eq!(parse(name), Ok(*unit));
+            assert!(unit.scale > 0.0, "{name}");
+        }
+    }
+
+    #[test]
+    fn same_dimension_conversions() {
+        assert_eq!(convert(1.0, "eV", "J"), Ok(ELECTRON_VOLT));
+        assert_eq!(convert(2.5, "J", "J"), Ok(2.5));
+        assert_eq!(convert(1.0, "bar", "Pa"), Ok(1e5));
+        assert_eq!(convert(760.0, "Torr", "atm"), Ok(1.0));
+        assert!(near(
+            convert(1.0, "hartree", "eV").unwrap(),
+            HARTREE_ENERGY_EV,
+            1e-11
+        ));
+        assert!(near(
+            convert(1.0, "psi", "kPa").unwrap(),
+            6.894_757,
+            1e-6
+        ));
+        assert!(near(convert(1.0, "ns", "fs").unwrap(), 1e6, 1e-12));
+        assert!(near(convert(1.0, "Ry", "Eh").unwrap(), 0.5, 1e-15));
+    }
+
+    #[test]
+    fn energy_equivalences_reproduce_ev_constants() {
+        for (to, expected) in [
+            ("kg", EV_TO_KG),
+            ("Hz", EV_TO_HZ),
+            ("K", EV_TO_KELVIN),
+            ("m^-1", EV_TO_INVERSE_METER),
+            ("u", cmn::constants::EV_TO_AMU),
+        ] {
+            let got = convert(1.0, "eV", to).unwrap();
+            assert!(near(got, expected, 1e-9), "eV -> {to}: {got}");
+        }
+    }
+
+    #[test]
+    fn cross_dimension_round_trips() {
+        for (from, to) in [
+            ("eV", "K"),
+            ("MeV/c^2", "GHz"),
+            ("kcal/mol", "cm^-1"),
+            ("hartree", "kJ/mol"),
+        ] {
+            let there = convert(3.0, from, to).unwrap();
+            let back = convert(there, to, from).unwrap();
+            assert!(near(back, 3.0, 1e-12), "{from} <-> {to}");
+        }
+    }
+
+    #[test]
+    fn molar_energy_conversions() {
+        let kcal = convert(1.0, "hartree", "kcal/mol").unwrap();
+        assert!(near(kcal, 627.509_474_063, 1e-9));
+        let kj = convert(1.0, "eV", "kJ/mol").unwrap();
+        assert!(near(kj, 96.485_332_12, 1e-9));
+    }
+
+    #[test]
+    fn gas_constant_in_litre_atmospheres() {
+        let si = cmn::units::LITER_ATMOSPHERE_PER_MOLE_KELVIN
+            .to_si(GAS_CONSTANT_L_ATM);
+        assert!(near(si, GAS_CONSTANT, 1e-9));
+    }
+
+    #[test]
+    fn prefixed_units_parse() {
+        for (symbol, unit, scale) in [
+            ("mV", VOLT, 1e-3),
+            ("mA", AMPERE, 1e-3),
+            ("kΩ", OHM, 1e3),
+            ("µmol", MOLE, 1e-6),
+            ("umol", MOLE, 1e-6),
+            ("mg", KILOGRAM, 1e-6),
+            ("GW", WATT, 1e9),
+            ("dam", METER, 10.0),
+            ("dA", AMPERE, 0.1),
+            (
+                "mL",
+                Unit::si("m^3", Dimension::new(3, 0, 0, 0, 0, 0, 0)),
+                1e-6,
+            ),
+            ("mbar", PASCAL, 100.0),
+            ("kDa", KILOGRAM, 1e3 * ATOMIC_MASS_UNIT),
+        ] {
+            let parsed = parse(symbol).unwrap();
+            assert_eq!(parsed.symbol, symbol);
+            assert_eq!(parsed.dimension, unit.dimension, "{symbol}");
+            assert_eq!(parsed.scale, scale, "{symbol}");
+        }
+        assert_eq!(parse("1"), Ok(ONE));
+        assert_eq!(parse(" m "), Ok(METER));
+    }
+
+    #[test]
+    fn compound_units_parse() {
+        let density = parse("kg m^-3").unwrap();
+        assert_eq!(density.symbol, "kg m^-3");
+        assert_eq!(
+            density.dimension,
+            Dimension::new(-3, 1, 0, 0, 0, 0, 0)
+        );
+        assert_eq!(density.scale, 1.0);
+        for (a, b) in [
+            ("J/s", "W"),
+            ("J/K", "J K^-1"),
+            ("m/s", "m s^-1"),
+            ("s^-1", "Hz"),
+            ("1/s", "Hz"),
+            ("kg m/s^2", "N"),
+            ("V/A", "Ω"),
+            ("C/V", "F"),
+            ("Wb/m^2", "T"),
+            ("W·m⁻²·K⁻⁴", "W m^-2 K^-4"),
+            ("J/(mol K)", "J mol^-1 K^-1"),
+            ("J/mol/K", "J*mol^-1*K^-1"),
+            ("(m/s)^2", "m² s⁻²"),
+            ("J/°C", "J/K"),
+        ] {
+            let (a, b) = (parse(a).unwrap(), parse(b).unwrap());
+            assert_eq!(a.dimension, b.dimension, "{a} vs {b}");
+            assert!(near(a.scale, b.scale, 1e-15), "{a} vs {b}");
+            assert_eq!(a.offset, 0.0);
+        }
+        assert!(near(
+            convert(1.0, "g/cm^3", "kg m^-3").unwrap(),
+            1e3,
+            1e-12
+        ));
+        assert!(near(convert(5.0, "mV", "V").unwrap(), 5e-3, 1e-15));
+        assert!(near(
+            convert(1.0, "µeV", "mK").unwrap(),
+            convert(1.0, "eV", "K").unwrap() * 1e-3,
+            1e-12
+        ));
+        assert!(matches!(
+            convert(1.0, "kcal/mol", "J/(mol K)"),
+            Err(ConversionError::Incompatible { .. })
+        ));
+    }
+
+    #[test]
+    fn malformed_units_are_rejected() {
+        for symbol in [
+            "",
+            "m/s/",
+            "J/mol K",
+            "m^",
+            "m^x",
+            "m^200",
+            "(m",
+            "m)",
+            "m^2s",
+            "m//s",
+            "* m",
+            "((((((((((m))))))))))",
+        ] {
+            assert_eq!(
+                parse(symbol),
+                Err(ConversionError::Malformed),
+                "{symbol}"
+            );
+        }
+        for symbol in ["furlong", "metres", "kkg", "m furlong"] {
+            assert_eq!(
+                parse(symbol),
+                Err(ConversionError::UnknownUnit),
+                "{symbol}"
+            );
+        }
+        assert_eq!(
+            ConversionError::Malformed.to_string(),
+            "malformed unit"
+        );
+    }
+
+    #[test]
+    fn conversion_errors() {
+        assert_eq!(
+            convert(1.0, "parsec", "m"),
+            Err(ConversionError::UnknownUnit)
+        );
+        let err = convert(1.0, "m", "K").unwrap_err();
+        assert_eq!(
+            err,
+            ConversionError::Incompatible {
+                from: METER.dimension,
+                to: KELVIN.dimension,
+            }
+        );
+        assert_eq!(err.to_string(), "cannot convert m to K");
+        assert_eq!(
+            KILOGRAM.convert(1.0, &OHM),
+            Err(ConversionError::Incompatible {
+                from: KILOGRAM.dimension,
+                to: OHM.dimension,
+            })
+        );
+        let boxed: Box<dyn std::error::Error> =
+            Box::new(ConversionError::UnknownUnit);
+        assert_eq!(boxed.to_string(), "unknown unit");
+    }
+}